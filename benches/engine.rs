@@ -0,0 +1,129 @@
+//! 변환 엔진 핫패스 벤치 (criterion, 엔진 전용 빌드에서도 실행)
+//!
+//! 실행: `cargo bench --bench engine`
+//! 엔진만: `cargo bench --no-default-features --features engine-only --bench engine`
+//!
+//! 기준선 저장/비교: `-- --save-baseline main` 후 `-- --baseline main`
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use koing::core::hangul_fsm::HangulFsm;
+use koing::core::jamo_mapper::map_to_jamo;
+use koing::{convert, korean_to_eng, AutoDetector, NgramModel};
+
+const MODEL_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data/ngram_model.json");
+
+/// 입력 크기 (글자 수)
+const SIZES: [usize; 3] = [8, 64, 512];
+
+/// 두벌식 키 입력 샘플 (convert / get_confidence 입력)
+const KEY_INPUTS: [(&str, &str); 3] = [
+    // 안녕하세요 오늘 날씨가 좋네요
+    ("korean", "dkssudgktpdy dhsmf skfTlrk whgspdy "),
+    ("english", "the quick brown fox jumps over lazy dog "),
+    // 안녕 hello 반가워 world
+    ("mixed", "dkssud hello qksrkdnj world "),
+];
+
+/// 한글 텍스트 샘플 (korean_to_eng / N-gram 스코어 입력)
+const KOREAN_INPUTS: [(&str, &str); 2] = [
+    ("korean", "안녕하세요오늘날씨가좋네요감사합니다"),
+    ("mixed", "안녕 hello 반가워 world "),
+];
+
+/// base를 반복해 정확히 chars 글자로 맞춤
+fn sized(base: &str, chars: usize) -> String {
+    base.chars().cycle().take(chars).collect()
+}
+
+fn bench_convert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("convert");
+    for (name, base) in KEY_INPUTS {
+        for size in SIZES {
+            let input = sized(base, size);
+            group.throughput(Throughput::Bytes(input.len() as u64));
+            group.bench_with_input(BenchmarkId::new(name, size), &input, |b, input| {
+                b.iter(|| convert(black_box(input)))
+            });
+        }
+    }
+    group.finish();
+}
+
+fn bench_fsm_feed(c: &mut Criterion) {
+    // 키 → 자모 매핑을 제외한 FSM 상태 전이만 측정
+    let mut group = c.benchmark_group("fsm_feed");
+    for size in SIZES {
+        let jamo: Vec<_> = sized(KEY_INPUTS[0].1, size)
+            .chars()
+            .filter_map(map_to_jamo)
+            .collect();
+        group.throughput(Throughput::Elements(jamo.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &jamo, |b, jamo| {
+            b.iter(|| {
+                let mut fsm = HangulFsm::new();
+                for &j in black_box(jamo) {
+                    fsm.feed(j);
+                }
+                fsm.finish()
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_korean_to_eng(c: &mut Criterion) {
+    let mut group = c.benchmark_group("korean_to_eng");
+    for (name, base) in KOREAN_INPUTS {
+        for size in SIZES {
+            let input = sized(base, size);
+            group.throughput(Throughput::Bytes(input.len() as u64));
+            group.bench_with_input(BenchmarkId::new(name, size), &input, |b, input| {
+                b.iter(|| korean_to_eng(black_box(input)))
+            });
+        }
+    }
+    group.finish();
+}
+
+fn bench_get_confidence(c: &mut Criterion) {
+    let detector = AutoDetector::with_defaults();
+    let mut group = c.benchmark_group("get_confidence");
+    for (name, base) in KEY_INPUTS {
+        for size in SIZES {
+            let input = sized(base, size);
+            group.throughput(Throughput::Bytes(input.len() as u64));
+            group.bench_with_input(BenchmarkId::new(name, size), &input, |b, input| {
+                b.iter(|| detector.get_confidence(black_box(input)))
+            });
+        }
+    }
+    group.finish();
+}
+
+fn bench_ngram_score(c: &mut Criterion) {
+    let json = std::fs::read_to_string(MODEL_PATH).expect("모델 파일 읽기 실패");
+    let model = NgramModel::from_json(&json).expect("모델 로드 실패");
+    let mut group = c.benchmark_group("ngram_score");
+    for (name, base) in KOREAN_INPUTS {
+        for size in SIZES {
+            let input = sized(base, size);
+            group.throughput(Throughput::Bytes(input.len() as u64));
+            group.bench_with_input(BenchmarkId::new(name, size), &input, |b, input| {
+                b.iter(|| model.score(black_box(input)))
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_convert,
+    bench_fsm_feed,
+    bench_korean_to_eng,
+    bench_get_confidence,
+    bench_ngram_score
+);
+criterion_main!(benches);
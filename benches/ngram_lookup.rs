@@ -0,0 +1,113 @@
+//! N-gram 스코어링 벤치 (criterion): char 키 해시맵 vs 심볼 키(음절 오프셋) 저장소
+//!
+//! 실행: `cargo bench --bench ngram_lookup`
+//!
+//! 기준선 저장/비교: `-- --save-baseline main` 후 `-- --baseline main`
+
+use std::collections::HashMap;
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use koing::{NgramConfig, NgramModel};
+
+const MODEL_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/data/ngram_model.json");
+const SAMPLES: [&str; 8] = [
+    "안녕하세요",
+    "한글",
+    "감사합니다",
+    "오늘날씨가좋네요",
+    "ㅁㄴㅇㄹ",
+    "쟈뮤츄",
+    "테스트중입니다",
+    "가나다라마바사",
+];
+
+/// char 키 해시맵 기준 구현
+struct CharKeyModel {
+    unigrams: HashMap<char, u64>,
+    bigrams: HashMap<(char, char), u64>,
+}
+
+impl CharKeyModel {
+    fn from_json(json: &str) -> Self {
+        let value: serde_json::Value = serde_json::from_str(json).expect("모델 JSON 파싱 실패");
+        let unigrams = value["unigrams"]
+            .as_object()
+            .expect("unigrams 필드")
+            .iter()
+            .filter_map(|(k, v)| Some((k.chars().next()?, v.as_u64()?)))
+            .collect();
+        let bigrams = value["bigrams"]
+            .as_object()
+            .expect("bigrams 필드")
+            .iter()
+            .filter_map(|(k, v)| {
+                let (a, b) = k.split_once('|')?;
+                Some(((a.chars().next()?, b.chars().next()?), v.as_u64()?))
+            })
+            .collect();
+        Self { unigrams, bigrams }
+    }
+
+    /// 변경 전 `analyze_with_config`와 같은 작업량 (미등록 유니그램/바이그램 집계 포함)
+    fn score(&self, text: &str, config: &NgramConfig) -> f64 {
+        let chars: Vec<char> = text.chars().collect();
+        let unknown_unigrams = chars
+            .iter()
+            .filter(|c| self.unigrams.get(c).copied().unwrap_or(0) == 0)
+            .count();
+        black_box(unknown_unigrams);
+
+        let k = config.smoothing_k;
+        let v = config.vocab_size as f64;
+        let mut log_prob_sum = 0.0;
+        let mut seen_bigrams = 0usize;
+        for w in chars.windows(2) {
+            let bigram = self.bigrams.get(&(w[0], w[1])).copied().unwrap_or(0) as f64;
+            let context = self.unigrams.get(&w[0]).copied().unwrap_or(0) as f64;
+            if bigram > 0.0 {
+                seen_bigrams += 1;
+            }
+            log_prob_sum += ((bigram + k) / (context + k * v)).ln();
+        }
+        black_box(seen_bigrams);
+        log_prob_sum / (chars.len() - 1) as f64
+    }
+}
+
+fn bench_ngram_lookup(c: &mut Criterion) {
+    let json = std::fs::read_to_string(MODEL_PATH).expect("모델 파일 읽기 실패");
+    let config = NgramConfig::default();
+    let symbol_model = NgramModel::from_json(&json).expect("모델 로드 실패");
+    let char_model = CharKeyModel::from_json(&json);
+
+    for text in SAMPLES {
+        assert_eq!(
+            symbol_model.score_with_config(text, &config).to_bits(),
+            char_model.score(text, &config).to_bits(),
+            "스코어 불일치: {}",
+            text
+        );
+    }
+
+    // 샘플 전체를 한 번 스코어링하는 시간 비교
+    let mut group = c.benchmark_group("ngram_lookup");
+    group.bench_function("char_key", |b| {
+        b.iter(|| {
+            for text in SAMPLES {
+                black_box(char_model.score(black_box(text), &config));
+            }
+        })
+    });
+    group.bench_function("symbol_key", |b| {
+        b.iter(|| {
+            for text in SAMPLES {
+                black_box(symbol_model.score_with_config(black_box(text), &config));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_ngram_lookup);
+criterion_main!(benches);
@@ -1,27 +1,238 @@
 //! 설정 파일 로드/저장 (JSON)
 
+use crate::core::converter::HangulForm;
+use crate::logging::LogFormat;
+use crate::ngram::NgramConfig;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::ffi::OsString;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// 현재 설정 스키마 버전 (저장 시 항상 이 버전으로 기록)
+pub const CONFIG_VERSION: u32 = 1;
+
+/// 설정 디렉토리 override 환경변수
+pub const CONFIG_DIR_ENV: &str = "KOING_CONFIG_DIR";
+/// 설정 디렉토리 override CLI 인자 (`--config-dir <경로>` 또는 `--config-dir=<경로>`)
+pub const CONFIG_DIR_ARG: &str = "--config-dir";
+
+/// CLI 인자로 지정된 설정 디렉토리 (실행 시 한 번만 설정)
+static CONFIG_DIR_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+/// 설정 마이그레이션 단계: `MIGRATIONS[n]`은 vn → vn+1
+const MIGRATIONS: [fn(&mut Map<String, Value>); CONFIG_VERSION as usize] = [migrate_v0_to_v1];
+
+/// 버퍼를 비우는 경계 키코드 기본값: Tab, Escape, 방향키(←→↓↑)
+pub const DEFAULT_RESET_KEYCODES: [u16; 6] = [48, 53, 123, 124, 125, 126];
 
 /// Koing 설정
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct KoingConfig {
+    /// 설정 스키마 버전 (없으면 레거시 v0)
+    #[serde(default)]
+    pub version: u32,
     /// Koing 활성화 여부
     #[serde(default = "default_enabled")]
     pub enabled: bool,
     /// 타이핑 멈춘 후 자동 변환까지 대기 시간 (ms)
     #[serde(default = "default_debounce_ms")]
     pub debounce_ms: u64,
-    /// 자동 변환 후 한글 자판 전환까지 대기 시간 (ms)
+    /// 입력 속도 기반 debounce (켜면 debounce_ms 대신 최근 키 간격으로 대기 시간 추정)
+    #[serde(default)]
+    pub adaptive_debounce: bool,
+    /// 자동 변환 후 한글 자판 전환까지 대기 시간 (ms, 0이면 변환 직후 바로 전환)
     #[serde(default = "default_switch_delay_ms")]
     pub switch_delay_ms: u64,
     /// 느린 변환 대기 시간 (ms) — N-gram 점수가 낮지만 유효한 한글용
     #[serde(default = "default_slow_debounce_ms")]
     pub slow_debounce_ms: u64,
+    /// 1단계(빠른) 변환 실패 시 2단계(느린 구조적) 변환 시도 여부 (false면 1단계만)
+    #[serde(default = "default_two_stage_conversion")]
+    pub two_stage_conversion: bool,
     /// 자동 변환에서 제외할 영문 단어 목록
     #[serde(default)]
     pub never_convert_words: Vec<String>,
+    /// 사용자 치환 규칙 (트리거, 치환 결과) — 버퍼가 트리거와 일치하면 두벌식 변환 대신 사용
+    #[serde(default)]
+    pub expansions: Vec<(String, String)>,
+    /// 변환 결과 출력 형태 (nfc: 완성형, nfd: 조합형 자모)
+    #[serde(default)]
+    pub output_form: HangulForm,
+    /// 같은 자음 2연타를 쌍자음으로 추론 (실험적, 오변환 위험으로 기본 off)
+    #[serde(default)]
+    pub infer_double_consonant: bool,
+    /// 변환 결과를 paste 대신 AX 텍스트 입력으로 커밋, 미지원 앱은 paste로 폴백 (실험적, 기본 off)
+    #[serde(default)]
+    pub commit_via_text_input: bool,
+    /// 한글 모드에서 친 영단어를 Space 입력 시 영문으로 역변환 (양방향 모드, 기본 off)
+    #[serde(default)]
+    pub reverse_to_english: bool,
+    /// 프로필별 감지 파라미터
+    #[serde(default = "default_profile_params")]
+    pub profile_params: BTreeMap<AppProfile, ProfileParams>,
+    /// 앱 bundle ID → 프로필 (접두사 "com.jetbrains"는 하위 ID 전체에 적용)
+    #[serde(default = "default_app_profiles")]
+    pub app_profiles: BTreeMap<String, AppProfile>,
+    /// 변환 민감도 — app_profiles에 없는 앱에 적용 (메뉴바에서 전환)
+    #[serde(default)]
+    pub sensitivity: AppProfile,
+    /// 변환은 하되 한글 입력 소스 전환은 건너뛸 앱 bundle ID (입력 소스 전환과 충돌하는 앱)
+    #[serde(default)]
+    pub no_switch_bundle_ids: Vec<String>,
+    /// 한글 전환에 쓸 입력 소스 ID (세벌식/구름 등, 비었거나 무효하면 기본 두벌식)
+    #[serde(default)]
+    pub korean_source_id: Option<String>,
+    /// 변환 거부 시 텍스트는 두고 입력 소스만 한글로 전환 (soft switch)
+    #[serde(default)]
+    pub soft_switch_on_reject: bool,
+    /// 변환 불가(결과가 원본과 동일)지만 한글 의도가 높으면 입력 소스만 한글로 전환 (기본 off)
+    #[serde(default)]
+    pub switch_on_unconvertible: bool,
+    /// 낱자모만으로 조합되는 입력("zzz" → ㅋㅋㅋ)은 자동 변환 시도 없이 통과
+    #[serde(default = "default_pass_through_jamo_only")]
+    pub pass_through_jamo_only: bool,
+    /// 변환 결과 끝에 남은 낱자모 하나를 앞 음절에 합치거나 제거 (후처리, 기본 off)
+    #[serde(default)]
+    pub finalize_syllables: bool,
+    /// 입력 시 버퍼와 변환 타이머를 초기화할 키코드 (예: Home 115, End 119 추가)
+    #[serde(default = "default_reset_keycodes")]
+    pub reset_keycodes: Vec<u16>,
+    /// 첫 실행 온보딩 완료 여부
+    #[serde(default)]
+    pub onboarded: bool,
+    /// 변환 이력 파일 저장 여부 (false면 재시작 후 Undo 불가, 기존 기록 삭제)
+    #[serde(default = "default_persist_history")]
+    pub persist_history: bool,
+    /// 변환 결과 한글을 음성으로 읽기 (한국어 음성이 설치되어 있을 때만)
+    #[serde(default)]
+    pub speak_on_convert: bool,
+    /// 변환/Undo/전환 이벤트를 Unix 소켓(koing.sock)으로 브로드캐스트
+    #[serde(default)]
+    pub ipc_enabled: bool,
+    /// 디버그 측정 활성화 (변환 단계별 소요 시간 집계, 디버그 빌드는 항상 측정)
+    #[serde(default)]
+    pub debug_logging: bool,
+    /// 로그 출력 형식 (json이면 주요 이벤트를 한 줄 JSON으로)
+    #[serde(default)]
+    pub log_format: LogFormat,
+    /// 디버그 오버레이 (커서 근처에 현재 버퍼/변환 후보/신뢰도 표시, 개발용)
+    #[serde(default)]
+    pub debug_overlay: bool,
+    /// 대안 신뢰도 기준(±10점)으로 실시간 판정을 다시 평가해 불일치를 로그 (튜닝용)
+    #[serde(default)]
+    pub shadow_eval: bool,
+    /// 단계별 소요 시간 로그 출력 주기 (초, 0이면 출력 안 함)
+    #[serde(default)]
+    pub timing_log_interval_secs: u64,
+    /// N-gram 검증 설정
+    #[serde(default)]
+    pub ngram: NgramSettings,
+}
+
+/// N-gram 검증 설정 (임계값/스무딩/모델 경로)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct NgramSettings {
+    /// 한글 판정 임계값 (로그 확률, 0 이하)
+    pub threshold: f64,
+    /// Add-k 스무딩 상수 (0보다 큼)
+    pub smoothing_k: f64,
+    /// 모델 파일 경로 (없으면 기본 모델 경로 탐색)
+    pub model_path: Option<String>,
+}
+
+impl Default for NgramSettings {
+    fn default() -> Self {
+        let defaults = NgramConfig::default();
+        Self {
+            threshold: defaults.threshold,
+            smoothing_k: defaults.smoothing_k,
+            model_path: None,
+        }
+    }
+}
+
+impl NgramSettings {
+    /// 검증기 설정으로 변환 (범위를 벗어난 값은 기본값으로 보정)
+    pub fn to_ngram_config(&self) -> NgramConfig {
+        let defaults = NgramConfig::default();
+        let mut config = NgramConfig::new();
+
+        if self.threshold.is_finite() && self.threshold <= 0.0 {
+            config.threshold = self.threshold;
+        } else {
+            log::warn!(
+                "ngram.threshold {}은(는) 로그 확률 범위가 아님, 기본값 {} 사용",
+                self.threshold,
+                defaults.threshold
+            );
+        }
+
+        if self.smoothing_k.is_finite() && self.smoothing_k > 0.0 {
+            config.smoothing_k = self.smoothing_k;
+        } else {
+            log::warn!(
+                "ngram.smoothing_k {}은(는) 양수가 아님, 기본값 {} 사용",
+                self.smoothing_k,
+                defaults.smoothing_k
+            );
+        }
+
+        match self.model_path.as_deref().map(str::trim) {
+            Some(path) if !path.is_empty() => config.with_model_path(path),
+            _ => config,
+        }
+    }
+}
+
+/// 포커스 앱별 변환 프로필
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum AppProfile {
+    /// 코드 에디터/터미널 — 확신이 높을 때만 변환
+    Conservative,
+    /// 기본값
+    #[default]
+    Normal,
+    /// 메신저 — 짧은 입력도 적극 변환
+    Aggressive,
+}
+
+/// 프로필별 자동 감지 파라미터
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct ProfileParams {
+    /// Space/Enter 변환 신뢰도 기준
+    pub threshold: f32,
+    /// 실시간 변환 신뢰도 기준
+    pub realtime_threshold: f32,
+    /// 감지 최소 버퍼 길이
+    pub min_length: usize,
+}
+
+impl AppProfile {
+    /// 프로필 기본 파라미터 (normal은 AutoDetectorConfig 기본값과 동일)
+    pub fn default_params(self) -> ProfileParams {
+        match self {
+            AppProfile::Conservative => ProfileParams {
+                threshold: 85.0,
+                realtime_threshold: 95.0,
+                min_length: 5,
+            },
+            AppProfile::Normal => ProfileParams {
+                threshold: 70.0,
+                realtime_threshold: 80.0,
+                min_length: 3,
+            },
+            AppProfile::Aggressive => ProfileParams {
+                threshold: 60.0,
+                realtime_threshold: 70.0,
+                min_length: 2,
+            },
+        }
+    }
 }
 
 fn default_enabled() -> bool {
@@ -40,51 +251,310 @@ fn default_slow_debounce_ms() -> u64 {
     1500
 }
 
+fn default_two_stage_conversion() -> bool {
+    true
+}
+
+fn default_reset_keycodes() -> Vec<u16> {
+    DEFAULT_RESET_KEYCODES.to_vec()
+}
+
+fn default_persist_history() -> bool {
+    true
+}
+
+fn default_pass_through_jamo_only() -> bool {
+    true
+}
+
+fn default_profile_params() -> BTreeMap<AppProfile, ProfileParams> {
+    [
+        AppProfile::Conservative,
+        AppProfile::Normal,
+        AppProfile::Aggressive,
+    ]
+    .into_iter()
+    .map(|profile| (profile, profile.default_params()))
+    .collect()
+}
+
+fn default_app_profiles() -> BTreeMap<String, AppProfile> {
+    const CONSERVATIVE: [&str; 7] = [
+        "com.microsoft.VSCode",
+        "com.apple.dt.Xcode",
+        "com.jetbrains",
+        "com.sublimetext.4",
+        "dev.zed.Zed",
+        "com.apple.Terminal",
+        "com.googlecode.iterm2",
+    ];
+    const AGGRESSIVE: [&str; 3] = [
+        "com.kakao.KakaoTalkMac",
+        "com.tinyspeck.slackmacgap",
+        "com.hnc.Discord",
+    ];
+
+    CONSERVATIVE
+        .iter()
+        .map(|id| (id.to_string(), AppProfile::Conservative))
+        .chain(
+            AGGRESSIVE
+                .iter()
+                .map(|id| (id.to_string(), AppProfile::Aggressive)),
+        )
+        .collect()
+}
+
+/// bundle ID에 적용할 프로필 결정
+///
+/// 정확히 일치하는 항목 우선, 없으면 '.' 경계의 가장 긴 접두사 항목, 둘 다 없으면 None
+pub fn resolve_app_profile(
+    apps: &BTreeMap<String, AppProfile>,
+    bundle_id: &str,
+) -> Option<AppProfile> {
+    if let Some(&profile) = apps.get(bundle_id) {
+        return Some(profile);
+    }
+
+    apps.iter()
+        .filter(|(prefix, _)| {
+            bundle_id.len() > prefix.len()
+                && bundle_id.starts_with(prefix.as_str())
+                && bundle_id.as_bytes()[prefix.len()] == b'.'
+        })
+        .max_by_key(|(prefix, _)| prefix.len())
+        .map(|(_, &profile)| profile)
+}
+
+impl KoingConfig {
+    /// 프로필의 감지 파라미터 (맵에 없으면 프로필 기본값)
+    pub fn params_for_profile(&self, profile: AppProfile) -> ProfileParams {
+        self.profile_params
+            .get(&profile)
+            .copied()
+            .unwrap_or_else(|| profile.default_params())
+    }
+}
+
 impl Default for KoingConfig {
     fn default() -> Self {
         Self {
+            version: CONFIG_VERSION,
             enabled: default_enabled(),
             debounce_ms: default_debounce_ms(),
+            adaptive_debounce: false,
             switch_delay_ms: default_switch_delay_ms(),
             slow_debounce_ms: default_slow_debounce_ms(),
+            two_stage_conversion: default_two_stage_conversion(),
             never_convert_words: Vec::new(),
+            expansions: Vec::new(),
+            output_form: HangulForm::default(),
+            infer_double_consonant: false,
+            commit_via_text_input: false,
+            reverse_to_english: false,
+            profile_params: default_profile_params(),
+            app_profiles: default_app_profiles(),
+            sensitivity: AppProfile::default(),
+            no_switch_bundle_ids: Vec::new(),
+            korean_source_id: None,
+            soft_switch_on_reject: false,
+            switch_on_unconvertible: false,
+            pass_through_jamo_only: default_pass_through_jamo_only(),
+            finalize_syllables: false,
+            reset_keycodes: default_reset_keycodes(),
+            onboarded: false,
+            persist_history: default_persist_history(),
+            speak_on_convert: false,
+            ipc_enabled: false,
+            debug_logging: false,
+            log_format: LogFormat::default(),
+            debug_overlay: false,
+            shadow_eval: false,
+            timing_log_interval_secs: 0,
+            ngram: NgramSettings::default(),
         }
     }
 }
 
-/// 설정 파일 경로: ~/Library/Application Support/koing/config.json
-pub fn config_path() -> PathBuf {
-    let home = std::env::var("HOME")
+/// 홈 디렉토리 (HOME 미설정이거나 유효하지 않으면 /var/tmp)
+pub(crate) fn home_dir() -> PathBuf {
+    std::env::var("HOME")
         .ok()
         .map(PathBuf::from)
         .filter(|p| p.is_absolute() && p.is_dir())
         .unwrap_or_else(|| {
             // HOME 미설정이거나 유효하지 않으면 /var/tmp 폴백 (쓰기 가능, /tmp보다 안전)
             PathBuf::from("/var/tmp")
-        });
+        })
+}
+
+/// CLI 인자에서 설정 디렉토리 추출 (없으면 None)
+pub fn config_dir_from_args<I>(args: I) -> Option<PathBuf>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == CONFIG_DIR_ARG {
+            return args.next().map(PathBuf::from);
+        }
+        if let Some(dir) = arg
+            .strip_prefix(CONFIG_DIR_ARG)
+            .and_then(|rest| rest.strip_prefix('='))
+        {
+            return Some(PathBuf::from(dir));
+        }
+    }
+    None
+}
+
+/// CLI 인자로 받은 설정 디렉토리 지정 (main에서 설정 로드 전에 호출, 두 번째 호출부터 무시)
+pub fn set_config_dir_override(dir: PathBuf) {
+    if CONFIG_DIR_OVERRIDE.set(dir).is_err() {
+        log::warn!("설정 디렉토리가 이미 지정됨, 무시");
+    }
+}
+
+/// override 경로로 쓸 수 있는지 (절대 경로이고 존재하는 디렉토리)
+fn is_valid_config_dir(dir: &Path) -> bool {
+    dir.is_absolute() && dir.is_dir()
+}
+
+/// 설정 디렉토리 결정
+///
+/// 우선순위: CLI 인자 > `KOING_CONFIG_DIR` > `<home>/Library/Application Support/koing`.
+/// 지정 경로가 유효하지 않으면(상대 경로, 없는 디렉토리) 다음 순위로 폴백하며,
+/// home 자체는 [`home_dir`]에서 HOME → /var/tmp 순으로 이미 폴백된 값입니다.
+fn resolve_config_dir(cli: Option<&Path>, env: Option<OsString>, home: &Path) -> PathBuf {
+    let env = env.filter(|v| !v.is_empty()).map(PathBuf::from);
+    for (source, dir) in [("인자", cli), ("환경변수", env.as_deref())] {
+        let Some(dir) = dir else {
+            continue;
+        };
+        if is_valid_config_dir(dir) {
+            return dir.to_path_buf();
+        }
+        log::warn!(
+            "설정 디렉토리 {} '{}'이(가) 유효하지 않음, 기본 경로 사용",
+            source,
+            dir.display()
+        );
+    }
     home.join("Library")
         .join("Application Support")
         .join("koing")
-        .join("config.json")
+}
+
+/// 설정 디렉토리 (config.json, stats.json, history.json 위치)
+pub fn config_dir() -> PathBuf {
+    resolve_config_dir(
+        CONFIG_DIR_OVERRIDE.get().map(PathBuf::as_path),
+        std::env::var_os(CONFIG_DIR_ENV),
+        &home_dir(),
+    )
+}
+
+/// 설정 파일 경로: 기본 ~/Library/Application Support/koing/config.json ([`config_dir`] 참고)
+pub fn config_path() -> PathBuf {
+    config_dir().join("config.json")
 }
 
 /// 설정 파일 로드 (파일 없거나 파싱 실패 시 기본값)
 pub fn load_config() -> KoingConfig {
-    let path = config_path();
-    match fs::read_to_string(&path) {
-        Ok(content) => serde_json::from_str(&content).unwrap_or_else(|_| KoingConfig::default()),
+    load_config_from(&config_path())
+}
+
+/// 지정한 경로의 설정 파일 로드
+fn load_config_from(path: &Path) -> KoingConfig {
+    match fs::read_to_string(path) {
+        Ok(content) => match serde_json::from_str::<Value>(&content) {
+            Ok(value) => migrate_config(value),
+            Err(e) => {
+                log::warn!("설정 파일 파싱 실패, 기본값 사용: {}", e);
+                KoingConfig::default()
+            }
+        },
         Err(_) => KoingConfig::default(),
     }
 }
 
+/// 설정 JSON을 최신 스키마로 마이그레이션
+///
+/// 버전 없는 파일은 v0으로 간주, 알 수 없는(미래) 버전은 읽을 수 있는 필드만 사용
+pub fn migrate_config(value: Value) -> KoingConfig {
+    let Value::Object(mut map) = value else {
+        log::warn!("설정 파일 형식 오류 (객체 아님), 기본값 사용");
+        return KoingConfig::default();
+    };
+
+    let version = map.get("version").and_then(Value::as_u64).unwrap_or(0);
+    if version > u64::from(CONFIG_VERSION) {
+        log::warn!(
+            "설정 파일 버전 {}은(는) 지원 버전 {}보다 높음, 알 수 있는 필드만 읽음",
+            version,
+            CONFIG_VERSION
+        );
+    }
+    for migrate in MIGRATIONS.iter().skip(version as usize) {
+        migrate(&mut map);
+    }
+
+    let mut config = parse_fields_lenient(map);
+    config.version = CONFIG_VERSION;
+    config
+}
+
+/// 필드 단위로 파싱 — 타입이 맞지 않는 필드만 버리고 나머지는 유지
+fn parse_fields_lenient(map: Map<String, Value>) -> KoingConfig {
+    if let Ok(config) = serde_json::from_value(Value::Object(map.clone())) {
+        return config;
+    }
+
+    let mut accepted = Map::new();
+    for (key, field) in map {
+        let mut candidate = accepted.clone();
+        candidate.insert(key.clone(), field);
+        if serde_json::from_value::<KoingConfig>(Value::Object(candidate.clone())).is_ok() {
+            accepted = candidate;
+        } else {
+            log::warn!("설정 필드 '{}' 형식 오류, 기본값 사용", key);
+        }
+    }
+    serde_json::from_value(Value::Object(accepted)).unwrap_or_default()
+}
+
+/// v0 → v1: 일부 프로필만 지정된 profile_params를 기본값으로 채움
+fn migrate_v0_to_v1(map: &mut Map<String, Value>) {
+    let Some(Value::Object(params)) = map.get_mut("profile_params") else {
+        return;
+    };
+    for (profile, defaults) in default_profile_params() {
+        let (Ok(Value::String(key)), Ok(defaults)) = (
+            serde_json::to_value(profile),
+            serde_json::to_value(defaults),
+        ) else {
+            continue;
+        };
+        params.entry(key).or_insert(defaults);
+    }
+}
+
 /// 설정 파일 저장
 pub fn save_config(config: &KoingConfig) -> Result<(), String> {
-    let path = config_path();
+    save_config_to(config, &config_path())
+}
+
+/// 지정한 경로에 설정 파일 저장
+fn save_config_to(config: &KoingConfig, path: &Path) -> Result<(), String> {
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent).map_err(|e| format!("설정 디렉토리 생성 실패: {}", e))?;
     }
-    let json = serde_json::to_string_pretty(config).map_err(|e| format!("직렬화 실패: {}", e))?;
-    fs::write(&path, json).map_err(|e| format!("설정 파일 저장 실패: {}", e))?;
+    let config = KoingConfig {
+        version: CONFIG_VERSION,
+        ..config.clone()
+    };
+    let json = serde_json::to_string_pretty(&config).map_err(|e| format!("직렬화 실패: {}", e))?;
+    fs::write(path, json).map_err(|e| format!("설정 파일 저장 실패: {}", e))?;
     Ok(())
 }
 
@@ -102,17 +572,87 @@ mod tests {
     #[test]
     fn test_serialize_deserialize() {
         let config = KoingConfig {
+            version: CONFIG_VERSION,
             enabled: true,
             debounce_ms: 150,
+            adaptive_debounce: true,
             switch_delay_ms: 50,
             slow_debounce_ms: 1500,
+            two_stage_conversion: false,
             never_convert_words: vec!["slack".to_string()],
+            expansions: vec![("brb".to_string(), "금방 올게".to_string())],
+            output_form: HangulForm::Nfd,
+            infer_double_consonant: true,
+            commit_via_text_input: true,
+            reverse_to_english: true,
+            profile_params: default_profile_params(),
+            app_profiles: BTreeMap::from([("com.example.App".to_string(), AppProfile::Aggressive)]),
+            sensitivity: AppProfile::Conservative,
+            no_switch_bundle_ids: vec!["com.example.Game".to_string()],
+            korean_source_id: Some("com.apple.inputmethod.Korean.3SetKorean".to_string()),
+            soft_switch_on_reject: true,
+            switch_on_unconvertible: true,
+            pass_through_jamo_only: false,
+            finalize_syllables: true,
+            reset_keycodes: vec![48, 53, 115, 119],
+            onboarded: true,
+            persist_history: false,
+            speak_on_convert: true,
+            ipc_enabled: true,
+            debug_logging: true,
+            log_format: LogFormat::Json,
+            debug_overlay: true,
+            shadow_eval: true,
+            timing_log_interval_secs: 60,
+            ngram: NgramSettings {
+                threshold: -8.0,
+                smoothing_k: 0.01,
+                model_path: Some("/tmp/model.bin".to_string()),
+            },
         };
         let json = serde_json::to_string(&config).unwrap();
         let parsed: KoingConfig = serde_json::from_str(&json).unwrap();
         assert_eq!(parsed.debounce_ms, 150);
+        assert!(parsed.adaptive_debounce);
         assert_eq!(parsed.switch_delay_ms, 50);
+        assert!(!parsed.two_stage_conversion);
         assert_eq!(parsed.never_convert_words, vec!["slack".to_string()]);
+        assert_eq!(
+            parsed.expansions,
+            vec![("brb".to_string(), "금방 올게".to_string())]
+        );
+        assert_eq!(parsed.output_form, HangulForm::Nfd);
+        assert!(parsed.infer_double_consonant);
+        assert!(parsed.commit_via_text_input);
+        assert!(parsed.reverse_to_english);
+        assert_eq!(
+            parsed.app_profiles.get("com.example.App"),
+            Some(&AppProfile::Aggressive)
+        );
+        assert_eq!(parsed.profile_params, default_profile_params());
+        assert_eq!(parsed.sensitivity, AppProfile::Conservative);
+        assert_eq!(
+            parsed.no_switch_bundle_ids,
+            vec!["com.example.Game".to_string()]
+        );
+        assert_eq!(
+            parsed.korean_source_id.as_deref(),
+            Some("com.apple.inputmethod.Korean.3SetKorean")
+        );
+        assert!(parsed.soft_switch_on_reject);
+        assert!(parsed.switch_on_unconvertible);
+        assert!(!parsed.pass_through_jamo_only);
+        assert!(parsed.finalize_syllables);
+        assert_eq!(parsed.reset_keycodes, vec![48, 53, 115, 119]);
+        assert!(parsed.onboarded);
+        assert!(!parsed.persist_history);
+        assert!(parsed.speak_on_convert);
+        assert!(parsed.ipc_enabled);
+        assert!(parsed.debug_logging);
+        assert_eq!(parsed.log_format, LogFormat::Json);
+        assert!(parsed.debug_overlay);
+        assert!(parsed.shadow_eval);
+        assert_eq!(parsed.timing_log_interval_secs, 60);
     }
 
     #[test]
@@ -121,7 +661,266 @@ mod tests {
         let json = r#"{"switch_delay_ms": 300}"#;
         let config: KoingConfig = serde_json::from_str(json).unwrap();
         assert_eq!(config.debounce_ms, 300);
+        assert!(!config.adaptive_debounce);
         assert_eq!(config.switch_delay_ms, 300);
+        assert!(config.two_stage_conversion);
         assert!(config.never_convert_words.is_empty());
+        assert!(config.expansions.is_empty());
+        assert_eq!(config.output_form, HangulForm::Nfc);
+        assert!(!config.infer_double_consonant);
+        assert!(!config.commit_via_text_input);
+        assert!(!config.reverse_to_english);
+        assert_eq!(config.profile_params.len(), 3);
+        assert!(!config.app_profiles.is_empty());
+        assert_eq!(config.sensitivity, AppProfile::Normal);
+        assert!(config.no_switch_bundle_ids.is_empty());
+        assert_eq!(config.korean_source_id, None);
+        assert!(!config.soft_switch_on_reject);
+        assert!(!config.switch_on_unconvertible);
+        assert!(config.pass_through_jamo_only);
+        assert!(!config.finalize_syllables);
+        assert_eq!(config.reset_keycodes, DEFAULT_RESET_KEYCODES.to_vec());
+        assert!(!config.onboarded);
+        assert!(config.persist_history);
+        assert!(!config.speak_on_convert);
+        assert!(!config.ipc_enabled);
+        assert!(!config.debug_logging);
+        assert_eq!(config.log_format, LogFormat::Text);
+        assert!(!config.debug_overlay);
+        assert!(!config.shadow_eval);
+        assert_eq!(config.timing_log_interval_secs, 0);
+    }
+
+    #[test]
+    fn test_migrate_legacy_v0() {
+        // 버전 없는 구버전 파일: 일부 프로필만 지정
+        let value = serde_json::json!({
+            "enabled": false,
+            "debounce_ms": 200,
+            "never_convert_words": ["slack"],
+            "profile_params": {
+                "aggressive": {"threshold": 50.0, "realtime_threshold": 55.0, "min_length": 2}
+            }
+        });
+        let config = migrate_config(value);
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert!(!config.enabled);
+        assert_eq!(config.debounce_ms, 200);
+        assert_eq!(config.never_convert_words, vec!["slack".to_string()]);
+        assert_eq!(config.profile_params.len(), 3);
+        assert_eq!(
+            config.profile_params[&AppProfile::Aggressive].threshold,
+            50.0
+        );
+        assert_eq!(
+            config.profile_params[&AppProfile::Conservative],
+            AppProfile::Conservative.default_params()
+        );
+        assert!(config.persist_history);
+    }
+
+    #[test]
+    fn test_migrate_skips_applied_steps() {
+        // v1 파일에는 v0 → v1 단계를 다시 적용하지 않음
+        let value = serde_json::json!({
+            "version": 1,
+            "profile_params": {
+                "normal": {"threshold": 65.0, "realtime_threshold": 75.0, "min_length": 3}
+            }
+        });
+        let config = migrate_config(value);
+        assert_eq!(config.profile_params.len(), 1);
+        assert_eq!(
+            config.params_for_profile(AppProfile::Normal).threshold,
+            65.0
+        );
+    }
+
+    #[test]
+    fn test_migrate_future_version_best_effort() {
+        let value = serde_json::json!({
+            "version": CONFIG_VERSION + 5,
+            "debounce_ms": "fast",
+            "switch_delay_ms": 700,
+            "unknown_option": true
+        });
+        let config = migrate_config(value);
+        // 형식이 맞지 않는 필드만 기본값, 나머지는 유지
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(config.debounce_ms, 300);
+        assert_eq!(config.switch_delay_ms, 700);
+    }
+
+    #[test]
+    fn test_migrate_non_object() {
+        let config = migrate_config(serde_json::json!([1, 2, 3]));
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(config.debounce_ms, 300);
+    }
+
+    #[test]
+    fn test_resolve_app_profile() {
+        let apps = default_app_profiles();
+        assert_eq!(
+            resolve_app_profile(&apps, "com.microsoft.VSCode"),
+            Some(AppProfile::Conservative)
+        );
+        assert_eq!(
+            resolve_app_profile(&apps, "com.kakao.KakaoTalkMac"),
+            Some(AppProfile::Aggressive)
+        );
+        // 접두사 항목은 '.' 경계에서만 일치
+        assert_eq!(
+            resolve_app_profile(&apps, "com.jetbrains.intellij"),
+            Some(AppProfile::Conservative)
+        );
+        assert_eq!(resolve_app_profile(&apps, "com.jetbrainsfake"), None);
+        assert_eq!(resolve_app_profile(&apps, "com.apple.Safari"), None);
+    }
+
+    #[test]
+    fn test_profile_params_partial_override() {
+        // 일부 프로필만 지정하면 나머지는 기본값
+        let json = r#"{"profile_params": {"aggressive": {"threshold": 50.0, "realtime_threshold": 55.0, "min_length": 2}}}"#;
+        let config: KoingConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            config.params_for_profile(AppProfile::Aggressive).threshold,
+            50.0
+        );
+        assert_eq!(
+            config.params_for_profile(AppProfile::Conservative),
+            AppProfile::Conservative.default_params()
+        );
+    }
+
+    #[test]
+    fn test_ngram_settings_to_config() {
+        let config = NgramSettings {
+            threshold: -8.0,
+            smoothing_k: 0.01,
+            model_path: Some("/tmp/model.bin".to_string()),
+        }
+        .to_ngram_config();
+        assert_eq!(config.threshold, -8.0);
+        assert_eq!(config.smoothing_k, 0.01);
+        assert_eq!(config.model_path, "/tmp/model.bin");
+
+        // 기본 설정은 NgramConfig 기본값과 동일
+        let defaults = NgramConfig::default();
+        let config = NgramSettings::default().to_ngram_config();
+        assert_eq!(config.threshold, defaults.threshold);
+        assert_eq!(config.smoothing_k, defaults.smoothing_k);
+        assert!(config.model_path.is_empty());
+    }
+
+    #[test]
+    fn test_ngram_settings_invalid_values_fall_back_to_defaults() {
+        let defaults = NgramConfig::default();
+        let config = NgramSettings {
+            threshold: 3.0,
+            smoothing_k: 0.0,
+            model_path: Some("  ".to_string()),
+        }
+        .to_ngram_config();
+        assert_eq!(config.threshold, defaults.threshold);
+        assert_eq!(config.smoothing_k, defaults.smoothing_k);
+        assert!(config.model_path.is_empty());
+
+        let config = NgramSettings {
+            threshold: f64::NAN,
+            smoothing_k: -1.0,
+            model_path: None,
+        }
+        .to_ngram_config();
+        assert_eq!(config.threshold, defaults.threshold);
+        assert_eq!(config.smoothing_k, defaults.smoothing_k);
+    }
+
+    #[test]
+    fn test_ngram_settings_partial_json() {
+        let config = migrate_config(serde_json::json!({
+            "version": CONFIG_VERSION,
+            "ngram": { "threshold": -12.5 }
+        }));
+        assert_eq!(config.ngram.threshold, -12.5);
+        assert_eq!(
+            config.ngram.smoothing_k,
+            NgramSettings::default().smoothing_k
+        );
+        assert_eq!(config.ngram.model_path, None);
+
+        // ngram 항목이 없는 기존 설정 파일은 기본값
+        let config = migrate_config(serde_json::json!({ "enabled": true }));
+        assert_eq!(config.ngram, NgramSettings::default());
+    }
+
+    #[test]
+    fn test_config_dir_precedence() {
+        let home = Path::new("/Users/test");
+        let default_dir = home.join("Library/Application Support/koing");
+        let cli = std::env::temp_dir();
+        let env = std::env::current_dir().unwrap();
+
+        assert_eq!(resolve_config_dir(None, None, home), default_dir);
+        assert_eq!(
+            resolve_config_dir(None, Some(env.clone().into()), home),
+            env
+        );
+        // CLI 인자가 환경변수보다 우선
+        assert_eq!(
+            resolve_config_dir(Some(&cli), Some(env.clone().into()), home),
+            cli
+        );
+        // 유효하지 않은 경로는 다음 순위로 폴백
+        assert_eq!(
+            resolve_config_dir(
+                Some(Path::new("relative/dir")),
+                Some(env.clone().into()),
+                home
+            ),
+            env
+        );
+        assert_eq!(
+            resolve_config_dir(None, Some("/nonexistent/koing".into()), home),
+            default_dir
+        );
+        assert_eq!(resolve_config_dir(None, Some("".into()), home), default_dir);
+    }
+
+    #[test]
+    fn test_config_dir_from_args() {
+        let args = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(config_dir_from_args(args(&[])), None);
+        assert_eq!(
+            config_dir_from_args(args(&["--config-dir", "/tmp/profile"])),
+            Some(PathBuf::from("/tmp/profile"))
+        );
+        assert_eq!(
+            config_dir_from_args(args(&["-v", "--config-dir=/tmp/a"])),
+            Some(PathBuf::from("/tmp/a"))
+        );
+        assert_eq!(config_dir_from_args(args(&["--config-dir"])), None);
+        assert_eq!(config_dir_from_args(args(&["--config-directory=/x"])), None);
+    }
+
+    #[test]
+    fn test_env_config_dir_isolates_save_and_load() {
+        let dir = std::env::temp_dir().join(format!("koing_config_env_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        // 프로세스 환경변수는 건드리지 않고 환경변수 값으로 결정된 경로를 사용
+        let home = Path::new("/Users/tester");
+        let path = resolve_config_dir(None, Some(dir.clone().into()), home).join("config.json");
+        assert_eq!(path, dir.join("config.json"));
+        let config = KoingConfig {
+            debounce_ms: 777,
+            ..KoingConfig::default()
+        };
+        save_config_to(&config, &path).unwrap();
+        assert!(path.is_file());
+        assert_eq!(load_config_from(&path).debounce_ms, 777);
+
+        let _ = fs::remove_dir_all(&dir);
     }
 }
@@ -1,14 +1,77 @@
 //! 영문 -> 한글 통합 변환기
 
 use crate::core::hangul_fsm::HangulFsm;
-use crate::core::jamo_mapper::map_to_jamo;
+use crate::core::jamo_mapper::{is_consonant, is_vowel, map_to_jamo, Jamo};
+use crate::core::unicode::{
+    combine_jongseong, combine_jungseong, decompose_syllable, is_compat_jamo, to_nfd,
+};
+use serde::{Deserialize, Serialize};
+
+/// 출력 한글 정규화 형태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HangulForm {
+    /// 완성형 (NFC) — 기본값
+    #[default]
+    Nfc,
+    /// 조합형 자모 분해 (NFD)
+    Nfd,
+}
+
+impl HangulForm {
+    /// 완성형 변환 결과를 이 형태로 정규화
+    pub fn apply(self, text: &str) -> String {
+        match self {
+            HangulForm::Nfc => text.to_string(),
+            HangulForm::Nfd => to_nfd(text),
+        }
+    }
+}
+
+/// 변환 경계 문자 (공백/탭/개행, NBSP, 전각 공백 등 유니코드 공백 전체)
+///
+/// 변환 시에는 다른 passthrough 문자처럼 조합 중인 음절을 확정하고,
+/// 이벤트 탭 키 버퍼에서는 버퍼를 비워 변환 구간을 나눔
+pub fn is_conversion_boundary(c: char) -> bool {
+    c.is_whitespace()
+}
+
+/// 전각 ASCII 블록 (U+FF01 ~ U+FF5E, 반각 `!` ~ `~`에 대응)
+const FULLWIDTH_ASCII: std::ops::RangeInclusive<char> = '\u{FF01}'..='\u{FF5E}';
+/// 전각 ASCII와 대응하는 반각 문자의 코드 포인트 차이
+const FULLWIDTH_OFFSET: u32 = 0xFEE0;
+
+/// 전각 영문을 반각으로 정규화 (두벌식 매핑 대상 문자만, 그 외 전각 기호는 그대로)
+///
+/// # Examples
+/// ```
+/// use koing::core::converter::normalize_halfwidth;
+///
+/// assert_eq!(normalize_halfwidth("ｄｋ"), "dk");
+/// assert_eq!(normalize_halfwidth("Ｒｋ！"), "Rk！");
+/// assert_eq!(normalize_halfwidth("dkssud"), "dkssud");
+/// ```
+pub fn normalize_halfwidth(input: &str) -> String {
+    input.chars().map(halfwidth_key).collect()
+}
+
+/// 전각 문자의 반각 키 (반각이 두벌식 매핑 대상일 때만, 아니면 그대로)
+fn halfwidth_key(c: char) -> char {
+    if !FULLWIDTH_ASCII.contains(&c) {
+        return c;
+    }
+    char::from_u32(c as u32 - FULLWIDTH_OFFSET)
+        .filter(|&half| map_to_jamo(half).is_some())
+        .unwrap_or(c)
+}
 
 /// 영문 문자열을 한글 문자열로 변환
-/// 변환할 수 없는 문자(숫자, 특수문자, 매핑 없는 영문)는 그대로 유지
+/// 전각 영문은 반각으로 보고 변환하며, 변환할 수 없는 문자(숫자, 특수문자,
+/// 매핑 없는 영문, 공백, 전각 기호)는 조합 중인 음절을 확정한 뒤 그대로 유지
 pub fn convert(input: &str) -> String {
     let mut fsm = HangulFsm::new();
 
-    for c in input.chars() {
+    for c in input.chars().map(halfwidth_key) {
         if let Some(jamo) = map_to_jamo(c) {
             fsm.feed(jamo);
         } else {
@@ -19,6 +82,147 @@ pub fn convert(input: &str) -> String {
     fsm.finish()
 }
 
+/// 변환 결과 글자 수 예측 (음절을 조합하지 않고 자음/모음 키 패턴으로 계산)
+///
+/// 모음마다 한 글자(이중모음으로 합쳐지면 제외)로 세고, 모음 사이 자음 중 받침
+/// (겹받침이면 2개)과 다음 초성이 되지 못한 자음을 낱자모 한 글자로 셉니다.
+/// 매핑 없는 문자는 한 글자. 출력 문자열을 만들지 않으므로 미리보기/Backspace 개수 추정용
+///
+/// # Examples
+/// ```
+/// use koing::core::converter::{convert, predict_syllable_count};
+///
+/// assert_eq!(predict_syllable_count("dkssud"), 2); // 안녕
+/// assert_eq!(predict_syllable_count("rkk"), convert("rkk").chars().count()); // 가ㅏ
+/// ```
+pub fn predict_syllable_count(input: &str) -> usize {
+    let mut count = 0;
+    // 받침을 받을 수 있는 음절(초성+중성)이 열려 있는지
+    let mut open = false;
+    // 직전 키가 모음이면 열린 음절의 중성 (이중모음 조합용)
+    let mut last_jung: Option<u32> = None;
+    // 마지막 모음 뒤 연속 자음 수와 앞 두 자음의 종성 인덱스
+    let mut consonants = 0;
+    let mut jongs: [Option<u32>; 2] = [None, None];
+
+    for c in input.chars().map(halfwidth_key) {
+        match map_to_jamo(c) {
+            Some(Jamo::Consonant { jong_index, .. }) => {
+                if consonants < 2 {
+                    jongs[consonants] = jong_index;
+                }
+                consonants += 1;
+                last_jung = None;
+            }
+            Some(Jamo::Vowel { jung_index }) => {
+                if consonants == 0 {
+                    let combined = last_jung.and_then(|jung| combine_jungseong(jung, jung_index));
+                    match combined {
+                        Some(jung) => last_jung = Some(jung),
+                        None => {
+                            // 초성 없는 모음은 낱자모
+                            count += 1;
+                            open = false;
+                            last_jung = None;
+                        }
+                    }
+                    continue;
+                }
+                // 마지막 자음은 새 음절 초성, 나머지는 받침 또는 낱자모
+                let rest = consonants - 1;
+                count += stray_consonants(open, rest, jongs) + 1;
+                open = true;
+                last_jung = Some(jung_index);
+                consonants = 0;
+            }
+            None => {
+                count += stray_consonants(open, consonants, jongs) + 1;
+                open = false;
+                last_jung = None;
+                consonants = 0;
+            }
+        }
+    }
+    count + stray_consonants(open, consonants, jongs)
+}
+
+/// 열린 음절 뒤 자음 count개 중 받침이 되지 못하고 낱자모로 남는 수
+fn stray_consonants(open: bool, count: usize, jongs: [Option<u32>; 2]) -> usize {
+    if !open {
+        return count;
+    }
+    let capacity = match jongs {
+        [Some(first), Some(second)] if combine_jongseong(first, second).is_some() => 2,
+        [Some(_), _] => 1,
+        _ => 0,
+    };
+    count - capacity.min(count)
+}
+
+/// 같은 자음 키 2연타를 쌍자음(Shift) 키로 치환하는 전처리 (실험적, 기본 off)
+///
+/// - 초성: 뒤에 모음이 오고 앞 키가 받침으로 붙지 못해 낱자모로 남는 경우 ("eekfrl" → "Ekfrl", 딸기)
+/// - 종성: 받침 없는 음절 뒤에서 모음이 이어지지 않는 경우, 종성이 가능한 ㄲ/ㅆ만 ("dltt" → "dlT", 있)
+///
+/// 앞 키가 받침으로 붙을 수 있으면("gkrry", 학교) 그대로 두고, ㄸ/ㅃ/ㅉ는 종성으로 만들지 않습니다.
+pub fn normalize_double_consonants(input: &str) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let mut output = String::with_capacity(input.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let double = double_consonant_key(c).filter(|_| chars.get(i + 1) == Some(&c));
+        if let Some(double) = double {
+            let replace = if chars.get(i + 2).is_some_and(|&next| is_vowel(next)) {
+                // 앞 키가 앞 음절 받침이 되지 못하고 낱자모로 남는지
+                let mut prefix = output.clone();
+                prefix.push(c);
+                convert(&prefix).chars().last().is_some_and(is_compat_jamo)
+            } else {
+                can_be_jongseong(double) && ends_with_open_syllable(&output)
+            };
+            if replace {
+                output.push(double);
+                i += 2;
+                continue;
+            }
+        }
+        output.push(c);
+        i += 1;
+    }
+
+    output
+}
+
+/// 쌍자음이 있는 자음 키의 Shift 키 (r→R, e→E, q→Q, t→T, w→W)
+fn double_consonant_key(c: char) -> Option<char> {
+    if !c.is_ascii_lowercase() || !is_consonant(c) {
+        return None;
+    }
+    let upper = c.to_ascii_uppercase();
+    is_consonant(upper).then_some(upper)
+}
+
+/// 종성으로 쓸 수 있는 자음 키인지 (ㄸ/ㅃ/ㅉ는 불가)
+fn can_be_jongseong(key: char) -> bool {
+    map_to_jamo(key).is_some_and(|jamo| jamo.jongseong_index().is_some())
+}
+
+/// 변환 결과가 받침 없는 완성 음절로 끝나는지
+fn ends_with_open_syllable(keys: &str) -> bool {
+    convert(keys)
+        .chars()
+        .last()
+        .and_then(decompose_syllable)
+        .is_some_and(|(_, _, jong)| jong == 0)
+}
+
+/// 영문 문자열을 한글로 변환하고 지정한 정규화 형태로 출력
+pub fn convert_normalized(input: &str, form: HangulForm) -> String {
+    form.apply(&convert(input))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -29,6 +233,125 @@ mod tests {
         assert_eq!(convert("dkssudgktpdy"), "안녕하세요");
     }
 
+    #[test]
+    fn test_predict_syllable_count_matches_convert() {
+        for input in [
+            "dkssudgktpdy", // 안녕하세요
+            "rkk",          // 가ㅏ
+            "dkseek",       // 안ㄷ다
+            "dlfrdjTek",    // 읽었다
+            "rhkdlf",       // 과일
+            "hkl",          // ㅗㅏㅣ
+            "rsef",         // ㄱㄴㄷㄹ
+            "rkEk",         // 가따
+            "qkfqEk",       // 밟따
+            "gksrmf 123",   // 한글 123
+            "hello world",
+            "",
+        ] {
+            assert_eq!(
+                predict_syllable_count(input),
+                convert(input).chars().count(),
+                "{} → {}",
+                input,
+                convert(input)
+            );
+        }
+    }
+
+    #[test]
+    fn test_predict_syllable_count_error_rate() {
+        // 자음(받침 불가/겹받침 포함), 모음(이중모음 포함), 비매핑 문자 조합 전수 측정
+        let keys = ['r', 'f', 't', 'E', 'Q', 'k', 'h', 'l', 'n', 'j', '1'];
+        let mut inputs = vec![String::new()];
+        for _ in 0..5 {
+            inputs = inputs
+                .iter()
+                .flat_map(|prefix| {
+                    keys.iter().map(move |&key| {
+                        let mut input = prefix.clone();
+                        input.push(key);
+                        input
+                    })
+                })
+                .collect();
+        }
+
+        let mut mismatches = 0;
+        let mut max_error = 0;
+        for input in &inputs {
+            let actual = convert(input).chars().count();
+            let error = predict_syllable_count(input).abs_diff(actual);
+            if error > 0 {
+                mismatches += 1;
+                max_error = max_error.max(error);
+            }
+        }
+        assert_eq!(
+            (mismatches, max_error),
+            (0, 0),
+            "{}개 중 {}개 불일치",
+            inputs.len(),
+            mismatches
+        );
+    }
+
+    #[test]
+    fn test_normalize_double_consonants_initial() {
+        // 단어 첫머리 2연타 + 모음 → 쌍자음 초성
+        assert_eq!(normalize_double_consonants("eekfrl"), "Ekfrl");
+        assert_eq!(convert(&normalize_double_consonants("eekfrl")), "딸기");
+        assert_eq!(convert(&normalize_double_consonants("qqkd")), "빵");
+        assert_eq!(convert(&normalize_double_consonants("wwkd")), "짱");
+        assert_eq!(convert(&normalize_double_consonants("rrhcl")), "꼬치");
+        assert_eq!(convert(&normalize_double_consonants("ttkdn")), "싸우");
+
+        // 받침으로 붙지 못하는 위치 (ㄴ+ㄷ은 겹받침 아님) → 낱자모 대신 쌍자음
+        assert_eq!(convert("dkseek"), "안ㄷ다");
+        assert_eq!(convert(&normalize_double_consonants("dkseek")), "안따");
+
+        // 숫자/기호 뒤는 단어 첫머리와 동일
+        assert_eq!(normalize_double_consonants("1eek"), "1Ek");
+    }
+
+    #[test]
+    fn test_normalize_double_consonants_final() {
+        // 받침 없는 음절 뒤 ㄲ/ㅆ → 쌍자음 종성
+        assert_eq!(convert(&normalize_double_consonants("dltt")), "있");
+        assert_eq!(convert(&normalize_double_consonants("dlttek")), "있다");
+        assert_eq!(convert(&normalize_double_consonants("gkrr")), "핚");
+
+        // ㄸ/ㅃ/ㅉ는 종성 불가 → 그대로
+        assert_eq!(normalize_double_consonants("gkee"), "gkee");
+        assert_eq!(normalize_double_consonants("gkqq"), "gkqq");
+        assert_eq!(normalize_double_consonants("gkww"), "gkww");
+    }
+
+    #[test]
+    fn test_normalize_double_consonants_keeps_ambiguous_input() {
+        // 앞 키가 받침이 되는 정상 입력 (학교, 갓서)
+        assert_eq!(normalize_double_consonants("gkrry"), "gkrry");
+        assert_eq!(convert("gkrry"), "학교");
+        assert_eq!(normalize_double_consonants("rkttj"), "rkttj");
+        // 겹받침이 될 수 있는 경우 (ㄹ+ㄱ)
+        assert_eq!(normalize_double_consonants("dkfrrk"), "dkfrrk");
+        // 받침이 이미 있는 음절 뒤 종성 자리
+        assert_eq!(normalize_double_consonants("dkstt"), "dkstt");
+
+        // 모음이 뒤따르지 않는 첫머리 연타 (ㄷㄷ, ㄱㄱ 같은 자음 표현)
+        assert_eq!(normalize_double_consonants("ee"), "ee");
+        assert_eq!(normalize_double_consonants("rr"), "rr");
+        // 쌍자음이 없는 자음(ㄴ, ㅇ, ㅋ)과 모음 연타
+        assert_eq!(normalize_double_consonants("dkssud"), "dkssud");
+        assert_eq!(normalize_double_consonants("ddkf"), "ddkf");
+        assert_eq!(normalize_double_consonants("zzzz"), "zzzz");
+        assert_eq!(normalize_double_consonants("book"), "book");
+        // 이미 Shift로 입력한 쌍자음, 대소문자 혼합
+        assert_eq!(normalize_double_consonants("EEk"), "EEk");
+        assert_eq!(normalize_double_consonants("eEk"), "eEk");
+        assert_eq!(normalize_double_consonants(""), "");
+    }
+
     #[test]
     fn test_jongseong() {
         assert_eq!(convert("gksrmf"), "한글");
@@ -40,6 +363,24 @@ mod tests {
         assert_eq!(convert("dhksfy"), "완료");
     }
 
+    #[test]
+    fn test_jungseong_ui_round_trip() {
+        use crate::ngram::korean_to_eng;
+
+        // ㅢ 포함 음절: 변환 → 역변환이 원래 키 입력과 일치
+        for (keys, hangul) in [
+            ("dml", "의"),
+            ("gml", "희"),
+            ("dmls", "읜"),
+            ("rmlt", "긧"),
+            ("Tmldml", "씌의"),
+            ("dmlrustn", "의견수"),
+        ] {
+            assert_eq!(convert(keys), hangul);
+            assert_eq!(korean_to_eng(hangul), keys);
+        }
+    }
+
     #[test]
     fn test_complex_jongseong() {
         assert_eq!(convert("dlfr"), "읽");
@@ -64,6 +405,35 @@ mod tests {
         assert_eq!(convert("rkXsk"), "가X나");
     }
 
+    #[test]
+    fn test_unicode_whitespace_forms_boundary() {
+        // 경계 뒤 모음이 앞 음절의 종성을 가져가지 않아야 함 ("dksk" → "아나")
+        assert_eq!(convert("dks k"), "안 ㅏ");
+        assert_eq!(convert("dks\u{00A0}k"), "안\u{00A0}ㅏ");
+        assert_eq!(convert("dks\u{3000}k"), "안\u{3000}ㅏ");
+        assert_eq!(convert("dks\tk"), "안\tㅏ");
+        assert_eq!(convert("dkssud\u{3000}gktpdy"), "안녕\u{3000}하세요");
+    }
+
+    #[test]
+    fn test_fullwidth_punctuation_forms_boundary() {
+        assert_eq!(convert("dks，k"), "안，ㅏ");
+        assert_eq!(convert("dkssud。"), "안녕。");
+        assert_eq!(convert("gks\u{FF01}rmf"), "한\u{FF01}글");
+        // 전각 영문자는 반각으로 보고 변환
+        assert_eq!(convert("ｒｋ"), "가");
+    }
+
+    #[test]
+    fn test_is_conversion_boundary() {
+        for c in [' ', '\t', '\n', '\r', '\u{00A0}', '\u{3000}', '\u{2009}'] {
+            assert!(is_conversion_boundary(c), "{:?}", c);
+        }
+        for c in ['r', '1', '!', '，', '\u{FF01}'] {
+            assert!(!is_conversion_boundary(c), "{:?}", c);
+        }
+    }
+
     #[test]
     fn test_empty_string() {
         assert_eq!(convert(""), "");
@@ -74,4 +444,56 @@ mod tests {
         // 종성이 다음 초성으로 분리
         assert_eq!(convert("rkrkrl"), "가가기");
     }
+
+    #[test]
+    fn test_convert_normalized_nfc() {
+        assert_eq!(convert_normalized("gksrmf", HangulForm::Nfc), "한글");
+        assert_eq!(
+            convert_normalized("gksrmf", HangulForm::Nfc),
+            convert("gksrmf")
+        );
+    }
+
+    #[test]
+    fn test_convert_normalized_nfd() {
+        // 한글 = ᄒ ᅡ ᆫ ᄀ ᅳ ᆯ
+        assert_eq!(
+            convert_normalized("gksrmf", HangulForm::Nfd),
+            "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}"
+        );
+        // 숫자/기호/낱자모는 분해 대상이 아님
+        assert_eq!(
+            convert_normalized("rk1r", HangulForm::Nfd),
+            "\u{1100}\u{1161}1ㄱ"
+        );
+    }
+
+    #[test]
+    fn test_fullwidth_input_converts_like_halfwidth() {
+        assert_eq!(convert("ｄｋ"), "아");
+        assert_eq!(convert("ｄｋｓｓｕｄ"), "안녕");
+        // 전각 대문자는 Shift 키 (ㄲ)
+        assert_eq!(convert("Ｒｋ"), "까");
+        // 매핑 없는 전각 문자는 음절을 확정하고 그대로 유지
+        assert_eq!(convert("ｄｋ１ｒｋ！"), "아１가！");
+        assert_eq!(predict_syllable_count("ｄｋｓｓｕｄ"), 2);
+
+        // 일반 입력은 정규화해도 그대로
+        for input in ["dkssud gktpdy", "rk1!", "안녕", "ㅋㅋ", "dks，k", ""] {
+            assert_eq!(normalize_halfwidth(input), input);
+        }
+        // 전각 공백/기호는 정규화하지 않음
+        assert_eq!(
+            normalize_halfwidth("ｆｕｌｌ　ｗｉｄｔｈ？"),
+            "full　width？"
+        );
+    }
+
+    #[test]
+    fn test_hangul_form_serde() {
+        assert_eq!(serde_json::to_string(&HangulForm::Nfd).unwrap(), "\"nfd\"");
+        let form: HangulForm = serde_json::from_str("\"nfc\"").unwrap();
+        assert_eq!(form, HangulForm::Nfc);
+        assert_eq!(HangulForm::default(), HangulForm::Nfc);
+    }
 }
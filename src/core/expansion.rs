@@ -0,0 +1,218 @@
+//! 사용자 치환 규칙 (트리거 → 한글 구절)
+//!
+//! 트리거는 입력 키("brb") 또는 두벌식 변환 결과("ㅇㅇ") 형태로 등록할 수 있습니다.
+
+use crate::core::converter::{convert, is_conversion_boundary};
+use std::collections::HashMap;
+
+/// 치환 규칙 테이블
+#[derive(Debug, Clone, Default)]
+pub struct ExpansionTable {
+    rules: HashMap<String, String>,
+    /// 가장 긴 트리거의 단어 수 (한 위치에서 검사할 구간 상한)
+    max_words: usize,
+}
+
+impl ExpansionTable {
+    /// 규칙 목록으로 생성 (트리거 앞뒤 공백 제거, 빈 트리거 무시, 중복 시 먼저 등록된 규칙 우선)
+    pub fn new(rules: &[(String, String)]) -> Self {
+        let mut table = Self::default();
+        for (trigger, replacement) in rules {
+            let trigger = trigger.trim();
+            if trigger.is_empty() {
+                continue;
+            }
+            table.max_words = table.max_words.max(words(trigger).len());
+            table
+                .rules
+                .entry(trigger.to_string())
+                .or_insert_with(|| replacement.clone());
+        }
+        table
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// 구간이 트리거와 정확히 일치하면 치환 결과 (키 그대로 또는 변환 결과 기준)
+    fn lookup(&self, span: &str) -> Option<&str> {
+        self.rules
+            .get(span)
+            .or_else(|| self.rules.get(&convert(span)))
+            .map(String::as_str)
+    }
+
+    /// 트리거와 일치하는 단어 구간을 치환, 나머지 단어는 convert_word로 변환
+    ///
+    /// 각 단어 위치에서 가장 긴(단어 수 기준) 트리거를 우선 적용하고,
+    /// 트리거는 단어 경계에서 시작/종료해야 합니다. 일치하는 규칙이 없으면 None.
+    pub fn expand<F>(&self, text: &str, convert_word: F) -> Option<String>
+    where
+        F: Fn(&str) -> String,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let words = words(text);
+        let mut result = String::with_capacity(text.len());
+        let mut matched = false;
+        let mut copied_to = 0;
+        let mut i = 0;
+        while i < words.len() {
+            let (start, end) = words[i];
+            result.push_str(&text[copied_to..start]);
+
+            let last = (i + self.max_words).min(words.len()) - 1;
+            let hit = (i..=last).rev().find_map(|j| {
+                self.lookup(&text[start..words[j].1])
+                    .map(|replacement| (j, replacement))
+            });
+            match hit {
+                Some((j, replacement)) => {
+                    result.push_str(replacement);
+                    copied_to = words[j].1;
+                    matched = true;
+                    i = j + 1;
+                }
+                None => {
+                    result.push_str(&convert_word(&text[start..end]));
+                    copied_to = end;
+                    i += 1;
+                }
+            }
+        }
+        result.push_str(&text[copied_to..]);
+
+        matched.then_some(result)
+    }
+}
+
+/// 단어(경계 문자로 나뉜 구간)의 바이트 범위
+fn words(text: &str) -> Vec<(usize, usize)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        match (is_conversion_boundary(c), start) {
+            (true, Some(s)) => {
+                words.push((s, i));
+                start = None;
+            }
+            (false, None) => start = Some(i),
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        words.push((s, text.len()));
+    }
+    words
+}
+
+/// 설정 편집용 텍스트("트리거 = 치환" 한 줄에 하나)를 규칙 목록으로 파싱
+///
+/// 첫 '='를 기준으로 나누며, '='가 없거나 트리거가 빈 줄은 무시합니다.
+pub fn parse_expansion_rules(text: &str) -> Vec<(String, String)> {
+    text.lines()
+        .filter_map(|line| {
+            let (trigger, replacement) = line.split_once('=')?;
+            let trigger = trigger.trim();
+            if trigger.is_empty() {
+                return None;
+            }
+            Some((trigger.to_string(), replacement.trim().to_string()))
+        })
+        .collect()
+}
+
+/// 규칙 목록을 설정 편집용 텍스트로
+pub fn format_expansion_rules(rules: &[(String, String)]) -> String {
+    rules
+        .iter()
+        .map(|(trigger, replacement)| format!("{} = {}", trigger, replacement))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(rules: &[(&str, &str)]) -> ExpansionTable {
+        let rules: Vec<(String, String)> = rules
+            .iter()
+            .map(|(t, r)| (t.to_string(), r.to_string()))
+            .collect();
+        ExpansionTable::new(&rules)
+    }
+
+    #[test]
+    fn test_exact_trigger_is_expanded() {
+        let table = table(&[("brb", "금방 올게"), ("ㅇㅇ", "응응")]);
+        assert_eq!(table.expand("brb", convert).as_deref(), Some("금방 올게"));
+        // 변환 결과 형태의 트리거는 키 시퀀스로도 일치
+        assert_eq!(table.expand("dd", convert).as_deref(), Some("응응"));
+        assert_eq!(table.expand("ㅇㅇ", convert).as_deref(), Some("응응"));
+    }
+
+    #[test]
+    fn test_partial_or_unregistered_input_is_not_expanded() {
+        let table = table(&[("brb", "금방 올게")]);
+        // 단어 일부만 일치하면 적용하지 않음
+        assert_eq!(table.expand("brbx", convert), None);
+        assert_eq!(table.expand("xbrb", convert), None);
+        assert_eq!(table.expand("br", convert), None);
+        // 대소문자는 구분 (Shift 키는 다른 자모)
+        assert_eq!(table.expand("BRB", convert), None);
+        assert_eq!(table.expand("dkssud", convert), None);
+        assert_eq!(ExpansionTable::default().expand("brb", convert), None);
+    }
+
+    #[test]
+    fn test_words_around_trigger_use_normal_conversion() {
+        let table = table(&[("brb", "금방 올게")]);
+        assert_eq!(
+            table.expand("dkssud brb  gkrh", convert).as_deref(),
+            Some("안녕 금방 올게  하고")
+        );
+        // 앞뒤 경계 문자는 그대로 유지
+        assert_eq!(
+            table.expand(" brb\n", convert).as_deref(),
+            Some(" 금방 올게\n")
+        );
+    }
+
+    #[test]
+    fn test_longest_match_wins() {
+        let table = table(&[("ok", "응"), ("ok bye", "응 안녕히"), ("bye", "잘 가")]);
+        assert_eq!(
+            table.expand("ok bye", convert).as_deref(),
+            Some("응 안녕히")
+        );
+        assert_eq!(table.expand("ok ok", convert).as_deref(), Some("응 응"));
+        assert_eq!(
+            table.expand("bye ok bye", convert).as_deref(),
+            Some("잘 가 응 안녕히")
+        );
+        // 중복 트리거는 먼저 등록된 규칙
+        let table = self::table(&[("brb", "금방 올게"), (" brb ", "곧 올게")]);
+        assert_eq!(table.expand("brb", convert).as_deref(), Some("금방 올게"));
+    }
+
+    #[test]
+    fn test_parse_and_format_rules() {
+        let rules = parse_expansion_rules("brb = 금방 올게\n\nㅇㅇ=응응\n=무시\n잘못된 줄\na=b=c");
+        assert_eq!(
+            rules,
+            vec![
+                ("brb".to_string(), "금방 올게".to_string()),
+                ("ㅇㅇ".to_string(), "응응".to_string()),
+                ("a".to_string(), "b=c".to_string()),
+            ]
+        );
+        assert_eq!(
+            parse_expansion_rules(&format_expansion_rules(&rules)),
+            rules
+        );
+    }
+}
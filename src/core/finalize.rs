@@ -0,0 +1,115 @@
+//! 변환 결과 후처리: 끝에 남은 낱자모 정리
+//!
+//! FSM은 앞 음절에 붙일 수 없는 자모를 낱자모로 내보냄 (예: `rkk` → "가ㅏ").
+//! 마지막 음절 뒤에 낱자모 하나만 남았으면 앞 음절에 합쳐 확정하고,
+//! 합칠 수 없으면 제거합니다. 2개 이상 이어진 낱자모(ㅋㅋ, ㅠㅠ)는 의도된 입력으로 보고 유지.
+
+use crate::core::unicode::{
+    choseong_to_jamo_char, combine_jongseong, combine_jungseong, compose_syllable,
+    decompose_syllable, is_compat_jamo, jongseong_to_choseong, jungseong_to_jamo_char,
+};
+
+/// 변환 결과 끝의 불완전한 낱자모 정리 (끝 공백은 유지)
+///
+/// - 앞 음절에 받침/겹받침/이중모음으로 합칠 수 있으면 확정: "가ㄴ" → "간", "갈ㄱ" → "갉"
+/// - 합칠 수 없으면 제거: "가ㅏ" → "가", "각ㄱ" → "각"
+/// - 낱자모가 2개 이상이거나 앞에 음절이 없으면 그대로: "가ㅋㅋ", "ㄱ"
+pub fn finalize_syllables(text: &str) -> String {
+    let body = text.trim_end();
+    let trailing_space = &text[body.len()..];
+
+    let mut chars = body.chars().rev();
+    let (Some(last), Some(prev)) = (chars.next(), chars.next()) else {
+        return text.to_string();
+    };
+    if !is_compat_jamo(last) || is_compat_jamo(prev) {
+        return text.to_string();
+    }
+    let Some(syllable) = decompose_syllable(prev) else {
+        return text.to_string();
+    };
+
+    let head = &body[..body.len() - last.len_utf8() - prev.len_utf8()];
+    let mut out = String::with_capacity(text.len());
+    out.push_str(head);
+    out.push(prev);
+    if let Some(merged) = merge_into_syllable(syllable, last) {
+        out.pop();
+        out.push(merged);
+    }
+    out.push_str(trailing_space);
+    out
+}
+
+/// 낱자모를 앞 음절(초성, 중성, 종성 인덱스)에 합친 음절
+fn merge_into_syllable((cho, jung, jong): (u32, u32, u32), jamo: char) -> Option<char> {
+    if let Some(vowel) = compat_to_jungseong(jamo) {
+        // 받침이 없을 때만 이중모음으로 (받침 뒤 모음은 다음 음절의 것)
+        if jong != 0 {
+            return None;
+        }
+        return compose_syllable(cho, combine_jungseong(jung, vowel)?, 0);
+    }
+    let tail = compat_to_jongseong(jamo)?;
+    let jong = if jong == 0 {
+        tail
+    } else {
+        combine_jongseong(jong, tail)?
+    };
+    compose_syllable(cho, jung, jong)
+}
+
+/// 호환용 모음 자모 → 중성 인덱스
+fn compat_to_jungseong(c: char) -> Option<u32> {
+    (0..21).find(|&jung| jungseong_to_jamo_char(jung) == Some(c))
+}
+
+/// 호환용 자음 자모 → 종성 인덱스 (받침으로 쓸 수 없는 ㄸ/ㅃ/ㅉ는 None)
+fn compat_to_jongseong(c: char) -> Option<u32> {
+    let cho = (0..19).find(|&cho| choseong_to_jamo_char(cho) == Some(c))?;
+    (1..28).find(|&jong| jongseong_to_choseong(jong) == Some(cho))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::converter::convert;
+
+    #[test]
+    fn test_finalize_merges_trailing_jamo() {
+        // 받침 / 겹받침 / 이중모음으로 확정
+        assert_eq!(finalize_syllables("가ㄴ"), "간");
+        assert_eq!(finalize_syllables("안녕하세ㅇ"), "안녕하셍");
+        assert_eq!(finalize_syllables("갈ㄱ"), "갉");
+        assert_eq!(finalize_syllables("업ㅅ"), "없");
+        assert_eq!(finalize_syllables("고ㅏ"), "과");
+        assert_eq!(finalize_syllables("그ㅣ"), "긔");
+        // 끝 공백은 유지
+        assert_eq!(finalize_syllables("가ㄴ "), "간 ");
+    }
+
+    #[test]
+    fn test_finalize_drops_unmergeable_jamo() {
+        assert_eq!(finalize_syllables("가ㅏ"), "가");
+        assert_eq!(finalize_syllables("각ㄱ"), "각");
+        assert_eq!(finalize_syllables("간ㅏ"), "간");
+        // 받침이 될 수 없는 쌍자음
+        assert_eq!(finalize_syllables("가ㄸ"), "가");
+        // FSM 결과의 trailing 낱자모
+        assert_eq!(finalize_syllables(&convert("rkk")), "가");
+        assert_eq!(finalize_syllables(&convert("dkssudr")), "안녕");
+        assert_eq!(finalize_syllables(&convert("gkrr")), "학");
+    }
+
+    #[test]
+    fn test_finalize_keeps_intentional_jamo() {
+        // 낱자모 연속 / 음절 없는 낱자모 / 낱자모 없는 결과는 그대로
+        assert_eq!(finalize_syllables("좋아ㅋㅋ"), "좋아ㅋㅋ");
+        assert_eq!(finalize_syllables("아ㅠㅠ"), "아ㅠㅠ");
+        assert_eq!(finalize_syllables("ㄱ"), "ㄱ");
+        assert_eq!(finalize_syllables("a ㄱ"), "a ㄱ");
+        assert_eq!(finalize_syllables("안녕"), "안녕");
+        assert_eq!(finalize_syllables(""), "");
+        assert_eq!(finalize_syllables("가1"), "가1");
+    }
+}
@@ -114,7 +114,8 @@ impl HangulFsm {
     fn feed_vowel(&mut self, jung_index: u32) {
         match self.state {
             State::Empty => {
-                // 모음만 단독 출력
+                // 초성 없는 모음은 복합 모음 후보("hk" → ㅗ+ㅏ)여도 조합하지 않고 각각 낱자모로 출력
+                // (초성 ㅇ 보충 없음) — 모음 키 하나가 화면 글자 하나라 지울 개수도 키 수와 같음
                 if let Some(c) = jungseong_to_jamo_char(jung_index) {
                     self.output.push(c);
                 }
@@ -263,6 +264,39 @@ mod tests {
         assert_eq!(convert("dml"), "의"); // ㅡ + ㅣ = ㅢ
     }
 
+    #[test]
+    fn test_jungseong_ui_transitions() {
+        // ㅡ(18) + ㅣ(20) = ㅢ(19): 초성 뒤 복합 모음은 같은 글자에서 조합
+        let mut fsm = HangulFsm::new();
+        for c in "dml".chars() {
+            fsm.feed(map_to_jamo(c).unwrap());
+        }
+        assert_eq!(fsm.state, State::ChoseongJungseong);
+        assert_eq!(fsm.jungseong, 19);
+
+        // ㅢ 뒤 자음은 종성으로 붙음
+        fsm.feed(map_to_jamo('s').unwrap());
+        assert_eq!(fsm.state, State::ChoseongJungseongJongseong);
+        assert_eq!(fsm.jungseong, 19);
+        assert_eq!(fsm.finish(), "읜");
+    }
+
+    #[test]
+    fn test_jungseong_ui_syllables() {
+        assert_eq!(convert("dml"), "의");
+        assert_eq!(convert("gml"), "희");
+        assert_eq!(convert("dmls"), "읜");
+        assert_eq!(convert("gmls"), "흰");
+        assert_eq!(convert("dmlf"), "읠");
+        // ㅢ + 복합 종성 ㄺ
+        assert_eq!(convert("dmlfr"), "읡");
+        // ㅢ 음절의 종성은 다음 모음 앞에서 초성으로 넘어감
+        assert_eq!(convert("dmlsk"), "의나");
+        assert_eq!(convert("dmldml"), "의의");
+        // 초성 없는 ㅡ + ㅣ는 결합하지 않음
+        assert_eq!(convert("ml"), "ㅡㅣ");
+    }
+
     #[test]
     fn test_complex_jongseong() {
         // d=ㅇ(초성11), k=ㅏ(중성0), f=ㄹ(종성8), r=ㄱ(종성1)
@@ -295,6 +329,23 @@ mod tests {
         assert_eq!(convert("kh"), "ㅏㅗ");
     }
 
+    #[test]
+    fn test_vowel_sequence_without_choseong() {
+        // 복합 모음이 되는 순서여도 초성이 없으면 각각 낱자모
+        assert_eq!(convert("hk"), "ㅗㅏ");
+        assert_eq!(convert("hl"), "ㅗㅣ");
+        assert_eq!(convert("nj"), "ㅜㅓ");
+        assert_eq!(convert("ml"), "ㅡㅣ");
+        // 초성이 있으면 복합 모음으로 조합
+        assert_eq!(convert("dhk"), "와");
+        assert_eq!(convert("dhl"), "외");
+        // 글자가 확정된 뒤 이어지는 모음도 초성 없는 모음과 같이 처리
+        assert_eq!(convert("rkhk"), "가ㅗㅏ");
+        assert_eq!(convert("dhkhk"), "와ㅗㅏ");
+        // 모음 뒤 자음은 새 글자의 초성
+        assert_eq!(convert("hkrk"), "ㅗㅏ가");
+    }
+
     #[test]
     fn test_empty() {
         assert_eq!(convert(""), "");
@@ -1,4 +1,7 @@
 pub mod converter;
+pub mod expansion;
+pub mod finalize;
 pub mod hangul_fsm;
 pub mod jamo_mapper;
+pub mod pipeline;
 pub mod unicode;
@@ -0,0 +1,95 @@
+//! 변환 파이프라인 단계 추상화 (영→한 변환 / 변환 시도 판정 / 결과 검증)
+//!
+//! 기본 구현은 [`TwoSetConverter`], `AutoDetector`(판정), `KoreanValidator`(검증)이며,
+//! 테스트에서는 결정적인 mock을 주입해 워커 흐름을 엔진 없이 검증할 수 있습니다.
+
+use crate::core::converter::convert;
+
+/// 영→한 변환기
+pub trait Converter: Send + Sync {
+    /// 두벌식 키 시퀀스를 한글로 변환 (자모가 아닌 문자는 그대로)
+    fn convert(&self, keys: &str) -> String;
+}
+
+/// 자동 변환 시도 여부 판정 (변환 전 1차 필터)
+pub trait ConversionGate: Send + Sync {
+    /// 변환을 시도할 입력인지 (Err: 거부 사유)
+    fn check_input(&self, judge: &str) -> Result<(), String>;
+
+    /// 영어 단어처럼 보이는 입력인지 (결과 검증 시 참고)
+    fn looks_like_english(&self, judge: &str) -> bool;
+
+    /// 한글 입력 의도 신뢰도 (0.0 ~ 100.0)
+    fn confidence(&self, keys: &str) -> f32;
+
+    /// 변환하지 않는 단어인지 (영어 예외어/사용자 예외어)
+    fn is_blocked_word(&self, judge: &str) -> bool;
+
+    /// 판정에 필요한 한글 키가 충분한지 (점수 계산 전 빠른 거부)
+    fn has_enough_keys(&self, judge: &str) -> bool;
+
+    /// 실시간(1단계) 자동 변환 판정
+    fn should_convert_realtime(&self, judge: &str) -> bool;
+}
+
+/// 자동 변환 결과 검증 (변환 후 2차 판정)
+pub trait ConversionValidator: Send + Sync {
+    /// 변환 결과를 받아들일지 (Err: 거부 사유)
+    /// - english_like: 감지기가 영어 단어처럼 본 입력인지
+    fn validate(&self, judge: &str, english_like: bool) -> Result<(), String>;
+
+    /// 구조 검사만 수행 (빈도 점수 미달은 통과, 2단계 느린 변환용)
+    fn check_structure(&self, judge: &str) -> Result<(), String>;
+
+    /// 공백 없이 이어 친 긴 변환 결과의 단어 분할 지점 (앞부분 음절 수, 기본: 나누지 않음)
+    fn split_point(&self, _hangul: &str) -> Option<usize> {
+        None
+    }
+}
+
+/// 두벌식 변환기 (`core::converter::convert`)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TwoSetConverter;
+
+impl Converter for TwoSetConverter {
+    fn convert(&self, keys: &str) -> String {
+        convert(keys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detection::AutoDetector;
+    use crate::ngram::KoreanValidator;
+
+    #[test]
+    fn test_default_stages_behind_trait_objects() {
+        let converter: Box<dyn Converter> = Box::new(TwoSetConverter);
+        let gate: Box<dyn ConversionGate> = Box::new(AutoDetector::default());
+        let validator: Box<dyn ConversionValidator> = Box::new(KoreanValidator::new());
+
+        assert_eq!(converter.convert("dkssud"), "안녕");
+        assert_eq!(converter.convert("rk1"), "가1");
+
+        assert!(gate.check_input("dkssud").is_ok());
+        // 한글 키 부족 / 낱자모 입력 / 영어 예외어
+        assert!(gate.check_input("rk").is_err());
+        assert!(gate.check_input("zzz").is_err());
+        assert!(gate.check_input("the").is_err());
+        assert!(gate.looks_like_english("running"));
+        assert!(gate.confidence("dkssud") > gate.confidence("hello"));
+        assert!(gate.is_blocked_word("the"));
+        assert!(!gate.is_blocked_word("dkssud"));
+        assert!(!gate.has_enough_keys("r"));
+        assert!(gate.should_convert_realtime("dkssudgktpdy"));
+        assert!(!gate.should_convert_realtime("hello"));
+
+        assert!(validator.validate("dkssud", false).is_ok());
+        // 낱자모가 남는 결과는 거부
+        assert!(validator.validate("rkr", false).is_err());
+        // 점수 미달은 구조 검사를 통과
+        assert!(validator.check_structure("rhoTla").is_ok());
+        assert!(validator.check_structure("rkr").is_err());
+    }
+}
@@ -1,7 +1,23 @@
 //! 유니코드 한글 조합/분해 유틸리티
 
 /// 한글 음절 시작 코드포인트 (가)
-const HANGUL_SYLLABLE_BASE: u32 = 0xAC00;
+pub const HANGUL_SYLLABLE_BASE: u32 = 0xAC00;
+/// 한글 완성형 음절 개수 (19 × 21 × 28)
+pub const HANGUL_SYLLABLE_COUNT: u32 = CHOSEONG_COUNT * JUNGSEONG_COUNT * JONGSEONG_COUNT;
+/// 한글 음절 마지막 코드포인트 (힣, 0xD7A3)
+pub const HANGUL_SYLLABLE_LAST: u32 = HANGUL_SYLLABLE_BASE + HANGUL_SYLLABLE_COUNT - 1;
+
+/// 조합형 초성 자모 시작 코드포인트 (ᄀ, U+1100)
+const CHOSEONG_JAMO_BASE: u32 = 0x1100;
+/// 조합형 중성 자모 시작 코드포인트 (ᅡ, U+1161)
+const JUNGSEONG_JAMO_BASE: u32 = 0x1161;
+/// 조합형 종성 자모 기준 코드포인트 (종성 인덱스 1 = ᆨ, U+11A8)
+const JONGSEONG_JAMO_BASE: u32 = 0x11A7;
+
+/// 호환용 자모 시작 코드포인트 (ㄱ)
+pub const COMPAT_JAMO_START: u32 = 0x3131;
+/// 호환용 자모 마지막 코드포인트 (ㆎ)
+pub const COMPAT_JAMO_END: u32 = 0x318E;
 
 /// 초성 개수
 const CHOSEONG_COUNT: u32 = 19;
@@ -24,13 +40,23 @@ pub fn compose_syllable(choseong: u32, jungseong: u32, jongseong: u32) -> Option
     char::from_u32(code)
 }
 
+/// 완성형 한글 음절(가-힣)인지 확인
+pub fn is_hangul_syllable(c: char) -> bool {
+    (HANGUL_SYLLABLE_BASE..HANGUL_SYLLABLE_BASE + HANGUL_SYLLABLE_COUNT).contains(&(c as u32))
+}
+
+/// 호환용 자모(ㄱ-ㅎ, ㅏ-ㅣ 등 낱자모)인지 확인
+pub fn is_compat_jamo(c: char) -> bool {
+    (COMPAT_JAMO_START..=COMPAT_JAMO_END).contains(&(c as u32))
+}
+
 /// 완성형 한글을 초성/중성/종성 인덱스로 분해
 /// 반환: (초성 인덱스, 중성 인덱스, 종성 인덱스)
 pub fn decompose_syllable(c: char) -> Option<(u32, u32, u32)> {
-    let code = c as u32;
-    if !(HANGUL_SYLLABLE_BASE..=HANGUL_SYLLABLE_BASE + 11171).contains(&code) {
+    if !is_hangul_syllable(c) {
         return None;
     }
+    let code = c as u32;
     let offset = code - HANGUL_SYLLABLE_BASE;
     let jongseong = offset % JONGSEONG_COUNT;
     let jungseong = (offset / JONGSEONG_COUNT) % JUNGSEONG_COUNT;
@@ -38,6 +64,39 @@ pub fn decompose_syllable(c: char) -> Option<(u32, u32, u32)> {
     Some((choseong, jungseong, jongseong))
 }
 
+/// 완성형 음절을 조합형 자모(초성, 중성, 종성)로 분해
+/// 완성형 음절이 아니면 None
+pub fn syllable_to_conjoining_jamo(c: char) -> Option<(char, char, Option<char>)> {
+    let (cho, jung, jong) = decompose_syllable(c)?;
+    let lead = char::from_u32(CHOSEONG_JAMO_BASE + cho)?;
+    let vowel = char::from_u32(JUNGSEONG_JAMO_BASE + jung)?;
+    let tail = if jong == 0 {
+        None
+    } else {
+        Some(char::from_u32(JONGSEONG_JAMO_BASE + jong)?)
+    };
+    Some((lead, vowel, tail))
+}
+
+/// 문자열의 완성형 음절을 조합형 자모로 분해 (유니코드 NFD의 한글 음절 분해와 동일)
+/// 완성형이 아닌 문자(호환용 자모 포함)는 그대로 유지
+pub fn to_nfd(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() * 3);
+    for c in text.chars() {
+        match syllable_to_conjoining_jamo(c) {
+            Some((lead, vowel, tail)) => {
+                out.push(lead);
+                out.push(vowel);
+                if let Some(tail) = tail {
+                    out.push(tail);
+                }
+            }
+            None => out.push(c),
+        }
+    }
+    out
+}
+
 /// 두 중성을 복합 모음으로 조합
 /// 반환: 복합 모음 인덱스 (실패 시 None)
 pub fn combine_jungseong(first: u32, second: u32) -> Option<u32> {
@@ -224,6 +283,69 @@ mod tests {
         assert_eq!(decompose_syllable('1'), None);
     }
 
+    #[test]
+    fn test_syllable_range_boundaries() {
+        assert_eq!(HANGUL_SYLLABLE_COUNT, 11172);
+        assert_eq!(HANGUL_SYLLABLE_LAST, 0xD7A3);
+
+        // 경계 음절 '가'(0xAC00), '힣'(0xD7A3)
+        assert_eq!(decompose_syllable('가'), Some((0, 0, 0)));
+        assert_eq!(decompose_syllable('힣'), Some((18, 20, 27)));
+        assert!(is_hangul_syllable('\u{D7A3}'));
+
+        // 바로 바깥 코드포인트 (0xABFF, 0xD7A4)는 음절 아님
+        assert_eq!(decompose_syllable('\u{ABFF}'), None);
+        assert_eq!(decompose_syllable('\u{D7A4}'), None);
+        assert!(!is_hangul_syllable('\u{D7A4}'));
+
+        // compose/decompose 왕복
+        assert_eq!(compose_syllable(18, 20, 27), Some('힣'));
+    }
+
+    #[test]
+    fn test_syllable_to_conjoining_jamo() {
+        // 한 = ᄒ(U+1112) + ᅡ(U+1161) + ᆫ(U+11AB)
+        assert_eq!(
+            syllable_to_conjoining_jamo('한'),
+            Some(('\u{1112}', '\u{1161}', Some('\u{11AB}')))
+        );
+        // 가 = ᄀ(U+1100) + ᅡ(U+1161), 종성 없음
+        assert_eq!(
+            syllable_to_conjoining_jamo('가'),
+            Some(('\u{1100}', '\u{1161}', None))
+        );
+        // 힣 = ᄒ(U+1112) + ᅵ(U+1175) + ᇂ(U+11C2)
+        assert_eq!(
+            syllable_to_conjoining_jamo('힣'),
+            Some(('\u{1112}', '\u{1175}', Some('\u{11C2}')))
+        );
+        assert_eq!(syllable_to_conjoining_jamo('ㄱ'), None);
+        assert_eq!(syllable_to_conjoining_jamo('a'), None);
+    }
+
+    #[test]
+    fn test_to_nfd() {
+        // 유니코드 NFD 기대값
+        assert_eq!(
+            to_nfd("한글"),
+            "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}"
+        );
+        assert_eq!(to_nfd("가1a"), "\u{1100}\u{1161}1a");
+        // 호환용 자모는 정규 분해가 없으므로 그대로
+        assert_eq!(to_nfd("ㄱㅏ"), "ㄱㅏ");
+        assert_eq!(to_nfd(""), "");
+    }
+
+    #[test]
+    fn test_is_compat_jamo() {
+        assert!(is_compat_jamo('ㄱ'));
+        assert!(is_compat_jamo('ㅣ'));
+        assert!(is_compat_jamo('\u{318E}'));
+        assert!(!is_compat_jamo('\u{3130}'));
+        assert!(!is_compat_jamo('\u{318F}'));
+        assert!(!is_compat_jamo('가'));
+    }
+
     #[test]
     fn test_combine_jungseong() {
         assert_eq!(combine_jungseong(8, 0), Some(9)); // ㅗ + ㅏ = ㅘ
@@ -2,12 +2,23 @@
 //!
 //! 휴리스틱 기반으로 입력이 한글인지 영어인지 판별합니다.
 
+use crate::core::converter::convert;
+use crate::core::jamo_mapper::{is_consonant, is_vowel, map_to_jamo};
+use crate::core::pipeline::ConversionGate;
+use crate::core::unicode::is_compat_jamo;
+use crate::ngram::common_syllable_ratio;
+
 use super::patterns::{
-    is_consonant_key, is_vowel_key, COMMON_ENGLISH_WORDS, ENGLISH_BIGRAMS, HANGUL_BIGRAMS,
+    is_consonant_key, is_shift_jamo_key, is_vowel_key, COMMON_ENGLISH_WORDS, ENGLISH_BIGRAMS,
+    EXPRESSIVE_JAMO_PATTERNS, HANGUL_BIGRAMS,
 };
-use super::validator::has_excessive_jamo;
+use super::repair::repair_candidates;
+use super::validator::{has_excessive_jamo, has_incomplete_jamo};
 use std::collections::HashSet;
 
+/// 실시간 변환 결과의 최소 흔한 음절 비율 (미만이면 드문 음절투성이로 보고 거부)
+pub const MIN_COMMON_SYLLABLE_RATIO: f32 = 0.3;
+
 /// 자동 감지기 설정
 #[derive(Debug, Clone)]
 pub struct AutoDetectorConfig {
@@ -38,6 +49,8 @@ pub struct AutoDetector {
     config: AutoDetectorConfig,
     enabled: bool,
     never_convert_words: HashSet<String>,
+    /// 낱자모만으로 조합되는 입력은 변환 시도 없이 통과
+    pass_through_jamo_only: bool,
 }
 
 impl AutoDetector {
@@ -47,6 +60,7 @@ impl AutoDetector {
             config,
             enabled: true,
             never_convert_words: HashSet::new(),
+            pass_through_jamo_only: true,
         }
     }
 
@@ -65,6 +79,18 @@ impl AutoDetector {
         self.enabled
     }
 
+    /// 현재 감지 설정
+    pub fn config(&self) -> &AutoDetectorConfig {
+        &self.config
+    }
+
+    /// 신뢰도 기준/최소 길이 변경 (앱 프로필 전환 시 사용)
+    pub fn set_sensitivity(&mut self, threshold: f32, realtime_threshold: f32, min_length: usize) {
+        self.config.threshold = threshold;
+        self.config.realtime_threshold = realtime_threshold;
+        self.config.min_length = min_length;
+    }
+
     /// 사용자 정의 자동 변환 제외 단어 설정
     pub fn set_never_convert_words(&mut self, words: Vec<String>) {
         self.never_convert_words = words
@@ -74,6 +100,13 @@ impl AutoDetector {
             .collect();
     }
 
+    /// 사용자 정의 자동 변환 제외 단어 (정렬됨)
+    pub fn never_convert_words(&self) -> Vec<String> {
+        let mut words: Vec<String> = self.never_convert_words.iter().cloned().collect();
+        words.sort();
+        words
+    }
+
     /// 자동 변환에서 제외할 영문 단어인지 확인
     pub fn is_blocked_english_word(&self, buffer: &str) -> bool {
         if buffer.is_empty() || !buffer.is_ascii() {
@@ -85,13 +118,50 @@ impl AutoDetector {
             || self.never_convert_words.contains(lower.as_str())
     }
 
+    /// 낱자모 입력 통과 여부 설정 (false면 다른 입력과 같이 판정)
+    pub fn set_pass_through_jamo_only(&mut self, enabled: bool) {
+        self.pass_through_jamo_only = enabled;
+    }
+
+    /// 낱자모 입력 통과 여부
+    pub fn is_pass_through_jamo_only(&self) -> bool {
+        self.pass_through_jamo_only
+    }
+
+    /// 변환을 시도하지 않고 통과시킬 낱자모 입력인지 ("zzz" → ㅋㅋㅋ, "bb" → ㅠㅠ)
+    pub fn is_jamo_pass_through(&self, buffer: &str) -> bool {
+        self.pass_through_jamo_only && classify_jamo_only(buffer).is_some()
+    }
+
     /// 자동 변환을 차단해야 하는 영어 입력 패턴인지 확인
     pub fn looks_like_english_word(&self, buffer: &str) -> bool {
         self.is_blocked_english_word(buffer) || has_english_pattern(buffer)
     }
 
+    /// 한글 자모 키가 최소 길이 이상 포함됐는지 빠르게 확인 (변환/N-gram 검증 전 pre-filter)
+    /// 숫자/기호 등 자모에 매핑되지 않는 키는 세지 않음
+    pub fn has_enough_hangul_keys(&self, buffer: &str) -> bool {
+        let min = self.config.min_length.max(1);
+        buffer
+            .chars()
+            .filter(|&c| map_to_jamo(c).is_some())
+            .take(min)
+            .count()
+            == min
+    }
+
     /// 입력 버퍼가 한글로 변환되어야 하는지 판별 (Space/Enter 시 사용)
     pub fn should_convert(&self, buffer: &str) -> bool {
+        self.should_convert_with_threshold(buffer, self.config.threshold)
+    }
+
+    /// 주어진 신뢰도 기준으로 변환 여부 판별 (필터는 동일)
+    pub fn should_convert_with_threshold(&self, buffer: &str, threshold: f32) -> bool {
+        self.passes_threshold(buffer, &convert(buffer), threshold)
+    }
+
+    /// 필터와 신뢰도 기준 판정 (converted: 버퍼의 변환 결과, 낱자모 판정에 재사용)
+    fn passes_threshold(&self, buffer: &str, converted: &str, threshold: f32) -> bool {
         if !self.enabled {
             return false;
         }
@@ -100,8 +170,24 @@ impl AutoDetector {
             return false;
         }
 
-        // 영어 단어 필터: 흔한 영어 단어는 변환하지 않음
-        if self.is_blocked_english_word(buffer) {
+        // 영어 단어/URL 필터: 흔한 영어 단어와 URL/이메일은 변환하지 않음
+        if self.is_blocked_english_word(buffer) || has_url_pattern(buffer) {
+            return false;
+        }
+
+        // 낱자모 입력(ㅋㅋㅋ, ㅠㅠ)은 변환을 시도하지 않음
+        let jamo_only = classify_converted_jamo_only(buffer, converted);
+        if self.pass_through_jamo_only && jamo_only.is_some() {
+            return false;
+        }
+
+        // 자음/모음만의 나열(ㄱㄴㄷ, ㅏㅓㅗ)은 pass-through 설정과 무관하게 거부
+        if is_bare_jamo_kind(buffer, jamo_only) {
+            return false;
+        }
+
+        // 변수명/약어처럼 Shift 자모 키가 아닌 대문자가 많은 입력은 신뢰도와 무관하게 거부
+        if has_excessive_uppercase(buffer) {
             return false;
         }
 
@@ -114,9 +200,9 @@ impl AutoDetector {
 
         // 짧은 입력(3~4자)에 대해 threshold +10점 추가 요구 (오탐 방지)
         let threshold = if buffer.len() <= 4 {
-            self.config.threshold + 10.0
+            threshold + 10.0
         } else {
-            self.config.threshold
+            threshold
         };
 
         confidence >= threshold
@@ -139,37 +225,44 @@ impl AutoDetector {
     }
 
     /// 실시간 변환 여부 판별 (debounce 타이머 만료 시 사용)
-    /// 더 높은 신뢰도와 영어 단어 필터링 적용
+    /// 더 높은 신뢰도와 영어 단어 필터링 적용, 변환 결과가 드문 음절투성이면 거부
     pub fn should_convert_realtime(&self, buffer: &str) -> bool {
-        if !self.enabled {
-            return false;
-        }
+        self.should_convert_realtime_with_threshold(buffer, self.config.realtime_threshold)
+    }
 
-        if buffer.len() < self.config.min_length {
-            return false;
-        }
+    /// 주어진 신뢰도 기준으로 실시간 변환 여부 판별 (shadow 평가에서 대안 기준 비교용)
+    pub fn should_convert_realtime_with_threshold(&self, buffer: &str, threshold: f32) -> bool {
+        let converted = convert(buffer);
+        self.passes_threshold(buffer, &converted, threshold)
+            && common_syllable_ratio(&converted) >= MIN_COMMON_SYLLABLE_RATIO
+    }
 
-        // 영어 단어 필터: 흔한 영어 단어는 변환하지 않음
-        if self.is_blocked_english_word(buffer) {
-            return false;
+    /// 변환이 거부된 입력의 인접 키 오타 교정 후보 (미리보기/수동 변환 제안용)
+    ///
+    /// 마지막 1~2개 키를 인접 키로 바꾼 후보 중 변환 판정과 음절 검사를 통과하는
+    /// 신뢰도가 가장 높은 키 시퀀스를 반환. 입력이 그대로 변환되면 교정하지 않음
+    pub fn repair_candidate(&self, input: &str) -> Option<String> {
+        if self.should_convert(input) {
+            return None;
         }
-
-        let confidence = self.get_confidence(buffer);
-
-        // 영어 패턴 필터: 매우 높은 신뢰도(90+)가 아니면 영어 패턴 감지 시 거부
-        if confidence < 90.0 && has_english_pattern(buffer) {
-            return false;
+        let mut best: Option<(f32, String)> = None;
+        for candidate in repair_candidates(input) {
+            if !self.should_convert(&candidate) {
+                continue;
+            }
+            let converted = convert(&candidate);
+            if has_incomplete_jamo(&converted)
+                || common_syllable_ratio(&converted) < MIN_COMMON_SYLLABLE_RATIO
+            {
+                continue;
+            }
+            let confidence = self.get_confidence(&candidate);
+            // 같은 신뢰도면 먼저 만든(적게 바꾼) 후보 유지
+            if best.as_ref().is_none_or(|(top, _)| confidence > *top) {
+                best = Some((confidence, candidate));
+            }
         }
-
-        // 짧은 입력(3~4자)에 대해 threshold +10점 추가 요구 (오탐 방지)
-        let threshold = if buffer.len() <= 4 {
-            self.config.realtime_threshold + 10.0
-        } else {
-            self.config.realtime_threshold
-        };
-
-        // 높은 신뢰도 요구
-        confidence >= threshold
+        best.map(|(_, candidate)| candidate)
     }
 
     /// debounce 타이머 값 반환
@@ -249,13 +342,21 @@ impl AutoDetector {
         let mut both_match = 0; // 한/영 모두 매칭 (겹침)
         let mut total_bigrams = 0;
 
-        let chars: Vec<char> = buffer.chars().collect();
-        for window in chars.windows(2) {
-            let bigram: String = window.iter().collect();
+        // 연속한 두 글자를 문자열 슬라이스로 조회 (바이그램마다 String 할당하지 않음)
+        let mut bounds = buffer
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain(std::iter::once(buffer.len()));
+        let (Some(mut start), Some(mut mid)) = (bounds.next(), bounds.next()) else {
+            return 0.0;
+        };
+        for end in bounds {
+            let bigram = &buffer[start..end];
+            (start, mid) = (mid, end);
             total_bigrams += 1;
 
-            let is_hangul = HANGUL_BIGRAMS.contains(bigram.as_str());
-            let is_english = ENGLISH_BIGRAMS.contains(bigram.as_str());
+            let is_hangul = HANGUL_BIGRAMS.contains(bigram);
+            let is_english = ENGLISH_BIGRAMS.contains(bigram);
 
             match (is_hangul, is_english) {
                 (true, true) => both_match += 1,
@@ -357,24 +458,153 @@ impl Default for AutoDetector {
     }
 }
 
+impl ConversionGate for AutoDetector {
+    /// 워커 자동 변환 1차 필터: 한글 키 부족, 낱자모 입력, 영어 예외어
+    fn check_input(&self, judge: &str) -> Result<(), String> {
+        if !self.has_enough_hangul_keys(judge) {
+            return Err("한글 키 부족".to_string());
+        }
+        if let Some(kind) = classify_jamo_only(judge).filter(|_| self.pass_through_jamo_only) {
+            return Err(format!("낱자모 입력 {:?}", kind));
+        }
+        if is_bare_jamo_sequence(judge) {
+            return Err("자음/모음 나열".to_string());
+        }
+        if self.is_blocked_english_word(judge) {
+            return Err("영어 예외어".to_string());
+        }
+        Ok(())
+    }
+
+    fn looks_like_english(&self, judge: &str) -> bool {
+        self.looks_like_english_word(judge)
+    }
+
+    fn confidence(&self, keys: &str) -> f32 {
+        self.get_confidence(keys)
+    }
+
+    fn is_blocked_word(&self, judge: &str) -> bool {
+        self.is_blocked_english_word(judge)
+    }
+
+    fn has_enough_keys(&self, judge: &str) -> bool {
+        self.has_enough_hangul_keys(judge)
+    }
+
+    fn should_convert_realtime(&self, judge: &str) -> bool {
+        AutoDetector::should_convert_realtime(self, judge)
+    }
+}
+
+/// 낱자모만으로 조합되는 입력 분류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JamoOnlyInput {
+    /// 감정 표현/초성 약어 ("zzz" → ㅋㅋㅋ, "dz" → ㅇㅋ)
+    Expressive,
+    /// 같은 키 반복 ("ccc" → ㅊㅊㅊ)
+    Repeated,
+    /// 그 밖의 낱자모 나열 ("rsef" → ㄱㄴㄷㄹ)
+    Jamo,
+}
+
+/// 변환 결과가 음절 없이 낱자모로만 이루어지는 입력인지 분류 (아니면 None)
+pub fn classify_jamo_only(buffer: &str) -> Option<JamoOnlyInput> {
+    classify_converted_jamo_only(buffer, &convert(buffer))
+}
+
+/// [`classify_jamo_only`]과 같은 분류 (converted: 이미 계산한 버퍼의 변환 결과)
+fn classify_converted_jamo_only(buffer: &str, converted: &str) -> Option<JamoOnlyInput> {
+    if buffer.chars().count() < 2 || !converted.chars().all(is_compat_jamo) {
+        return None;
+    }
+
+    let is_expressive = EXPRESSIVE_JAMO_PATTERNS.iter().any(|pattern| {
+        converted.starts_with(pattern) && converted.chars().all(|c| pattern.contains(c))
+    });
+    if is_expressive {
+        Some(JamoOnlyInput::Expressive)
+    } else if buffer.chars().skip(1).all(|c| buffer.starts_with(c)) {
+        Some(JamoOnlyInput::Repeated)
+    } else {
+        Some(JamoOnlyInput::Jamo)
+    }
+}
+
+/// 자음 키만 또는 모음 키만으로 된 입력인지 ("rsef" → ㄱㄴㄷㄹ, "kjh" → ㅏㅓㅗ)
+pub fn is_single_jamo_class(buffer: &str) -> bool {
+    buffer.chars().count() >= 2
+        && (buffer.chars().all(is_consonant) || buffer.chars().all(is_vowel))
+}
+
+/// 실제 단어가 될 수 없는 자음/모음 나열인지 (자음 키만/모음 키만이거나 변환 결과가 모두 낱자모)
+///
+/// 감정 표현(ㅋㅋ, ㅠㅠ)은 제외 — `pass_through_jamo_only` 설정이 따로 판단
+pub fn is_bare_jamo_sequence(buffer: &str) -> bool {
+    is_bare_jamo_kind(buffer, classify_jamo_only(buffer))
+}
+
+fn is_bare_jamo_kind(buffer: &str, jamo_only: Option<JamoOnlyInput>) -> bool {
+    match jamo_only {
+        Some(JamoOnlyInput::Expressive) => false,
+        Some(_) => true,
+        None => is_single_jamo_class(buffer),
+    }
+}
+
+/// 비한글 대문자 비율 상한 (초과 시 자동 변환 거부)
+pub const NON_HANGUL_UPPERCASE_RATIO_LIMIT: f32 = 0.3;
+
+/// 영문자 중 비한글 대문자(Shift 자모 키 R E Q T W O P 제외) 비율 (영문자가 없으면 0)
+pub fn non_hangul_uppercase_ratio(buffer: &str) -> f32 {
+    let mut letters = 0usize;
+    let mut uppers = 0usize;
+    for c in buffer.chars().filter(char::is_ascii_alphabetic) {
+        letters += 1;
+        if c.is_ascii_uppercase() && !is_shift_jamo_key(c) {
+            uppers += 1;
+        }
+    }
+    if letters == 0 {
+        return 0.0;
+    }
+    uppers as f32 / letters as f32
+}
+
+/// 비한글 대문자 비율이 상한을 넘는지 (예: "getUserID", "URLs")
+pub fn has_excessive_uppercase(buffer: &str) -> bool {
+    non_hangul_uppercase_ratio(buffer) > NON_HANGUL_UPPERCASE_RATIO_LIMIT
+}
+
 /// 영어 패턴 감지 — 다음 패턴 중 하나라도 해당하면 자동 변환 거부
 /// - 전체 대문자 2자 이상 (약어: "OK", "PDF", "API")
-/// - CamelCase 패턴 (변수명: "onClick", "setState")
+/// - CamelCase 패턴 (변수명: "onClick", "setState"), 단 Shift 자모 키로 완성 음절만 만드는 입력은 제외
 /// - 영어 접미사 (-tion, -ment, -ness, -ing, -able, -ful, -less)
 /// - 5자 이상에서 영어 접두사 (un-, re-, pre-, dis-, mis-)
 fn has_english_pattern(buffer: &str) -> bool {
+    if has_url_pattern(buffer) {
+        return true;
+    }
+
     // 전체 대문자 2자 이상 (약어)
     if buffer.len() >= 2 && buffer.chars().all(|c| c.is_ascii_uppercase()) {
         return true;
     }
 
     // CamelCase: 소문자 시작 후 대문자 포함 (예: onClick, setState)
+    // Shift 조합 자모 키(R→ㄲ, O→ㅒ, P→ㅖ 등)만 대문자인 경우는
+    // 변환 결과가 완성 음절로만 이루어지면 한글 입력으로 본다 (예: rPtks → 계산)
     let chars: Vec<char> = buffer.chars().collect();
-    if chars.len() >= 3 {
-        let starts_lower = chars[0].is_ascii_lowercase();
-        let has_upper = chars[1..].iter().any(|c| c.is_ascii_uppercase());
-        if starts_lower && has_upper {
-            return true;
+    if chars.len() >= 3 && chars[0].is_ascii_lowercase() {
+        let mut uppers = chars[1..]
+            .iter()
+            .filter(|c| c.is_ascii_uppercase())
+            .peekable();
+        if uppers.peek().is_some() {
+            let only_shift_jamo = uppers.all(|&c| is_shift_jamo_key(c));
+            if !only_shift_jamo || has_incomplete_jamo(&convert(buffer)) {
+                return true;
+            }
         }
     }
 
@@ -402,6 +632,59 @@ fn has_english_pattern(buffer: &str) -> bool {
     false
 }
 
+/// URL/이메일에서 흔한 최상위 도메인
+const URL_TLDS: [&str; 14] = [
+    "com", "net", "org", "io", "dev", "app", "co", "kr", "me", "ai", "gov", "edu", "jp", "us",
+];
+
+/// URL/이메일/경로 입력인지 확인 (원문 버퍼 기준, 기호 포함)
+///
+/// 점 하나만으로는 거부하지 않고 스킴(`http`, `www.`), `://`, `@` 앞 영문 ID,
+/// 알려진 TLD, 경로 시작(`/`, `./`, `~/`) 같은 신호가 있을 때만 true
+pub fn has_url_pattern(buffer: &str) -> bool {
+    let lower = buffer.to_ascii_lowercase();
+
+    if lower.contains("://")
+        || lower.starts_with("www.")
+        || lower.starts_with("http:")
+        || lower.starts_with("https:")
+    {
+        return true;
+    }
+
+    if ["/", "./", "../", "~/"]
+        .iter()
+        .any(|prefix| lower.starts_with(prefix) && lower.len() > prefix.len())
+    {
+        return true;
+    }
+
+    // 이메일: '@' 앞이 영문/숫자 ID이고 뒤에 도메인 입력이 시작됨
+    if let Some((local, domain)) = lower.split_once('@') {
+        if !local.is_empty()
+            && domain.starts_with(|c: char| c.is_ascii_alphanumeric())
+            && local
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-' | '+'))
+        {
+            return true;
+        }
+    }
+
+    // 도메인: 경로 앞 호스트가 "이름.TLD" 형태
+    let host = lower.split('/').next().unwrap_or_default();
+    match host.rsplit_once('.') {
+        Some((name, tld)) => {
+            !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-'))
+                && URL_TLDS.contains(&tld)
+        }
+        None => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -413,6 +696,76 @@ mod tests {
         assert!(!detector.should_convert("rk")); // 2글자도 최소 길이 미달
     }
 
+    #[test]
+    fn test_classify_jamo_only() {
+        // 감정 표현 (반복 포함)
+        assert_eq!(classify_jamo_only("zzz"), Some(JamoOnlyInput::Expressive));
+        assert_eq!(classify_jamo_only("bb"), Some(JamoOnlyInput::Expressive));
+        assert_eq!(classify_jamo_only("gggg"), Some(JamoOnlyInput::Expressive));
+        assert_eq!(classify_jamo_only("dzzz"), Some(JamoOnlyInput::Expressive));
+        // 같은 키 반복 (감정 표현 목록 밖)
+        assert_eq!(classify_jamo_only("ccc"), Some(JamoOnlyInput::Repeated));
+        // 그 밖의 낱자모 나열
+        assert_eq!(classify_jamo_only("rsef"), Some(JamoOnlyInput::Jamo));
+        assert_eq!(classify_jamo_only("zzgg"), Some(JamoOnlyInput::Jamo));
+
+        // 음절이 하나라도 조합되면 해당 없음
+        assert_eq!(classify_jamo_only("dkssud"), None);
+        assert_eq!(classify_jamo_only("zzzk"), None);
+        assert_eq!(classify_jamo_only("zz1"), None);
+        assert_eq!(classify_jamo_only("z"), None);
+        assert_eq!(classify_jamo_only(""), None);
+    }
+
+    #[test]
+    fn test_jamo_only_input_passes_through() {
+        let mut detector = AutoDetector::with_defaults();
+        for buffer in ["zzz", "zzzz", "bbb", "ccc", "rsef"] {
+            assert!(detector.is_jamo_pass_through(buffer), "{}", buffer);
+            assert!(!detector.should_convert(buffer), "{}", buffer);
+            assert!(!detector.should_convert_realtime(buffer), "{}", buffer);
+        }
+        assert!(!detector.is_jamo_pass_through("dkssud"));
+
+        detector.set_pass_through_jamo_only(false);
+        assert!(!detector.is_jamo_pass_through("zzz"));
+    }
+
+    #[test]
+    fn test_bare_jamo_sequence_rejected() {
+        // 자음 키만 / 모음 키만
+        assert!(is_single_jamo_class("rsef"));
+        assert!(is_single_jamo_class("kjh"));
+        assert!(is_single_jamo_class("RQT"));
+        assert!(!is_single_jamo_class("dkssud"));
+        assert!(!is_single_jamo_class("rs1"));
+        assert!(!is_single_jamo_class("r"));
+
+        // 감정 표현은 pass-through 설정에 맡김
+        assert!(is_bare_jamo_sequence("rsef"));
+        assert!(is_bare_jamo_sequence("kjh"));
+        assert!(is_bare_jamo_sequence("ccc"));
+        assert!(!is_bare_jamo_sequence("zzz"));
+        assert!(!is_bare_jamo_sequence("bb"));
+        assert!(!is_bare_jamo_sequence("dkssud"));
+
+        // pass-through를 꺼도 자음/모음 나열은 거부, 감정 표현은 일반 판정으로
+        let mut detector = AutoDetector::with_defaults();
+        detector.set_pass_through_jamo_only(false);
+        for buffer in ["rsef", "rsgw", "kjh", "hkjl", "ccc"] {
+            assert!(!detector.should_convert(buffer), "{}", buffer);
+            assert!(!detector.should_convert_realtime(buffer), "{}", buffer);
+            assert_eq!(
+                detector.check_input(buffer),
+                Err("자음/모음 나열".to_string()),
+                "{}",
+                buffer
+            );
+        }
+        assert!(detector.check_input("zzz").is_ok());
+        assert!(detector.check_input("dkssud").is_ok());
+    }
+
     #[test]
     fn test_should_convert_hangul_pattern() {
         let detector = AutoDetector::with_defaults();
@@ -501,12 +854,42 @@ mod tests {
         assert!(!detector.should_convert_realtime("rk")); // 2글자
     }
 
+    #[test]
+    fn test_has_enough_hangul_keys_boundary() {
+        let mut detector = AutoDetector::with_defaults();
+        assert!(!detector.has_enough_hangul_keys(""));
+        assert!(!detector.has_enough_hangul_keys("rk"));
+        assert!(detector.has_enough_hangul_keys("rks"));
+        // 비매핑 키는 세지 않음
+        assert!(!detector.has_enough_hangul_keys("12345!@#"));
+        assert!(!detector.has_enough_hangul_keys("r1k2"));
+        assert!(detector.has_enough_hangul_keys("r1k2s"));
+        // Shift 자모 키도 한글 키
+        assert!(detector.has_enough_hangul_keys("QWE"));
+
+        // 최소 길이는 민감도 설정을 따름
+        detector.set_sensitivity(70.0, 80.0, 2);
+        assert!(detector.has_enough_hangul_keys("rk"));
+        assert!(!detector.has_enough_hangul_keys("r!"));
+    }
+
     #[test]
     fn test_debounce_ms() {
         let detector = AutoDetector::with_defaults();
         assert_eq!(detector.debounce_ms(), 500);
     }
 
+    #[test]
+    fn test_set_sensitivity_changes_min_length() {
+        let mut detector = AutoDetector::with_defaults();
+        assert!(detector.should_convert("dkssud"));
+
+        detector.set_sensitivity(70.0, 80.0, 10);
+        assert_eq!(detector.config().min_length, 10);
+        assert!(!detector.should_convert("dkssud"));
+        assert!(!detector.should_convert_realtime("dkssud"));
+    }
+
     #[test]
     fn test_should_convert_filters_english_words() {
         let detector = AutoDetector::with_defaults();
@@ -529,6 +912,20 @@ mod tests {
         assert!(!detector.should_convert_realtime("figma"));
     }
 
+    #[test]
+    fn test_embedded_english_words_are_blocked() {
+        let detector = AutoDetector::with_defaults();
+        for word in [
+            "people", "because", "always", "today", "video", "special", "girl",
+        ] {
+            assert!(detector.is_blocked_english_word(word), "{}", word);
+            assert!(!detector.should_convert(word), "{}", word);
+            assert!(!detector.should_convert_realtime(word), "{}", word);
+        }
+        // 한글 입력은 영향 없음
+        assert!(detector.should_convert("dkssudgktpdy"));
+    }
+
     #[test]
     fn test_custom_never_convert_words_are_blocked() {
         let mut detector = AutoDetector::with_defaults();
@@ -570,6 +967,47 @@ mod tests {
         assert!(has_english_pattern("HTTP"));
     }
 
+    #[test]
+    fn test_non_hangul_uppercase_ratio() {
+        // 소문자 한글 입력과 Shift 자모 키(쌍자음, ㅒ, ㅖ)는 0
+        assert_eq!(non_hangul_uppercase_ratio("dkssud"), 0.0);
+        assert_eq!(non_hangul_uppercase_ratio("rPtks"), 0.0);
+        assert_eq!(non_hangul_uppercase_ratio("QWERTOP"), 0.0);
+        // 영문자가 없으면 0
+        assert_eq!(non_hangul_uppercase_ratio(""), 0.0);
+        assert_eq!(non_hangul_uppercase_ratio("123!"), 0.0);
+        // 숫자/기호는 분모에서 제외: A / (A, b)
+        assert_eq!(non_hangul_uppercase_ratio("A1b!"), 0.5);
+        // Shift 자모 키는 분모에만 포함: D / (g, e, t, D)
+        assert_eq!(non_hangul_uppercase_ratio("getD"), 0.25);
+        // T, P는 Shift 자모 키이므로 H만 비한글 대문자
+        assert_eq!(non_hangul_uppercase_ratio("HTTP"), 0.25);
+    }
+
+    #[test]
+    fn test_has_excessive_uppercase_boundary() {
+        // 정확히 30%는 허용, 초과부터 거부
+        assert!(!has_excessive_uppercase("Abcdefghij1"));
+        assert!(!has_excessive_uppercase("ABCdefghij"));
+        assert!(has_excessive_uppercase("ABCDefghij"));
+        assert!(has_excessive_uppercase("getUserID"));
+        assert!(has_excessive_uppercase("URLs"));
+        // 쌍자음/ㅒ/ㅖ 위주 입력은 거부하지 않음
+        assert!(!has_excessive_uppercase("QkQk"));
+        assert!(!has_excessive_uppercase("dkTsms"));
+    }
+
+    #[test]
+    fn test_should_convert_rejects_excessive_uppercase() {
+        let detector = AutoDetector::with_defaults();
+        // 기본 한글 입력은 영향 없음
+        assert!(detector.should_convert("dkssudgktpdy"));
+        assert!(detector.should_convert("rPtksgkqslek"));
+        // 한글 키 사이에 비한글 대문자가 많으면 거부
+        assert!(!detector.should_convert("dkssudHKLM"));
+        assert!(!detector.should_convert_realtime("dkssudHKLM"));
+    }
+
     #[test]
     fn test_has_english_pattern_camelcase() {
         // CamelCase 변수명
@@ -630,4 +1068,97 @@ mod tests {
         assert!(!detector.should_convert("running")); // -ing 접미사
         assert!(!detector.should_convert("disable")); // dis- 접두사
     }
+
+    #[test]
+    fn test_shift_vowel_keys_are_not_camelcase() {
+        let detector = AutoDetector::with_defaults();
+
+        // ㅒ(O)/ㅖ(P)가 들어간 한글 입력은 CamelCase로 오인하지 않음
+        assert!(!has_english_pattern("rPtks")); // 계산
+        assert!(!has_english_pattern("rOsms")); // 걔는
+        assert!(detector.should_convert_realtime("rPtks"));
+        assert!(detector.should_convert_realtime("dPdir")); // 예약
+        assert!(detector.should_convert("dOrl")); // 얘기
+
+        // 변환 결과에 낱자모가 남으면 여전히 CamelCase
+        assert!(has_english_pattern("isOpen"));
+        assert!(has_english_pattern("onPress"));
+        assert!(has_english_pattern("getRect"));
+    }
+
+    #[test]
+    fn test_has_url_pattern_rejects_urls_and_emails() {
+        for buffer in [
+            "github.com/user",
+            "naver.com",
+            "https://koing",
+            "http:",
+            "www.",
+            "www.google",
+            "user@gmail",
+            "abc.def@x",
+            "crates.io/koing",
+            "~/src",
+            "./run",
+        ] {
+            assert!(has_url_pattern(buffer), "{}", buffer);
+        }
+    }
+
+    #[test]
+    fn test_has_url_pattern_allows_hangul_input() {
+        // 한글 입력 중 우연한 점/기호는 신호가 아님
+        for buffer in [
+            "dkssud",
+            "dkssud.",
+            "gksrmf.rk",
+            "1.5",
+            "tkfkd@",
+            "@dkssud",
+            "/",
+        ] {
+            assert!(!has_url_pattern(buffer), "{}", buffer);
+        }
+    }
+
+    #[test]
+    fn test_url_buffers_are_not_converted() {
+        let detector = AutoDetector::with_defaults();
+        assert!(detector.looks_like_english_word("naver.com"));
+        assert!(!detector.should_convert("gksrmf.com"));
+        assert!(!detector.should_convert_realtime("dkssud@naver"));
+        assert!(detector.should_convert("dkssud"));
+    }
+
+    #[test]
+    fn test_realtime_rejects_rare_syllable_results() {
+        let detector = AutoDetector::default();
+        let realtime = detector.config().realtime_threshold;
+        // 신뢰도는 통과하지만 변환 결과(퍄퍙)가 드문 음절투성이
+        assert!(detector.should_convert_with_threshold("vivid", realtime));
+        assert!(common_syllable_ratio(&convert("vivid")) < MIN_COMMON_SYLLABLE_RATIO);
+        assert!(!detector.should_convert_realtime("vivid"));
+        // 흔한 음절로 된 한글은 그대로 변환
+        for keys in ["gktpdy", "rkqwkrl", "xkqtm"] {
+            assert!(detector.should_convert_realtime(keys), "{}", keys);
+        }
+    }
+
+    #[test]
+    fn test_repair_candidate_fixes_adjacent_key_typos() {
+        let detector = AutoDetector::default();
+        // 학교 → 학ㄱㅅ (y 대신 t), 최고 → 쵝ㅎ (h 대신 g)
+        for (typo, expected) in [("gkrrt", "gkrry"), ("chlrg", "chlrh")] {
+            assert!(!detector.should_convert(typo), "{}", typo);
+            assert_eq!(
+                detector.repair_candidate(typo).as_deref(),
+                Some(expected),
+                "{}",
+                typo
+            );
+        }
+        // 그대로 변환되는 입력/자모가 아닌 입력은 교정하지 않음
+        assert_eq!(detector.repair_candidate("dkssud"), None);
+        assert_eq!(detector.repair_candidate("1234"), None);
+    }
 }
@@ -0,0 +1,119 @@
+//! 변환 방향 판정 (한글 모드에서 잘못 친 영단어 → 영문 역변환)
+//!
+//! 한글 입력 소스에서 입력된 키 시퀀스가 영단어이고, 조합된 한글이
+//! 한국어로는 부자연스러울 때만 영문으로 되돌립니다.
+
+use crate::core::converter::convert;
+use crate::core::jamo_mapper::map_to_jamo;
+
+/// 역변환 최소 키 수 (짧은 입력은 영단어/한글 모두 흔해 오판 위험)
+pub const REVERSE_MIN_KEYS: usize = 3;
+
+/// 역변환 후보 키 시퀀스인지 (영문자만, 최소 길이 이상)
+pub fn is_reverse_candidate(keys: &str) -> bool {
+    keys.chars().count() >= REVERSE_MIN_KEYS && keys.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+/// 역변환 결정
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReversePlan {
+    /// 화면의 한글 구간(`segment`)을 영문(`english`)으로 교체
+    Replace { segment: String, english: String },
+    /// 영단어 후보가 아님
+    NotEnglish,
+    /// 한글로도 자연스러움 (의도한 한글일 수 있어 유지)
+    NaturalKorean,
+}
+
+/// 한글 모드에서 키 시퀀스가 화면에 남긴 텍스트
+///
+/// Shift 자모 키가 아닌 대문자는 한글 자판에서 소문자와 같은 자모를 입력함
+fn screen_hangul(keys: &str) -> String {
+    let typed: String = keys
+        .chars()
+        .map(|c| {
+            if c.is_ascii_uppercase() && map_to_jamo(c).is_none() {
+                c.to_ascii_lowercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+    convert(&typed)
+}
+
+/// 역변환 경로 결정
+///
+/// - `delimiter`: 역변환을 트리거한 구분자 (화면에 이미 입력됨, 교체 구간에 포함)
+/// - `is_english_word`: 키 시퀀스가 영단어 사전/사용자 예외어에 있는지
+/// - `hangul_natural`: 조합된 한글이 한국어 검증(음절 구조/n-gram)을 통과하는지
+pub fn plan_reverse(
+    keys: &str,
+    delimiter: &str,
+    is_english_word: bool,
+    hangul_natural: bool,
+) -> ReversePlan {
+    if !is_reverse_candidate(keys) || !is_english_word {
+        return ReversePlan::NotEnglish;
+    }
+    if hangul_natural {
+        return ReversePlan::NaturalKorean;
+    }
+    ReversePlan::Replace {
+        segment: format!("{}{}", screen_hangul(keys), delimiter),
+        english: format!("{}{}", keys, delimiter),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reverse_candidate() {
+        assert!(is_reverse_candidate("hello"));
+        assert!(is_reverse_candidate("The"));
+        // 너무 짧거나 영문자 외 문자 포함
+        assert!(!is_reverse_candidate("go"));
+        assert!(!is_reverse_candidate("abc1"));
+        assert!(!is_reverse_candidate("can't"));
+        assert!(!is_reverse_candidate(""));
+    }
+
+    #[test]
+    fn test_plan_reverse_replaces_english_word() {
+        assert_eq!(
+            plan_reverse("hello", " ", true, false),
+            ReversePlan::Replace {
+                segment: "ㅗ디ㅣㅐ ".to_string(),
+                english: "hello ".to_string(),
+            }
+        );
+        // Shift 자모 키가 아닌 대문자는 화면에 소문자 자모로 입력됨
+        assert_eq!(
+            plan_reverse("Hello", " ", true, false),
+            ReversePlan::Replace {
+                segment: "ㅗ디ㅣㅐ ".to_string(),
+                english: "Hello ".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_plan_reverse_keeps_korean() {
+        // 사전에 없는 키 시퀀스 (일반 한글 입력)
+        assert_eq!(
+            plan_reverse("dkssud", " ", false, true),
+            ReversePlan::NotEnglish
+        );
+        assert_eq!(
+            plan_reverse("go", " ", true, false),
+            ReversePlan::NotEnglish
+        );
+        // 영단어지만 조합된 한글도 자연스러움 — 의도한 한글일 수 있음
+        assert_eq!(
+            plan_reverse("tax", " ", true, true),
+            ReversePlan::NaturalKorean
+        );
+    }
+}
@@ -0,0 +1,143 @@
+//! 세션 단위 변환 결과 피드백 (신뢰도 기준 소폭 조정)
+//!
+//! 최근 변환 결과에서 Undo가 잦으면 감지기가 너무 공격적이므로 기준을 올리고,
+//! 거부가 잦으면 너무 보수적이므로 기준을 내립니다. 조정 폭은 ±`FEEDBACK_MAX_OFFSET`으로 제한하며
+//! 저장하지 않으므로 세션이 끝나면 사라집니다.
+
+use std::collections::VecDeque;
+
+/// 판정에 사용할 최근 변환 결과 개수
+pub const FEEDBACK_WINDOW: usize = 20;
+/// 이 개수보다 적게 쌓이면 조정하지 않음
+pub const FEEDBACK_MIN_SAMPLES: usize = 5;
+/// 신뢰도 기준 조정 상·하한 (점)
+pub const FEEDBACK_MAX_OFFSET: f32 = 5.0;
+
+/// 변환 한 번의 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversionOutcome {
+    /// 변환 후 유지
+    Kept,
+    /// 변환 후 Undo
+    Undone,
+    /// 검증 거부 (변환하지 않음)
+    Rejected,
+}
+
+/// 최근 변환 결과 기반 신뢰도 기준 조정기
+#[derive(Debug, Clone)]
+pub struct ConversionFeedback {
+    recent: VecDeque<ConversionOutcome>,
+    window: usize,
+}
+
+impl ConversionFeedback {
+    pub fn new(window: usize) -> Self {
+        Self {
+            recent: VecDeque::with_capacity(window),
+            window: window.max(1),
+        }
+    }
+
+    /// 결과 기록
+    ///
+    /// Undo는 직전에 유지로 기록된 변환을 되돌린 것이므로 가장 최근 `Kept`를 `Undone`으로 바꿈
+    pub fn record(&mut self, outcome: ConversionOutcome) {
+        if outcome == ConversionOutcome::Undone {
+            if let Some(last_kept) = self
+                .recent
+                .iter_mut()
+                .rev()
+                .find(|o| **o == ConversionOutcome::Kept)
+            {
+                *last_kept = ConversionOutcome::Undone;
+                return;
+            }
+        }
+        if self.recent.len() == self.window {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(outcome);
+    }
+
+    /// 신뢰도 기준에 더할 값 (Undo가 많으면 +, 거부가 많으면 -)
+    pub fn threshold_offset(&self) -> f32 {
+        let total = self.recent.len();
+        if total < FEEDBACK_MIN_SAMPLES {
+            return 0.0;
+        }
+        let count = |target| self.recent.iter().filter(|o| **o == target).count() as f32;
+        let balance =
+            (count(ConversionOutcome::Undone) - count(ConversionOutcome::Rejected)) / total as f32;
+        (balance * FEEDBACK_MAX_OFFSET).clamp(-FEEDBACK_MAX_OFFSET, FEEDBACK_MAX_OFFSET)
+    }
+
+    /// 기록 초기화 (조정 없음으로 복귀)
+    pub fn reset(&mut self) {
+        self.recent.clear();
+    }
+}
+
+impl Default for ConversionFeedback {
+    fn default() -> Self {
+        Self::new(FEEDBACK_WINDOW)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offset_follows_outcome_balance() {
+        let mut feedback = ConversionFeedback::default();
+        // 표본이 적으면 조정 없음
+        for _ in 0..FEEDBACK_MIN_SAMPLES - 1 {
+            feedback.record(ConversionOutcome::Rejected);
+        }
+        assert_eq!(feedback.threshold_offset(), 0.0);
+
+        // 거부가 이어지면 기준을 내림
+        feedback.record(ConversionOutcome::Rejected);
+        assert_eq!(feedback.threshold_offset(), -FEEDBACK_MAX_OFFSET);
+
+        // 유지가 섞이면 조정 폭이 줄어듦
+        for _ in 0..5 {
+            feedback.record(ConversionOutcome::Kept);
+        }
+        let offset = feedback.threshold_offset();
+        assert!(offset < 0.0 && offset > -FEEDBACK_MAX_OFFSET, "{}", offset);
+
+        // 유지했던 변환을 Undo하면 기준을 올리는 방향
+        for _ in 0..5 {
+            feedback.record(ConversionOutcome::Undone);
+        }
+        assert_eq!(feedback.threshold_offset(), 0.0);
+        for _ in 0..FEEDBACK_WINDOW {
+            feedback.record(ConversionOutcome::Kept);
+            feedback.record(ConversionOutcome::Undone);
+        }
+        assert_eq!(feedback.threshold_offset(), FEEDBACK_MAX_OFFSET);
+
+        feedback.reset();
+        assert_eq!(feedback.threshold_offset(), 0.0);
+    }
+
+    #[test]
+    fn test_window_drops_old_outcomes() {
+        let mut feedback = ConversionFeedback::new(5);
+        for _ in 0..5 {
+            feedback.record(ConversionOutcome::Rejected);
+        }
+        for _ in 0..5 {
+            feedback.record(ConversionOutcome::Kept);
+        }
+        assert_eq!(feedback.threshold_offset(), 0.0);
+        // 유지 기록이 없을 때의 Undo는 그대로 추가
+        let mut feedback = ConversionFeedback::new(5);
+        for _ in 0..5 {
+            feedback.record(ConversionOutcome::Undone);
+        }
+        assert_eq!(feedback.threshold_offset(), FEEDBACK_MAX_OFFSET);
+    }
+}
@@ -1,8 +1,23 @@
 //! 자동 한글 입력 감지 모듈
 
 mod auto_detect;
+mod direction;
+mod feedback;
 mod patterns;
+mod repair;
+mod shadow;
 pub mod validator;
 
-pub use auto_detect::AutoDetector;
+pub use auto_detect::{
+    classify_jamo_only, has_excessive_uppercase, has_url_pattern, is_bare_jamo_sequence,
+    is_single_jamo_class, non_hangul_uppercase_ratio, AutoDetector, JamoOnlyInput,
+    MIN_COMMON_SYLLABLE_RATIO, NON_HANGUL_UPPERCASE_RATIO_LIMIT,
+};
+pub use direction::{is_reverse_candidate, plan_reverse, ReversePlan, REVERSE_MIN_KEYS};
+pub use feedback::{
+    ConversionFeedback, ConversionOutcome, FEEDBACK_MAX_OFFSET, FEEDBACK_MIN_SAMPLES,
+    FEEDBACK_WINDOW,
+};
+pub use repair::{repair_candidates, MAX_REPAIR_CANDIDATES, REPAIR_TAIL_KEYS};
+pub use shadow::{ShadowEvaluator, ShadowMismatch, ShadowVariantStats, DEFAULT_SHADOW_DELTAS};
 pub use validator::{has_excessive_jamo, has_incomplete_jamo, is_valid_hangul_result};
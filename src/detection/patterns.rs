@@ -5,55 +5,17 @@
 use std::collections::HashSet;
 use std::sync::LazyLock;
 
+/// 내장 영어 단어 목록 원본 (한 줄에 한 단어)
+const ENGLISH_WORDS_DATA: &str = include_str!("../../resources/english_words.txt");
+
 /// 흔한 영어 단어 목록 - 이 단어들은 자동 변환에서 제외
+/// 바이너리에 포함된 원본 문자열을 그대로 참조하므로 단어별 할당이 없음
 pub static COMMON_ENGLISH_WORDS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
-    let mut set = HashSet::new();
-    // 3글자 영어 단어
-    for w in [
-        "the", "and", "for", "are", "but", "not", "you", "all", "can", "had", "her", "was", "one",
-        "our", "out", "has", "his", "how", "its", "let", "may", "new", "now", "old", "see", "two",
-        "way", "who", "did", "get", "com", "use", "man", "day", "too", "any", "put", "say", "she",
-        "own", "try", "set", "run", "end", "act", "ask", "big", "buy", "cut", "far", "few", "got",
-        "him", "hot", "job", "key", "low", "lot", "off", "pay", "per", "red", "sit", "six", "ten",
-        "top", "war", "why", "yes", "yet", "ago", "air", "art", "bad", "bed", "bit", "box", "car",
-        "cup", "dog", "eat", "eye", "fly", "fun", "god", "gun", "hit", "law", "lie", "map", "oil",
-        "old", "add", "age", "arm", "bag", "bar", "bus", "cry", "die", "dry", "ear", "egg", "fan",
-        "fat", "fit", "gas", "hat", "ice", "kid", "lap", "leg", "lip", "mix", "net", "nor", "nut",
-        "odd", "pan", "pet", "pie", "pin", "pop", "pot", "raw", "row", "sad", "sea", "sky", "son",
-        "sun", "tax", "tea", "tie", "tip", "via", "wet", "win", "won", "app", "web", "api", "url",
-        "dev",
-    ] {
-        set.insert(w);
-    }
-    // 4글자 영어 단어
-    for w in [
-        "that", "with", "have", "this", "will", "your", "from", "they", "been", "call", "come",
-        "made", "find", "long", "down", "side", "more", "each", "said", "time", "very", "when",
-        "make", "only", "here", "must", "into", "year", "take", "them", "some", "then", "than",
-        "look", "also", "well", "back", "over", "such", "good", "give", "most", "just", "even",
-        "work", "know", "life", "hand", "part", "code", "file", "test", "data", "user", "type",
-        "name", "wifi", "list", "help", "want", "need", "open", "save", "edit", "view", "show",
-        "hide", "read", "send", "copy", "move", "text", "link", "next", "home", "page", "form",
-        "true", "null", "void", "self", "func", "main", "init", "free", "size", "loop", "bool",
-        "byte", "char", "case", "else", "enum", "goto", "left", "last", "none", "pass", "push",
-        "pull", "sort", "stop", "wait", "wrap", "exit", "like",
-    ] {
-        set.insert(w);
-    }
-    // 5글자+ 영어 단어
-    for w in [
-        "hello", "world", "there", "which", "their", "would", "about", "these", "could", "other",
-        "after", "first", "never", "where", "those", "being", "every", "under", "think", "still",
-        "while", "found", "great", "right", "three", "place", "thing", "point", "string",
-        "function", "return", "public", "private", "static", "class", "const", "import", "export",
-        "default", "async", "await", "break", "catch", "throw", "final", "super", "print", "input",
-        "output", "error", "value", "array", "index", "count", "start", "false", "begin", "check",
-        "clear", "close", "build", "write", "event", "state", "props", "style", "click", "focus",
-        "fetch", "slack", "figma", "github", "notion", "discord", "chrome", "zoom",
-    ] {
-        set.insert(w);
-    }
-    set
+    ENGLISH_WORDS_DATA
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect()
 });
 
 /// 한글 가능성이 높은 바이그램 패턴
@@ -139,6 +101,12 @@ pub static ENGLISH_BIGRAMS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
     set
 });
 
+/// 감정 표현/초성 약어로 쓰이는 낱자모 패턴 (한글 모드에서만 자연스러운 입력)
+pub const EXPRESSIVE_JAMO_PATTERNS: [&str; 14] = [
+    "ㅋㅋ", "ㅎㅎ", "ㅠㅠ", "ㅜㅜ", "ㄷㄷ", "ㅇㅇ", "ㄴㄴ", "ㅇㅋ", "ㄱㅅ", "ㅊㅋ", "ㅈㅅ", "ㅂㅂ",
+    "ㅎㅇ", "ㅡㅡ",
+];
+
 /// 문자가 두벌식 자음 키인지 확인
 pub fn is_consonant_key(c: char) -> bool {
     crate::core::jamo_mapper::is_consonant(c)
@@ -149,6 +117,11 @@ pub fn is_vowel_key(c: char) -> bool {
     crate::core::jamo_mapper::is_vowel(c)
 }
 
+/// 문자가 Shift 조합 자모 키인지 확인 (Q W E R T → 쌍자음, O P → ㅒ ㅖ)
+pub fn is_shift_jamo_key(c: char) -> bool {
+    c.is_ascii_uppercase() && crate::core::jamo_mapper::map_to_jamo(c).is_some()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -178,4 +151,66 @@ mod tests {
         assert!(!is_consonant_key('k'));
         assert!(!is_vowel_key('r'));
     }
+
+    #[test]
+    fn test_english_words_include_previous_builtin_words() {
+        for word in [
+            "the", "and", "for", "are", "but", "not", "you", "all", "can", "had", "her", "was",
+            "one", "our", "out", "has", "his", "how", "its", "let", "may", "new", "now", "old",
+            "see", "two", "way", "who", "did", "get", "com", "use", "man", "day", "too", "any",
+            "put", "say", "she", "own", "try", "set", "run", "end", "act", "ask", "big", "buy",
+            "cut", "far", "few", "got", "him", "hot", "job", "key", "low", "lot", "off", "pay",
+            "per", "red", "sit", "six", "ten", "top", "war", "why", "yes", "yet", "ago", "air",
+            "art", "bad", "bed", "bit", "box", "car", "cup", "dog", "eat", "eye", "fly", "fun",
+            "god", "gun", "hit", "law", "lie", "map", "oil", "add", "age", "arm", "bag", "bar",
+            "bus", "cry", "die", "dry", "ear", "egg", "fan", "fat", "fit", "gas", "hat", "ice",
+            "kid", "lap", "leg", "lip", "mix", "net", "nor", "nut", "odd", "pan", "pet", "pie",
+            "pin", "pop", "pot", "raw", "row", "sad", "sea", "sky", "son", "sun", "tax", "tea",
+            "tie", "tip", "via", "wet", "win", "won", "app", "web", "api", "url", "dev", "that",
+            "with", "have", "this", "will", "your", "from", "they", "been", "call", "come", "made",
+            "find", "long", "down", "side", "more", "each", "said", "time", "very", "when", "make",
+            "only", "here", "must", "into", "year", "take", "them", "some", "then", "than", "look",
+            "also", "well", "back", "over", "such", "good", "give", "most", "just", "even", "work",
+            "know", "life", "hand", "part", "code", "file", "test", "data", "user", "type", "name",
+            "wifi", "list", "help", "want", "need", "open", "save", "edit", "view", "show", "hide",
+            "read", "send", "copy", "move", "text", "link", "next", "home", "page", "form", "true",
+            "null", "void", "self", "func", "main", "init", "free", "size", "loop", "bool", "byte",
+            "char", "case", "else", "enum", "goto", "left", "last", "none", "pass", "push", "pull",
+            "sort", "stop", "wait", "wrap", "exit", "like", "hello", "world", "there", "which",
+            "their", "would", "about", "these", "could", "other", "after", "first", "never",
+            "where", "those", "being", "every", "under", "think", "still", "while", "found",
+            "great", "right", "three", "place", "thing", "point", "string", "function", "return",
+            "public", "private", "static", "class", "const", "import", "export", "default",
+            "async", "await", "break", "catch", "throw", "final", "super", "print", "input",
+            "output", "error", "value", "array", "index", "count", "start", "false", "begin",
+            "check", "clear", "close", "build", "write", "event", "state", "props", "style",
+            "click", "focus", "fetch", "slack", "figma", "github", "notion", "discord", "chrome",
+            "zoom",
+        ] {
+            assert!(COMMON_ENGLISH_WORDS.contains(word), "{}", word);
+        }
+    }
+
+    #[test]
+    fn test_english_words_data_format() {
+        assert!(COMMON_ENGLISH_WORDS.len() > 5000);
+        for word in COMMON_ENGLISH_WORDS.iter() {
+            assert!(word.len() >= 3, "{}", word);
+            assert!(word.bytes().all(|b| b.is_ascii_lowercase()), "{}", word);
+        }
+        // 두벌식으로 실제 한글 단어가 되는 입력은 포함하지 않음
+        for keys in ["dud", "rid", "dkssud", "gksrmf"] {
+            assert!(!COMMON_ENGLISH_WORDS.contains(keys), "{}", keys);
+        }
+    }
+
+    #[test]
+    fn test_shift_jamo_keys() {
+        for c in ['Q', 'W', 'E', 'R', 'T', 'O', 'P'] {
+            assert!(is_shift_jamo_key(c), "{}", c);
+        }
+        assert!(!is_shift_jamo_key('o'));
+        assert!(!is_shift_jamo_key('C'));
+        assert!(!is_shift_jamo_key('1'));
+    }
 }
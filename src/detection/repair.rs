@@ -0,0 +1,81 @@
+//! 자판 인접 키 오타 교정 후보 생성
+//!
+//! 한글을 치다 옆 키를 잘못 눌러 변환이 거부된 입력에서, 마지막 몇 개 키를
+//! 자판에서 인접한 키로 바꾼 후보를 만듭니다. 후보 판정은 [`AutoDetector::repair_candidate`]에서 합니다.
+//!
+//! [`AutoDetector::repair_candidate`]: super::AutoDetector::repair_candidate
+
+use crate::ngram::adjacent_keys;
+
+/// 교정 대상 — 입력 끝에서부터 이 개수의 키만 바꿔 봄
+pub const REPAIR_TAIL_KEYS: usize = 2;
+/// 한 입력에서 만들 후보 수 상한
+pub const MAX_REPAIR_CANDIDATES: usize = 64;
+
+/// 두벌식 자판의 키 행 (QWERTY 위치)
+const KEY_ROWS: [&str; 3] = ["qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+/// 자판에서 인접한 키 — 같은 행(좌우로 미끄러진 오타)이 먼저, 대문자는 Shift 유지
+fn neighbors(key: char) -> Vec<char> {
+    let lower = key.to_ascii_lowercase();
+    let row = |c: char| KEY_ROWS.iter().position(|keys| keys.contains(c));
+    let mut keys: Vec<char> = adjacent_keys(lower).chars().collect();
+    keys.sort_by_key(|&c| row(c) != row(lower));
+    if key.is_ascii_uppercase() {
+        keys.iter_mut().for_each(|c| *c = c.to_ascii_uppercase());
+    }
+    keys
+}
+
+/// 마지막 [`REPAIR_TAIL_KEYS`]개 키를 인접 키로 바꾼 후보 (한 키만 바꾼 후보가 앞, 최대 [`MAX_REPAIR_CANDIDATES`]개)
+pub fn repair_candidates(input: &str) -> Vec<String> {
+    let keys: Vec<char> = input.chars().collect();
+    let tail_start = keys.len().saturating_sub(REPAIR_TAIL_KEYS);
+    let mut candidates = Vec::new();
+
+    // 한 키만 바꾼 후보 (마지막 키부터)
+    for i in (tail_start..keys.len()).rev() {
+        for neighbor in neighbors(keys[i]) {
+            let mut candidate = keys.clone();
+            candidate[i] = neighbor;
+            candidates.push(candidate.into_iter().collect());
+        }
+    }
+    // 마지막 두 키를 모두 바꾼 후보
+    if keys.len() - tail_start == 2 {
+        let (first, last) = (tail_start, tail_start + 1);
+        for a in neighbors(keys[first]) {
+            for b in neighbors(keys[last]) {
+                let mut candidate = keys.clone();
+                candidate[first] = a;
+                candidate[last] = b;
+                candidates.push(candidate.into_iter().collect());
+            }
+        }
+    }
+
+    candidates.truncate(MAX_REPAIR_CANDIDATES);
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repair_candidates_limited_to_tail() {
+        let candidates = repair_candidates("dkssud");
+        // 앞쪽 키는 그대로, 한 키만 바꾼 후보가 먼저
+        assert!(candidates.iter().all(|c| c.starts_with("dkss")));
+        assert_eq!(candidates[0], "dkssuf");
+        assert!(candidates.contains(&"dkssyd".to_string()));
+        assert!(candidates.contains(&"dkssyf".to_string()));
+        assert!(candidates.len() <= MAX_REPAIR_CANDIDATES);
+        assert!(!candidates.contains(&"dkssud".to_string()));
+
+        assert_eq!(repair_candidates("p"), vec!["o", "l"]);
+        // Shift 키(쌍자음/ㅒ/ㅖ)는 대문자 그대로 교정
+        assert_eq!(repair_candidates("R"), vec!["E", "T", "D", "F"]);
+        assert!(repair_candidates("").is_empty());
+    }
+}
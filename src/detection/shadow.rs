@@ -0,0 +1,194 @@
+//! 변환 임계값 shadow 평가 (휴리스틱 튜닝용)
+//!
+//! 실제 변환은 현재 기준으로 하고, 신뢰도 기준을 바꾼 대안들로 같은 입력을 다시 판정해
+//! "이 기준이었다면 변환했을까"를 비교합니다. 불일치만 돌려주고 대안별로 집계하며,
+//! 로그 출력은 호출 측(앱)에서 담당합니다.
+
+use super::AutoDetector;
+
+/// 기본 대안: 현재 신뢰도 기준 대비 -10 / +10점
+pub const DEFAULT_SHADOW_DELTAS: [f32; 2] = [-10.0, 10.0];
+
+/// 대안 하나의 누적 집계
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ShadowVariantStats {
+    /// 현재 기준 대비 신뢰도 기준 차이
+    pub threshold_delta: f32,
+    /// 평가 횟수
+    pub evaluated: u64,
+    /// 실제로는 변환하지 않았지만 대안은 변환했을 횟수
+    pub would_convert: u64,
+    /// 실제로는 변환했지만 대안은 변환하지 않았을 횟수
+    pub would_skip: u64,
+}
+
+impl ShadowVariantStats {
+    /// 판정이 달랐던 횟수
+    pub fn mismatches(&self) -> u64 {
+        self.would_convert + self.would_skip
+    }
+
+    /// 이름 ("threshold-10", "threshold+10")
+    pub fn name(&self) -> String {
+        format!("threshold{:+}", self.threshold_delta)
+    }
+}
+
+/// 실제 판정과 다르게 나온 대안 판정
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShadowMismatch {
+    /// 대안 이름
+    pub variant: String,
+    /// 대안이 사용한 신뢰도 기준
+    pub threshold: f32,
+    /// 실제 판정 (변환 여부)
+    pub actual: bool,
+    /// 대안 판정 (변환 여부)
+    pub shadow: bool,
+    /// 이 대안의 누적 불일치 횟수 / 평가 횟수
+    pub mismatches: u64,
+    pub evaluated: u64,
+}
+
+/// 대안 신뢰도 기준별 shadow 판정기
+#[derive(Debug, Clone)]
+pub struct ShadowEvaluator {
+    variants: Vec<ShadowVariantStats>,
+}
+
+impl ShadowEvaluator {
+    /// deltas: 현재 기준에 더할 신뢰도 차이 목록 (대안마다 하나)
+    pub fn new(deltas: &[f32]) -> Self {
+        Self {
+            variants: deltas
+                .iter()
+                .map(|&threshold_delta| ShadowVariantStats {
+                    threshold_delta,
+                    ..Default::default()
+                })
+                .collect(),
+        }
+    }
+
+    /// 기본 대안(±10점)으로 생성
+    pub fn with_defaults() -> Self {
+        Self::new(&DEFAULT_SHADOW_DELTAS)
+    }
+
+    /// 대안별 누적 집계
+    pub fn stats(&self) -> &[ShadowVariantStats] {
+        &self.variants
+    }
+
+    /// 실시간 판정(`should_convert_realtime`)과 대안 기준 판정 비교
+    ///
+    /// - actual: 실제 판정 결과 (필터/버퍼 조건을 포함한 최종 결정)
+    pub fn evaluate_realtime(
+        &mut self,
+        detector: &AutoDetector,
+        judge: &str,
+        actual: bool,
+    ) -> Vec<ShadowMismatch> {
+        let base = detector.config().realtime_threshold;
+        self.evaluate(base, actual, |threshold| {
+            detector.should_convert_realtime_with_threshold(judge, threshold)
+        })
+    }
+
+    /// 대안마다 judge(기준)로 판정하고 실제 판정과 다른 것만 집계/반환
+    pub fn evaluate(
+        &mut self,
+        base_threshold: f32,
+        actual: bool,
+        mut judge: impl FnMut(f32) -> bool,
+    ) -> Vec<ShadowMismatch> {
+        let mut mismatches = Vec::new();
+        for variant in &mut self.variants {
+            let threshold = base_threshold + variant.threshold_delta;
+            let shadow = judge(threshold);
+            variant.evaluated += 1;
+            match (actual, shadow) {
+                (false, true) => variant.would_convert += 1,
+                (true, false) => variant.would_skip += 1,
+                _ => continue,
+            }
+            mismatches.push(ShadowMismatch {
+                variant: variant.name(),
+                threshold,
+                actual,
+                shadow,
+                mismatches: variant.mismatches(),
+                evaluated: variant.evaluated,
+            });
+        }
+        mismatches
+    }
+}
+
+impl Default for ShadowEvaluator {
+    fn default() -> Self {
+        Self::with_defaults()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_mismatches_are_reported_and_counted() {
+        let mut evaluator = ShadowEvaluator::new(&[-10.0, 10.0]);
+        // 신뢰도 75: 기준 80에서는 거부, 70이면 변환, 90이면 거부
+        let confidence = 75.0;
+        let mismatches = evaluator.evaluate(80.0, false, |threshold| confidence >= threshold);
+        assert_eq!(
+            mismatches,
+            vec![ShadowMismatch {
+                variant: "threshold-10".to_string(),
+                threshold: 70.0,
+                actual: false,
+                shadow: true,
+                mismatches: 1,
+                evaluated: 1,
+            }]
+        );
+
+        // 신뢰도 85: 실제 변환, +10 대안만 거부
+        let mismatches = evaluator.evaluate(80.0, true, |threshold| 85.0 >= threshold);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].variant, "threshold+10");
+        assert!(!mismatches[0].shadow);
+
+        // 모두 일치하면 보고 없음 (평가 횟수만 증가)
+        assert!(evaluator.evaluate(80.0, true, |_| true).is_empty());
+
+        let stats = evaluator.stats();
+        assert_eq!(stats[0].evaluated, 3);
+        assert_eq!((stats[0].would_convert, stats[0].would_skip), (1, 0));
+        assert_eq!(stats[1].evaluated, 3);
+        assert_eq!((stats[1].would_convert, stats[1].would_skip), (0, 1));
+        assert_eq!(stats[1].mismatches(), 1);
+    }
+
+    #[test]
+    fn test_realtime_evaluation_uses_detector_threshold() {
+        let detector = AutoDetector::default();
+        let mut evaluator = ShadowEvaluator::new(&[-100.0, 100.0]);
+        assert!(detector.should_convert_realtime("dkssudgktpdy"));
+
+        // 기준을 크게 올리면 변환하지 않았을 것
+        let mismatches = evaluator.evaluate_realtime(&detector, "dkssudgktpdy", true);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].variant, "threshold+100");
+
+        // 영어 예외어는 기준과 무관하게 필터에서 거부 — 불일치 없음
+        assert!(evaluator
+            .evaluate_realtime(&detector, "the", false)
+            .is_empty());
+        // 드문 음절투성이 결과(퍄퍙)도 기준과 무관하게 거부 — 불일치 없음
+        assert!(!detector.should_convert_realtime("vivid"));
+        assert!(evaluator
+            .evaluate_realtime(&detector, "vivid", false)
+            .is_empty());
+    }
+}
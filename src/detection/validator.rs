@@ -2,19 +2,15 @@
 //!
 //! 낱자모 검출 및 가짜 한글 필터링
 
+use crate::core::unicode::{is_compat_jamo, is_hangul_syllable};
+
 /// 완성형 한글이 아닌 낱자모가 포함되어 있는지 검사
 ///
 /// 호환용 자모 영역 (ㄱ-ㅎ, ㅏ-ㅣ): U+3131 ~ U+318E
 /// 이 영역의 문자가 포함되어 있으면 불완전한 한글로 판단
 pub fn has_incomplete_jamo(text: &str) -> bool {
-    for ch in text.chars() {
-        let cp = ch as u32;
-        // 호환용 자모 영역
-        if (0x3131..=0x318E).contains(&cp) {
-            return true;
-        }
-    }
-    false
+    // 호환용 자모 영역
+    text.chars().any(is_compat_jamo)
 }
 
 /// 낱자모 비율 계산 (0.0 ~ 1.0)
@@ -24,12 +20,11 @@ pub fn incomplete_jamo_ratio(text: &str) -> f32 {
     let mut hangul_count = 0;
 
     for ch in text.chars() {
-        let cp = ch as u32;
-        if (0x3131..=0x318E).contains(&cp) {
+        if is_compat_jamo(ch) {
             // 낱자모
             jamo_count += 1;
             hangul_count += 1;
-        } else if (0xAC00..=0xD7A3).contains(&cp) {
+        } else if is_hangul_syllable(ch) {
             // 완성형 한글
             hangul_count += 1;
         }
@@ -50,8 +45,7 @@ pub fn has_excessive_jamo(text: &str) -> bool {
 
 /// 문자가 완성형 한글(가-힣)인지 확인
 pub fn is_complete_hangul(ch: char) -> bool {
-    let cp = ch as u32;
-    (0xAC00..=0xD7A3).contains(&cp)
+    is_hangul_syllable(ch)
 }
 
 /// 변환 결과가 유효한 한글인지 검증
@@ -123,6 +117,21 @@ mod tests {
         assert!(!is_complete_hangul('1'));
     }
 
+    #[test]
+    fn test_syllable_boundary_codepoints() {
+        // '힣'(0xD7A3)은 완성형, 다음 코드포인트 0xD7A4는 비음절
+        assert!(is_complete_hangul('\u{D7A3}'));
+        assert!(!is_complete_hangul('\u{D7A4}'));
+        assert!(!is_complete_hangul('\u{ABFF}'));
+        assert!(has_any_hangul("힣"));
+        assert!(!has_any_hangul("\u{D7A4}"));
+        assert!(!is_valid_hangul_result("\u{D7A4}"));
+
+        // 비음절 코드포인트는 낱자모로도 세지 않음
+        assert!(!has_incomplete_jamo("\u{D7A4}"));
+        assert_eq!(incomplete_jamo_ratio("힣\u{D7A4}"), 0.0);
+    }
+
     #[test]
     fn test_is_valid_hangul_result() {
         // 유효한 결과
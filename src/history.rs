@@ -0,0 +1,186 @@
+//! 변환 이력 저장/로드 (앱 재시작 후 Undo)
+
+use crate::config::config_path;
+use crate::platform::event_tap::ConversionHistory;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// 저장하는 최대 이력 수 (입력 내용이 남으므로 최근 것만 보관)
+pub const MAX_HISTORY_ENTRIES: usize = 10;
+
+/// 최근 변환 이력 (오래된 것부터, 상한 초과 시 가장 오래된 항목 제거)
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct HistoryLog {
+    #[serde(default)]
+    entries: VecDeque<ConversionHistory>,
+}
+
+impl HistoryLog {
+    /// 이력 추가
+    pub fn push(&mut self, entry: ConversionHistory) {
+        self.entries.push_back(entry);
+        self.truncate();
+    }
+
+    /// 마지막 이력 제거 (Undo 완료 시)
+    pub fn pop_last(&mut self) -> Option<ConversionHistory> {
+        self.entries.pop_back()
+    }
+
+    /// 마지막 이력 (재시작 후 Undo 대상)
+    pub fn last(&self) -> Option<&ConversionHistory> {
+        self.entries.back()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn truncate(&mut self) {
+        while self.entries.len() > MAX_HISTORY_ENTRIES {
+            self.entries.pop_front();
+        }
+    }
+}
+
+/// 이력 파일 경로: ~/Library/Application Support/koing/history.json
+pub fn history_path() -> PathBuf {
+    config_path().with_file_name("history.json")
+}
+
+/// 이력 로드 (파일 없거나 손상 시 빈 이력)
+pub fn load_history() -> HistoryLog {
+    load_history_from(&history_path())
+}
+
+/// 지정 경로에서 이력 로드
+pub fn load_history_from(path: &Path) -> HistoryLog {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) => {
+            if e.kind() != ErrorKind::NotFound {
+                log::warn!("변환 이력 읽기 실패: {}", e);
+            }
+            return HistoryLog::default();
+        }
+    };
+    match serde_json::from_str::<HistoryLog>(&content) {
+        Ok(mut history) => {
+            history.truncate();
+            history
+        }
+        Err(e) => {
+            log::warn!("변환 이력 파일 손상, 무시: {}", e);
+            HistoryLog::default()
+        }
+    }
+}
+
+/// 이력 저장
+pub fn save_history(history: &HistoryLog) -> Result<(), String> {
+    save_history_to(&history_path(), history)
+}
+
+/// 지정 경로에 이력 저장
+pub fn save_history_to(path: &Path, history: &HistoryLog) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("이력 디렉토리 생성 실패: {}", e))?;
+    }
+    let json = serde_json::to_string(history).map_err(|e| format!("직렬화 실패: {}", e))?;
+    fs::write(path, json).map_err(|e| format!("이력 파일 저장 실패: {}", e))?;
+    Ok(())
+}
+
+/// 이력 파일 삭제 ("기록 저장 안 함" 설정 시)
+pub fn clear_history() -> Result<(), String> {
+    match fs::remove_file(history_path()) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(format!("이력 파일 삭제 실패: {}", e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(original: &str, converted: &str) -> ConversionHistory {
+        ConversionHistory {
+            original: original.to_string(),
+            converted: converted.to_string(),
+        }
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("koing_history_{}_{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn test_push_keeps_recent_entries_only() {
+        let mut history = HistoryLog::default();
+        for i in 0..MAX_HISTORY_ENTRIES + 3 {
+            history.push(entry(&format!("e{}", i), "x"));
+        }
+        assert_eq!(history.len(), MAX_HISTORY_ENTRIES);
+        assert_eq!(
+            history.last().map(|h| h.original.as_str()),
+            Some(format!("e{}", MAX_HISTORY_ENTRIES + 2).as_str())
+        );
+
+        assert_eq!(
+            history.pop_last().map(|h| h.original),
+            Some(format!("e{}", MAX_HISTORY_ENTRIES + 2))
+        );
+        assert_eq!(history.len(), MAX_HISTORY_ENTRIES - 1);
+    }
+
+    #[test]
+    fn test_save_load_round_trip() {
+        let path = temp_path("round_trip.json");
+        let mut history = HistoryLog::default();
+        history.push(entry("dkssud", "안녕"));
+        history.push(entry("gksrmf", "한글"));
+
+        save_history_to(&path, &history).unwrap();
+        let loaded = load_history_from(&path);
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(loaded, history);
+        assert_eq!(loaded.last(), Some(&entry("gksrmf", "한글")));
+    }
+
+    #[test]
+    fn test_load_missing_or_corrupt_file() {
+        assert!(load_history_from(&temp_path("missing.json")).is_empty());
+
+        let path = temp_path("corrupt.json");
+        fs::write(&path, "{\"entries\": [{\"original\": 1").unwrap();
+        let loaded = load_history_from(&path);
+        let _ = fs::remove_file(&path);
+        assert!(loaded.is_empty());
+    }
+
+    #[test]
+    fn test_load_truncates_oversized_file() {
+        let entries: Vec<_> = (0..MAX_HISTORY_ENTRIES + 5)
+            .map(|i| entry(&format!("e{}", i), "x"))
+            .collect();
+        let path = temp_path("oversized.json");
+        fs::write(&path, serde_json::json!({ "entries": entries }).to_string()).unwrap();
+        let loaded = load_history_from(&path);
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(loaded.len(), MAX_HISTORY_ENTRIES);
+        assert_eq!(
+            loaded.last().map(|h| h.original.as_str()),
+            Some(format!("e{}", MAX_HISTORY_ENTRIES + 4).as_str())
+        );
+    }
+}
@@ -0,0 +1,405 @@
+//! 변환 이벤트 브로드캐스트 (Unix domain socket, 읽기 전용 구독)
+//!
+//! 설정(`ipc_enabled`)을 켜면 설정 디렉토리의 `koing.sock`에 접속한 구독자에게
+//! 변환/Undo/입력 소스 전환 이벤트를 JSON 한 줄씩 보냅니다. 구독자가 보내는 데이터는 읽지 않습니다.
+//!
+//! 이벤트는 채널로 넘기고 전용 스레드가 논블로킹으로 쓰므로 변환 경로는 멈추지 않습니다.
+//! 읽지 않는 구독자는 밀린 양이 상한을 넘으면 연결을 끊고, 끊긴 연결은 다음 쓰기에서 정리합니다.
+//! 서버를 시작하지 않으면 [`publish`]는 아무 것도 하지 않습니다.
+
+use serde::Serialize;
+use std::io::{self, ErrorKind, Write};
+use std::os::unix::fs::PermissionsExt;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, OnceLock};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// 소켓 파일 이름 (설정 디렉토리 아래)
+pub const SOCKET_FILE_NAME: &str = "koing.sock";
+/// 구독자별 밀린 데이터 상한 — 넘으면 읽지 않는 구독자로 보고 연결 해제
+pub const MAX_PENDING_BYTES: usize = 64 * 1024;
+/// 이벤트가 없을 때 새 구독자 확인/밀린 데이터 재전송 주기
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// 구독자에게 보내는 이벤트
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum IpcEvent {
+    /// 텍스트 변환 (영→한, 한→영 역변환 포함)
+    Conversion {
+        original: String,
+        converted: String,
+        manual: bool,
+    },
+    /// 변환 취소 (converted를 지우고 original 복원)
+    Undo { original: String, converted: String },
+    /// Koing이 입력 소스를 전환함 ("korean" / "english")
+    Switch { target: &'static str },
+}
+
+#[derive(Serialize)]
+struct Envelope<'a> {
+    /// 이벤트 시각 (epoch ms)
+    at_ms: u64,
+    #[serde(flatten)]
+    event: &'a IpcEvent,
+}
+
+impl IpcEvent {
+    /// 전송용 JSON 한 줄 (줄바꿈 포함)
+    pub fn to_json_line(&self, at_ms: u64) -> String {
+        let mut line = serde_json::to_string(&Envelope { at_ms, event: self })
+            .unwrap_or_else(|_| "{}".to_string());
+        line.push('\n');
+        line
+    }
+}
+
+/// 구독자 하나 (논블로킹 writer + 아직 못 보낸 데이터)
+struct Subscriber<W> {
+    writer: W,
+    pending: Vec<u8>,
+}
+
+impl<W: Write> Subscriber<W> {
+    /// 밀린 데이터를 가능한 만큼 씀 (false: 연결 끊김)
+    fn flush(&mut self) -> bool {
+        while !self.pending.is_empty() {
+            match self.writer.write(&self.pending) {
+                Ok(0) => return false,
+                Ok(written) => {
+                    self.pending.drain(..written);
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => return true,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(_) => return false,
+            }
+        }
+        true
+    }
+}
+
+/// 구독자 목록과 구독자별 전송 큐
+pub struct Broadcaster<W> {
+    subscribers: Vec<Subscriber<W>>,
+}
+
+impl<W: Write> Broadcaster<W> {
+    pub fn new() -> Self {
+        Self {
+            subscribers: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, writer: W) {
+        self.subscribers.push(Subscriber {
+            writer,
+            pending: Vec::new(),
+        });
+    }
+
+    /// 연결된 구독자 수
+    pub fn len(&self) -> usize {
+        self.subscribers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.subscribers.is_empty()
+    }
+
+    /// 모든 구독자 큐에 추가 후 전송 (상한을 넘긴 구독자는 해제)
+    pub fn broadcast(&mut self, line: &[u8]) {
+        for subscriber in &mut self.subscribers {
+            subscriber.pending.extend_from_slice(line);
+        }
+        self.subscribers.retain(|s| {
+            if s.pending.len() > MAX_PENDING_BYTES {
+                log::debug!("IPC 구독자 밀린 데이터 초과, 연결 해제");
+                return false;
+            }
+            true
+        });
+        self.flush();
+    }
+
+    /// 밀린 데이터 재전송, 끊긴 구독자 정리
+    pub fn flush(&mut self) {
+        self.subscribers.retain_mut(Subscriber::flush);
+    }
+}
+
+impl<W: Write> Default for Broadcaster<W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 브로드캐스트 서버 핸들 (이벤트 채널 + 구독자 수)
+pub struct IpcServer {
+    sender: Sender<IpcEvent>,
+    subscribers: Arc<AtomicUsize>,
+    path: PathBuf,
+}
+
+impl IpcServer {
+    /// 소켓을 열고 전송 스레드 시작
+    ///
+    /// 응답하지 않는 소켓 파일은 지우고, 다른 인스턴스가 쓰는 중이면 `AddrInUse` 오류
+    pub fn start(path: &Path) -> io::Result<Self> {
+        if path.exists() {
+            if UnixStream::connect(path).is_ok() {
+                return Err(io::Error::new(
+                    ErrorKind::AddrInUse,
+                    format!("다른 인스턴스가 사용 중: {}", path.display()),
+                ));
+            }
+            std::fs::remove_file(path)?;
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let listener = UnixListener::bind(path)?;
+        // 입력 이벤트가 흐르는 소켓 — 소유자만 접근
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+        listener.set_nonblocking(true)?;
+
+        let (sender, receiver) = mpsc::channel::<IpcEvent>();
+        let subscribers = Arc::new(AtomicUsize::new(0));
+        let subscriber_count = Arc::clone(&subscribers);
+        thread::Builder::new()
+            .name("koing-ipc".to_string())
+            .spawn(move || {
+                let mut broadcaster = Broadcaster::new();
+                loop {
+                    accept_subscribers(&listener, &mut broadcaster);
+                    match receiver.recv_timeout(POLL_INTERVAL) {
+                        Ok(event) => {
+                            broadcaster.broadcast(event.to_json_line(epoch_ms()).as_bytes())
+                        }
+                        Err(RecvTimeoutError::Timeout) => broadcaster.flush(),
+                        Err(RecvTimeoutError::Disconnected) => return,
+                    }
+                    subscriber_count.store(broadcaster.len(), Ordering::Release);
+                }
+            })?;
+
+        Ok(Self {
+            sender,
+            subscribers,
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// 이벤트 전송 요청 (대기 없음)
+    pub fn publish(&self, event: IpcEvent) {
+        let _ = self.sender.send(event);
+    }
+
+    /// 연결된 구독자 수 (전송 스레드가 마지막으로 확인한 값)
+    pub fn subscriber_count(&self) -> usize {
+        self.subscribers.load(Ordering::Acquire)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// 대기 중인 연결을 모두 구독자로 등록
+fn accept_subscribers(listener: &UnixListener, broadcaster: &mut Broadcaster<UnixStream>) {
+    loop {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                if let Err(e) = stream.set_nonblocking(true) {
+                    log::debug!("IPC 구독자 설정 실패: {}", e);
+                    continue;
+                }
+                // 읽기 전용 구독 — 구독자가 보내는 데이터는 받지 않음
+                let _ = stream.shutdown(std::net::Shutdown::Read);
+                broadcaster.add(stream);
+            }
+            Err(e) if e.kind() == ErrorKind::WouldBlock => return,
+            Err(e) => {
+                log::debug!("IPC 연결 수락 실패: {}", e);
+                return;
+            }
+        }
+    }
+}
+
+fn epoch_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+static SERVER: OnceLock<IpcServer> = OnceLock::new();
+
+/// 앱 전역 서버 시작 (이미 시작했으면 무시)
+pub fn start(path: &Path) -> io::Result<()> {
+    if SERVER.get().is_some() {
+        return Ok(());
+    }
+    let server = IpcServer::start(path)?;
+    log::info!("IPC 이벤트 소켓 시작: {}", server.path().display());
+    let _ = SERVER.set(server);
+    Ok(())
+}
+
+/// 전역 서버로 이벤트 전송 (서버를 시작하지 않았으면 무시)
+pub fn publish(event: IpcEvent) {
+    if let Some(server) = SERVER.get() {
+        server.publish(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader};
+    use std::time::Instant;
+
+    /// 한 번에 limit 바이트까지만 받는 writer (0이면 WouldBlock, None이면 끊김)
+    struct MockWriter {
+        received: Vec<u8>,
+        limit: Option<usize>,
+    }
+
+    impl Write for MockWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            match self.limit {
+                None => Err(io::Error::from(ErrorKind::BrokenPipe)),
+                Some(0) => Err(io::Error::from(ErrorKind::WouldBlock)),
+                Some(limit) => {
+                    let n = buf.len().min(limit);
+                    self.received.extend_from_slice(&buf[..n]);
+                    Ok(n)
+                }
+            }
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn writer(limit: Option<usize>) -> MockWriter {
+        MockWriter {
+            received: Vec::new(),
+            limit,
+        }
+    }
+
+    #[test]
+    fn test_event_json_lines() {
+        let conversion = IpcEvent::Conversion {
+            original: "dkssud".to_string(),
+            converted: "안녕".to_string(),
+            manual: false,
+        };
+        assert_eq!(
+            conversion.to_json_line(1_700_000_000_000),
+            "{\"at_ms\":1700000000000,\"event\":\"conversion\",\"original\":\"dkssud\",\"converted\":\"안녕\",\"manual\":false}\n"
+        );
+        let undo = IpcEvent::Undo {
+            original: "dkssud".to_string(),
+            converted: "안녕".to_string(),
+        };
+        assert!(undo.to_json_line(0).contains("\"event\":\"undo\""));
+        assert_eq!(
+            IpcEvent::Switch { target: "korean" }.to_json_line(5),
+            "{\"at_ms\":5,\"event\":\"switch\",\"target\":\"korean\"}\n"
+        );
+    }
+
+    #[test]
+    fn test_broadcast_queues_slow_and_drops_closed_subscribers() {
+        let mut broadcaster = Broadcaster::new();
+        broadcaster.add(writer(Some(usize::MAX)));
+        broadcaster.add(writer(Some(3)));
+        broadcaster.add(writer(None));
+
+        broadcaster.broadcast(b"hello\n");
+        // 끊긴 구독자는 정리, 느린 구독자는 받을 수 있는 만큼 계속 씀
+        assert_eq!(broadcaster.len(), 2);
+        assert_eq!(broadcaster.subscribers[0].writer.received, b"hello\n");
+        assert_eq!(broadcaster.subscribers[1].writer.received, b"hello\n");
+        assert!(broadcaster.subscribers[1].pending.is_empty());
+
+        // 쓰기가 막힌 구독자는 큐에 쌓아 두었다가 재전송
+        broadcaster.subscribers[1].writer.limit = Some(0);
+        broadcaster.broadcast(b"world\n");
+        assert_eq!(broadcaster.subscribers[1].pending, b"world\n");
+        broadcaster.subscribers[1].writer.limit = Some(usize::MAX);
+        broadcaster.flush();
+        assert_eq!(
+            broadcaster.subscribers[1].writer.received,
+            b"hello\nworld\n"
+        );
+
+        // 상한을 넘도록 읽지 않으면 연결 해제
+        broadcaster.subscribers[1].writer.limit = Some(0);
+        let line = vec![b'x'; MAX_PENDING_BYTES / 2];
+        broadcaster.broadcast(&line);
+        broadcaster.broadcast(&line);
+        assert_eq!(broadcaster.len(), 2);
+        broadcaster.broadcast(b"!\n");
+        assert_eq!(broadcaster.len(), 1);
+    }
+
+    #[test]
+    fn test_server_pushes_events_to_subscribers() {
+        let dir = std::env::temp_dir().join(format!("koing_ipc_test_{}", std::process::id()));
+        let path = dir.join(SOCKET_FILE_NAME);
+        let server = IpcServer::start(&path).unwrap();
+        let stream = UnixStream::connect(&path).unwrap();
+        stream
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+
+        // 전송 스레드가 구독자를 등록할 때까지 대기
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while server.subscriber_count() == 0 && Instant::now() < deadline {
+            server.publish(IpcEvent::Switch { target: "english" });
+            thread::sleep(Duration::from_millis(50));
+        }
+        server.publish(IpcEvent::Switch { target: "korean" });
+
+        let mut reader = BufReader::new(stream);
+        let mut line = String::new();
+        loop {
+            line.clear();
+            reader.read_line(&mut line).unwrap();
+            if line.contains("korean") {
+                break;
+            }
+            assert!(line.contains("\"event\":\"switch\""), "{}", line);
+        }
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_server_replaces_stale_socket_but_not_live_one() {
+        let dir = std::env::temp_dir().join(format!("koing_ipc_stale_{}", std::process::id()));
+        let path = dir.join(SOCKET_FILE_NAME);
+        std::fs::create_dir_all(&dir).unwrap();
+        // 비정상 종료로 남은 소켓 파일 (듣는 쪽 없음)
+        drop(UnixListener::bind(&path).unwrap());
+        assert!(path.exists());
+
+        let server = IpcServer::start(&path).unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+
+        // 살아 있는 소켓은 지우지 않음
+        let err = IpcServer::start(&path).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::AddrInUse);
+        assert!(UnixStream::connect(server.path()).is_ok());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
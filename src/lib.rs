@@ -1,11 +1,33 @@
-pub mod config;
+// 변환 엔진 (플랫폼 독립)
 pub mod core;
 pub mod detection;
 pub mod ngram;
+
+// 앱 전용 (설정/통계/스레드 감시)
+#[cfg(feature = "app")]
+pub mod config;
+#[cfg(all(feature = "app", unix))]
+pub mod ipc;
+#[cfg(feature = "app")]
+pub mod logging;
+#[cfg(feature = "app")]
+pub mod snapshot;
+#[cfg(feature = "app")]
+pub mod stats;
+#[cfg(feature = "app")]
+pub mod supervisor;
+#[cfg(feature = "app")]
+pub mod timing;
+
+// macOS 전용 (이벤트 탭/메뉴바)
+#[cfg(all(feature = "app", target_os = "macos"))]
+pub mod history;
+#[cfg(all(feature = "app", target_os = "macos"))]
 pub mod platform;
+#[cfg(all(feature = "app", target_os = "macos"))]
 pub mod ui;
 
-pub use core::converter::convert;
+pub use core::converter::{convert, convert_normalized, normalize_double_consonants, HangulForm};
 pub use detection::{
     has_excessive_jamo, has_incomplete_jamo, is_valid_hangul_result, AutoDetector,
 };
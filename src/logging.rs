@@ -0,0 +1,309 @@
+//! 로그 출력 형식 (텍스트 / 한 줄 JSON)
+//!
+//! env_logger를 감싼 로거 레이어입니다. Json 모드에서는 모든 로그를 한 줄 JSON으로 출력하고,
+//! 주요 이벤트(변환 판정, 탭 재활성화, 입력 소스 전환 실패)는 [`LogEvent`]로 이벤트 타입과
+//! 필드를 함께 남겨 진단 자동화 도구가 파싱할 수 있게 합니다.
+//! 입력 텍스트 같은 민감 필드는 debug 로그가 켜져 있을 때만 포함합니다.
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 로그 출력 형식
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// env_logger 기본 텍스트 — 기본값
+    #[default]
+    Text,
+    /// 한 줄 JSON (ts, level, event, message, fields)
+    Json,
+}
+
+/// 현재 로그 형식 (설정 로드 전에도 로거가 동작하므로 전역으로 전환)
+static LOG_FORMAT: AtomicU8 = AtomicU8::new(LogFormat::Text as u8);
+
+/// 구조적 이벤트 로그 target (JSON 포맷터가 이벤트 필드를 펼침)
+pub const EVENT_TARGET: &str = "koing::event";
+
+/// 로그 형식 설정 (설정 로드 후 호출)
+pub fn set_log_format(format: LogFormat) {
+    LOG_FORMAT.store(format as u8, Ordering::Relaxed);
+}
+
+/// 현재 로그 형식
+pub fn log_format() -> LogFormat {
+    match LOG_FORMAT.load(Ordering::Relaxed) {
+        raw if raw == LogFormat::Json as u8 => LogFormat::Json,
+        _ => LogFormat::Text,
+    }
+}
+
+/// env_logger 초기화 (RUST_LOG가 없으면 default_filter), 형식 전환 레이어 포함
+pub fn init_logger(default_filter: &str) {
+    let inner =
+        env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(default_filter))
+            .build();
+    let max_level = inner.filter();
+    if log::set_boxed_logger(Box::new(FormatLogger { inner })).is_ok() {
+        log::set_max_level(max_level);
+    }
+}
+
+/// 로그 형식에 따라 env_logger(텍스트) 또는 한 줄 JSON으로 출력하는 로거
+struct FormatLogger<L> {
+    inner: L,
+}
+
+impl<L: Log> Log for FormatLogger<L> {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.inner.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        match log_format() {
+            LogFormat::Text => self.inner.log(record),
+            LogFormat::Json => {
+                if !self.inner.enabled(record.metadata()) {
+                    return;
+                }
+                let message = record.args().to_string();
+                eprintln!(
+                    "{}",
+                    json_line(now_ms(), record.level(), record.target(), &message)
+                );
+            }
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// 로그 한 줄을 JSON 객체로 직렬화
+///
+/// 이벤트 target의 메시지는 [`LogEvent`]가 만든 JSON 객체이므로 그대로 펼치고,
+/// 일반 로그는 `event: "log"`와 target/메시지로 감쌉니다.
+pub fn json_line(ts_ms: u64, level: Level, target: &str, message: &str) -> String {
+    let mut line = Map::new();
+    line.insert("ts".to_string(), Value::from(ts_ms));
+    line.insert("level".to_string(), Value::from(level.as_str()));
+    let event = (target == EVENT_TARGET)
+        .then(|| serde_json::from_str::<Map<String, Value>>(message).ok())
+        .flatten();
+    match event {
+        Some(event) => line.extend(event),
+        None => {
+            line.insert("event".to_string(), Value::from("log"));
+            line.insert("target".to_string(), Value::from(target));
+            line.insert("message".to_string(), Value::from(message));
+        }
+    }
+    Value::Object(line).to_string()
+}
+
+/// 구조적 로그 이벤트
+///
+/// ```
+/// use koing::logging::LogEvent;
+///
+/// LogEvent::new(log::Level::Warn, "tap_reenable", "이벤트 탭 재활성화 실패")
+///     .field("attempt", 1)
+///     .emit();
+/// ```
+#[derive(Debug, Clone)]
+pub struct LogEvent {
+    level: Level,
+    kind: &'static str,
+    message: String,
+    fields: Vec<(&'static str, Value)>,
+    /// 입력 텍스트 등 (debug 로그가 켜져 있을 때만 출력)
+    sensitive: Vec<(&'static str, String)>,
+}
+
+impl LogEvent {
+    /// kind: 이벤트 타입 (snake_case), message: 민감 정보 없는 요약
+    pub fn new(level: Level, kind: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            level,
+            kind,
+            message: message.into(),
+            fields: Vec::new(),
+            sensitive: Vec::new(),
+        }
+    }
+
+    pub fn field(mut self, key: &'static str, value: impl Into<Value>) -> Self {
+        self.fields.push((key, value.into()));
+        self
+    }
+
+    /// 민감 필드 (입력/변환 텍스트) — debug 레벨에서만 포함
+    pub fn sensitive(mut self, key: &'static str, value: impl Into<String>) -> Self {
+        self.sensitive.push((key, value.into()));
+        self
+    }
+
+    /// 현재 로그 형식으로 출력
+    pub fn emit(self) {
+        if !log::log_enabled!(target: EVENT_TARGET, self.level) {
+            return;
+        }
+        let include_sensitive = log::max_level() >= LevelFilter::Debug;
+        match log_format() {
+            LogFormat::Text => log::log!(self.level, "{}", self.to_text(include_sensitive)),
+            LogFormat::Json => log::log!(
+                target: EVENT_TARGET,
+                self.level,
+                "{}",
+                self.to_json(include_sensitive)
+            ),
+        }
+    }
+
+    /// 텍스트 형식: "메시지 (키=값, ...)"
+    fn to_text(&self, include_sensitive: bool) -> String {
+        let fields: Vec<String> = self
+            .visible_fields(include_sensitive)
+            .map(|(key, value)| match value {
+                Value::String(s) => format!("{}={}", key, s),
+                other => format!("{}={}", key, other),
+            })
+            .collect();
+        if fields.is_empty() {
+            self.message.clone()
+        } else {
+            format!("{} ({})", self.message, fields.join(", "))
+        }
+    }
+
+    /// JSON 형식: {"event", "message", "fields"} (ts/level은 [`json_line`]이 추가)
+    fn to_json(&self, include_sensitive: bool) -> String {
+        let fields: Map<String, Value> = self
+            .visible_fields(include_sensitive)
+            .map(|(key, value)| (key.to_string(), value))
+            .collect();
+        let mut event = Map::new();
+        event.insert("event".to_string(), Value::from(self.kind));
+        event.insert("message".to_string(), Value::from(self.message.as_str()));
+        event.insert("fields".to_string(), Value::Object(fields));
+        Value::Object(event).to_string()
+    }
+
+    fn visible_fields(&self, include_sensitive: bool) -> impl Iterator<Item = (&str, Value)> {
+        let sensitive = self
+            .sensitive
+            .iter()
+            .filter(move |_| include_sensitive)
+            .map(|(key, value)| (*key, Value::from(value.as_str())));
+        self.fields
+            .iter()
+            .map(|(key, value)| (*key, value.clone()))
+            .chain(sensitive)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(line: &str) -> Map<String, Value> {
+        serde_json::from_str(line).expect("유효한 JSON 한 줄")
+    }
+
+    fn sample_event() -> LogEvent {
+        LogEvent::new(Level::Debug, "conversion_decision", "자동 변환 스킵")
+            .field("decision", "reject")
+            .field("length", 6)
+            .sensitive("text", "dkssud")
+    }
+
+    #[test]
+    fn test_event_json_line_has_expected_fields() {
+        let event = sample_event();
+        let line = json_line(
+            1_700_000_000_000,
+            Level::Debug,
+            EVENT_TARGET,
+            &event.to_json(false),
+        );
+        assert!(!line.contains('\n'));
+
+        let parsed = parse(&line);
+        assert_eq!(parsed["ts"], 1_700_000_000_000u64);
+        assert_eq!(parsed["level"], "DEBUG");
+        assert_eq!(parsed["event"], "conversion_decision");
+        assert_eq!(parsed["message"], "자동 변환 스킵");
+        assert_eq!(parsed["fields"]["decision"], "reject");
+        assert_eq!(parsed["fields"]["length"], 6);
+        // 민감 텍스트는 기본 제외
+        assert!(parsed["fields"].get("text").is_none());
+        assert!(!line.contains("dkssud"));
+    }
+
+    #[test]
+    fn test_sensitive_fields_only_when_debug() {
+        let event = sample_event();
+        let parsed = parse(&json_line(
+            0,
+            Level::Debug,
+            EVENT_TARGET,
+            &event.to_json(true),
+        ));
+        assert_eq!(parsed["fields"]["text"], "dkssud");
+
+        assert_eq!(
+            event.to_text(false),
+            "자동 변환 스킵 (decision=reject, length=6)"
+        );
+        assert_eq!(
+            event.to_text(true),
+            "자동 변환 스킵 (decision=reject, length=6, text=dkssud)"
+        );
+        assert_eq!(
+            LogEvent::new(Level::Warn, "tap_reenable", "재활성화 실패").to_text(true),
+            "재활성화 실패"
+        );
+    }
+
+    #[test]
+    fn test_plain_log_is_wrapped_as_json() {
+        // 따옴표/줄바꿈이 있는 메시지도 한 줄 JSON으로 이스케이프
+        let line = json_line(
+            5,
+            Level::Warn,
+            "koing::platform::event_tap",
+            "이벤트 탭 \"비활성화\"\n감지",
+        );
+        assert!(!line.contains('\n'));
+        let parsed = parse(&line);
+        assert_eq!(parsed["event"], "log");
+        assert_eq!(parsed["level"], "WARN");
+        assert_eq!(parsed["target"], "koing::platform::event_tap");
+        assert_eq!(parsed["message"], "이벤트 탭 \"비활성화\"\n감지");
+
+        // 이벤트 target이라도 JSON이 아니면 일반 로그로 감쌈
+        let parsed = parse(&json_line(5, Level::Info, EVENT_TARGET, "not json"));
+        assert_eq!(parsed["event"], "log");
+    }
+
+    #[test]
+    fn test_log_format_setting() {
+        assert_eq!(
+            serde_json::from_str::<LogFormat>("\"json\"").unwrap(),
+            LogFormat::Json
+        );
+        assert_eq!(serde_json::to_string(&LogFormat::Text).unwrap(), "\"text\"");
+        assert_eq!(LogFormat::default(), LogFormat::Text);
+    }
+}
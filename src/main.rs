@@ -1,41 +1,477 @@
 //! Koing - macOS 한영 자동변환 프로그램
 
-use koing::config::load_config;
-use koing::ngram::{KoreanValidator, RejectReason};
+use koing::config::{
+    config_dir_from_args, config_path, load_config, set_config_dir_override, KoingConfig,
+};
+use koing::core::finalize::finalize_syllables;
+use koing::core::pipeline::{ConversionGate, ConversionValidator, Converter, TwoSetConverter};
+use koing::detection::{has_excessive_uppercase, has_url_pattern, plan_reverse, ReversePlan};
+use koing::history::{clear_history, load_history, save_history, HistoryLog};
+use koing::ipc::{self, IpcEvent};
+use koing::logging::{init_logger, set_log_format, LogEvent};
+use koing::ngram::{korean_to_eng, KoreanValidator};
 use koing::platform::{
-    event_tap::{start_event_tap, EventTapState, HotkeyConfig},
-    input_source::{start_input_source_observers, switch_to_korean_on_main_with_timeout},
+    accessibility::announce,
+    app_focus::start_app_focus_observer,
+    dispatch_to_main,
+    event_tap::{
+        backspace_count_for, backspace_count_for_conversion, convertible_segment,
+        extract_hangul_keys, run_event_tap, ConversionHistory, EventTapState, HotkeyConfig,
+        SwitchCommand,
+    },
+    focused_element::{focused_selected_text, replace_focused_selected_text},
+    input_source::{
+        cached_input_source_snapshot, current_input_source_state_on_main,
+        set_preferred_korean_source, start_input_source_observers, switch_failure_event,
+        switch_to_english, switch_to_english_on_main_with_timeout,
+        switch_to_korean_on_main_with_timeout, InputSourceState, SwitchOutcome,
+    },
     os_version::{get_macos_version, is_sonoma_or_later},
     permissions::{
         check_accessibility_permission, request_accessibility_permission,
         reset_accessibility_permission, wait_for_accessibility_permission,
     },
-    text_replacer::{replace_text, undo_replace_text},
+    set_thread_qos,
+    speech::speak_conversion,
+    text_replacer::{
+        replace_text, replace_text_at_cursor, replace_text_via_text_input, undo_replace_text,
+    },
+    ThreadQos,
 };
-use koing::ui::menubar::MenuBarApp;
-use koing::AutoDetector;
+use koing::stats::{load_stats, StatKind};
+use koing::supervisor::{install_panic_hook, spawn_supervised, RestartPolicy};
+use koing::timing::{timing_stats, Stage};
+use koing::ui::accessibility::{conversion_announcement, undo_announcement, A11yLanguage};
+use koing::ui::debug_overlay::show_debug_overlay;
+use koing::ui::menubar::{apply_enabled_change, update_tap_status, MenuBarApp};
+use koing::ui::onboarding::{needs_onboarding, show_onboarding_window};
+use koing::{has_excessive_jamo, normalize_double_consonants, AutoDetector};
 use std::sync::atomic::Ordering as AtomicOrdering;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{mpsc, Arc};
+use std::sync::{mpsc, Arc, Mutex};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 /// 워커 스레드가 처리할 작업 항목
+#[derive(Debug, PartialEq)]
 enum WorkItem {
     /// 영문→한글 변환 (버퍼 내용, 수동 변환 여부)
     Convert(String, bool),
     /// Undo (한글 텍스트, 원본 영문)
     Undo(String, String),
+    /// 선택 텍스트 일괄 변환 (버퍼가 빈 상태의 수동 단축키)
+    ConvertSelection,
+    /// 한글→영문 역변환 (한글 모드에서 입력된 영단어 키 시퀀스, 뒤에 Space 입력됨)
+    Reverse(String),
+}
+
+/// 선택 텍스트 변환 결정
+#[derive(Debug, PartialEq)]
+enum SelectionPlan {
+    /// 선택 텍스트 조회 실패 — 아무 것도 하지 않음
+    Unavailable,
+    /// 선택 없음
+    NoSelection,
+    /// 변환 결과가 원문과 같거나 낱자모 과다 — 교체하지 않음
+    Invalid,
+    /// 변환 결과로 선택 영역 교체
+    Replace(String),
+}
+
+/// 선택 텍스트(None: 조회 실패)에 대한 변환 결정
+fn plan_selection_conversion(selected: Option<&str>) -> SelectionPlan {
+    let Some(selected) = selected else {
+        return SelectionPlan::Unavailable;
+    };
+    if selected.is_empty() {
+        return SelectionPlan::NoSelection;
+    }
+    let hangul = koing::convert(selected);
+    if hangul == selected || has_excessive_jamo(&hangul) {
+        SelectionPlan::Invalid
+    } else {
+        SelectionPlan::Replace(hangul)
+    }
+}
+
+/// 변환 불가 입력에서 입력 소스만 한글로 전환할 최소 신뢰도
+const UNCONVERTIBLE_SWITCH_MIN_CONFIDENCE: f32 = 70.0;
+/// 변환 불가 전환 후 다음 전환까지 최소 간격 (과도한 전환 방지)
+const UNCONVERTIBLE_SWITCH_COOLDOWN: Duration = Duration::from_secs(5);
+
+/// 변환 결과가 비었거나 원본과 같지만(변환 불가) 한글 입력 의도가 높은지
+fn is_unconvertible_korean_intent(confidence: f32, keys: &str, converted: &str) -> bool {
+    (converted.is_empty() || converted == keys) && confidence >= UNCONVERTIBLE_SWITCH_MIN_CONFIDENCE
+}
+
+/// 일정 간격 안에서는 한 번만 허용하는 쿨다운
+struct Cooldown {
+    interval: Duration,
+    last: Option<Instant>,
+}
+
+impl Cooldown {
+    fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last: None,
+        }
+    }
+
+    /// 마지막 허용 후 간격이 지났으면 now를 기록하고 true
+    fn try_fire(&mut self, now: Instant) -> bool {
+        if self
+            .last
+            .is_some_and(|last| now.saturating_duration_since(last) < self.interval)
+        {
+            return false;
+        }
+        self.last = Some(now);
+        true
+    }
+}
+
+/// paste 반영을 확인하지 못했을 때 입력 소스 전환 전 추가 대기
+const PASTE_SETTLE_DELAY: Duration = Duration::from_millis(200);
+/// 메인 스레드 한글 전환(검증 포함) 최대 대기
+const KOREAN_SWITCH_TIMEOUT: Duration = Duration::from_millis(500);
+/// 교체 직전 메인 스레드 입력 소스 확인/영문 전환 최대 대기
+const PRE_REPLACE_SWITCH_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// 교체 → paste 반영 대기 → 입력 소스 전환 → is_replacing 해제 순서로 실행
+///
+/// 전환 전에 해제하면 그 사이 입력된 키가 영문으로 남으므로, is_replacing은
+/// switch가 끝난(전환 검증 또는 타임아웃) 뒤에만 해제합니다.
+/// - replace: 텍스트 교체, Ok(true)면 반영(커서 이동)을 확인한 것 — settle 생략
+/// - 교체 실패 시 전환 없이 해제
+fn replace_then_switch(
+    replacing: &AtomicBool,
+    replace: impl FnOnce() -> Result<bool, String>,
+    settle: impl FnOnce(),
+    switch: impl FnOnce(),
+) -> Result<(), String> {
+    replacing.store(true, AtomicOrdering::Release);
+    let result = replace().map(|confirmed| {
+        if !confirmed {
+            settle();
+        }
+        switch();
+    });
+    replacing.store(false, AtomicOrdering::Release);
+    result
+}
+
+/// 교체 전후 입력 소스 전환 계획
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ReplaceSwitchPlan {
+    /// 교체 전 영문 전환 (이미 한글 모드라 Backspace/paste가 한글 조합과 섞일 수 있음)
+    english_before: bool,
+    /// 교체 후 한글 전환
+    korean_after: bool,
+}
+
+/// 교체 직전 입력 소스 상태로 전환 계획 결정
+///
+/// - 한글 모드일 때만 교체 전 영문 전환 (전환 비용이 커서 영문/Unknown이면 생략)
+/// - 교체 후 한글 전환은 앱이 허용할 때, 또는 교체 전에 영문으로 바꿨다면 원래대로 되돌림
+fn plan_replace_switch(current: InputSourceState, switch_allowed: bool) -> ReplaceSwitchPlan {
+    let english_before = current == InputSourceState::NonEnglish;
+    ReplaceSwitchPlan {
+        english_before,
+        korean_after: switch_allowed || english_before,
+    }
+}
+
+/// 교체 직전 입력 소스 재확인 (is_replacing 구간 안에서 호출)
+///
+/// 캐시가 신선하면 그대로 쓰고, 아니면 메인 스레드에서 확인 — 한글 모드면 영문으로 전환
+fn prepare_replace_switch(state: &EventTapState) -> ReplaceSwitchPlan {
+    let snapshot = cached_input_source_snapshot();
+    let current = if snapshot.is_fresh {
+        snapshot.state
+    } else {
+        current_input_source_state_on_main(PRE_REPLACE_SWITCH_TIMEOUT)
+    };
+    let plan = plan_replace_switch(current, state.switch_allowed_for_focused_app());
+    if plan.english_before {
+        let outcome = switch_to_english_on_main_with_timeout(PRE_REPLACE_SWITCH_TIMEOUT);
+        log::debug!("교체 전 한글 모드 감지, 영문 전환: {:?}", outcome);
+    }
+    plan
+}
+
+/// 변환 후 한글 전환 경로
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KoreanSwitchPath {
+    /// 워커가 교체 직후 바로 전환 (전환 검증까지 대기)
+    Immediate,
+    /// 전환 타이머로 switch_delay_ms 뒤 전환 (그 사이 키 입력이 있으면 취소)
+    Timer,
+}
+
+/// switch_delay_ms에 따른 전환 경로 (0이면 타이머를 거치지 않음)
+fn korean_switch_path(switch_delay_ms: u64) -> KoreanSwitchPath {
+    if switch_delay_ms == 0 {
+        KoreanSwitchPath::Immediate
+    } else {
+        KoreanSwitchPath::Timer
+    }
+}
+
+/// 변환 교체 직후 한글 자판 전환
+///
+/// 메인 스레드가 응답하지 않으면 타임아웃 후 진행하여 worker 블로킹 방지.
+/// 입력 소스 전환과 충돌하는 앱(no_switch_bundle_ids)에서는 교체만 수행.
+fn switch_to_korean_after_replace(state: &EventTapState) {
+    if state.switch_allowed_for_focused_app() {
+        switch_to_korean_after_convert(state);
+    }
+}
+
+fn switch_to_korean_after_convert(state: &EventTapState) {
+    match korean_switch_path(state.get_switch_delay_ms()) {
+        KoreanSwitchPath::Immediate => switch_to_korean_verified(),
+        KoreanSwitchPath::Timer => state.send_switch_command(SwitchCommand::Reset),
+    }
+}
+
+fn switch_to_korean_verified() {
+    let outcome = switch_to_korean_on_main_with_timeout(KOREAN_SWITCH_TIMEOUT);
+    if outcome != SwitchOutcome::Switched {
+        log::debug!("변환 후 한글 전환 미완료: {:?}", outcome);
+    }
+}
+
+/// 변환 요청 판정 결과
+#[derive(Debug, PartialEq)]
+enum ConvertPlan {
+    /// 변환 결과로 교체
+    Replace(String),
+    /// 변환하지 않음 (사유)
+    Skip(String),
+    /// 자동 변환 검증 거부 (통계에 집계)
+    Reject(String),
+    /// 변환 결과가 비었거나 원본과 같음
+    Unconvertible { keys: String, converted: String },
+}
+
+/// 워커 변환 판정 (엔진 구성요소는 trait object로 주입)
+struct ConversionPipeline {
+    converter: Box<dyn Converter>,
+    gate: Box<dyn ConversionGate>,
+    validator: Box<dyn ConversionValidator>,
+    /// 실험적: 같은 자음 2연타를 쌍자음으로 추론
+    infer_double_consonant: bool,
+    /// 변환 결과 끝의 낱자모 정리 (후처리)
+    finalize_syllables: bool,
+}
+
+impl ConversionPipeline {
+    /// 설정 기반 기본 구성 (두벌식 변환기, AutoDetector, KoreanValidator)
+    fn from_config(config: &KoingConfig) -> Self {
+        let mut detector = AutoDetector::default();
+        detector.set_never_convert_words(config.never_convert_words.clone());
+        detector.set_pass_through_jamo_only(config.pass_through_jamo_only);
+        Self {
+            converter: Box::new(TwoSetConverter),
+            gate: Box::new(detector),
+            validator: Box::new(KoreanValidator::load_with_config(
+                config.ngram.to_ngram_config(),
+            )),
+            infer_double_consonant: config.infer_double_consonant,
+            finalize_syllables: config.finalize_syllables,
+        }
+    }
+
+    /// 판정/변환에 쓸 키 (쌍자음 추론 적용, 지울 개수는 화면의 원문 기준)
+    fn keys_for(&self, text: &str) -> String {
+        if self.infer_double_consonant {
+            normalize_double_consonants(text)
+        } else {
+            text.to_string()
+        }
+    }
+
+    /// 공백 없이 이어 친 구간을 단어 경계에서 나눔 (앞부분, 뒷부분 — 나눌 곳이 없으면 뒷부분은 빈 문자열)
+    ///
+    /// 한글 키로만 된 구간에서, 변환 결과를 역변환한 키가 원문과 같을 때만 나눔
+    fn split_long_segment<'a>(&self, segment: &'a str) -> (&'a str, &'a str) {
+        if extract_hangul_keys(segment) != segment {
+            return (segment, "");
+        }
+        let hangul = self.converter.convert(&self.keys_for(segment));
+        if korean_to_eng(&hangul) != segment {
+            return (segment, "");
+        }
+        match self.validator.split_point(&hangul) {
+            Some(at) => {
+                let front: String = hangul.chars().take(at).collect();
+                segment.split_at(korean_to_eng(&front).len())
+            }
+            None => (segment, ""),
+        }
+    }
+
+    /// 단어 변환 (치환 규칙에 걸리지 않은 단어용)
+    fn convert_word(&self, word: &str) -> String {
+        self.converter.convert(&self.keys_for(word))
+    }
+
+    /// 변환 구간(buffer)의 변환 여부 판정 (raw_buffer: 앞쪽 비한글 키 포함 원문)
+    ///
+    /// 수동 변환은 URL/대문자/1차 필터/결과 검증을 건너뜀
+    fn plan(&self, raw_buffer: &str, buffer: &str, is_manual: bool) -> ConvertPlan {
+        // URL/이메일 입력은 자동 변환하지 않음
+        if !is_manual && has_url_pattern(raw_buffer) {
+            return ConvertPlan::Skip("URL/이메일".to_string());
+        }
+        // 변수명/약어처럼 비한글 대문자가 많은 입력 (판정용 키에서는 빠지므로 원문 기준)
+        if !is_manual && has_excessive_uppercase(buffer) {
+            return ConvertPlan::Skip("비한글 대문자 과다".to_string());
+        }
+
+        let keys = self.keys_for(buffer);
+        // 판정은 한글 전용 키 기준, 교체는 원문(숫자/기호 포함) 기준
+        let judge = extract_hangul_keys(&keys);
+        if !is_manual {
+            if let Err(reason) = self.gate.check_input(&judge) {
+                return ConvertPlan::Skip(reason);
+            }
+        }
+
+        let started = timing_stats().start();
+        let hangul = self.converter.convert(&keys);
+        timing_stats().finish(Stage::Convert, started);
+        if hangul.is_empty() || hangul == keys {
+            return ConvertPlan::Unconvertible {
+                keys,
+                converted: hangul,
+            };
+        }
+
+        if !is_manual {
+            let started = timing_stats().start();
+            let verdict = self
+                .validator
+                .validate(&judge, self.gate.looks_like_english(&judge));
+            timing_stats().finish(Stage::Validate, started);
+            if let Err(reason) = verdict {
+                return ConvertPlan::Reject(reason);
+            }
+        }
+
+        // 역변환한 키 수가 변환한 키와 다르면 교체하지 않음
+        // (불일치 내역은 backspace_count_for_conversion에서 로그)
+        if backspace_count_for_conversion(&keys, &hangul).is_none() {
+            return ConvertPlan::Skip("변환 전후 키 수 불일치".to_string());
+        }
+        if self.finalize_syllables {
+            return ConvertPlan::Replace(finalize_syllables(&hangul));
+        }
+        ConvertPlan::Replace(hangul)
+    }
+}
+
+/// 워커 변환 판정 로그 이벤트 (입력 텍스트는 debug 로그에서만 포함)
+fn conversion_decision_event(
+    decision: &'static str,
+    reason: &str,
+    buffer: &str,
+    is_manual: bool,
+) -> LogEvent {
+    let message = if decision == "replace" {
+        "변환 판정: 교체"
+    } else {
+        "자동 변환 스킵"
+    };
+    let mut event = LogEvent::new(log::Level::Debug, "conversion_decision", message)
+        .field("decision", decision)
+        .field("manual", is_manual)
+        .field("length", buffer.chars().count());
+    if !reason.is_empty() {
+        event = event.field("reason", reason);
+    }
+    event.sensitive("text", buffer)
+}
+
+/// 변환 이력 파일에 기록
+fn persist_conversion(history: &mut HistoryLog, original: &str, converted: &str) {
+    history.push(ConversionHistory {
+        original: original.to_string(),
+        converted: converted.to_string(),
+    });
+    if let Err(e) = save_history(history) {
+        log::warn!("{}", e);
+    }
+}
+
+/// 대기 작업 목록에서 뒤에 더 최신 변환이 있는 변환 요청 제거 (순서 유지)
+///
+/// 우선순위:
+/// - Undo/선택 텍스트 변환/역변환: 사용자 명시 동작이거나 단어마다 한 번뿐이므로 항상 처리
+/// - 수동 변환: 더 최신 수동 변환이 있을 때만 건너뜀
+/// - 자동 변환: 더 최신 변환(자동/수동)이 있으면 건너뜀
+fn coalesce_work_items(items: Vec<WorkItem>) -> Vec<WorkItem> {
+    let mut later_convert = false;
+    let mut later_manual = false;
+    let mut kept: Vec<WorkItem> = items
+        .into_iter()
+        .rev()
+        .filter(|item| match item {
+            WorkItem::Convert(_, is_manual) => {
+                let superseded = if *is_manual {
+                    later_manual
+                } else {
+                    later_convert
+                };
+                later_convert = true;
+                later_manual |= *is_manual;
+                !superseded
+            }
+            WorkItem::Undo(..) | WorkItem::ConvertSelection | WorkItem::Reverse(_) => true,
+        })
+        .collect();
+    kept.reverse();
+    kept
+}
+
+/// 첫 작업과 채널에 대기 중인 작업을 모두 꺼내 coalescing
+/// 반환: (처리할 작업, 꺼낸 자동 변환 요청 수 — 건너뛴 요청 포함)
+fn drain_pending(first: WorkItem, rx: &mpsc::Receiver<WorkItem>) -> (Vec<WorkItem>, usize) {
+    let mut items = vec![first];
+    items.extend(rx.try_iter());
+    let total = items.len();
+    let auto_converts = items
+        .iter()
+        .filter(|item| matches!(item, WorkItem::Convert(_, false)))
+        .count();
+    let items = coalesce_work_items(items);
+    if items.len() < total {
+        log::debug!("오래된 변환 요청 {}건 스킵", total - items.len());
+    }
+    (items, auto_converts)
 }
 
 fn main() {
-    // 로깅 초기화 (error/warn만 출력)
-    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("warn")).init();
+    // 로깅 초기화 (error/warn만 출력, 형식은 설정 로드 후 적용)
+    init_logger("warn");
+    // 패닉을 ~/Library/Logs/Koing/panic.log에 기록
+    install_panic_hook();
 
     // macOS 버전 로깅
     let version = get_macos_version();
     log::warn!("macOS {} 에서 실행 중", version);
 
+    // 설정 디렉토리 override (--config-dir, 없으면 KOING_CONFIG_DIR 또는 기본 경로)
+    if let Some(dir) = config_dir_from_args(std::env::args().skip(1)) {
+        set_config_dir_override(dir);
+    }
+
+    // 설정 로드 (설정 파일이 없으면 최초 실행)
+    let config = load_config();
+    set_log_format(config.log_format);
+    let first_run = needs_onboarding(config_path().exists(), &config);
+
     // Accessibility 권한 확인
     // 재설치/업그레이드 시 이전 빌드의 ad-hoc 서명에 연결된 stale TCC 항목이
     // 남아있으면 권한이 인식되지 않으므로, 권한 미획득 시 TCC 항목을 초기화
@@ -43,7 +479,10 @@ fn main() {
         reset_accessibility_permission();
     }
 
-    if is_sonoma_or_later() {
+    if first_run {
+        // 최초 실행: 종료하지 않고 온보딩 윈도우에서 권한 안내 (이벤트 탭은 허용될 때까지 대기)
+        log::warn!("최초 실행, 온보딩 표시");
+    } else if is_sonoma_or_later() {
         // Sonoma/Sequoia에서는 TCC DB 업데이트가 지연될 수 있으므로 폴링 대기
         if !wait_for_accessibility_permission(Duration::from_secs(30)) {
             eprintln!();
@@ -64,8 +503,20 @@ fn main() {
         std::process::exit(1);
     }
 
-    // 설정 로드
-    let config = load_config();
+    // 변환 단계별 시간 측정 (디버그 빌드 또는 debug_logging 설정 시)
+    timing_stats().set_enabled(cfg!(debug_assertions) || config.debug_logging);
+    if timing_stats().is_enabled() && config.timing_log_interval_secs > 0 {
+        let interval = Duration::from_secs(config.timing_log_interval_secs);
+        thread::spawn(move || {
+            set_thread_qos(ThreadQos::Utility);
+            loop {
+                thread::sleep(interval);
+                if let Some(summary) = timing_stats().summary() {
+                    log::warn!("{}", summary);
+                }
+            }
+        });
+    }
     start_input_source_observers();
 
     // 앱 실행 상태
@@ -75,118 +526,358 @@ fn main() {
     let event_state = Arc::new(EventTapState::new(HotkeyConfig::default()));
     event_state.set_enabled(config.enabled);
     event_state.set_debounce_ms(config.debounce_ms);
+    event_state.set_adaptive_debounce(config.adaptive_debounce);
     event_state.set_switch_delay_ms(config.switch_delay_ms);
     event_state.set_slow_debounce_ms(config.slow_debounce_ms);
+    event_state.set_two_stage_conversion(config.two_stage_conversion);
     if let Ok(mut detector) = event_state.auto_detector.lock() {
         detector.set_never_convert_words(config.never_convert_words.clone());
+        detector.set_pass_through_jamo_only(config.pass_through_jamo_only);
+    }
+    event_state.set_app_profiles(config.app_profiles.clone(), config.profile_params.clone());
+    event_state.set_sensitivity(config.sensitivity);
+    event_state.set_soft_switch_on_reject(config.soft_switch_on_reject);
+    event_state.set_reverse_to_english(config.reverse_to_english);
+    event_state.set_reset_keycodes(config.reset_keycodes.clone());
+    event_state.set_ngram_settings(config.ngram.clone());
+    event_state.set_expansions(&config.expansions);
+    event_state.set_no_switch_bundle_ids(config.no_switch_bundle_ids.clone());
+    set_preferred_korean_source(config.korean_source_id.as_deref());
+
+    // 직전 세션의 마지막 변환 복원 (재시작 후 Undo)
+    let history = if config.persist_history {
+        load_history()
+    } else {
+        if let Err(e) = clear_history() {
+            log::warn!("{}", e);
+        }
+        HistoryLog::default()
+    };
+    if let Some(last) = history.last() {
+        event_state.save_conversion_history(last.original.clone(), last.converted.clone());
+    }
+    // 누적 변환 통계 복원 (종료 시 메뉴바에서 저장)
+    event_state.set_stats(load_stats());
+
+    // 외부 구독자용 이벤트 소켓 (실패해도 변환 기능은 그대로 동작)
+    if config.ipc_enabled {
+        if let Err(e) = ipc::start(&config_path().with_file_name(ipc::SOCKET_FILE_NAME)) {
+            log::warn!("IPC 이벤트 소켓 시작 실패: {}", e);
+        }
     }
 
+    // 포커스 앱 전환 시 감지 프로필 갱신 (코드 에디터 보수적, 메신저 적극적)
+    let event_state_for_focus = Arc::clone(&event_state);
+    start_app_focus_observer(move |bundle_id| {
+        // 앱이 바뀌면 이전 앱에서 입력하던 버퍼/타이머는 무효
+        event_state_for_focus.reset_input_state();
+        event_state_for_focus.apply_profile_for_app(bundle_id);
+    });
+
     // 워커 스레드 채널 — 변환/Undo 작업을 단일 스레드에서 직렬 처리
     let (work_tx, work_rx) = mpsc::channel::<WorkItem>();
+    // 패닉 후 재시작된 워커가 같은 채널/이력을 이어받도록 공유
+    let work_rx = Mutex::new(work_rx);
+    let history = Mutex::new(history);
 
     let event_state_for_worker = Arc::clone(&event_state);
     let worker_config = config.clone();
-    thread::spawn(move || {
-        let validator = KoreanValidator::load_default().unwrap_or_else(|e| {
-            log::warn!(
-                "기본 N-gram 모델 로드 실패, 휴리스틱 모드로 계속 진행: {}",
-                e
-            );
-            KoreanValidator::new()
-        });
-        let mut english_detector = AutoDetector::default();
-        english_detector.set_never_convert_words(worker_config.never_convert_words);
-
-        while let Ok(item) = work_rx.recv() {
-            match item {
-                WorkItem::Convert(buffer, is_manual) => {
-                    if !is_manual && english_detector.is_blocked_english_word(&buffer) {
-                        log::debug!("자동 변환 차단: 영어 예외어 '{}'", buffer);
-                        continue;
-                    }
+    spawn_supervised("koing-worker", RestartPolicy::default(), move || {
+        // 변환 요청은 사용자가 결과를 기다리는 작업
+        set_thread_qos(ThreadQos::UserInitiated);
+        let work_rx = work_rx.lock().unwrap_or_else(|e| e.into_inner());
+        let mut history = history.lock().unwrap_or_else(|e| e.into_inner());
+        let pipeline = ConversionPipeline::from_config(&worker_config);
+        let mut unconvertible_switch = Cooldown::new(UNCONVERTIBLE_SWITCH_COOLDOWN);
 
-                    let result = validator.analyze(&buffer);
-                    let hangul = result.converted;
+        while let Ok(first) = work_rx.recv() {
+            // 대기 중인 작업을 모두 꺼내 오래된 변환 요청은 건너뜀
+            let (items, auto_converts) = drain_pending(first, &work_rx);
+            for item in items {
+                match item {
+                    WorkItem::Convert(raw_buffer, is_manual) => {
+                        // 앞쪽 비한글 키는 화면에 확정된 텍스트 — 변환/삭제 대상에서 제외
+                        let segment = convertible_segment(&raw_buffer);
+                        // 교체 구간 길이가 비었거나 상한 초과 시 과삭제 방지를 위해 중단
+                        let Some(backspace_count) = backspace_count_for(segment) else {
+                            log::warn!(
+                                "변환 스킵: 교체 구간 길이 비정상 ({}자, '{}')",
+                                raw_buffer.chars().count(),
+                                raw_buffer
+                            );
+                            continue;
+                        };
+                        // 공백 없이 이어 친 긴 구간은 단어 경계 앞부분만 변환
+                        // (뒷부분은 원문 그대로 다시 붙여넣고 버퍼로 되돌려 다시 판정)
+                        let (front, tail) = if is_manual {
+                            (segment, "")
+                        } else {
+                            pipeline.split_long_segment(segment)
+                        };
+                        if !tail.is_empty() {
+                            log::debug!("긴 구간 분할: 앞 {}자 먼저 변환", front.len());
+                        }
+                        let (segment, buffer, tail) =
+                            (segment.to_string(), front.to_string(), tail.to_string());
 
-                    // 변환 불가능 (원본과 동일)
-                    if hangul == buffer {
-                        log::debug!("자동 변환 스킵: 변환 결과 동일 ({})", buffer);
-                        continue;
-                    }
+                        // 치환 규칙: 트리거와 일치하면 자동 감지/검증 없이 치환 결과 사용
+                        let expanded = event_state_for_worker
+                            .expand(&buffer, |word| pipeline.convert_word(word));
+                        let hangul = match expanded {
+                            Some(expanded) => {
+                                log::debug!("치환 규칙 적용: '{}'", buffer);
+                                expanded
+                            }
+                            None => match pipeline.plan(&raw_buffer, &buffer, is_manual) {
+                                ConvertPlan::Replace(hangul) => {
+                                    conversion_decision_event("replace", "", &buffer, is_manual)
+                                        .emit();
+                                    hangul
+                                }
+                                ConvertPlan::Skip(reason) => {
+                                    conversion_decision_event("skip", &reason, &buffer, is_manual)
+                                        .emit();
+                                    continue;
+                                }
+                                ConvertPlan::Reject(reason) => {
+                                    conversion_decision_event(
+                                        "reject", &reason, &buffer, is_manual,
+                                    )
+                                    .emit();
+                                    event_state_for_worker.record_stat(StatKind::Rejection);
+                                    continue;
+                                }
+                                ConvertPlan::Unconvertible { keys, converted } => {
+                                    conversion_decision_event(
+                                        "unconvertible",
+                                        "변환 결과 동일",
+                                        &buffer,
+                                        is_manual,
+                                    )
+                                    .emit();
+                                    // 옵션: 한글 의도가 높으면 입력 소스만 한글로 전환
+                                    if worker_config.switch_on_unconvertible
+                                        && is_unconvertible_korean_intent(
+                                            pipeline.gate.confidence(&keys),
+                                            &keys,
+                                            &converted,
+                                        )
+                                        && event_state_for_worker.switch_allowed_for_focused_app()
+                                        && unconvertible_switch.try_fire(Instant::now())
+                                    {
+                                        log::debug!(
+                                            "변환 불가, 입력 소스만 한글로 전환 ({})",
+                                            buffer
+                                        );
+                                        switch_to_korean_on_main_with_timeout(
+                                            KOREAN_SWITCH_TIMEOUT,
+                                        );
+                                    }
+                                    continue;
+                                }
+                            },
+                        };
 
-                    if !is_manual {
-                        // 자동 변환: 음절구조/n-gram 2차 검증
-                        if hangul.chars().count() <= 1 {
-                            log::debug!("자동 변환 스킵: 1글자 변환 ({})", hangul);
+                        // 출력 형태(NFC/NFD)는 붙여넣기에만 적용 — Undo 이력은 완성형 기준
+                        // (macOS 텍스트 뷰는 조합형 음절도 한 글자 단위로 삭제)
+                        let output = worker_config.output_form.apply(&hangul) + &tail;
+                        // 교체 → paste 반영 → 한글 전환 검증 후에 is_replacing 해제
+                        // (실시간 변환 레이스와 전환 전 키 입력이 영문으로 남는 문제 방지)
+                        let korean_after = std::cell::Cell::new(false);
+                        let replaced = replace_then_switch(
+                            &event_state_for_worker.is_replacing,
+                            || {
+                                // 그 사이 한글 모드가 됐으면 영문으로 바꾼 뒤 교체 (한글 중복 입력 방지)
+                                let switch_plan = prepare_replace_switch(&event_state_for_worker);
+                                // 뒷부분을 이어 치는 중이면 영문 유지
+                                korean_after.set(switch_plan.korean_after && tail.is_empty());
+                                let started = timing_stats().start();
+                                // 실험적: AX 텍스트 입력으로 커밋, 미지원 앱은 paste로 폴백
+                                let result = if worker_config.commit_via_text_input {
+                                    replace_text_via_text_input(backspace_count, &segment, &output)
+                                } else {
+                                    replace_text_at_cursor(backspace_count, &segment, &output)
+                                };
+                                timing_stats().finish(Stage::Replace, started);
+                                result
+                            },
+                            || thread::sleep(PASTE_SETTLE_DELAY),
+                            || {
+                                if korean_after.get() {
+                                    switch_to_korean_after_convert(&event_state_for_worker);
+                                }
+                            },
+                        );
+
+                        if let Err(e) = replaced {
+                            log::error!("텍스트 교체 실패: {}", e);
                             continue;
                         }
-                        if english_detector.looks_like_english_word(&buffer)
-                            && result.seen_bigram_count == Some(0)
-                            && result.unknown_bigram_ratio.unwrap_or_default() >= 1.0
-                        {
-                            log::debug!(
-                                "자동 변환 스킵: 영어 입력 + 미등록 bigram ({}, {:?})",
-                                buffer,
-                                result.unknown_bigram_ratio
-                            );
-                            continue;
+                        if !tail.is_empty() {
+                            event_state_for_worker.requeue_keys(&tail);
                         }
-                        if !result.should_convert {
-                            let reason = result.reject_reason.unwrap_or(RejectReason::LowScore);
-                            log::debug!("자동 변환 스킵: {:?} ({})", reason, buffer);
-                            continue;
+
+                        // VoiceOver 공지
+                        announce(&conversion_announcement(&hangul, A11yLanguage::current()));
+                        if worker_config.speak_on_convert {
+                            speak_conversion(&hangul);
                         }
+                        event_state_for_worker.record_stat(StatKind::Conversion);
+
+                        // 변환 이력 저장 (Undo용, 원문으로 남긴 뒷부분 포함)
+                        let (original, hangul) = (buffer + &tail, hangul + &tail);
+                        ipc::publish(IpcEvent::Conversion {
+                            original: original.clone(),
+                            converted: hangul.clone(),
+                            manual: is_manual,
+                        });
+                        if worker_config.persist_history {
+                            persist_conversion(&mut history, &original, &hangul);
+                        }
+                        event_state_for_worker.save_conversion_history(original, hangul);
                     }
+                    WorkItem::ConvertSelection => {
+                        let selected = focused_selected_text();
+                        let hangul = match plan_selection_conversion(selected.as_deref()) {
+                            SelectionPlan::Replace(hangul) => hangul,
+                            plan => {
+                                log::debug!("선택 텍스트 변환 스킵: {:?}", plan);
+                                continue;
+                            }
+                        };
+                        let original = selected.unwrap_or_default();
+
+                        // AX로 선택 영역 교체(동기 반영), 지원하지 않는 앱은 붙여넣기로 선택 영역을 덮어씀
+                        let output = worker_config.output_form.apply(&hangul);
+                        let replaced = replace_then_switch(
+                            &event_state_for_worker.is_replacing,
+                            || {
+                                replace_focused_selected_text(&output)
+                                    .map(|()| true)
+                                    .or_else(|e| {
+                                        log::debug!(
+                                            "AX 선택 텍스트 교체 실패, 붙여넣기로 대체: {}",
+                                            e
+                                        );
+                                        replace_text(0, &output).map(|()| false)
+                                    })
+                            },
+                            || thread::sleep(PASTE_SETTLE_DELAY),
+                            || switch_to_korean_after_replace(&event_state_for_worker),
+                        );
+
+                        if let Err(e) = replaced {
+                            log::error!("선택 텍스트 교체 실패: {}", e);
+                            continue;
+                        }
 
-                    // 텍스트 교체 중 플래그 설정 (실시간 변환 레이스 방지)
-                    event_state_for_worker
-                        .is_replacing
-                        .store(true, AtomicOrdering::Release);
+                        announce(&conversion_announcement(&hangul, A11yLanguage::current()));
+                        if worker_config.speak_on_convert {
+                            speak_conversion(&hangul);
+                        }
+                        event_state_for_worker.record_stat(StatKind::Conversion);
+                        ipc::publish(IpcEvent::Conversion {
+                            original: original.clone(),
+                            converted: hangul.clone(),
+                            manual: true,
+                        });
 
-                    let backspace_count = buffer.chars().count();
-                    let replace_result = replace_text(backspace_count, &hangul);
+                        if worker_config.persist_history {
+                            persist_conversion(&mut history, &original, &hangul);
+                        }
+                        event_state_for_worker.save_conversion_history(original, hangul);
+                    }
+                    WorkItem::Reverse(keys) => {
+                        // 조합된 한글이 한국어로도 자연스러우면 의도한 한글일 수 있어 유지
+                        let hangul_natural = pipeline.validator.validate(&keys, false).is_ok();
+                        let plan = plan_reverse(
+                            &keys,
+                            " ",
+                            pipeline.gate.is_blocked_word(&keys),
+                            hangul_natural,
+                        );
+                        let ReversePlan::Replace { segment, english } = plan else {
+                            log::debug!("역변환 스킵: {:?} ({})", plan, keys);
+                            continue;
+                        };
 
-                    if let Err(e) = replace_result {
                         event_state_for_worker
                             .is_replacing
-                            .store(false, AtomicOrdering::Release);
-                        log::error!("텍스트 교체 실패: {}", e);
-                        continue;
-                    }
+                            .store(true, AtomicOrdering::Release);
 
-                    // paste 처리 완료 대기 (is_replacing=true 유지하여 이벤트 탭 간섭 차단)
-                    thread::sleep(Duration::from_millis(200));
+                        // 커서 직전 텍스트가 조합된 한글 + Space와 다르면 교체하지 않음
+                        let replace_result =
+                            replace_text_at_cursor(segment.chars().count(), &segment, &english);
 
-                    // 한글 자판 전환 (is_replacing=true 상태에서 타임아웃 포함 실행)
-                    // 메인 스레드에서 완료될 때까지 최대 500ms 대기하여,
-                    // 전환 전 키 입력이 영문으로 처리되는 레이스 컨디션 방지.
-                    // 타임아웃 발생 시에도 is_replacing을 해제하여 worker 블로킹 방지.
-                    switch_to_korean_on_main_with_timeout(Duration::from_millis(500));
+                        if let Err(e) = replace_result {
+                            event_state_for_worker
+                                .is_replacing
+                                .store(false, AtomicOrdering::Release);
+                            log::debug!("역변환 교체 실패: {}", e);
+                            continue;
+                        }
 
-                    event_state_for_worker
-                        .is_replacing
-                        .store(false, AtomicOrdering::Release);
+                        thread::sleep(PASTE_SETTLE_DELAY);
+                        // 이어지는 입력도 영어일 가능성이 높으므로 영문 자판으로 전환
+                        dispatch_to_main(|| {
+                            if let Err(e) = switch_to_english() {
+                                switch_failure_event("english", &e).emit();
+                            }
+                        });
+                        event_state_for_worker
+                            .is_replacing
+                            .store(false, AtomicOrdering::Release);
 
-                    // 변환 이력 저장 (Undo용)
-                    event_state_for_worker.save_conversion_history(buffer, hangul);
-                }
-                WorkItem::Undo(hangul, original) => {
-                    // 텍스트 교체 중 플래그 설정 (실시간 변환 레이스 방지)
-                    event_state_for_worker
-                        .is_replacing
-                        .store(true, AtomicOrdering::Release);
+                        event_state_for_worker.record_stat(StatKind::Conversion);
+                        ipc::publish(IpcEvent::Conversion {
+                            original: segment.clone(),
+                            converted: english.clone(),
+                            manual: false,
+                        });
+                        if worker_config.persist_history {
+                            persist_conversion(&mut history, &segment, &english);
+                        }
+                        event_state_for_worker.save_conversion_history(segment, english);
+                    }
+                    WorkItem::Undo(hangul, original) => {
+                        // 텍스트 교체 중 플래그 설정 (실시간 변환 레이스 방지)
+                        event_state_for_worker
+                            .is_replacing
+                            .store(true, AtomicOrdering::Release);
 
-                    let result = undo_replace_text(&hangul, &original);
+                        let result = undo_replace_text(&hangul, &original);
 
-                    event_state_for_worker
-                        .is_replacing
-                        .store(false, AtomicOrdering::Release);
+                        event_state_for_worker
+                            .is_replacing
+                            .store(false, AtomicOrdering::Release);
 
-                    if let Err(e) = result {
-                        log::error!("Undo 텍스트 교체 실패: {}", e);
+                        match result {
+                            Ok(()) => {
+                                event_state_for_worker.record_stat(StatKind::Undo);
+                                ipc::publish(IpcEvent::Undo {
+                                    original: original.clone(),
+                                    converted: hangul.clone(),
+                                });
+                                // 되돌린 변환은 재시작 후 다시 Undo되지 않도록 이력에서 제거
+                                if worker_config.persist_history
+                                    && history.last().is_some_and(|last| {
+                                        last.converted == hangul && last.original == original
+                                    })
+                                {
+                                    history.pop_last();
+                                    if let Err(e) = save_history(&history) {
+                                        log::warn!("{}", e);
+                                    }
+                                }
+                                announce(&undo_announcement(&original, A11yLanguage::current()))
+                            }
+                            Err(e) => log::error!("Undo 텍스트 교체 실패: {}", e),
+                        }
                     }
                 }
             }
+            // 교체가 끝난 뒤 보류한 Enter 재전송 (교체 → 줄바꿈 순서 보장)
+            event_state_for_worker.finish_auto_conversions(auto_converts);
         }
     });
 
@@ -198,23 +889,456 @@ fn main() {
         let _ = convert_tx.send(WorkItem::Convert(buffer, is_manual));
     });
 
+    // 선택 텍스트 변환 콜백 설정 (AX 조회는 워커에서 수행)
+    let selection_tx = work_tx.clone();
+    event_state.set_convert_selection_callback(move || {
+        let _ = selection_tx.send(WorkItem::ConvertSelection);
+    });
+
+    // 역변환 콜백 설정 (양방향 모드, 판정은 워커에서 n-gram 검증과 함께 수행)
+    let reverse_tx = work_tx.clone();
+    event_state.set_reverse_callback(move |keys: String| {
+        let _ = reverse_tx.send(WorkItem::Reverse(keys));
+    });
+
     // Undo 콜백 설정
     let undo_tx = work_tx;
     event_state.set_undo_callback(move |hangul: String, original: String| {
         let _ = undo_tx.send(WorkItem::Undo(hangul, original));
     });
 
-    // 이벤트 탭 스레드 시작
+    // 이벤트 탭 상태 변경 시 메뉴바 경고 표시 갱신 (메뉴바 생성 후 메인 큐에서 실행)
+    event_state.set_tap_status_callback(|status| {
+        dispatch_to_main(move || update_tap_status(status));
+    });
+
+    // 단축키(Option+Shift+Space)로 활성화 토글 시 메뉴바 체크마크/아이콘 갱신
+    event_state.set_toggle_callback(|enabled| {
+        dispatch_to_main(move || apply_enabled_change(enabled));
+    });
+
+    // 디버그 오버레이: 버퍼 변경마다 메인 스레드에서 갱신 (설정이 꺼져 있으면 호출되지 않음)
+    event_state.set_debug_overlay(config.debug_overlay);
+    event_state.set_buffer_change_callback(|buffer, preview, confidence| {
+        dispatch_to_main(move || show_debug_overlay(&buffer, &preview, confidence));
+    });
+
+    // shadow 판정: 대안 신뢰도 기준과 실제 판정이 다를 때만 구조적 로그 (기본 off)
+    event_state.set_shadow_eval(config.shadow_eval);
+
+    // 이벤트 탭 스레드 시작 (시작 실패 시 권한 획득 후 자동 재시작)
     let event_state_for_thread = Arc::clone(&event_state);
     let running_for_thread = Arc::clone(&running);
     thread::spawn(move || {
-        if let Err(e) = start_event_tap(event_state_for_thread) {
-            log::error!("Event tap 시작 실패: {}", e);
-        }
+        run_event_tap(event_state_for_thread);
         running_for_thread.store(false, Ordering::Release);
     });
 
     // 메뉴바 앱 실행 (메인 스레드에서)
     let app = MenuBarApp::new(Arc::clone(&running), Arc::clone(&event_state));
+    if first_run {
+        show_onboarding_window();
+    }
     app.run();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(buffer: &str, is_manual: bool) -> WorkItem {
+        WorkItem::Convert(buffer.to_string(), is_manual)
+    }
+
+    fn undo(hangul: &str, original: &str) -> WorkItem {
+        WorkItem::Undo(hangul.to_string(), original.to_string())
+    }
+
+    #[test]
+    fn test_coalesce_keeps_latest_convert_only() {
+        let items = vec![
+            convert("dkssud", false),
+            convert("gksrmf", false),
+            convert("dlfma", false),
+        ];
+        assert_eq!(coalesce_work_items(items), vec![convert("dlfma", false)]);
+    }
+
+    #[test]
+    fn test_coalesce_never_drops_undo_and_keeps_order() {
+        let items = vec![
+            convert("dkssud", false),
+            undo("안녕", "dkssud"),
+            convert("gksrmf", false),
+            undo("한글", "gksrmf"),
+        ];
+        assert_eq!(
+            coalesce_work_items(items),
+            vec![
+                undo("안녕", "dkssud"),
+                convert("gksrmf", false),
+                undo("한글", "gksrmf"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_coalesce_manual_convert_not_superseded_by_auto() {
+        let items = vec![convert("dkssud", true), convert("gksrmf", false)];
+        assert_eq!(
+            coalesce_work_items(items),
+            vec![convert("dkssud", true), convert("gksrmf", false)]
+        );
+
+        let items = vec![convert("dkssud", true), convert("gksrmf", true)];
+        assert_eq!(coalesce_work_items(items), vec![convert("gksrmf", true)]);
+
+        // 자동 변환은 뒤따르는 수동 변환에 밀림
+        let items = vec![convert("dkssud", false), convert("gksrmf", true)];
+        assert_eq!(coalesce_work_items(items), vec![convert("gksrmf", true)]);
+    }
+
+    #[test]
+    fn test_coalesce_keeps_reverse_conversions() {
+        let reverse = |keys: &str| WorkItem::Reverse(keys.to_string());
+        let items = vec![reverse("hello"), convert("dkssud", false), reverse("world")];
+        assert_eq!(
+            coalesce_work_items(items),
+            vec![reverse("hello"), convert("dkssud", false), reverse("world"),]
+        );
+    }
+
+    #[test]
+    fn test_coalesce_keeps_selection_conversion() {
+        let items = vec![
+            WorkItem::ConvertSelection,
+            convert("dkssud", false),
+            WorkItem::ConvertSelection,
+        ];
+        assert_eq!(
+            coalesce_work_items(items),
+            vec![
+                WorkItem::ConvertSelection,
+                convert("dkssud", false),
+                WorkItem::ConvertSelection,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unconvertible_korean_intent() {
+        // 결과가 원본과 같거나 비었고 신뢰도가 높을 때만
+        assert!(is_unconvertible_korean_intent(80.0, "123", "123"));
+        assert!(is_unconvertible_korean_intent(70.0, "", ""));
+        assert!(!is_unconvertible_korean_intent(69.9, "123", "123"));
+        // 변환된 경우는 해당 없음
+        assert!(!is_unconvertible_korean_intent(95.0, "dkssud", "안녕"));
+    }
+
+    #[test]
+    fn test_cooldown_limits_repeated_switches() {
+        let mut cooldown = Cooldown::new(Duration::from_secs(5));
+        let start = Instant::now();
+        assert!(cooldown.try_fire(start));
+        assert!(!cooldown.try_fire(start + Duration::from_secs(1)));
+        assert!(!cooldown.try_fire(start + Duration::from_millis(4_999)));
+        assert!(cooldown.try_fire(start + Duration::from_secs(5)));
+        // 거부된 시도는 쿨다운을 연장하지 않음
+        assert!(!cooldown.try_fire(start + Duration::from_secs(6)));
+        assert!(cooldown.try_fire(start + Duration::from_secs(10)));
+    }
+
+    /// replace_then_switch 각 단계와 그 시점의 is_replacing 값
+    fn run_sequence(replace_result: Result<bool, String>) -> (Vec<(&'static str, bool)>, bool) {
+        let replacing = AtomicBool::new(false);
+        let steps = std::cell::RefCell::new(Vec::new());
+        let record = |step| {
+            steps
+                .borrow_mut()
+                .push((step, replacing.load(Ordering::Acquire)))
+        };
+        let result = replace_then_switch(
+            &replacing,
+            || {
+                record("replace");
+                replace_result
+            },
+            || record("settle"),
+            || record("switch"),
+        );
+        assert!(!replacing.load(Ordering::Acquire), "항상 해제");
+        (steps.into_inner(), result.is_ok())
+    }
+
+    #[test]
+    fn test_korean_switch_path_by_delay() {
+        // 0은 타이머 없이 바로 전환, 지연을 설정한 경우만 타이머 사용
+        assert_eq!(korean_switch_path(0), KoreanSwitchPath::Immediate);
+        for delay_ms in [10, 50, 1500] {
+            assert_eq!(korean_switch_path(delay_ms), KoreanSwitchPath::Timer);
+        }
+    }
+
+    #[test]
+    fn test_plan_replace_switch_only_when_korean() {
+        // 영문/Unknown: 교체 전 전환 없음, 교체 후 전환은 앱 허용 여부대로
+        for current in [InputSourceState::English, InputSourceState::Unknown] {
+            assert_eq!(
+                plan_replace_switch(current, true),
+                ReplaceSwitchPlan {
+                    english_before: false,
+                    korean_after: true,
+                }
+            );
+            assert_eq!(
+                plan_replace_switch(current, false),
+                ReplaceSwitchPlan {
+                    english_before: false,
+                    korean_after: false,
+                }
+            );
+        }
+        // 한글 모드: 영문 전환 후 교체, 전환 금지 앱이어도 한글로 되돌림
+        for switch_allowed in [true, false] {
+            assert_eq!(
+                plan_replace_switch(InputSourceState::NonEnglish, switch_allowed),
+                ReplaceSwitchPlan {
+                    english_before: true,
+                    korean_after: true,
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_replace_then_switch_releases_after_switch() {
+        // paste 반영 확인 → 대기 없이 전환, 전환 중에도 is_replacing 유지
+        assert_eq!(
+            run_sequence(Ok(true)),
+            (vec![("replace", true), ("switch", true)], true)
+        );
+        // 반영 미확인 → 대기 후 전환
+        assert_eq!(
+            run_sequence(Ok(false)),
+            (
+                vec![("replace", true), ("settle", true), ("switch", true)],
+                true
+            )
+        );
+        // 교체 실패 → 전환하지 않음
+        assert_eq!(
+            run_sequence(Err("실패".to_string())),
+            (vec![("replace", true)], false)
+        );
+    }
+
+    /// 등록된 키만 변환하는 결정적 변환기
+    struct MockConverter(&'static [(&'static str, &'static str)]);
+
+    impl Converter for MockConverter {
+        fn convert(&self, keys: &str) -> String {
+            self.0
+                .iter()
+                .find(|(from, _)| *from == keys)
+                .map_or_else(|| keys.to_string(), |(_, to)| to.to_string())
+        }
+    }
+
+    /// 지정한 입력만 거부하고, 영어처럼 보이는 입력을 지정하는 판정기
+    struct MockGate {
+        rejected: &'static str,
+        english: &'static str,
+    }
+
+    impl ConversionGate for MockGate {
+        fn check_input(&self, judge: &str) -> Result<(), String> {
+            if judge == self.rejected {
+                Err("mock 거부".to_string())
+            } else {
+                Ok(())
+            }
+        }
+
+        fn looks_like_english(&self, judge: &str) -> bool {
+            judge == self.english
+        }
+
+        fn confidence(&self, _keys: &str) -> f32 {
+            100.0
+        }
+
+        fn is_blocked_word(&self, judge: &str) -> bool {
+            judge == self.rejected
+        }
+
+        fn has_enough_keys(&self, _judge: &str) -> bool {
+            true
+        }
+
+        fn should_convert_realtime(&self, judge: &str) -> bool {
+            judge != self.rejected
+        }
+    }
+
+    /// 영어처럼 보이는 입력의 결과만 거부하고, '반' 앞에서 나누는 검증기
+    struct MockValidator;
+
+    impl ConversionValidator for MockValidator {
+        fn validate(&self, _judge: &str, english_like: bool) -> Result<(), String> {
+            if english_like {
+                Err("mock 영어".to_string())
+            } else {
+                Ok(())
+            }
+        }
+
+        fn check_structure(&self, _judge: &str) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn split_point(&self, hangul: &str) -> Option<usize> {
+            hangul.chars().position(|c| c == '반').filter(|&at| at > 0)
+        }
+    }
+
+    fn mock_pipeline() -> ConversionPipeline {
+        ConversionPipeline {
+            converter: Box::new(MockConverter(&[
+                ("dkssud", "안녕"),
+                ("gksrmf", "한글"),
+                ("rkskek", "가나다"),
+                ("rk1sk", "가1나"),
+                ("dkssudr", "안녕ㄱ"),
+                ("dkssudgktpdyqksrkqtmqslek", "안녕하세요반갑습니다"),
+                ("dkssudgktpdy1qksrkqtmqslek", "안녕하세요1반갑습니다"),
+            ])),
+            gate: Box::new(MockGate {
+                rejected: "gksrmf",
+                english: "rkskek",
+            }),
+            validator: Box::new(MockValidator),
+            infer_double_consonant: false,
+            finalize_syllables: false,
+        }
+    }
+
+    #[test]
+    fn test_pipeline_plan_with_mock_stages() {
+        let pipeline = mock_pipeline();
+        let plan = |buffer, is_manual| pipeline.plan(buffer, buffer, is_manual);
+
+        assert_eq!(
+            plan("dkssud", false),
+            ConvertPlan::Replace("안녕".to_string())
+        );
+        // 판정은 한글 키 기준, 변환/교체는 원문 기준
+        assert_eq!(
+            plan("rk1sk", false),
+            ConvertPlan::Replace("가1나".to_string())
+        );
+        // 1차 필터 거부 → 스킵 (검증 거부 통계에 넣지 않음)
+        assert_eq!(
+            plan("gksrmf", false),
+            ConvertPlan::Skip("mock 거부".to_string())
+        );
+        // 결과 검증 거부 (감지기의 영어 판정이 검증기로 전달됨)
+        assert_eq!(
+            plan("rkskek", false),
+            ConvertPlan::Reject("mock 영어".to_string())
+        );
+        // 변환기가 바꾸지 못한 입력
+        assert_eq!(
+            plan("qwer", false),
+            ConvertPlan::Unconvertible {
+                keys: "qwer".to_string(),
+                converted: "qwer".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_pipeline_manual_conversion_skips_filters() {
+        let pipeline = mock_pipeline();
+        for buffer in ["gksrmf", "rkskek"] {
+            assert!(
+                matches!(pipeline.plan(buffer, buffer, true), ConvertPlan::Replace(_)),
+                "{}",
+                buffer
+            );
+        }
+        // URL 원문은 자동 변환만 스킵
+        assert!(matches!(
+            pipeline.plan("naver.com/dkssud", "dkssud", false),
+            ConvertPlan::Skip(_)
+        ));
+        assert_eq!(
+            pipeline.plan("naver.com/dkssud", "dkssud", true),
+            ConvertPlan::Replace("안녕".to_string())
+        );
+    }
+
+    #[test]
+    fn test_pipeline_splits_long_segment_at_word_boundary() {
+        let pipeline = mock_pipeline();
+        assert_eq!(
+            pipeline.split_long_segment("dkssudgktpdyqksrkqtmqslek"),
+            ("dkssudgktpdy", "qksrkqtmqslek")
+        );
+        // 나눌 곳이 없거나 비한글 키가 섞인 구간은 그대로
+        assert_eq!(pipeline.split_long_segment("dkssud"), ("dkssud", ""));
+        assert_eq!(
+            pipeline.split_long_segment("dkssudgktpdy1qksrkqtmqslek"),
+            ("dkssudgktpdy1qksrkqtmqslek", "")
+        );
+    }
+
+    #[test]
+    fn test_pipeline_finalizes_trailing_jamo_when_enabled() {
+        let mut pipeline = mock_pipeline();
+        assert_eq!(
+            pipeline.plan("dkssudr", "dkssudr", true),
+            ConvertPlan::Replace("안녕ㄱ".to_string())
+        );
+        pipeline.finalize_syllables = true;
+        assert_eq!(
+            pipeline.plan("dkssudr", "dkssudr", true),
+            ConvertPlan::Replace("안녕".to_string())
+        );
+    }
+
+    #[test]
+    fn test_plan_selection_conversion() {
+        assert_eq!(plan_selection_conversion(None), SelectionPlan::Unavailable);
+        assert_eq!(
+            plan_selection_conversion(Some("")),
+            SelectionPlan::NoSelection
+        );
+        assert_eq!(
+            plan_selection_conversion(Some("dkssud gktpdy")),
+            SelectionPlan::Replace("안녕 하세요".to_string())
+        );
+        // 변환해도 그대로인 텍스트, 낱자모만 남는 텍스트는 교체하지 않음
+        assert_eq!(
+            plan_selection_conversion(Some("123 !")),
+            SelectionPlan::Invalid
+        );
+        assert_eq!(
+            plan_selection_conversion(Some("rrrr")),
+            SelectionPlan::Invalid
+        );
+    }
+
+    #[test]
+    fn test_drain_pending_collects_queued_items() {
+        let (tx, rx) = mpsc::channel();
+        tx.send(convert("gksrmf", false)).unwrap();
+        tx.send(undo("안녕", "dkssud")).unwrap();
+        tx.send(convert("dlfma", false)).unwrap();
+
+        let (items, auto_converts) = drain_pending(convert("dkssud", false), &rx);
+        assert_eq!(items, vec![undo("안녕", "dkssud"), convert("dlfma", false)]);
+        // 건너뛴 자동 변환도 완료 통지 대상
+        assert_eq!(auto_converts, 3);
+        assert!(rx.try_recv().is_err());
+    }
+}
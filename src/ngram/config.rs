@@ -2,6 +2,8 @@
 //!
 //! 스코어링 및 판정에 사용되는 설정값 정의
 
+use crate::core::unicode::HANGUL_SYLLABLE_COUNT;
+
 /// N-gram 검증 설정
 #[derive(Debug, Clone)]
 pub struct NgramConfig {
@@ -20,15 +22,23 @@ pub struct NgramConfig {
 
     /// N-gram 모델 파일 경로
     pub model_path: String,
+
+    /// 자연스러움 점수 시그모이드 중심 (이 스코어가 50점)
+    pub naturalness_center: f64,
+
+    /// 자연스러움 점수 시그모이드 기울기 (양수, 클수록 중심 부근에서 급격히 변화)
+    pub naturalness_slope: f64,
 }
 
 impl Default for NgramConfig {
     fn default() -> Self {
         Self {
             smoothing_k: 0.001,
-            vocab_size: 11172, // 한글 완성형 음절 수
-            threshold: -10.0,  // 로그 확률 기준
+            vocab_size: HANGUL_SYLLABLE_COUNT as usize,
+            threshold: -10.0, // 로그 확률 기준
             model_path: String::new(),
+            naturalness_center: -10.0, // 기본 임계값과 일치
+            naturalness_slope: 0.5,
         }
     }
 }
@@ -56,6 +66,26 @@ impl NgramConfig {
         self.smoothing_k = k;
         self
     }
+
+    /// 자연스러움 점수 매핑 파라미터 설정
+    pub fn with_naturalness(mut self, center: f64, slope: f64) -> Self {
+        self.naturalness_center = center;
+        self.naturalness_slope = slope;
+        self
+    }
+
+    /// N-gram 스코어(로그 확률)를 0~100 자연스러움 점수로 변환
+    ///
+    /// 시그모이드 매핑 후 반올림하므로 중심에서 멀어지면 0 또는 100으로 포화됩니다.
+    /// -inf(빈 텍스트)와 NaN은 0점, +inf는 100점입니다.
+    pub fn naturalness(&self, score: f64) -> u8 {
+        if score.is_nan() {
+            return 0;
+        }
+        let x = self.naturalness_slope * (score - self.naturalness_center);
+        let ratio = 1.0 / (1.0 + (-x).exp());
+        (ratio * 100.0).round().clamp(0.0, 100.0) as u8
+    }
 }
 
 #[cfg(test)]
@@ -81,4 +111,32 @@ mod tests {
         assert!((config.threshold - (-8.0)).abs() < f64::EPSILON);
         assert!((config.smoothing_k - 0.01).abs() < f64::EPSILON);
     }
+
+    #[test]
+    fn test_naturalness_mapping() {
+        let config = NgramConfig::new();
+        assert_eq!(config.naturalness(config.naturalness_center), 50);
+
+        // 스코어 순서 보존
+        let scores = [-40.0, -15.0, -11.0, -10.0, -9.0, -5.0, -0.5];
+        let mapped: Vec<u8> = scores.iter().map(|&s| config.naturalness(s)).collect();
+        assert!(mapped.windows(2).all(|w| w[0] <= w[1]), "{:?}", mapped);
+        assert!(mapped[2] < 50 && mapped[4] > 50);
+
+        // 경계 포화
+        assert_eq!(config.naturalness(-1000.0), 0);
+        assert_eq!(config.naturalness(1000.0), 100);
+        assert_eq!(config.naturalness(f64::NEG_INFINITY), 0);
+        assert_eq!(config.naturalness(f64::INFINITY), 100);
+        assert_eq!(config.naturalness(f64::NAN), 0);
+    }
+
+    #[test]
+    fn test_naturalness_parameters() {
+        let steep = NgramConfig::new().with_naturalness(-5.0, 2.0);
+        assert_eq!(steep.naturalness(-5.0), 50);
+        // 기울기가 클수록 같은 거리에서 더 빨리 포화
+        assert!(steep.naturalness(-4.0) > NgramConfig::new().naturalness(-9.0));
+        assert_eq!(steep.naturalness(-1.0), 100);
+    }
 }
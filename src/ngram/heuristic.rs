@@ -0,0 +1,209 @@
+//! 모델 없이 쓰는 빈도 기반 자연스러움 점수
+//!
+//! N-gram 모델 파일이 없을 때의 보조 판정용입니다. 흔한 음절 목록(빈도순)과
+//! 초성/중성 분포만으로 점수를 매기므로 모델보다 거칠지만, 영단어가 우연히 완성형으로
+//! 조합된 결과("right" → "갸홋")처럼 드문 음절이 이어지는 경우를 걸러냅니다.
+
+use crate::core::unicode::decompose_syllable;
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+
+/// 흔한 한글 음절 (대략 빈도순, 앞일수록 흔함)
+const COMMON_SYLLABLES: &str = concat!(
+    "이다는의에하고을가한지대로서사기있리자어를도시정아것인수나일적해국주게구들전부보",
+    "면상제과요스여되세우라장만으그성경소중무동원오학방년미선거저문트위비니식생때화연",
+    "치진공내관신말마실까행없개드와발모조유분업회물산안할계용날심금현당데같러체양교명",
+    "또단력결통음점차간히편영입민터름군등노후반호질론배운했속야습표작본집향설판출활재",
+    "매처권법건더각직감목형취태초외파료역백황두득록술근별종복람져예곤귀품레김래송박최",
+    "준엇울번남른던난께너희네봐줘좀왜뭐디언누잘못녕랑친늘얼굴눈손밥길돈글책님엄빠머버",
+    "족혼커피맛좋싫쁘멋힘괜찮슬프났졸려빨천많짜항끔벌써함막옆앞뒤밖떻몇째월토침녁밤낮",
+    "새벽봄겨바땅강꽃풀닭돼겠었았였죠줄알볼갈올될텐럼듯걸며워웠쓰받넣놓찾잡열닫움빛색",
+    "깔흰검붉푸란컴퓨핸폰메악춤축임갑객격견곡골곳광괴규극급꼭꾸끝낙납낭널녀념녹논놀농",
+    "높뉴느능달담답댁덕독돌된둘든듬따뜻락략량렇련렬령례루류륙률르릉린림립맞맥먹먼멀몸",
+    "밀및밝범변병봉북불붙빌살삼석섬센숙순숨쉬승십싸씨않암압애액약억염온옷완왕웃육윤은",
+    "응익읽잔잠쟁절접존좌죽즉짓징쪽착찰참창채철첫청촌총추충측층칠카케코크큰클키타탁탄",
+    "탈탐택테투특틀티팔패퍼페평폐포풍필합핵허헌험혈협혜혹홍확환획효훈휘휴흐흑흔흘흥톡",
+    "퇴폴찜샤떡볶앗값삶꿈꽤쫄깃팍쿠텔팀핑헤혀꺼꼬끄끼떠떨뚜뜨띠뻐뽀뿌삐쌀쌍쏘쑥씀씩쩌",
+    "쭉찌찍",
+);
+
+/// 흔한 음절 집합 (변환 결과의 흔한 음절 비율 계산용)
+static COMMON_SYLLABLE_SET: LazyLock<HashSet<char>> =
+    LazyLock::new(|| COMMON_SYLLABLES.chars().collect());
+
+/// 흔한 음절의 빈도 순위 (0이 가장 흔함, 음절 점수 계산용)
+static COMMON_SYLLABLE_RANK: LazyLock<HashMap<char, usize>> = LazyLock::new(|| {
+    COMMON_SYLLABLES
+        .chars()
+        .enumerate()
+        .map(|(rank, c)| (c, rank))
+        .collect()
+});
+
+/// 초성 빈도 (음절 1000개당, 초성 인덱스 순서 ㄱ ㄲ ㄴ ㄷ ㄸ ㄹ ㅁ ㅂ ㅃ ㅅ ㅆ ㅇ ㅈ ㅉ ㅊ ㅋ ㅌ ㅍ ㅎ)
+const CHOSEONG_FREQ: [u32; 19] = [
+    126, 10, 68, 84, 7, 53, 55, 50, 2, 86, 10, 226, 88, 3, 24, 8, 14, 12, 74,
+];
+
+/// 중성 빈도 (음절 1000개당, 중성 인덱스 순서 ㅏ ㅐ ㅑ ㅒ ㅓ ㅔ ㅕ ㅖ ㅗ ㅘ ㅙ ㅚ ㅛ ㅜ ㅝ ㅞ ㅟ ㅠ ㅡ ㅢ ㅣ)
+const JUNGSEONG_FREQ: [u32; 21] = [
+    220, 50, 8, 1, 110, 35, 40, 6, 100, 20, 2, 10, 15, 70, 8, 1, 5, 10, 120, 15, 170,
+];
+
+/// 흔한 음절 목록에 없는 음절의 최대 점수 (목록 음절은 이보다 높음)
+const UNCOMMON_MAX: f64 = 0.5;
+/// 드문 음절 점수의 하한 비율 (초성/중성이 아주 드물어도 0점은 아님)
+const UNCOMMON_FLOOR: f64 = 0.2;
+/// 받침 있는 드문 음절 감점 비율
+const UNCOMMON_JONG_FACTOR: f64 = 0.8;
+
+/// 모델이 없을 때 자동 변환을 허용할 최소 휴리스틱 점수
+pub const HEURISTIC_MIN_SCORE: f64 = 0.2;
+
+/// 한글 텍스트의 빈도 기반 자연스러움 점수 (0.0 ~ 1.0, 높을수록 흔한 한글)
+///
+/// 음절별 점수의 평균입니다. 흔한 음절은 순위에 따라 0.5~1.0,
+/// 그 외 음절은 초성/중성 빈도에 따라 0.1~0.5 (받침이 있으면 감점).
+/// 완성형 음절이 없으면 0.0
+///
+/// # Examples
+/// ```
+/// use koing::ngram::heuristic_score;
+///
+/// assert!(heuristic_score("안녕하세요") > heuristic_score("갸홋"));
+/// assert_eq!(heuristic_score("abc"), 0.0);
+/// ```
+pub fn heuristic_score(text: &str) -> f64 {
+    let scores: Vec<f64> = text.chars().filter_map(syllable_score).collect();
+    if scores.is_empty() {
+        return 0.0;
+    }
+    scores.iter().sum::<f64>() / scores.len() as f64
+}
+
+/// 완성형 음절 중 흔한 음절 집합에 속하는 비율 (0.0 ~ 1.0, 완성형 음절이 없으면 0.0)
+///
+/// # Examples
+/// ```
+/// use koing::ngram::common_syllable_ratio;
+///
+/// assert_eq!(common_syllable_ratio("안녕하세요"), 1.0);
+/// assert_eq!(common_syllable_ratio("갸홋"), 0.0);
+/// ```
+pub fn common_syllable_ratio(text: &str) -> f32 {
+    let (syllables, common) = text
+        .chars()
+        .filter(|&c| decompose_syllable(c).is_some())
+        .fold((0usize, 0usize), |(total, common), c| {
+            (
+                total + 1,
+                common + usize::from(COMMON_SYLLABLE_SET.contains(&c)),
+            )
+        });
+    if syllables == 0 {
+        return 0.0;
+    }
+    common as f32 / syllables as f32
+}
+
+/// 음절 하나의 점수 (완성형 음절이 아니면 None)
+fn syllable_score(c: char) -> Option<f64> {
+    let (cho, jung, jong) = decompose_syllable(c)?;
+    if let Some(&rank) = COMMON_SYLLABLE_RANK.get(&c) {
+        let common_count = COMMON_SYLLABLE_RANK.len();
+        return Some(1.0 - UNCOMMON_MAX * rank as f64 / common_count as f64);
+    }
+    // 초성/중성 상대 빈도의 기하 평균
+    let weight = (relative_freq(&CHOSEONG_FREQ, cho) * relative_freq(&JUNGSEONG_FREQ, jung)).sqrt();
+    let jong_factor = if jong == 0 { 1.0 } else { UNCOMMON_JONG_FACTOR };
+    Some(UNCOMMON_MAX * (UNCOMMON_FLOOR + (1.0 - UNCOMMON_FLOOR) * weight) * jong_factor)
+}
+
+/// 분포에서 index의 빈도를 최빈값 대비 비율로 (0.0 ~ 1.0)
+fn relative_freq(freq: &[u32], index: u32) -> f64 {
+    let max = freq.iter().copied().max().unwrap_or(1);
+    f64::from(freq[index as usize]) / f64::from(max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::converter::convert;
+
+    #[test]
+    fn test_common_syllables_are_unique_hangul() {
+        let mut seen = std::collections::HashSet::new();
+        for c in COMMON_SYLLABLES.chars() {
+            assert!(decompose_syllable(c).is_some(), "{}", c);
+            assert!(seen.insert(c), "중복 음절 {}", c);
+        }
+        assert!(seen.len() >= 300);
+    }
+
+    #[test]
+    fn test_common_korean_scores_above_misconverted_english() {
+        let korean = [
+            "dkssud", "gksrmf", "tkfkd", "dhsmf", "gkrry", "rkatk", "tkfka",
+        ];
+        // 영단어가 완성형으로 조합된 결과 (재깅, 갸홋, 낵교)
+        let english = ["world", "right", "sorry"];
+
+        let lowest_korean = korean
+            .iter()
+            .map(|keys| heuristic_score(&convert(keys)))
+            .fold(f64::INFINITY, f64::min);
+        for keys in english {
+            let converted = convert(keys);
+            let score = heuristic_score(&converted);
+            assert!(
+                score < lowest_korean,
+                "{} ({}): {} >= {}",
+                keys,
+                converted,
+                score,
+                lowest_korean
+            );
+        }
+        assert!(lowest_korean >= HEURISTIC_MIN_SCORE);
+        assert!(heuristic_score("갸홋") < HEURISTIC_MIN_SCORE);
+    }
+
+    #[test]
+    fn test_common_syllable_ratio_separates_misconversions() {
+        for keys in ["dkssudgktpdy", "gksrmf", "tkfkd", "dhsmf", "rkatkgkqslek"] {
+            let converted = convert(keys);
+            assert!(
+                common_syllable_ratio(&converted) >= 0.5,
+                "{} ({})",
+                keys,
+                converted
+            );
+        }
+        // 영단어가 완성형으로 조합된 결과는 드문 음절투성이 (갸홋, 놳, 퍄퍙)
+        for keys in ["right", "short", "vivid"] {
+            let converted = convert(keys);
+            assert!(
+                common_syllable_ratio(&converted) < 0.5,
+                "{} ({})",
+                keys,
+                converted
+            );
+        }
+        // 완성형 음절만 세고, 없으면 0
+        assert_eq!(common_syllable_ratio("안ㄴ!"), 1.0);
+        assert_eq!(common_syllable_ratio("abc"), 0.0);
+    }
+
+    #[test]
+    fn test_heuristic_score_range() {
+        assert_eq!(heuristic_score(""), 0.0);
+        assert_eq!(heuristic_score("hello"), 0.0);
+        // 가장 흔한 음절은 1.0, 비한글 문자는 무시
+        assert_eq!(heuristic_score("이"), 1.0);
+        assert_eq!(heuristic_score("이!"), 1.0);
+        for text in ["안녕", "뷁", "꿰뚫", "ㅋㅋ가"] {
+            let score = heuristic_score(text);
+            assert!((0.0..=1.0).contains(&score), "{}: {}", text, score);
+        }
+    }
+}
@@ -5,6 +5,43 @@
 
 use crate::core::unicode::decompose_syllable;
 
+/// 키 입력 하나 (기본 키 + Shift 여부)
+///
+/// `korean_to_eng`의 대문자(`R`, `T`, `O` 등)가 Shift 조합임을 명시적으로 표현합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyStroke {
+    /// Shift 없이 누르는 기본 키 (영문은 소문자)
+    pub ch: char,
+    /// Shift 필요 여부
+    pub shift: bool,
+}
+
+impl KeyStroke {
+    /// 입력 문자 → 키 입력 (ASCII 대문자는 소문자 + Shift, 그 외는 그대로)
+    pub fn from_char(c: char) -> Self {
+        if c.is_ascii_uppercase() {
+            Self {
+                ch: c.to_ascii_lowercase(),
+                shift: true,
+            }
+        } else {
+            Self {
+                ch: c,
+                shift: false,
+            }
+        }
+    }
+
+    /// 키 입력으로 입력되는 문자
+    pub fn to_char(self) -> char {
+        if self.shift {
+            self.ch.to_ascii_uppercase()
+        } else {
+            self.ch
+        }
+    }
+}
+
 /// 한글 문자열을 두벌식 영문 키 시퀀스로 역변환
 ///
 /// # Examples
@@ -35,6 +72,65 @@ pub fn korean_to_eng(input: &str) -> String {
     result
 }
 
+/// 한글 문자열을 두벌식 키 입력 시퀀스로 역변환 (합성 타이핑 재현용)
+///
+/// 쌍자음(ㄲ ㄸ ㅃ ㅆ ㅉ)과 ㅒ/ㅖ는 Shift 키 입력, 한글이 아닌 문자는 그대로 유지합니다.
+///
+/// # Examples
+/// ```
+/// use koing::ngram::{korean_to_keystrokes, KeyStroke};
+/// assert_eq!(
+///     korean_to_keystrokes("까"),
+///     vec![KeyStroke { ch: 'r', shift: true }, KeyStroke { ch: 'k', shift: false }]
+/// );
+/// ```
+pub fn korean_to_keystrokes(input: &str) -> Vec<KeyStroke> {
+    korean_to_eng(input)
+        .chars()
+        .map(KeyStroke::from_char)
+        .collect()
+}
+
+/// QWERTY 자판에서 인접한 영문 키 (오타 보정 후보용, 소문자만)
+///
+/// # Examples
+/// ```
+/// use koing::ngram::adjacent_keys;
+/// assert_eq!(adjacent_keys('s'), "weadzx");
+/// assert_eq!(adjacent_keys('Q'), "");
+/// ```
+pub fn adjacent_keys(key: char) -> &'static str {
+    match key {
+        'q' => "wa",
+        'w' => "qeas",
+        'e' => "wrsd",
+        'r' => "etdf",
+        't' => "ryfg",
+        'y' => "tugh",
+        'u' => "yihj",
+        'i' => "uojk",
+        'o' => "ipkl",
+        'p' => "ol",
+        'a' => "qwsz",
+        's' => "weadzx",
+        'd' => "erfsxc",
+        'f' => "rtdgcv",
+        'g' => "tyfhvb",
+        'h' => "yugjbn",
+        'j' => "uihknm",
+        'k' => "iojlm",
+        'l' => "opk",
+        'z' => "asx",
+        'x' => "zsdc",
+        'c' => "xdfv",
+        'v' => "cfgb",
+        'b' => "vghn",
+        'n' => "bhjm",
+        'm' => "njk",
+        _ => "",
+    }
+}
+
 /// 초성 인덱스 -> 영문 키
 fn choseong_to_eng(cho: u32) -> Option<char> {
     // 초성 인덱스: ㄱ(0) ㄲ(1) ㄴ(2) ㄷ(3) ㄸ(4) ㄹ(5) ㅁ(6) ㅂ(7) ㅃ(8) ㅅ(9)
@@ -251,6 +347,42 @@ mod tests {
         assert_eq!(korean_to_eng("빠"), "Qk"); // ㅃ
     }
 
+    #[test]
+    fn test_ssang_jongseong_roundtrip() {
+        use crate::core::converter::convert;
+        use crate::core::unicode::{compose_syllable, decompose_syllable};
+
+        // ㄲ/ㅆ 받침은 Shift 키 하나로 역변환되고 다시 같은 받침으로 조합
+        assert_eq!(korean_to_eng("밖"), "qkR");
+        assert_eq!(korean_to_eng("갔"), "rkT");
+        assert_eq!(korean_to_eng("났"), "skT");
+
+        // 뒤 음절: 모음 시작(ㅇ)과 모든 초성
+        let followers: Vec<char> = (0..19)
+            .filter_map(|cho| compose_syllable(cho, 0, 0))
+            .chain(['아', '어', '요', '의'])
+            .collect();
+        for syllable in '가'..='힣' {
+            let (_, _, jong) = decompose_syllable(syllable).unwrap();
+            if jong != 2 && jong != 20 {
+                continue;
+            }
+            let alone = syllable.to_string();
+            assert_eq!(convert(&korean_to_eng(&alone)), alone);
+            for next in &followers {
+                let text = format!("{}{}", syllable, next);
+                assert_eq!(convert(&korean_to_eng(&text)), text);
+            }
+        }
+
+        // 모음이 이어지면 받침 ㄲ/ㅆ이 다음 음절 초성으로 분리
+        assert_eq!(korean_to_eng("깎아"), "RkRdk");
+        assert_eq!(convert("RkRdk"), "깎아");
+        assert_eq!(convert("RkRk"), "까까");
+        assert_eq!(convert("rkTj"), "가써");
+        assert_eq!(convert(&korean_to_eng("가써")), "가써");
+    }
+
     #[test]
     fn test_mixed_text() {
         assert_eq!(korean_to_eng("가1나"), "rk1sk");
@@ -264,6 +396,80 @@ mod tests {
         assert_eq!(korean_to_eng(""), "");
     }
 
+    fn keys(spec: &[(char, bool)]) -> Vec<KeyStroke> {
+        spec.iter()
+            .map(|&(ch, shift)| KeyStroke { ch, shift })
+            .collect()
+    }
+
+    #[test]
+    fn test_keystrokes_shift_jamo() {
+        // ㄲ(초성/종성), ㅆ 종성, ㅒ, ㅖ는 Shift 입력
+        assert_eq!(
+            korean_to_keystrokes("깎"),
+            keys(&[('r', true), ('k', false), ('r', true)])
+        );
+        assert_eq!(
+            korean_to_keystrokes("있"),
+            keys(&[('d', false), ('l', false), ('t', true)])
+        );
+        assert_eq!(
+            korean_to_keystrokes("얘"),
+            keys(&[('d', false), ('o', true)])
+        );
+        assert_eq!(
+            korean_to_keystrokes("례"),
+            keys(&[('f', false), ('p', true)])
+        );
+        assert_eq!(
+            korean_to_keystrokes("떴"),
+            keys(&[('e', true), ('j', false), ('t', true)])
+        );
+    }
+
+    #[test]
+    fn test_keystrokes_plain_and_non_hangul() {
+        // 복합 종성(ㄳ)은 Shift 없는 두 키
+        assert_eq!(
+            korean_to_keystrokes("넋"),
+            keys(&[('s', false), ('j', false), ('r', false), ('t', false)])
+        );
+        assert_eq!(
+            korean_to_keystrokes("A1!"),
+            keys(&[('a', true), ('1', false), ('!', false)])
+        );
+        assert!(korean_to_keystrokes("").is_empty());
+    }
+
+    #[test]
+    fn test_keystrokes_match_korean_to_eng() {
+        for text in ["안녕하세요", "까치", "있었다", "얘기", "예뻐", "Rust 한글"] {
+            let typed: String = korean_to_keystrokes(text)
+                .into_iter()
+                .map(KeyStroke::to_char)
+                .collect();
+            assert_eq!(typed, korean_to_eng(text), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_adjacent_keys_are_symmetric() {
+        for key in 'a'..='z' {
+            let neighbors = adjacent_keys(key);
+            assert!(!neighbors.is_empty(), "{}", key);
+            for neighbor in neighbors.chars() {
+                assert!(
+                    adjacent_keys(neighbor).contains(key),
+                    "{} <-> {}",
+                    key,
+                    neighbor
+                );
+            }
+        }
+        assert_eq!(adjacent_keys('A'), "");
+        assert_eq!(adjacent_keys('1'), "");
+    }
+
     #[test]
     fn test_roundtrip_conversion() {
         // 영문 -> 한글 -> 영문 라운드트립 테스트
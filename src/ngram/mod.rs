@@ -10,13 +10,14 @@
 //! 1. **영문 → 한글 변환**: 기존 `core::converter` 사용
 //! 2. **낱자모 검사**: 변환 결과에 불완전한 자모가 있으면 거부
 //! 3. **N-gram 스코어**: 학습된 모델로 자연스러운 한글인지 확인
+//!    (모델이 없으면 내장 음절/초성 빈도 휴리스틱으로 대신 판정)
 //!
 //! # 사용 예시
 //!
 //! ```no_run
 //! use koing::ngram::{KoreanValidator, NgramModel, NgramConfig};
 //!
-//! // 모델 없이 낱자모 검사만 사용
+//! // 모델 없이 낱자모 검사 + 빈도 휴리스틱 사용
 //! let validator = KoreanValidator::new();
 //! assert!(validator.should_convert_to_korean("dkssud"));  // "안녕" -> true
 //! assert!(!validator.should_convert_to_korean("name"));   // "ㅜ믇" -> false
@@ -39,14 +40,18 @@
 //! ```
 
 mod config;
+mod heuristic;
 mod keymap;
 mod model;
+mod split;
 mod syllable_validator;
 mod validator;
 
 // 공개 인터페이스
 pub use config::NgramConfig;
-pub use keymap::korean_to_eng;
-pub use model::{NgramAnalysis, NgramError, NgramModel};
+pub use heuristic::{common_syllable_ratio, heuristic_score, HEURISTIC_MIN_SCORE};
+pub use keymap::{adjacent_keys, korean_to_eng, korean_to_keystrokes, KeyStroke};
+pub use model::{ModelMetadata, NgramAnalysis, NgramError, NgramModel, PARALLEL_SCORE_MIN_CHARS};
+pub use split::{find_split_point, SPLIT_MIN_CHARS, SPLIT_MIN_DROP, SPLIT_MIN_PART_CHARS};
 pub use syllable_validator::check_syllable_structure;
 pub use validator::{KoreanValidator, RejectReason, ValidationResult};
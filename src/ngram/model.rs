@@ -2,12 +2,30 @@
 //!
 //! JSON 형식의 N-gram 모델 파일을 로드하고
 //! 바이그램 로그 확률을 계산합니다.
+//!
+//! 대형 모델은 정렬된 키 배열로 된 바이너리 포맷으로 저장해 두고
+//! `memmap2`로 매핑하면 파싱 없이 이진 탐색으로 조회할 수 있습니다.
+//! 배포 크기를 줄이려면 JSON 모델을 gzip(`.gz`)으로 압축해 둘 수 있습니다.
+//!
+//! JSON에서 파싱한 빈도는 문자 대신 정수 심볼 키로 저장합니다.
+//! 완성형 한글은 연속 코드포인트이므로 음절 오프셋을 키로 쓰고,
+//! 그 외 문자(낱자모, 영문 등)는 음절 범위 뒤쪽 키 공간에 배치합니다.
 
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::fs::File;
-use std::io::BufReader;
+use std::hash::{BuildHasherDefault, Hasher};
+use std::io::{BufReader, Read};
+use std::ops::Deref;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+use std::sync::Arc;
+
+use flate2::read::GzDecoder;
+use memmap2::Mmap;
 
 use super::config::NgramConfig;
+use crate::core::unicode::{HANGUL_SYLLABLE_BASE, HANGUL_SYLLABLE_COUNT};
 
 /// N-gram 모델 로드/파싱 에러
 #[derive(Debug)]
@@ -38,18 +56,356 @@ impl From<std::io::Error> for NgramError {
     }
 }
 
+/// 이 글자 수 이상이면 바이그램 로그 확률을 병렬 합산 (`parallel` feature)
+pub const PARALLEL_SCORE_MIN_CHARS: usize = 1000;
+
+/// 바이너리 모델 파일 매직 넘버
+const BINARY_MAGIC: &[u8; 4] = b"KNGM";
+/// 바이너리 모델 포맷 버전 (v2: 헤더 뒤에 메타데이터 블록)
+const BINARY_VERSION: u32 = 2;
+/// v1 헤더 크기: 매직(4) + 버전(4) + 유니그램 수(4) + 바이그램 수(4) + 유니그램 총 빈도(8)
+const BINARY_V1_HEADER_LEN: usize = 24;
+/// v2 헤더 크기: v1 헤더 + 메타데이터 길이(4)
+const BINARY_HEADER_LEN: usize = 28;
+/// 엔트리 크기: 키(u64) + 빈도(u64)
+const BINARY_ENTRY_LEN: usize = 16;
+
+/// 모델이 학습된 자판 배열 (Koing은 두벌식만 지원)
+const SUPPORTED_LAYOUT: &str = "dubeolsik";
+
+/// 모델 파일 메타데이터 (JSON `metadata` 필드)
+///
+/// 필드가 없으면 `None` — 기존 모델 파일은 메타데이터 없이 그대로 동작합니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModelMetadata {
+    /// 학습 시 사용한 어휘 크기 (스무딩 분모)
+    pub vocab_size: Option<usize>,
+    /// 자판 배열 (예: "dubeolsik")
+    pub layout: Option<String>,
+    /// 모델 버전 (숫자/문자열 모두 허용, 문자열로 보관)
+    pub model_version: Option<String>,
+}
+
 /// N-gram 모델
 ///
 /// 유니그램과 바이그램 빈도 데이터를 저장하고
 /// 텍스트의 로그 확률 스코어를 계산합니다.
 #[derive(Debug, Clone)]
 pub struct NgramModel {
-    /// 유니그램 빈도: 문자 -> 빈도
-    unigrams: HashMap<char, u64>,
-    /// 바이그램 빈도: (첫 번째 문자, 두 번째 문자) -> 빈도
-    bigrams: HashMap<(char, char), u64>,
+    /// 빈도 저장소
+    storage: Storage,
     /// 유니그램 총 빈도
     total_unigrams: u64,
+    /// 모델 메타데이터
+    metadata: ModelMetadata,
+    /// vocab_size 불일치 경고 출력 여부 (스코어링마다 반복 출력 방지)
+    vocab_mismatch_warned: Arc<AtomicBool>,
+}
+
+/// N-gram 빈도 저장소
+#[derive(Debug, Clone)]
+enum Storage {
+    /// JSON에서 파싱한 해시맵 표현
+    Hash {
+        /// 유니그램 빈도: 심볼 키 -> 빈도
+        unigrams: SymbolMap<u32>,
+        /// 바이그램 빈도: 심볼 바이그램 키 -> 빈도
+        bigrams: SymbolMap<u64>,
+    },
+    /// 정렬된 키 배열 + 이진 탐색 표현 (읽기 전용)
+    Sorted(SortedTable),
+}
+
+/// 바이너리 모델의 원본 바이트
+#[derive(Debug)]
+enum ModelBytes {
+    Owned(Vec<u8>),
+    Mapped(Mmap),
+}
+
+impl Deref for ModelBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            ModelBytes::Owned(bytes) => bytes,
+            ModelBytes::Mapped(mmap) => mmap,
+        }
+    }
+}
+
+/// 바이너리 포맷 위의 정렬된 조회 테이블
+///
+/// 포맷 (리틀 엔디언):
+/// - 헤더: `KNGM` | 버전 u32 | 유니그램 수 u32 | 바이그램 수 u32 | 유니그램 총 빈도 u64 | 메타데이터 길이 u32
+/// - 메타데이터: JSON `metadata`와 같은 형태의 UTF-8 JSON 객체 (v1 파일에는 없음)
+/// - 유니그램 엔트리: 키(문자 코드 u64) | 빈도 u64, 키 오름차순
+/// - 바이그램 엔트리: 키(첫 문자 << 32 | 둘째 문자, u64) | 빈도 u64, 키 오름차순
+#[derive(Debug, Clone)]
+struct SortedTable {
+    bytes: Arc<ModelBytes>,
+    /// 첫 엔트리 위치 (헤더 + 메타데이터 블록 뒤)
+    entries_offset: usize,
+    unigram_len: usize,
+    bigram_len: usize,
+}
+
+impl SortedTable {
+    /// 헤더/메타데이터를 검증하고 테이블 생성 (엔트리는 파싱하지 않음)
+    fn new(bytes: ModelBytes) -> Result<(Self, u64, ModelMetadata), NgramError> {
+        let invalid_header =
+            || NgramError::FormatError("바이너리 모델 헤더가 올바르지 않습니다".into());
+        if bytes.len() < BINARY_V1_HEADER_LEN || &bytes[0..4] != BINARY_MAGIC {
+            return Err(invalid_header());
+        }
+
+        let version = read_u32(&bytes, 4);
+        let unigram_len = read_u32(&bytes, 8) as usize;
+        let bigram_len = read_u32(&bytes, 12) as usize;
+        let total_unigrams = read_u64(&bytes, 16);
+
+        let (entries_offset, metadata) = match version {
+            // v1: 메타데이터 없음
+            1 => (BINARY_V1_HEADER_LEN, ModelMetadata::default()),
+            BINARY_VERSION => {
+                if bytes.len() < BINARY_HEADER_LEN {
+                    return Err(invalid_header());
+                }
+                let metadata_len = read_u32(&bytes, 24) as usize;
+                let block = bytes
+                    .get(BINARY_HEADER_LEN..BINARY_HEADER_LEN + metadata_len)
+                    .ok_or_else(invalid_header)?;
+                let value: serde_json::Value = serde_json::from_slice(block).map_err(|e| {
+                    NgramError::FormatError(format!("바이너리 모델 메타데이터 오류: {}", e))
+                })?;
+                (
+                    BINARY_HEADER_LEN + metadata_len,
+                    parse_metadata(Some(&value))?,
+                )
+            }
+            _ => {
+                return Err(NgramError::FormatError(format!(
+                    "지원하지 않는 바이너리 모델 버전: {}",
+                    version
+                )))
+            }
+        };
+
+        let expected = entries_offset + (unigram_len + bigram_len) * BINARY_ENTRY_LEN;
+        if bytes.len() != expected {
+            return Err(NgramError::FormatError(format!(
+                "바이너리 모델 크기 불일치: {} (expected {})",
+                bytes.len(),
+                expected
+            )));
+        }
+
+        let table = Self {
+            bytes: Arc::new(bytes),
+            entries_offset,
+            unigram_len,
+            bigram_len,
+        };
+        Ok((table, total_unigrams, metadata))
+    }
+
+    fn unigram_count(&self, c: char) -> u64 {
+        self.lookup(self.entries_offset, self.unigram_len, c as u64)
+    }
+
+    fn bigram_count(&self, first: char, second: char) -> u64 {
+        self.lookup(
+            self.bigram_offset(),
+            self.bigram_len,
+            bigram_key(first, second),
+        )
+    }
+
+    fn bigram_offset(&self) -> usize {
+        self.entries_offset + self.unigram_len * BINARY_ENTRY_LEN
+    }
+
+    /// 정렬된 엔트리 구간에서 키를 이진 탐색
+    fn lookup(&self, offset: usize, len: usize, key: u64) -> u64 {
+        let (mut lo, mut hi) = (0, len);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let entry = offset + mid * BINARY_ENTRY_LEN;
+            match read_u64(&self.bytes, entry).cmp(&key) {
+                Ordering::Less => lo = mid + 1,
+                Ordering::Greater => hi = mid,
+                Ordering::Equal => return read_u64(&self.bytes, entry + 8),
+            }
+        }
+        0
+    }
+
+    fn entries(&self, offset: usize, len: usize) -> impl Iterator<Item = (u64, u64)> + '_ {
+        (0..len).map(move |i| {
+            let entry = offset + i * BINARY_ENTRY_LEN;
+            (
+                read_u64(&self.bytes, entry),
+                read_u64(&self.bytes, entry + 8),
+            )
+        })
+    }
+}
+
+/// 경로의 확장자가 `ext`인지 확인 (대소문자 무관)
+pub(crate) fn has_extension(path: &str, ext: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case(ext))
+}
+
+/// `metadata` 필드 파싱 (없거나 null이면 기본값)
+fn parse_metadata(value: Option<&serde_json::Value>) -> Result<ModelMetadata, NgramError> {
+    let Some(obj) = value.filter(|v| !v.is_null()) else {
+        return Ok(ModelMetadata::default());
+    };
+    let obj = obj
+        .as_object()
+        .ok_or_else(|| NgramError::FormatError("metadata는 객체여야 합니다".into()))?;
+
+    let vocab_size = match obj.get("vocab_size") {
+        None => None,
+        Some(v) => match v.as_u64() {
+            Some(n) if n > 0 => Some(n as usize),
+            _ => {
+                return Err(NgramError::FormatError(format!(
+                    "유효하지 않은 vocab_size: {}",
+                    v
+                )))
+            }
+        },
+    };
+
+    let layout = match obj.get("layout") {
+        None => None,
+        Some(v) => Some(
+            v.as_str()
+                .ok_or_else(|| NgramError::FormatError(format!("유효하지 않은 layout: {}", v)))?
+                .to_string(),
+        ),
+    };
+    if let Some(layout) = layout.as_deref().filter(|&l| l != SUPPORTED_LAYOUT) {
+        log::warn!(
+            "N-gram 모델 자판 배열이 {}가 아닙니다: {}",
+            SUPPORTED_LAYOUT,
+            layout
+        );
+    }
+
+    let model_version = match obj.get("model_version") {
+        None => None,
+        Some(serde_json::Value::String(s)) => Some(s.clone()),
+        Some(serde_json::Value::Number(n)) => Some(n.to_string()),
+        Some(v) => {
+            return Err(NgramError::FormatError(format!(
+                "유효하지 않은 model_version: {}",
+                v
+            )))
+        }
+    };
+
+    Ok(ModelMetadata {
+        vocab_size,
+        layout,
+        model_version,
+    })
+}
+
+/// 바이너리 포맷의 메타데이터 블록 (값이 있는 필드만 담은 JSON 객체)
+fn metadata_json(metadata: &ModelMetadata) -> Vec<u8> {
+    let mut obj = serde_json::Map::new();
+    if let Some(vocab_size) = metadata.vocab_size {
+        obj.insert("vocab_size".into(), vocab_size.into());
+    }
+    if let Some(layout) = &metadata.layout {
+        obj.insert("layout".into(), layout.as_str().into());
+    }
+    if let Some(model_version) = &metadata.model_version {
+        obj.insert("model_version".into(), model_version.as_str().into());
+    }
+    serde_json::Value::Object(obj).to_string().into_bytes()
+}
+
+fn bigram_key(first: char, second: char) -> u64 {
+    ((first as u64) << 32) | second as u64
+}
+
+/// 조회 대상 문자와 심볼 키
+#[derive(Debug, Clone, Copy)]
+struct Symbol {
+    ch: char,
+    key: u32,
+}
+
+impl Symbol {
+    /// 완성형 음절은 음절 오프셋(0..11172), 그 외 문자는 코드포인트 + 11172
+    fn new(ch: char) -> Self {
+        let code = ch as u32;
+        let key = match code.checked_sub(HANGUL_SYLLABLE_BASE) {
+            Some(offset) if offset < HANGUL_SYLLABLE_COUNT => offset,
+            _ => code + HANGUL_SYLLABLE_COUNT,
+        };
+        Self { ch, key }
+    }
+
+    /// 심볼 키 → 문자 (바이너리 직렬화용)
+    fn char_of(key: u32) -> char {
+        let code = if key < HANGUL_SYLLABLE_COUNT {
+            HANGUL_SYLLABLE_BASE + key
+        } else {
+            key - HANGUL_SYLLABLE_COUNT
+        };
+        char::from_u32(code).expect("심볼 키는 항상 유효한 문자에서 생성됩니다")
+    }
+}
+
+/// 심볼 키 전용 해셔 (곱셈 해시)
+///
+/// 키가 정수이고 모델 데이터는 신뢰할 수 있으므로 SipHash의 DoS 방어가 필요 없음
+#[derive(Default)]
+struct SymbolHasher(u64);
+
+impl Hasher for SymbolHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.write_u64(b as u64);
+        }
+    }
+
+    fn write_u32(&mut self, n: u32) {
+        self.write_u64(n as u64);
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.0 = (self.0.rotate_left(5) ^ n).wrapping_mul(0x517c_c1b7_2722_0a95);
+    }
+}
+
+/// 심볼 키 → 빈도 해시맵
+type SymbolMap<K> = HashMap<K, u64, BuildHasherDefault<SymbolHasher>>;
+
+fn symbol_bigram_key(first: u32, second: u32) -> u64 {
+    ((first as u64) << 32) | second as u64
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    let mut buf = [0u8; 4];
+    buf.copy_from_slice(&bytes[offset..offset + 4]);
+    u32::from_le_bytes(buf)
+}
+
+fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&bytes[offset..offset + 8]);
+    u64::from_le_bytes(buf)
 }
 
 /// 텍스트에 대한 N-gram 분석 결과
@@ -75,13 +431,28 @@ impl NgramModel {
     ///   "bigrams": { "가|나": 4567, "나|다": 2345 }
     /// }
     /// ```
+    ///
+    /// `.gz` 확장자(대소문자 무관)는 gzip으로 보고 스트리밍 해제하며 파싱합니다.
     pub fn load(path: &str) -> Result<Self, NgramError> {
         let file = File::open(path)?;
-        let reader = BufReader::new(file);
+        if has_extension(path, "gz") {
+            Self::load_from_reader(BufReader::new(GzDecoder::new(file)))
+        } else {
+            Self::load_from_reader(BufReader::new(file))
+        }
+    }
 
-        // serde_json으로 파싱
-        let value: serde_json::Value =
-            serde_json::from_reader(reader).map_err(|e| NgramError::ParseError(e.to_string()))?;
+    /// 임의의 JSON 스트림에서 모델 로드
+    ///
+    /// 압축 해제 등 읽기 단계 실패는 `IoError`, JSON 구문/형식 오류는 `ParseError`.
+    pub fn load_from_reader<R: Read>(reader: R) -> Result<Self, NgramError> {
+        let value: serde_json::Value = serde_json::from_reader(reader).map_err(|e| {
+            if e.is_io() {
+                NgramError::IoError(e.into())
+            } else {
+                NgramError::ParseError(e.to_string())
+            }
+        })?;
 
         Self::from_json_value(&value)
     }
@@ -96,13 +467,15 @@ impl NgramModel {
 
     /// serde_json::Value에서 모델 생성
     fn from_json_value(value: &serde_json::Value) -> Result<Self, NgramError> {
+        let metadata = parse_metadata(value.get("metadata"))?;
+
         // 유니그램 파싱
         let unigrams_obj = value
             .get("unigrams")
             .and_then(|v| v.as_object())
             .ok_or_else(|| NgramError::FormatError("unigrams 필드가 없습니다".into()))?;
 
-        let mut unigrams = HashMap::new();
+        let mut unigrams = SymbolMap::default();
         let mut total_unigrams = 0u64;
 
         for (key, val) in unigrams_obj {
@@ -113,7 +486,7 @@ impl NgramModel {
             let count = val
                 .as_u64()
                 .ok_or_else(|| NgramError::FormatError(format!("유효하지 않은 빈도값: {}", key)))?;
-            unigrams.insert(char, count);
+            unigrams.insert(Symbol::new(char).key, count);
             total_unigrams += count;
         }
 
@@ -123,7 +496,7 @@ impl NgramModel {
             .and_then(|v| v.as_object())
             .ok_or_else(|| NgramError::FormatError("bigrams 필드가 없습니다".into()))?;
 
-        let mut bigrams = HashMap::new();
+        let mut bigrams = SymbolMap::default();
 
         for (key, val) in bigrams_obj {
             // "가|나" 형식 파싱
@@ -146,33 +519,199 @@ impl NgramModel {
                 .as_u64()
                 .ok_or_else(|| NgramError::FormatError(format!("유효하지 않은 빈도값: {}", key)))?;
 
-            bigrams.insert((first, second), count);
+            bigrams.insert(
+                symbol_bigram_key(Symbol::new(first).key, Symbol::new(second).key),
+                count,
+            );
         }
 
         Ok(Self {
-            unigrams,
-            bigrams,
+            storage: Storage::Hash { unigrams, bigrams },
+            total_unigrams,
+            metadata,
+            vocab_mismatch_warned: Arc::default(),
+        })
+    }
+
+    /// 바이너리 모델 파일을 메모리 매핑하여 로드
+    ///
+    /// 엔트리를 파싱하지 않고 헤더만 검증하므로 대형 모델도 즉시 열립니다.
+    /// 파일 형식은 [`NgramModel::to_binary`] 참고.
+    pub fn load_binary(path: &str) -> Result<Self, NgramError> {
+        let file = File::open(path)?;
+        // SAFETY: 모델 파일은 읽기 전용으로 배포되며 실행 중 수정되지 않는다고 가정
+        let mmap = unsafe { Mmap::map(&file)? };
+        Self::from_model_bytes(ModelBytes::Mapped(mmap))
+    }
+
+    /// 바이너리 바이트열에서 모델 로드
+    pub fn from_binary(bytes: Vec<u8>) -> Result<Self, NgramError> {
+        Self::from_model_bytes(ModelBytes::Owned(bytes))
+    }
+
+    fn from_model_bytes(bytes: ModelBytes) -> Result<Self, NgramError> {
+        let (table, total_unigrams, metadata) = SortedTable::new(bytes)?;
+        Ok(Self {
+            storage: Storage::Sorted(table),
             total_unigrams,
+            metadata,
+            vocab_mismatch_warned: Arc::default(),
         })
     }
 
+    /// 정렬된 키 배열 바이너리 포맷으로 직렬화
+    pub fn to_binary(&self) -> Vec<u8> {
+        if let Storage::Sorted(table) = &self.storage {
+            return table.bytes.to_vec();
+        }
+
+        let unigrams = self.sorted_unigrams();
+        let bigrams = self.sorted_bigrams();
+        let metadata = metadata_json(&self.metadata);
+
+        let mut out = Vec::with_capacity(
+            BINARY_HEADER_LEN
+                + metadata.len()
+                + (unigrams.len() + bigrams.len()) * BINARY_ENTRY_LEN,
+        );
+        out.extend_from_slice(BINARY_MAGIC);
+        out.extend_from_slice(&BINARY_VERSION.to_le_bytes());
+        out.extend_from_slice(&(unigrams.len() as u32).to_le_bytes());
+        out.extend_from_slice(&(bigrams.len() as u32).to_le_bytes());
+        out.extend_from_slice(&self.total_unigrams.to_le_bytes());
+        out.extend_from_slice(&(metadata.len() as u32).to_le_bytes());
+        out.extend_from_slice(&metadata);
+        for (key, count) in unigrams.into_iter().chain(bigrams) {
+            out.extend_from_slice(&key.to_le_bytes());
+            out.extend_from_slice(&count.to_le_bytes());
+        }
+        out
+    }
+
+    /// 바이너리 포맷으로 파일 저장
+    pub fn write_binary(&self, path: &str) -> Result<(), NgramError> {
+        std::fs::write(path, self.to_binary())?;
+        Ok(())
+    }
+
+    /// 정렬 저장소(이진 탐색)로 변환한 모델 (메타데이터 유지)
+    pub fn to_sorted(&self) -> Self {
+        match &self.storage {
+            Storage::Sorted(_) => self.clone(),
+            Storage::Hash { .. } => Self::from_binary(self.to_binary())
+                .expect("직렬화한 바이너리 모델은 항상 유효합니다"),
+        }
+    }
+
+    /// 정렬 저장소 사용 여부
+    pub fn is_sorted(&self) -> bool {
+        matches!(self.storage, Storage::Sorted(_))
+    }
+
+    fn sorted_unigrams(&self) -> Vec<(u64, u64)> {
+        let mut entries: Vec<(u64, u64)> = match &self.storage {
+            Storage::Hash { unigrams, .. } => unigrams
+                .iter()
+                .map(|(&key, &count)| (Symbol::char_of(key) as u64, count))
+                .collect(),
+            Storage::Sorted(table) => table
+                .entries(table.entries_offset, table.unigram_len)
+                .collect(),
+        };
+        entries.sort_unstable();
+        entries
+    }
+
+    fn sorted_bigrams(&self) -> Vec<(u64, u64)> {
+        let mut entries: Vec<(u64, u64)> = match &self.storage {
+            Storage::Hash { bigrams, .. } => bigrams
+                .iter()
+                .map(|(&key, &count)| {
+                    let first = Symbol::char_of((key >> 32) as u32);
+                    let second = Symbol::char_of(key as u32);
+                    (bigram_key(first, second), count)
+                })
+                .collect(),
+            Storage::Sorted(table) => table
+                .entries(table.bigram_offset(), table.bigram_len)
+                .collect(),
+        };
+        entries.sort_unstable();
+        entries
+    }
+
     /// 빈 모델 생성 (테스트용)
     pub fn empty() -> Self {
         Self {
-            unigrams: HashMap::new(),
-            bigrams: HashMap::new(),
+            storage: Storage::Hash {
+                unigrams: SymbolMap::default(),
+                bigrams: SymbolMap::default(),
+            },
             total_unigrams: 0,
+            metadata: ModelMetadata::default(),
+            vocab_mismatch_warned: Arc::default(),
         }
     }
 
+    /// 모델 메타데이터
+    pub fn metadata(&self) -> &ModelMetadata {
+        &self.metadata
+    }
+
+    /// 설정과 모델의 vocab_size가 다르면 (모델 값, 설정 값) 반환
+    pub fn vocab_size_mismatch(&self, config: &NgramConfig) -> Option<(usize, usize)> {
+        self.metadata
+            .vocab_size
+            .filter(|&model_vocab| model_vocab != config.vocab_size)
+            .map(|model_vocab| (model_vocab, config.vocab_size))
+    }
+
+    /// 스무딩에 사용할 어휘 크기
+    ///
+    /// 모델 빈도는 학습 시 vocab_size 기준으로 해석해야 하므로
+    /// 메타데이터에 값이 있으면 설정보다 모델 값을 우선합니다.
+    pub fn effective_vocab_size(&self, config: &NgramConfig) -> usize {
+        if let Some((model_vocab, config_vocab)) = self.vocab_size_mismatch(config) {
+            if !self
+                .vocab_mismatch_warned
+                .swap(true, AtomicOrdering::Relaxed)
+            {
+                log::warn!(
+                    "N-gram 모델 vocab_size 불일치: 모델 {} / 설정 {} — 모델 값 사용",
+                    model_vocab,
+                    config_vocab
+                );
+            }
+            return model_vocab;
+        }
+        config.vocab_size
+    }
+
     /// 유니그램 빈도 조회
     pub fn unigram_count(&self, c: char) -> u64 {
-        self.unigrams.get(&c).copied().unwrap_or(0)
+        self.symbol_unigram_count(Symbol::new(c))
     }
 
     /// 바이그램 빈도 조회
     pub fn bigram_count(&self, first: char, second: char) -> u64 {
-        self.bigrams.get(&(first, second)).copied().unwrap_or(0)
+        self.symbol_bigram_count(Symbol::new(first), Symbol::new(second))
+    }
+
+    fn symbol_unigram_count(&self, symbol: Symbol) -> u64 {
+        match &self.storage {
+            Storage::Hash { unigrams, .. } => unigrams.get(&symbol.key).copied().unwrap_or(0),
+            Storage::Sorted(table) => table.unigram_count(symbol.ch),
+        }
+    }
+
+    fn symbol_bigram_count(&self, first: Symbol, second: Symbol) -> u64 {
+        match &self.storage {
+            Storage::Hash { bigrams, .. } => bigrams
+                .get(&symbol_bigram_key(first.key, second.key))
+                .copied()
+                .unwrap_or(0),
+            Storage::Sorted(table) => table.bigram_count(first.ch, second.ch),
+        }
     }
 
     /// 총 유니그램 빈도
@@ -198,11 +737,13 @@ impl NgramModel {
 
     /// 설정을 적용한 상세 분석 결과 계산
     pub fn analyze_with_config(&self, text: &str, config: &NgramConfig) -> NgramAnalysis {
-        let chars: Vec<char> = text.chars().collect();
-        let total_unigrams = chars.len();
-        let unknown_unigrams = chars
+        let vocab_size = self.effective_vocab_size(config);
+        // 문자별 심볼 키는 한 번만 계산
+        let symbols: Vec<Symbol> = text.chars().map(Symbol::new).collect();
+        let total_unigrams = symbols.len();
+        let unknown_unigrams = symbols
             .iter()
-            .filter(|&&ch| self.unigram_count(ch) == 0)
+            .filter(|&&symbol| self.symbol_unigram_count(symbol) == 0)
             .count();
         let unknown_unigram_ratio = if total_unigrams == 0 {
             0.0
@@ -210,9 +751,9 @@ impl NgramModel {
             unknown_unigrams as f64 / total_unigrams as f64
         };
 
-        if chars.len() < 2 {
+        if symbols.len() < 2 {
             // 1글자 이하면 유니그램 확률만 사용
-            if chars.is_empty() {
+            if symbols.is_empty() {
                 return NgramAnalysis {
                     score: f64::NEG_INFINITY,
                     unknown_unigram_ratio,
@@ -221,38 +762,17 @@ impl NgramModel {
                 };
             }
             return NgramAnalysis {
-                score: self.unigram_log_prob(chars[0], config),
+                score: self.unigram_log_prob(symbols[0], config.smoothing_k, vocab_size),
                 unknown_unigram_ratio,
                 unknown_bigram_ratio: 0.0,
                 seen_bigram_count: 0,
             };
         }
 
-        let mut log_prob_sum = 0.0;
-        let mut count = 0;
-        let mut unknown_bigrams = 0usize;
-        let mut seen_bigrams = 0usize;
-
-        for window in chars.windows(2) {
-            let first = window[0];
-            let second = window[1];
-
-            let bigram_count = self.bigram_count(first, second) as f64;
-            let context_count = self.unigram_count(first) as f64;
-            if bigram_count > 0.0 {
-                seen_bigrams += 1;
-            } else {
-                unknown_bigrams += 1;
-            }
-
-            // Add-k 스무딩
-            let k = config.smoothing_k;
-            let v = config.vocab_size as f64;
-
-            let prob = (bigram_count + k) / (context_count + k * v);
-            log_prob_sum += prob.ln();
-            count += 1;
-        }
+        let (log_prob_sum, seen_bigrams) =
+            self.bigram_sums(&symbols, config.smoothing_k, vocab_size as f64);
+        let count = symbols.len() - 1;
+        let unknown_bigrams = count - seen_bigrams;
 
         let score = if count == 0 {
             f64::NEG_INFINITY
@@ -273,17 +793,71 @@ impl NgramModel {
         }
     }
 
+    /// 인접 음절 경계별 바이그램 로그 확률 (i: i번째와 i+1번째 문자 사이, 단어 분할용)
+    pub fn boundary_scores(&self, text: &str, config: &NgramConfig) -> Vec<f64> {
+        let v = self.effective_vocab_size(config) as f64;
+        let symbols: Vec<Symbol> = text.chars().map(Symbol::new).collect();
+        symbols
+            .windows(2)
+            .map(|window| {
+                self.bigram_log_prob(window[0], window[1], config.smoothing_k, v)
+                    .0
+            })
+            .collect()
+    }
+
+    /// 바이그램 로그 확률 합과 등록된 바이그램 수
+    ///
+    /// 긴 입력(`PARALLEL_SCORE_MIN_CHARS` 이상)은 `parallel` feature에서 병렬 합산하고,
+    /// 짧은 입력은 스레드 분배 오버헤드를 피하도록 순차 합산합니다.
+    fn bigram_sums(&self, symbols: &[Symbol], k: f64, v: f64) -> (f64, usize) {
+        #[cfg(feature = "parallel")]
+        if symbols.len() >= PARALLEL_SCORE_MIN_CHARS {
+            return self.bigram_sums_parallel(symbols, k, v);
+        }
+        self.bigram_sums_sequential(symbols, k, v)
+    }
+
+    fn bigram_sums_sequential(&self, symbols: &[Symbol], k: f64, v: f64) -> (f64, usize) {
+        symbols
+            .windows(2)
+            .map(|window| self.bigram_log_prob(window[0], window[1], k, v))
+            .fold((0.0, 0), |(sum, seen), (log_prob, known)| {
+                (sum + log_prob, seen + usize::from(known))
+            })
+    }
+
+    #[cfg(feature = "parallel")]
+    fn bigram_sums_parallel(&self, symbols: &[Symbol], k: f64, v: f64) -> (f64, usize) {
+        use rayon::prelude::*;
+
+        symbols
+            .par_windows(2)
+            .map(|window| {
+                let (log_prob, known) = self.bigram_log_prob(window[0], window[1], k, v);
+                (log_prob, usize::from(known))
+            })
+            .reduce(|| (0.0, 0), |a, b| (a.0 + b.0, a.1 + b.1))
+    }
+
+    /// Add-k 스무딩 바이그램 로그 확률과 등록 여부
+    fn bigram_log_prob(&self, first: Symbol, second: Symbol, k: f64, v: f64) -> (f64, bool) {
+        let bigram_count = self.symbol_bigram_count(first, second) as f64;
+        let context_count = self.symbol_unigram_count(first) as f64;
+        let prob = (bigram_count + k) / (context_count + k * v);
+        (prob.ln(), bigram_count > 0.0)
+    }
+
     /// 유니그램 로그 확률
-    fn unigram_log_prob(&self, c: char, config: &NgramConfig) -> f64 {
-        let count = self.unigram_count(c) as f64;
+    fn unigram_log_prob(&self, symbol: Symbol, k: f64, vocab_size: usize) -> f64 {
+        let count = self.symbol_unigram_count(symbol) as f64;
         let total = self.total_unigrams as f64;
 
         if total == 0.0 {
             return f64::NEG_INFINITY;
         }
 
-        let k = config.smoothing_k;
-        let v = config.vocab_size as f64;
+        let v = vocab_size as f64;
 
         let prob = (count + k) / (total + k * v);
         prob.ln()
@@ -291,17 +865,23 @@ impl NgramModel {
 
     /// 모델에 데이터가 있는지 확인
     pub fn is_empty(&self) -> bool {
-        self.unigrams.is_empty() && self.bigrams.is_empty()
+        self.unigram_count_total() == 0 && self.bigram_count_total() == 0
     }
 
     /// 유니그램 수
     pub fn unigram_count_total(&self) -> usize {
-        self.unigrams.len()
+        match &self.storage {
+            Storage::Hash { unigrams, .. } => unigrams.len(),
+            Storage::Sorted(table) => table.unigram_len,
+        }
     }
 
     /// 바이그램 수
     pub fn bigram_count_total(&self) -> usize {
-        self.bigrams.len()
+        match &self.storage {
+            Storage::Hash { bigrams, .. } => bigrams.len(),
+            Storage::Sorted(table) => table.bigram_len,
+        }
     }
 }
 
@@ -347,6 +927,77 @@ mod tests {
         assert!(score_unknown < score_known);
     }
 
+    /// 학습된 패턴과 미등록 음절이 섞인 긴 텍스트
+    fn long_text(min_chars: usize) -> String {
+        let mut text = String::new();
+        while text.chars().count() < min_chars {
+            text.push_str("안녕하세요 뷁 하세요안녕 ");
+        }
+        text
+    }
+
+    #[test]
+    fn test_bigram_sums_match_per_window_sum() {
+        let model = NgramModel::from_json(sample_model_json()).unwrap();
+        let config = NgramConfig::default();
+        for text in [
+            "안녕하세요",
+            long_text(PARALLEL_SCORE_MIN_CHARS * 2).as_str(),
+        ] {
+            let symbols: Vec<Symbol> = text.chars().map(Symbol::new).collect();
+            let v = config.vocab_size as f64;
+            let (sum, seen) = model.bigram_sums(&symbols, config.smoothing_k, v);
+
+            let expected: f64 = symbols
+                .windows(2)
+                .map(|w| model.bigram_log_prob(w[0], w[1], config.smoothing_k, v).0)
+                .sum();
+            assert!((sum - expected).abs() <= 1e-9 * expected.abs().max(1.0));
+            assert!(seen > 0 && seen < symbols.len());
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_score_matches_sequential() {
+        let model = NgramModel::from_json(sample_model_json()).unwrap();
+        let sorted = NgramModel::from_binary(model.to_binary()).unwrap();
+        let config = NgramConfig::default();
+        let v = config.vocab_size as f64;
+
+        for len in [
+            2,
+            10,
+            PARALLEL_SCORE_MIN_CHARS - 1,
+            PARALLEL_SCORE_MIN_CHARS,
+            20_000,
+        ] {
+            let text: String = long_text(len).chars().take(len).collect();
+            let symbols: Vec<Symbol> = text.chars().map(Symbol::new).collect();
+            for model in [&model, &sorted] {
+                let (seq_sum, seq_seen) = model.bigram_sums_sequential(&symbols, 0.001, v);
+                let (par_sum, par_seen) = model.bigram_sums_parallel(&symbols, 0.001, v);
+                assert_eq!(par_seen, seq_seen, "len={}", len);
+                // 합산 순서만 다르므로 상대 오차 범위 내에서 동일
+                assert!(
+                    (par_sum - seq_sum).abs() <= 1e-9 * seq_sum.abs(),
+                    "len={} seq={} par={}",
+                    len,
+                    seq_sum,
+                    par_sum
+                );
+
+                let score = model.score_with_config(&text, &config);
+                let expected = seq_sum / (symbols.len() - 1) as f64;
+                assert!(
+                    (score - expected).abs() <= 1e-9 * expected.abs(),
+                    "len={}",
+                    len
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_empty_model() {
         let model = NgramModel::empty();
@@ -379,4 +1030,402 @@ mod tests {
         let result = NgramModel::from_json(invalid_bigram);
         assert!(matches!(result, Err(NgramError::FormatError(_))));
     }
+
+    #[test]
+    fn test_metadata_absent_keeps_defaults() {
+        let model = NgramModel::from_json(sample_model_json()).unwrap();
+        assert_eq!(model.metadata(), &ModelMetadata::default());
+
+        let config = NgramConfig::default();
+        assert_eq!(model.vocab_size_mismatch(&config), None);
+        assert_eq!(model.effective_vocab_size(&config), config.vocab_size);
+    }
+
+    #[test]
+    fn test_metadata_parsed() {
+        let json = r#"{
+            "metadata": { "vocab_size": 11172, "layout": "dubeolsik", "model_version": 3 },
+            "unigrams": { "안": 10 },
+            "bigrams": {}
+        }"#;
+        let model = NgramModel::from_json(json).unwrap();
+        let metadata = model.metadata();
+        assert_eq!(metadata.vocab_size, Some(11172));
+        assert_eq!(metadata.layout.as_deref(), Some("dubeolsik"));
+        assert_eq!(metadata.model_version.as_deref(), Some("3"));
+
+        // 정렬 저장소로 변환해도 메타데이터 유지
+        assert_eq!(model.to_sorted().metadata(), metadata);
+    }
+
+    #[test]
+    fn test_vocab_size_mismatch_prefers_model_value() {
+        let json = r#"{
+            "metadata": { "vocab_size": 2000 },
+            "unigrams": { "안": 100, "녕": 80 },
+            "bigrams": { "안|녕": 50 }
+        }"#;
+        let model = NgramModel::from_json(json).unwrap();
+        let config = NgramConfig::default();
+        assert_eq!(model.vocab_size_mismatch(&config), Some((2000, 11172)));
+        assert_eq!(model.effective_vocab_size(&config), 2000);
+
+        // 모델 vocab_size로 설정한 경우와 점수가 같아야 함
+        let matching = NgramConfig {
+            vocab_size: 2000,
+            ..NgramConfig::default()
+        };
+        assert_eq!(model.vocab_size_mismatch(&matching), None);
+        assert_eq!(
+            model.score_with_config("안녕", &config),
+            model.score_with_config("안녕", &matching)
+        );
+        assert_eq!(
+            model.score_with_config("안", &config),
+            model.score_with_config("안", &matching)
+        );
+    }
+
+    #[test]
+    fn test_invalid_metadata_format_error() {
+        for metadata in [
+            r#""v1""#,
+            r#"{ "vocab_size": 0 }"#,
+            r#"{ "vocab_size": "11172" }"#,
+            r#"{ "layout": 2 }"#,
+            r#"{ "model_version": [1] }"#,
+        ] {
+            let json = format!(
+                r#"{{ "metadata": {}, "unigrams": {{}}, "bigrams": {{}} }}"#,
+                metadata
+            );
+            let result = NgramModel::from_json(&json);
+            assert!(
+                matches!(result, Err(NgramError::FormatError(_))),
+                "{}",
+                metadata
+            );
+        }
+    }
+
+    /// 테스트용 결정적 의사 난수 (xorshift)
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn pick(&mut self, chars: &[char]) -> char {
+            chars[(self.next() % chars.len() as u64) as usize]
+        }
+    }
+
+    fn random_model_json(rng: &mut Rng, alphabet: &[char]) -> String {
+        let mut unigrams = serde_json::Map::new();
+        let mut bigrams = serde_json::Map::new();
+        for _ in 0..alphabet.len() {
+            let c = rng.pick(alphabet);
+            unigrams.insert(c.to_string(), (rng.next() % 1000 + 1).into());
+        }
+        for _ in 0..alphabet.len() * 3 {
+            let key = format!("{}|{}", rng.pick(alphabet), rng.pick(alphabet));
+            bigrams.insert(key, (rng.next() % 500 + 1).into());
+        }
+        serde_json::json!({ "unigrams": unigrams, "bigrams": bigrams }).to_string()
+    }
+
+    #[test]
+    fn test_sorted_storage_matches_hash_storage() {
+        let alphabet: Vec<char> = "가나다라마바사아자차카타파하안녕요세abc".chars().collect();
+        let config = NgramConfig::default();
+        let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+
+        for _ in 0..50 {
+            let hash_model =
+                NgramModel::from_json(&random_model_json(&mut rng, &alphabet)).unwrap();
+            let sorted_model = NgramModel::from_binary(hash_model.to_binary()).unwrap();
+            assert!(sorted_model.is_sorted());
+            assert_eq!(sorted_model.total_unigrams(), hash_model.total_unigrams());
+            assert_eq!(
+                sorted_model.unigram_count_total(),
+                hash_model.unigram_count_total()
+            );
+            assert_eq!(
+                sorted_model.bigram_count_total(),
+                hash_model.bigram_count_total()
+            );
+
+            for _ in 0..20 {
+                let len = (rng.next() % 8) as usize;
+                let text: String = (0..len).map(|_| rng.pick(&alphabet)).collect();
+                let hash_score = hash_model.score_with_config(&text, &config);
+                let sorted_score = sorted_model.score_with_config(&text, &config);
+                assert_eq!(
+                    hash_score.to_bits(),
+                    sorted_score.to_bits(),
+                    "text={}",
+                    text
+                );
+                assert_eq!(
+                    hash_model.analyze_with_config(&text, &config),
+                    sorted_model.analyze_with_config(&text, &config)
+                );
+            }
+
+            for &a in &alphabet {
+                assert_eq!(sorted_model.unigram_count(a), hash_model.unigram_count(a));
+                for &b in &alphabet {
+                    assert_eq!(
+                        sorted_model.bigram_count(a, b),
+                        hash_model.bigram_count(a, b)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_symbol_key_round_trip() {
+        for c in [
+            '가',
+            '힣',
+            '안',
+            'ㄱ',
+            'ㅏ',
+            'a',
+            '1',
+            '|',
+            '\u{D7A4}',
+            '\u{10FFFF}',
+        ] {
+            assert_eq!(Symbol::char_of(Symbol::new(c).key), c);
+        }
+        // 완성형 음절은 음절 오프셋, 그 외 문자는 음절 범위 뒤쪽 키
+        assert_eq!(Symbol::new('가').key, 0);
+        assert_eq!(Symbol::new('힣').key, HANGUL_SYLLABLE_COUNT - 1);
+        assert_eq!(Symbol::new('\0').key, HANGUL_SYLLABLE_COUNT);
+        assert!(Symbol::new('ㄱ').key >= HANGUL_SYLLABLE_COUNT);
+    }
+
+    /// char 키 해시맵으로 계산한 기준 스코어 (심볼 키 도입 전 구현)
+    fn reference_score(json: &str, text: &str, config: &NgramConfig) -> f64 {
+        let value: serde_json::Value = serde_json::from_str(json).unwrap();
+        let unigrams: HashMap<char, u64> = value["unigrams"]
+            .as_object()
+            .unwrap()
+            .iter()
+            .map(|(k, v)| (k.chars().next().unwrap(), v.as_u64().unwrap()))
+            .collect();
+        let bigrams: HashMap<(char, char), u64> = value["bigrams"]
+            .as_object()
+            .unwrap()
+            .iter()
+            .map(|(k, v)| {
+                let (a, b) = k.split_once('|').unwrap();
+                let key = (a.chars().next().unwrap(), b.chars().next().unwrap());
+                (key, v.as_u64().unwrap())
+            })
+            .collect();
+        let total: u64 = unigrams.values().sum();
+        let k = config.smoothing_k;
+        let v = config.vocab_size as f64;
+        let chars: Vec<char> = text.chars().collect();
+
+        match chars.len() {
+            0 => f64::NEG_INFINITY,
+            1 => {
+                let count = unigrams.get(&chars[0]).copied().unwrap_or(0) as f64;
+                ((count + k) / (total as f64 + k * v)).ln()
+            }
+            _ => {
+                let sum: f64 = chars
+                    .windows(2)
+                    .map(|w| {
+                        let bigram = bigrams.get(&(w[0], w[1])).copied().unwrap_or(0) as f64;
+                        let context = unigrams.get(&w[0]).copied().unwrap_or(0) as f64;
+                        ((bigram + k) / (context + k * v)).ln()
+                    })
+                    .sum();
+                sum / (chars.len() - 1) as f64
+            }
+        }
+    }
+
+    #[test]
+    fn test_symbol_keys_match_char_key_scores() {
+        // 완성형 음절 + 낱자모/영문/숫자 혼합
+        let alphabet: Vec<char> = "가각힣안녕하세요ㄱㅏㅎaZ1!".chars().collect();
+        let config = NgramConfig::default();
+        let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+
+        for _ in 0..30 {
+            let json = random_model_json(&mut rng, &alphabet);
+            let model = NgramModel::from_json(&json).unwrap();
+            for _ in 0..20 {
+                let len = (rng.next() % 8) as usize;
+                let text: String = (0..len).map(|_| rng.pick(&alphabet)).collect();
+                assert_eq!(
+                    model.score_with_config(&text, &config).to_bits(),
+                    reference_score(&json, &text, &config).to_bits(),
+                    "text={}",
+                    text
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_load_binary_via_mmap() {
+        let model = NgramModel::from_json(sample_model_json()).unwrap();
+        let path = std::env::temp_dir().join(format!("koing_ngram_{}.bin", std::process::id()));
+        let path_str = path.to_string_lossy().to_string();
+        model.write_binary(&path_str).unwrap();
+
+        let mapped = NgramModel::load_binary(&path_str).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert!(mapped.is_sorted());
+        assert_eq!(mapped.unigram_count('안'), 100);
+        assert_eq!(mapped.bigram_count('안', '녕'), 50);
+        assert_eq!(mapped.bigram_count('녕', '안'), 0);
+        assert_eq!(mapped.score("안녕하세요"), model.score("안녕하세요"));
+        assert_eq!(mapped.to_binary(), model.to_binary());
+    }
+
+    fn gzip(bytes: &[u8]) -> Vec<u8> {
+        use flate2::write::GzEncoder;
+        use flate2::Compression;
+        use std::io::Write;
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(bytes).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn test_load_from_reader_plain_and_gzip_match() {
+        let plain = NgramModel::load_from_reader(sample_model_json().as_bytes()).unwrap();
+        let compressed = gzip(sample_model_json().as_bytes());
+        let gz = NgramModel::load_from_reader(GzDecoder::new(compressed.as_slice())).unwrap();
+
+        assert_eq!(gz.to_binary(), plain.to_binary());
+        assert_eq!(gz.score("안녕하세요"), plain.score("안녕하세요"));
+    }
+
+    #[test]
+    fn test_load_dispatches_gzip_by_extension() {
+        let plain = NgramModel::from_json(sample_model_json()).unwrap();
+        let compressed = gzip(sample_model_json().as_bytes());
+
+        for name in ["model.json.gz", "MODEL.JSON.GZ", "model.Gz"] {
+            let path = std::env::temp_dir().join(format!("koing_{}_{}", std::process::id(), name));
+            let path_str = path.to_string_lossy().to_string();
+            std::fs::write(&path, &compressed).unwrap();
+            let loaded = NgramModel::load(&path_str);
+            let _ = std::fs::remove_file(&path);
+
+            assert_eq!(loaded.unwrap().to_binary(), plain.to_binary(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_corrupt_gzip_errors_are_distinguished() {
+        // gzip 스트림 자체가 손상 → IoError
+        let mut corrupt = gzip(sample_model_json().as_bytes());
+        let mid = corrupt.len() / 2;
+        corrupt.truncate(mid);
+        assert!(matches!(
+            NgramModel::load_from_reader(GzDecoder::new(corrupt.as_slice())),
+            Err(NgramError::IoError(_))
+        ));
+        assert!(matches!(
+            NgramModel::load_from_reader(GzDecoder::new(&b"not gzip"[..])),
+            Err(NgramError::IoError(_))
+        ));
+
+        // 정상 gzip이지만 내용이 잘못된 JSON → ParseError
+        let bad_json = gzip(b"{ invalid json }");
+        assert!(matches!(
+            NgramModel::load_from_reader(GzDecoder::new(bad_json.as_slice())),
+            Err(NgramError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_has_extension_case_insensitive() {
+        assert!(has_extension("model.json.gz", "gz"));
+        assert!(has_extension("MODEL.JSON.GZ", "gz"));
+        assert!(has_extension("/data/ngram_model.BIN", "bin"));
+        assert!(!has_extension("model.json", "gz"));
+        assert!(!has_extension("model.gzip", "gz"));
+        assert!(!has_extension("gz", "gz"));
+    }
+
+    #[test]
+    fn test_binary_format_error() {
+        assert!(matches!(
+            NgramModel::from_binary(b"nope".to_vec()),
+            Err(NgramError::FormatError(_))
+        ));
+
+        let mut truncated = NgramModel::from_json(sample_model_json())
+            .unwrap()
+            .to_binary();
+        truncated.pop();
+        assert!(matches!(
+            NgramModel::from_binary(truncated),
+            Err(NgramError::FormatError(_))
+        ));
+    }
+
+    #[test]
+    fn test_binary_roundtrip_keeps_metadata() {
+        let json = r#"{
+            "metadata": { "vocab_size": 2000, "layout": "dubeolsik", "model_version": 3 },
+            "unigrams": { "안": 100, "녕": 80 },
+            "bigrams": { "안|녕": 50 }
+        }"#;
+        let model = NgramModel::from_json(json).unwrap();
+        let path =
+            std::env::temp_dir().join(format!("koing_ngram_meta_{}.bin", std::process::id()));
+        let path_str = path.to_string_lossy().to_string();
+        model.write_binary(&path_str).unwrap();
+        let mapped = NgramModel::load_binary(&path_str).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(mapped.metadata(), model.metadata());
+        assert_eq!(mapped.metadata().vocab_size, Some(2000));
+        assert_eq!(mapped.metadata().model_version.as_deref(), Some("3"));
+        assert_eq!(mapped.bigram_count('안', '녕'), 50);
+        // 모델 vocab_size로 스무딩하는 점수도 JSON 모델과 같아야 함
+        assert_eq!(mapped.score("안녕"), model.score("안녕"));
+    }
+
+    #[test]
+    fn test_binary_v1_without_metadata_still_loads() {
+        let model = NgramModel::from_json(sample_model_json()).unwrap();
+        let v2 = model.to_binary();
+        let metadata_len = read_u32(&v2, 24) as usize;
+        // v1: 메타데이터 길이/블록 없이 바로 엔트리
+        let mut v1 = v2[..BINARY_V1_HEADER_LEN].to_vec();
+        v1[4..8].copy_from_slice(&1u32.to_le_bytes());
+        v1.extend_from_slice(&v2[BINARY_HEADER_LEN + metadata_len..]);
+
+        let loaded = NgramModel::from_binary(v1).unwrap();
+        assert_eq!(loaded.metadata(), &ModelMetadata::default());
+        assert_eq!(loaded.unigram_count('안'), 100);
+        assert_eq!(loaded.bigram_count('안', '녕'), 50);
+    }
+
+    #[test]
+    fn test_empty_model_roundtrip_binary() {
+        let model = NgramModel::empty().to_sorted();
+        assert!(model.is_sorted());
+        assert!(model.is_empty());
+        assert_eq!(model.unigram_count('가'), 0);
+    }
 }
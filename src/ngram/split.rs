@@ -0,0 +1,59 @@
+//! 공백 없이 이어 친 긴 한글의 단어 분할
+//!
+//! "안녕하세요반갑습니다"처럼 여러 단어가 붙어 있으면 단어 경계의 바이그램 점수가
+//! 단어 안쪽보다 크게 낮습니다. 점수가 가장 낮은 경계를 분할 지점으로 골라
+//! 앞부분만 먼저 변환할 수 있게 합니다.
+
+/// 이보다 짧은 텍스트(음절)는 나누지 않음
+pub const SPLIT_MIN_CHARS: usize = 6;
+/// 나눈 양쪽의 최소 음절 수
+pub const SPLIT_MIN_PART_CHARS: usize = 2;
+/// 분할 경계 점수가 나머지 경계 평균보다 이만큼(로그 확률) 낮아야 분할
+pub const SPLIT_MIN_DROP: f64 = 1.0;
+
+/// 경계 점수에서 분할 지점 찾기 (반환: 앞부분 음절 수, 나눌 곳이 없으면 None)
+///
+/// boundary_scores[i]는 i번째와 i+1번째 음절 사이 점수 (높을수록 자연스럽게 이어짐)
+///
+/// # Examples
+/// ```
+/// use koing::ngram::find_split_point;
+///
+/// // 다섯 번째 음절 뒤 경계만 점수가 급락
+/// let scores = [-2.0, -2.5, -2.0, -1.5, -9.0, -2.0, -2.5, -2.0, -1.5];
+/// assert_eq!(find_split_point(&scores), Some(5));
+/// // 고르게 이어지면 나누지 않음
+/// assert_eq!(find_split_point(&[-2.0; 9]), None);
+/// ```
+pub fn find_split_point(boundary_scores: &[f64]) -> Option<usize> {
+    let chars = boundary_scores.len() + 1;
+    if chars < SPLIT_MIN_CHARS || boundary_scores.len() < 2 {
+        return None;
+    }
+    // 경계 i에서 나누면 앞부분은 i + 1음절
+    let candidates = SPLIT_MIN_PART_CHARS - 1..=chars - SPLIT_MIN_PART_CHARS - 1;
+    let (index, lowest) = candidates
+        .map(|i| (i, boundary_scores[i]))
+        .min_by(|a, b| a.1.total_cmp(&b.1))?;
+    let others =
+        (boundary_scores.iter().sum::<f64>() - lowest) / (boundary_scores.len() - 1) as f64;
+    (others - lowest >= SPLIT_MIN_DROP).then_some(index + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_keeps_minimum_parts() {
+        // 가장 낮은 경계가 끝에 붙어 있으면 한 음절짜리가 남으므로 그 다음으로 낮은 경계
+        let scores = [-9.0, -2.0, -6.0, -2.0, -2.0, -9.0];
+        assert_eq!(find_split_point(&scores), Some(3));
+        // 짧은 텍스트는 나누지 않음
+        assert_eq!(find_split_point(&[-2.0, -9.0, -2.0, -2.0]), None);
+        assert_eq!(find_split_point(&[]), None);
+        // 동점이면 앞쪽 경계 (먼저 변환할 부분을 짧게)
+        let scores = [-2.0, -8.0, -2.0, -8.0, -2.0, -2.0];
+        assert_eq!(find_split_point(&scores), Some(2));
+    }
+}
@@ -17,18 +17,20 @@ fn is_rare_onset(cho: u32, jung: u32) -> bool {
         return true;
     }
 
-    // Rule 2: ㅙ(10) — 왜(11) 제외, 대부분 희귀
-    if jung == 10 && cho != 11 {
+    // Rule 2: ㅙ(10) — 괘(0), 꽤(1), 돼(3), 쇄(9), 왜(11), 쾌(15), 홰(18) 제외
+    if jung == 10 && !matches!(cho, 0 | 1 | 3 | 9 | 11 | 15 | 18) {
         return true;
     }
 
-    // Rule 3: ㅞ(15) — 웨(11) 제외, 대부분 희귀
-    if jung == 15 && cho != 11 {
+    // Rule 3: ㅞ(15) — 궤(0), 꿰(1), 웨(11), 췌(14), 퀘(15), 훼(18) 제외
+    if jung == 15 && !matches!(cho, 0 | 1 | 11 | 14 | 15 | 18) {
         return true;
     }
 
-    // Rule 4: 쌍자음(ㄲ1,ㄸ4,ㅃ8,ㅉ13) + y계열(ㅑ2,ㅖ7,ㅛ12,ㅠ17) 및 ㅢ(19)
-    if matches!(cho, 1 | 4 | 8 | 13) && matches!(jung, 2 | 7 | 12 | 17 | 19) {
+    // Rule 4: 쌍자음(ㄲ1,ㄸ4,ㅃ8,ㅉ13) + y계열(ㅑ2,ㅖ7,ㅛ12,ㅠ17) 및 ㅢ(19), 단 띄(4,19) 제외
+    if matches!(cho, 1 | 4 | 8 | 13)
+        && (matches!(jung, 2 | 7 | 12 | 17) || (jung == 19 && cho != 4))
+    {
         return true;
     }
 
@@ -41,6 +43,16 @@ fn is_rare_onset(cho: u32, jung: u32) -> bool {
     false
 }
 
+/// 받침까지 보고 음절이 희귀한지 판별
+///
+/// ㄹ/ㅎ+ㅑ는 받침 없이는 드물지만 ㄱ/ㅇ 받침과는 흔함 (전략, 용량, 방향)
+fn is_rare_syllable(cho: u32, jung: u32, jong: u32) -> bool {
+    if jung == 2 && matches!(cho, 5 | 18) && matches!(jong, 1 | 21) {
+        return false;
+    }
+    is_rare_onset(cho, jung)
+}
+
 /// 종성→초성 전이가 한국어에서 극히 드문지 판별
 ///
 /// 종성 idx: 0=없음 1=ㄱ 2=ㄲ 3=ㄳ 4=ㄴ 5=ㄵ 6=ㄶ 7=ㄷ 8=ㄹ 9=ㄺ 10=ㄻ
@@ -55,9 +67,8 @@ fn is_rare_transition(prev_jong: u32, next_cho: u32) -> bool {
     }
 
     // 한국어에서 극히 드문 종성→초성 전이 패턴
+    // ㅂ→ㅍ(집필, 갑판), ㅊ→ㅊ(몇천, 빛처럼)은 실제 단어에 나타나므로 제외
     match (prev_jong, next_cho) {
-        // ㅂ종성(17) → ㅍ초성(17): 극히 드묾
-        (17, 17) => true,
         // ㄷ종성(7) → ㅌ초성(16): 극히 드묾
         (7, 16) => true,
         // ㄱ종성(1) → ㅋ초성(15): 극히 드묾
@@ -68,8 +79,6 @@ fn is_rare_transition(prev_jong: u32, next_cho: u32) -> bool {
         (7, 4) => true,
         // ㅈ종성(22) → ㅉ초성(13): 극히 드묾
         (22, 13) => true,
-        // ㅊ종성(23) → ㅊ초성(14): 극히 드묾
-        (23, 14) => true,
         // ㅋ종성(24) → ㅋ초성(15): 극히 드묾
         (24, 15) => true,
         // ㅌ종성(25) → ㅌ초성(16): 극히 드묾
@@ -94,7 +103,7 @@ pub fn check_syllable_structure(text: &str) -> bool {
     for ch in text.chars() {
         if let Some((cho, jung, jong)) = decompose_syllable(ch) {
             total_syllables += 1;
-            if is_rare_onset(cho, jung) {
+            if is_rare_syllable(cho, jung, jong) {
                 rare_count += 1;
                 consecutive_rare += 1;
                 if consecutive_rare >= 2 {
@@ -177,15 +186,19 @@ mod tests {
 
         // Rule 2: ㅙ — 왜(cho=11) should NOT be rare
         assert!(!is_rare_onset(11, 10)); // 왜
-        assert!(is_rare_onset(0, 10)); // 괘 — rare
+        assert!(!is_rare_onset(3, 10)); // 돼
+        assert!(is_rare_onset(2, 10)); // 놰 — rare
 
         // Rule 3: ㅞ — 웨(cho=11) should NOT be rare
         assert!(!is_rare_onset(11, 15)); // 웨
-        assert!(is_rare_onset(0, 15)); // 궤... actually this is 괞? No — 궤 — rare by rule
+        assert!(!is_rare_onset(0, 15)); // 궤
+        assert!(is_rare_onset(2, 15)); // 눼 — rare
 
         // Rule 4: 쌍자음 + y계열
         assert!(is_rare_onset(1, 2)); // ㄲ+ㅑ — rare
         assert!(is_rare_onset(4, 7)); // ㄸ+ㅖ — rare
+        assert!(is_rare_onset(1, 19)); // ㄲ+ㅢ — rare
+        assert!(!is_rare_onset(4, 19)); // 띄
 
         // Rule 5: ㅑ — 화이트리스트 (갸, 냐, 샤, 야만 허용)
         assert!(is_rare_onset(6, 2)); // 먀 — rare
@@ -206,8 +219,8 @@ mod tests {
 
     #[test]
     fn test_rare_transition() {
-        // ㅂ종성→ㅍ초성: 극히 드묾
-        assert!(is_rare_transition(17, 17));
+        // ㅂ종성→ㅃ초성: 극히 드묾
+        assert!(is_rare_transition(17, 8));
         // ㄷ종성→ㅌ초성: 극히 드묾
         assert!(is_rare_transition(7, 16));
         // ㄱ종성→ㅋ초성: 극히 드묾
@@ -218,6 +231,9 @@ mod tests {
         assert!(!is_rare_transition(4, 11));
         // ㄹ종성→ㅇ초성: 자연스러운 전이
         assert!(!is_rare_transition(8, 11));
+        // 집필(ㅂ→ㅍ), 몇천(ㅊ→ㅊ): 실제 단어에 나타나는 전이
+        assert!(!is_rare_transition(17, 17));
+        assert!(!is_rare_transition(23, 14));
     }
 
     #[test]
@@ -229,4 +245,102 @@ mod tests {
         // "가나다라" — 종성 없음, 전이 검사 스킵
         assert!(check_syllable_structure("가나다라"));
     }
+
+    #[test]
+    fn test_rare_syllable_allows_common_finals() {
+        assert!(!is_rare_syllable(5, 2, 1)); // 략
+        assert!(!is_rare_syllable(5, 2, 21)); // 량
+        assert!(!is_rare_syllable(18, 2, 21)); // 향
+        assert!(is_rare_syllable(5, 2, 0)); // 랴
+        assert!(is_rare_syllable(18, 2, 0)); // 햐
+        assert!(is_rare_syllable(12, 2, 21)); // 쟝
+    }
+
+    /// 회귀 세트: 희귀 규칙에 걸리기 쉬운 실제 한국어 단어는 통과
+    #[test]
+    fn test_regression_common_words_pass() {
+        let words = [
+            // ㅙ/ㅞ
+            "괜히",
+            "꽤",
+            "안돼",
+            "돼지",
+            "왜요",
+            "쇄도",
+            "횃불",
+            "괘씸",
+            "쾌적",
+            "궤도",
+            "꿰매",
+            "췌장",
+            "퀘스트",
+            "훼손",
+            "웬일",
+            // ㄹ/ㅎ+ㅑ + 받침
+            "전략",
+            "생략",
+            "용량",
+            "수량",
+            "방향",
+            "영향",
+            "향상",
+            // 쌍자음 + ㅢ
+            "띄워",
+            "띄어쓰기",
+            // 종성→초성 전이
+            "빛처럼",
+            "몇천",
+            "꽃차",
+            "집필",
+            "갑판",
+            "합평",
+            "부엌칼",
+            // ㅒ/ㅑ 허용 음절
+            "걔",
+            "얘기",
+            "쟤",
+            "갸웃",
+            "냐옹",
+            "샤워",
+            // 겹받침/쌍자음
+            "닭고기",
+            "없어",
+            "밟다",
+            "읽고",
+            "앉아",
+            "떡볶이",
+            "낚시",
+            "있다",
+            "쫄깃",
+        ];
+        for word in words {
+            assert!(check_syllable_structure(word), "{} 거부됨", word);
+        }
+    }
+
+    /// 회귀 세트: 영문을 한글 자판으로 입력한 오변환 결과는 계속 거부
+    #[test]
+    fn test_regression_misconversions_rejected() {
+        let cases = [
+            ("wifi", "쟈랴"),
+            ("with", "쟈소"),
+            ("video", "퍙대"),
+            ("their", "소댝"),
+            ("figma", "랴흠"),
+        ];
+        for (english, converted) in cases {
+            assert_eq!(crate::core::converter::convert(english), converted);
+            assert!(!check_syllable_structure(converted), "{} 통과됨", english);
+        }
+    }
+
+    #[test]
+    fn test_short_text_with_rare_transition_and_onset_rejected() {
+        // 3음절: 희귀 onset 1/3 (비율 미달) + 희귀 전이(ㄱ→ㅋ) 1개 → 거부
+        assert!(!check_syllable_structure("먁코다"));
+        // 같은 조합이라도 4음절 이상이면 허용
+        assert!(check_syllable_structure("먁코다라"));
+        // 희귀 전이만 있으면 허용
+        assert!(check_syllable_structure("박코다"));
+    }
 }
@@ -6,11 +6,17 @@
 //! 3. 최종 판정
 
 use crate::core::converter::convert;
+use crate::core::pipeline::ConversionValidator;
+use crate::core::unicode::is_compat_jamo;
 use crate::detection::validator::has_incomplete_jamo;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use super::config::NgramConfig;
-use super::model::{NgramAnalysis, NgramModel};
+use super::heuristic::{heuristic_score, HEURISTIC_MIN_SCORE};
+use super::keymap::adjacent_keys;
+use super::model::{has_extension, NgramAnalysis, NgramModel};
+use super::split::find_split_point;
 use super::syllable_validator::check_syllable_structure;
 
 /// N-gram 기반 한글 검증기
@@ -18,8 +24,8 @@ use super::syllable_validator::check_syllable_structure;
 /// 영문 입력이 한글로 변환되어야 하는지 종합적으로 판정합니다.
 #[derive(Debug)]
 pub struct KoreanValidator {
-    /// N-gram 모델 (없으면 스코어 검사 생략)
-    model: Option<NgramModel>,
+    /// N-gram 모델 (없으면 스코어 검사 생략, 여러 검증기가 공유 가능)
+    model: Option<Arc<NgramModel>>,
     /// 설정
     config: NgramConfig,
 }
@@ -30,6 +36,38 @@ impl Default for KoreanValidator {
     }
 }
 
+impl ConversionValidator for KoreanValidator {
+    /// 자동 변환 2차 판정: 음절구조/1글자/n-gram, 영어 입력 + 미등록 bigram
+    fn validate(&self, judge: &str, english_like: bool) -> Result<(), String> {
+        let result = self.analyze(judge);
+        if let Err(reason) = result.classify() {
+            return Err(format!("{:?}", reason));
+        }
+        if english_like
+            && result.seen_bigram_count == Some(0)
+            && result.unknown_bigram_ratio.unwrap_or_default() >= 1.0
+        {
+            return Err(format!(
+                "영어 입력 + 미등록 bigram {:?}",
+                result.unknown_bigram_ratio
+            ));
+        }
+        Ok(())
+    }
+
+    /// 낱자모/음절 구조/1글자만 거부 (점수 미달은 2단계가 구제하려는 입력)
+    fn check_structure(&self, judge: &str) -> Result<(), String> {
+        match self.classify(judge) {
+            Ok(_) | Err(RejectReason::LowScore) => Ok(()),
+            Err(reason) => Err(format!("{:?}", reason)),
+        }
+    }
+
+    fn split_point(&self, hangul: &str) -> Option<usize> {
+        KoreanValidator::split_point(self, hangul)
+    }
+}
+
 impl KoreanValidator {
     /// 기본 설정으로 검증기 생성 (모델 없음)
     pub fn new() -> Self {
@@ -41,12 +79,35 @@ impl KoreanValidator {
 
     /// 모델과 설정을 지정하여 검증기 생성
     pub fn with_model(model: NgramModel, config: NgramConfig) -> Self {
+        Self::with_shared_model(Arc::new(model), config)
+    }
+
+    /// 다른 검증기와 공유하는 모델로 검증기 생성 (모델을 복제하지 않음)
+    ///
+    /// # Examples
+    /// ```
+    /// use koing::ngram::{KoreanValidator, NgramConfig, NgramModel};
+    /// use std::sync::Arc;
+    ///
+    /// let json = r#"{"metadata": {}, "unigrams": {"안": 10}, "bigrams": {}}"#;
+    /// let model = Arc::new(NgramModel::from_json(json).unwrap());
+    /// let worker = KoreanValidator::with_shared_model(Arc::clone(&model), NgramConfig::new());
+    /// let preview = KoreanValidator::with_shared_model(Arc::clone(&model), NgramConfig::new());
+    /// assert_eq!(Arc::strong_count(&model), 3);
+    /// assert_eq!(worker.score("안"), preview.score("안"));
+    /// ```
+    pub fn with_shared_model(model: Arc<NgramModel>, config: NgramConfig) -> Self {
         Self {
             model: Some(model),
             config,
         }
     }
 
+    /// 다른 검증기와 공유할 모델 핸들 (모델이 없으면 None)
+    pub fn shared_model(&self) -> Option<Arc<NgramModel>> {
+        self.model.clone()
+    }
+
     /// 설정만 지정하여 검증기 생성
     pub fn with_config(config: NgramConfig) -> Self {
         Self {
@@ -56,14 +117,53 @@ impl KoreanValidator {
     }
 
     /// 모델 파일에서 로드하여 검증기 생성
+    ///
+    /// `.bin` 확장자는 바이너리 포맷(mmap), `.gz`는 gzip JSON, 그 외는 JSON으로 로드합니다.
     pub fn load(path: &str) -> Result<Self, super::model::NgramError> {
-        let model = NgramModel::load(path)?;
+        let model = if has_extension(path, "bin") {
+            NgramModel::load_binary(path)?
+        } else {
+            NgramModel::load(path)?
+        };
         Ok(Self {
-            model: Some(model),
+            model: Some(Arc::new(model)),
             config: NgramConfig::new().with_model_path(path),
         })
     }
 
+    /// 설정으로 검증기 생성
+    ///
+    /// `model_path`가 있으면 해당 모델, 없거나 로드에 실패하면 기본 모델 경로,
+    /// 그마저 없으면 모델 없이(구조 검사만) 동작합니다.
+    pub fn load_with_config(config: NgramConfig) -> Self {
+        let loaded = if config.model_path.is_empty() {
+            Self::load_default()
+        } else {
+            Self::load(&config.model_path).or_else(|e| {
+                log::warn!(
+                    "N-gram 모델 로드 실패 ({}), 기본 모델로 폴백: {}",
+                    config.model_path,
+                    e
+                );
+                Self::load_default()
+            })
+        };
+
+        match loaded {
+            Ok(validator) => Self {
+                config: NgramConfig {
+                    model_path: validator.config.model_path,
+                    ..config
+                },
+                model: validator.model,
+            },
+            Err(e) => {
+                log::warn!("N-gram 모델 없음, 휴리스틱 모드로 계속 진행: {}", e);
+                Self::with_config(config)
+            }
+        }
+    }
+
     /// 일반 실행/앱 번들 환경에서 기본 모델 경로를 찾아 로드
     pub fn load_default() -> Result<Self, super::model::NgramError> {
         for candidate in default_model_candidates() {
@@ -94,13 +194,29 @@ impl KoreanValidator {
     /// // 낱자모가 있는 경우 → false
     /// assert!(!validator.should_convert_to_korean("name")); // "ㅜ믇"
     ///
-    /// // 정상 한글 → true (모델 없으면 낱자모 검사만)
+    /// // 정상 한글 → true (모델 없으면 낱자모 검사 + 빈도 휴리스틱)
     /// assert!(validator.should_convert_to_korean("dkssud")); // "안녕"
     /// ```
     pub fn should_convert_to_korean(&self, english_input: &str) -> bool {
         self.analyze(english_input).should_convert
     }
 
+    /// 자동 변환 기준으로 판정하고 거부 사유를 반환
+    ///
+    /// `should_convert_to_korean`의 판정에 더해 1글자 변환도 거부합니다.
+    ///
+    /// # Examples
+    /// ```
+    /// use koing::ngram::{KoreanValidator, RejectReason};
+    ///
+    /// let validator = KoreanValidator::new();
+    /// assert_eq!(validator.classify("dkssud"), Ok("안녕".to_string()));
+    /// assert_eq!(validator.classify("name"), Err(RejectReason::IncompleteJamo));
+    /// ```
+    pub fn classify(&self, english_input: &str) -> Result<String, RejectReason> {
+        self.analyze(english_input).classify()
+    }
+
     /// 변환된 한글의 N-gram 스코어 반환
     ///
     /// 모델이 없으면 None
@@ -110,6 +226,14 @@ impl KoreanValidator {
             .map(|m| m.score_with_config(korean_text, &self.config))
     }
 
+    /// 한글 텍스트의 자연스러움 점수 (0~100, UI/외부 도구용)
+    ///
+    /// N-gram 스코어를 설정의 시그모이드 파라미터로 정규화합니다. 모델이 없으면 None
+    pub fn naturalness(&self, korean_text: &str) -> Option<u8> {
+        self.score(korean_text)
+            .map(|score| self.config.naturalness(score))
+    }
+
     /// 영문 입력을 한글로 변환하고 스코어 반환
     ///
     /// # Returns
@@ -119,7 +243,7 @@ impl KoreanValidator {
             return ValidationResult::rejected(
                 english_input,
                 String::new(),
-                RejectReason::EmptyInput,
+                RejectReason::Empty,
                 None,
             );
         }
@@ -129,7 +253,7 @@ impl KoreanValidator {
             return ValidationResult::rejected(
                 english_input,
                 converted,
-                RejectReason::Unchanged,
+                RejectReason::NoChange,
                 None,
             );
         }
@@ -149,7 +273,7 @@ impl KoreanValidator {
             return ValidationResult::rejected(
                 english_input,
                 converted,
-                RejectReason::UnnaturalSyllables,
+                RejectReason::UnnaturalSyllable,
                 None,
             );
         }
@@ -159,7 +283,11 @@ impl KoreanValidator {
             .as_ref()
             .map(|model| model.analyze_with_config(&converted, &self.config));
         let score = analysis.as_ref().map(|result| result.score);
-        let should_convert = score.map(|s| s >= self.config.threshold).unwrap_or(true);
+        // 모델이 없으면 내장 빈도 휴리스틱을 보조 게이트로 사용
+        let should_convert = match score {
+            Some(score) => score >= self.config.threshold,
+            None => heuristic_score(&converted) >= HEURISTIC_MIN_SCORE,
+        };
         let reject_reason = if should_convert {
             None
         } else {
@@ -180,6 +308,75 @@ impl KoreanValidator {
         }
     }
 
+    /// 낱자모 때문에 변환이 거부된 입력의 대체 한글 후보 제안
+    ///
+    /// 끝의 미완성 자모 제거, 인접 키 1개 오타 보정으로 후보를 만들고 음절 구조 검사를
+    /// 통과한 것 중 N-gram 스코어가 가장 높은 후보를 반환합니다.
+    /// 모델이 없으면 보정이 적은 후보(동률이면 자모 제거 우선)를 고릅니다.
+    ///
+    /// # Examples
+    /// ```
+    /// use koing::ngram::KoreanValidator;
+    ///
+    /// let validator = KoreanValidator::new();
+    /// assert_eq!(validator.suggest_korean("dkssudg"), Some("안녕".to_string())); // 안녕ㅎ
+    /// assert_eq!(validator.suggest_korean("name"), None);
+    /// ```
+    pub fn suggest_korean(&self, english_input: &str) -> Option<String> {
+        let converted = convert(english_input);
+        if converted == english_input {
+            return None;
+        }
+        if !has_incomplete_jamo(&converted) {
+            return is_complete_candidate(&converted).then_some(converted);
+        }
+
+        // (보정 횟수, 후보)
+        let mut candidates: Vec<(usize, String)> = Vec::new();
+
+        let trimmed = converted.trim_end_matches(is_compat_jamo);
+        if trimmed.len() < converted.len() {
+            let removed = converted.chars().count() - trimmed.chars().count();
+            candidates.push((removed, trimmed.to_string()));
+        }
+
+        for (i, key) in english_input.char_indices() {
+            for neighbor in adjacent_keys(key).chars() {
+                let mut fixed = String::with_capacity(english_input.len());
+                fixed.push_str(&english_input[..i]);
+                fixed.push(neighbor);
+                fixed.push_str(&english_input[i + key.len_utf8()..]);
+                candidates.push((1, convert(&fixed)));
+            }
+        }
+
+        candidates.retain(|(_, candidate)| is_complete_candidate(candidate));
+
+        match &self.model {
+            Some(model) => candidates
+                .into_iter()
+                .map(|(_, candidate)| {
+                    let score = model.score_with_config(&candidate, &self.config);
+                    (score, candidate)
+                })
+                // min_by는 동률 시 앞선 후보 유지
+                .min_by(|a, b| b.0.total_cmp(&a.0))
+                .map(|(_, candidate)| candidate),
+            None => candidates
+                .into_iter()
+                .min_by_key(|(cost, _)| *cost)
+                .map(|(_, candidate)| candidate),
+        }
+    }
+
+    /// 공백 없이 이어 친 한글의 단어 분할 지점 (앞부분 음절 수)
+    ///
+    /// 바이그램 점수가 급락하는 경계를 찾으며, 모델이 없거나 나눌 곳이 없으면 None
+    pub fn split_point(&self, korean_text: &str) -> Option<usize> {
+        let model = self.model.as_ref()?;
+        find_split_point(&model.boundary_scores(korean_text, &self.config))
+    }
+
     /// 현재 설정의 임계값 반환
     pub fn threshold(&self) -> f64 {
         self.config.threshold
@@ -217,6 +414,18 @@ pub struct ValidationResult {
 }
 
 impl ValidationResult {
+    /// 자동 변환 기준 판정: 통과 시 변환 결과, 거부 시 사유
+    ///
+    /// 구조적 거부 사유가 1글자 검사보다, 1글자 검사가 N-gram 스코어보다 우선합니다.
+    pub fn classify(&self) -> Result<String, RejectReason> {
+        match self.reject_reason {
+            Some(reason) if reason != RejectReason::LowScore => Err(reason),
+            _ if self.converted.chars().count() <= 1 => Err(RejectReason::SingleChar),
+            Some(reason) => Err(reason),
+            None => Ok(self.converted.clone()),
+        }
+    }
+
     fn rejected(
         english_input: &str,
         converted: String,
@@ -227,7 +436,7 @@ impl ValidationResult {
             original: english_input.to_string(),
             converted,
             has_incomplete_jamo: matches!(reject_reason, RejectReason::IncompleteJamo),
-            has_unnatural_syllables: matches!(reject_reason, RejectReason::UnnaturalSyllables),
+            has_unnatural_syllables: matches!(reject_reason, RejectReason::UnnaturalSyllable),
             ngram_score: analysis.map(|result| result.score),
             should_convert: false,
             unknown_unigram_ratio: analysis.map(|result| result.unknown_unigram_ratio),
@@ -241,28 +450,48 @@ impl ValidationResult {
 /// 자동 변환 거부 이유
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RejectReason {
-    EmptyInput,
-    Unchanged,
+    /// 빈 입력
+    Empty,
+    /// 변환 결과가 원본과 동일 (한글 키 없음)
+    NoChange,
+    /// 낱자모(미완성 자모) 포함
     IncompleteJamo,
-    UnnaturalSyllables,
+    /// 비자연스러운 음절 구조
+    UnnaturalSyllable,
+    /// N-gram 스코어가 임계값 미만
     LowScore,
+    /// 1글자 변환 (오탐 방지)
+    SingleChar,
 }
 
+/// 제안 후보 조건: 비어 있지 않고 낱자모가 없으며 음절 구조 검사 통과
+fn is_complete_candidate(text: &str) -> bool {
+    !text.is_empty() && !has_incomplete_jamo(text) && check_syllable_structure(text)
+}
+
+/// 기본 모델 파일명 (바이너리 포맷 우선)
+const DEFAULT_MODEL_FILES: [&str; 3] =
+    ["ngram_model.bin", "ngram_model.json.gz", "ngram_model.json"];
+
 fn default_model_candidates() -> Vec<PathBuf> {
-    let mut candidates = Vec::new();
+    let mut data_dirs = Vec::new();
 
     if let Ok(current_dir) = std::env::current_dir() {
-        candidates.push(current_dir.join("data").join("ngram_model.json"));
+        data_dirs.push(current_dir.join("data"));
     }
 
     if let Ok(exe_path) = std::env::current_exe() {
         if let Some(exe_dir) = exe_path.parent() {
-            candidates.push(exe_dir.join("../Resources/data/ngram_model.json"));
-            candidates.push(exe_dir.join("../../data/ngram_model.json"));
-            candidates.push(exe_dir.join("../data/ngram_model.json"));
+            data_dirs.push(exe_dir.join("../Resources/data"));
+            data_dirs.push(exe_dir.join("../../data"));
+            data_dirs.push(exe_dir.join("../data"));
         }
     }
 
+    let candidates = data_dirs
+        .iter()
+        .flat_map(|dir| DEFAULT_MODEL_FILES.iter().map(move |file| dir.join(file)));
+
     let mut unique = Vec::new();
     for candidate in candidates {
         if !unique
@@ -293,6 +522,9 @@ mod tests {
         assert!(!validator.should_convert_to_korean("hello")); // ㅗ디ㅣㅐ
         assert!(!validator.should_convert_to_korean("code")); // ㅊㅐㅇㄷ
 
+        // 드문 음절만 이어지는 완성형 -> 빈도 휴리스틱으로 거부
+        assert_eq!(validator.classify("right"), Err(RejectReason::LowScore)); // 갸홋
+
         // 빈 입력 -> false
         assert!(!validator.should_convert_to_korean(""));
 
@@ -320,6 +552,77 @@ mod tests {
         assert!(!validator.should_convert_to_korean("name"));
     }
 
+    #[test]
+    fn test_suggest_korean_without_model() {
+        let validator = KoreanValidator::new();
+
+        // 끝의 미완성 자모 제거
+        assert_eq!(
+            validator.suggest_korean("dkssudg"),
+            Some("안녕".to_string())
+        ); // 안녕ㅎ
+        assert_eq!(
+            validator.suggest_korean("gksrmfz"),
+            Some("한글".to_string())
+        ); // 한글ㅋ
+
+        // 인접 키 보정 (y → t)
+        assert_eq!(validator.suggest_korean("yksrmf"), Some("산글".to_string())); // ㅛㅏㄴ글
+
+        // 이미 완성된 변환은 그대로
+        assert_eq!(validator.suggest_korean("dkssud"), Some("안녕".to_string()));
+
+        // 보정 후보가 모두 낱자모를 포함하거나 변환 불가
+        assert_eq!(validator.suggest_korean("name"), None);
+        assert_eq!(validator.suggest_korean("12345"), None);
+        assert_eq!(validator.suggest_korean(""), None);
+    }
+
+    #[test]
+    fn test_suggest_korean_prefers_model_score() {
+        let json = r#"{
+            "metadata": { "corpus_size": 1000 },
+            "unigrams": { "안": 100, "녕": 80, "한": 90, "글": 70 },
+            "bigrams": { "안|녕": 50, "한|글": 40 }
+        }"#;
+        let model = NgramModel::from_json(json).unwrap();
+        let validator = KoreanValidator::with_model(model, NgramConfig::new());
+
+        // 인접 키 후보 중 학습된 "한글"(y → g)이 "산글"(y → t)보다 우선
+        assert_eq!(validator.suggest_korean("yksrmf"), Some("한글".to_string()));
+
+        assert_eq!(
+            validator.suggest_korean("dkssudg"),
+            Some("안녕".to_string())
+        );
+    }
+
+    #[test]
+    fn test_naturalness() {
+        assert_eq!(KoreanValidator::new().naturalness("안녕"), None);
+
+        let json = r#"{
+            "metadata": { "corpus_size": 1000 },
+            "unigrams": { "안": 100, "녕": 80, "한": 90, "글": 70, "가": 100, "나": 80, "다": 70 },
+            "bigrams": { "안|녕": 50, "한|글": 40, "가|나": 30, "나|다": 25 }
+        }"#;
+        let model = NgramModel::from_json(json).unwrap();
+        let validator = KoreanValidator::with_model(model, NgramConfig::new());
+
+        let texts = ["안녕", "가나다", "뷁뛟", "hello", ""];
+        let scores: Vec<u8> = texts
+            .iter()
+            .map(|text| validator.naturalness(text).unwrap())
+            .collect();
+        assert!(scores.iter().all(|&s| s <= 100), "{:?}", scores);
+
+        // 학습된 한글 > 미등록 음절/비한글 > 빈 텍스트
+        assert!(scores[0] >= scores[1], "{:?}", scores);
+        assert!(scores[1] > scores[2], "{:?}", scores);
+        assert!(scores[1] > scores[3], "{:?}", scores);
+        assert_eq!(scores[4], 0);
+    }
+
     #[test]
     fn test_analyze() {
         let validator = KoreanValidator::new();
@@ -354,6 +657,40 @@ mod tests {
         assert!(no_model.score("안녕").is_none());
     }
 
+    #[test]
+    fn test_shared_model_is_not_cloned() {
+        let json = r#"{
+            "metadata": {},
+            "unigrams": { "안": 100, "녕": 80, "한": 90, "글": 70 },
+            "bigrams": { "안|녕": 50, "한|글": 40 }
+        }"#;
+        let owned = KoreanValidator::with_model(
+            NgramModel::from_json(json).unwrap(),
+            NgramConfig::default(),
+        );
+
+        let model = Arc::new(NgramModel::from_json(json).unwrap());
+        let worker = KoreanValidator::with_shared_model(Arc::clone(&model), NgramConfig::default());
+        assert_eq!(Arc::strong_count(&model), 2);
+        let preview = KoreanValidator::with_shared_model(
+            worker.shared_model().unwrap(),
+            NgramConfig::default(),
+        );
+        assert_eq!(Arc::strong_count(&model), 3);
+        assert!(Arc::ptr_eq(&worker.shared_model().unwrap(), &model));
+
+        // 공유 모델과 소유 모델의 점수가 같음
+        for text in ["안녕", "한글", "가나"] {
+            assert_eq!(worker.score(text), owned.score(text), "{}", text);
+            assert_eq!(preview.score(text), owned.score(text), "{}", text);
+        }
+
+        drop(preview);
+        drop(worker);
+        assert_eq!(Arc::strong_count(&model), 1);
+        assert!(KoreanValidator::new().shared_model().is_none());
+    }
+
     #[test]
     fn test_english_preservation() {
         let validator = KoreanValidator::new();
@@ -454,6 +791,40 @@ mod tests {
         assert!(validator.has_model());
     }
 
+    #[test]
+    fn test_load_with_config_keeps_thresholds() {
+        let config = NgramConfig::new()
+            .with_threshold(-12.0)
+            .with_smoothing(0.01);
+        let validator = KoreanValidator::load_with_config(config);
+        assert!(validator.has_model());
+        assert_eq!(validator.threshold(), -12.0);
+    }
+
+    #[test]
+    fn test_load_with_config_falls_back_when_model_missing() {
+        let config = NgramConfig::new()
+            .with_model_path("/nonexistent/koing_model.bin")
+            .with_threshold(-9.0);
+        let validator = KoreanValidator::load_with_config(config);
+        // 지정 경로 실패 → 기본 모델로 폴백, 설정값은 유지
+        assert!(validator.has_model());
+        assert_ne!(validator.config.model_path, "/nonexistent/koing_model.bin");
+        assert_eq!(validator.threshold(), -9.0);
+    }
+
+    #[test]
+    fn test_split_point_at_word_boundary() {
+        let validator = KoreanValidator::load_default().unwrap();
+        let text = "안녕하세요반갑습니다";
+        let at = validator.split_point(text).unwrap();
+        let front: String = text.chars().take(at).collect();
+        assert_eq!(front, "안녕하세요");
+        // 짧은 텍스트는 나누지 않음, 모델이 없으면 판단하지 않음
+        assert_eq!(validator.split_point("안녕하세요"), None);
+        assert_eq!(KoreanValidator::new().split_point(text), None);
+    }
+
     #[test]
     fn test_analyze_tracks_unknown_ngram_metrics() {
         let validator = KoreanValidator::load_default().unwrap();
@@ -463,4 +834,40 @@ mod tests {
         assert!(result.unknown_bigram_ratio.unwrap() >= 1.0);
         assert_eq!(result.seen_bigram_count, Some(0));
     }
+
+    #[test]
+    fn test_classify_reject_reasons() {
+        let validator = KoreanValidator::new();
+
+        assert_eq!(validator.classify(""), Err(RejectReason::Empty));
+        assert_eq!(validator.classify("12345"), Err(RejectReason::NoChange));
+        assert_eq!(
+            validator.classify("name"),
+            Err(RejectReason::IncompleteJamo)
+        ); // ㅜ믇
+        assert_eq!(
+            validator.classify("virus"),
+            Err(RejectReason::UnnaturalSyllable)
+        ); // 퍄견
+        assert_eq!(validator.classify("rk"), Err(RejectReason::SingleChar)); // 가
+        assert_eq!(validator.classify("dkssud"), Ok("안녕".to_string()));
+
+        // 1글자는 bool API에서는 허용 (기존 동작 유지)
+        assert!(validator.should_convert_to_korean("rk"));
+    }
+
+    #[test]
+    fn test_classify_low_score() {
+        let json = r#"{
+            "metadata": {},
+            "unigrams": { "안": 10 },
+            "bigrams": {}
+        }"#;
+        let model = NgramModel::from_json(json).unwrap();
+        let validator = KoreanValidator::with_model(model, NgramConfig::new().with_threshold(0.0));
+
+        assert_eq!(validator.classify("dkssud"), Err(RejectReason::LowScore));
+        // 1글자 검사가 스코어 검사보다 우선
+        assert_eq!(validator.classify("dk"), Err(RejectReason::SingleChar));
+    }
 }
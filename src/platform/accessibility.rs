@@ -0,0 +1,87 @@
+//! VoiceOver 접근성 헬퍼 (NSAccessibility)
+#![allow(deprecated)] // cocoa 크레이트 deprecated API 사용
+
+use cocoa::appkit::NSApp;
+use cocoa::base::{id, nil};
+use cocoa::foundation::NSString;
+use objc::{class, msg_send, sel, sel_impl};
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+/// NSAccessibilityPriorityHigh — 진행 중인 음성을 끊고 즉시 읽음
+const ACCESSIBILITY_PRIORITY_HIGH: i64 = 90;
+
+#[link(name = "AppKit", kind = "framework")]
+extern "C" {
+    fn NSAccessibilityPostNotificationWithUserInfo(element: id, notification: id, user_info: id);
+    static NSAccessibilityAnnouncementRequestedNotification: id;
+    static NSAccessibilityAnnouncementKey: id;
+    static NSAccessibilityPriorityKey: id;
+}
+
+/// 스크린리더 공지 (어느 스레드에서든 호출 가능, 메인 스레드에서 게시)
+pub fn announce(message: &str) {
+    let message = message.to_string();
+    super::dispatch_to_main(move || unsafe {
+        let text = NSString::alloc(nil).init_str(&message);
+        let priority: id =
+            msg_send![class!(NSNumber), numberWithInteger: ACCESSIBILITY_PRIORITY_HIGH];
+        let keys = [NSAccessibilityAnnouncementKey, NSAccessibilityPriorityKey];
+        let values = [text, priority];
+        let user_info: id = msg_send![class!(NSDictionary),
+            dictionaryWithObjects: values.as_ptr()
+            forKeys: keys.as_ptr()
+            count: keys.len()];
+        NSAccessibilityPostNotificationWithUserInfo(
+            NSApp(),
+            NSAccessibilityAnnouncementRequestedNotification,
+            user_info,
+        );
+        let _: () = msg_send![text, release];
+    });
+}
+
+/// 뷰/컨트롤의 접근성 라벨 설정 (메인 스레드 전용)
+///
+/// # Safety
+/// `element`는 `setAccessibilityLabel:`에 응답하는 유효한 객체여야 함
+pub unsafe fn set_accessibility_label(element: id, label: &str) {
+    if element == nil {
+        return;
+    }
+    let text = NSString::alloc(nil).init_str(label);
+    let _: () = msg_send![element, setAccessibilityLabel: text];
+    let _: () = msg_send![text, release];
+}
+
+/// 뷰/컨트롤의 접근성 값 설정 (메인 스레드 전용)
+///
+/// # Safety
+/// `element`는 `setAccessibilityValue:`에 응답하는 유효한 객체여야 함
+pub unsafe fn set_accessibility_value(element: id, value: &str) {
+    if element == nil {
+        return;
+    }
+    let text = NSString::alloc(nil).init_str(value);
+    let _: () = msg_send![element, setAccessibilityValue: text];
+    let _: () = msg_send![text, release];
+}
+
+/// 사용자 선호 언어 코드 (예: "ko-KR", "en-US")
+pub fn preferred_language_code() -> Option<String> {
+    unsafe {
+        let languages: id = msg_send![class!(NSLocale), preferredLanguages];
+        if languages == nil {
+            return None;
+        }
+        let first: id = msg_send![languages, firstObject];
+        if first == nil {
+            return None;
+        }
+        let utf8: *const c_char = msg_send![first, UTF8String];
+        if utf8.is_null() {
+            return None;
+        }
+        Some(CStr::from_ptr(utf8).to_string_lossy().into_owned())
+    }
+}
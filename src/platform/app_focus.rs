@@ -0,0 +1,121 @@
+//! 포커스 앱 변경 감지 (NSWorkspace 알림)
+#![allow(deprecated)] // cocoa 크레이트 deprecated API 사용
+
+use cocoa::base::{id, nil};
+use cocoa::foundation::NSString;
+use objc::declare::ClassDecl;
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::sync::{Mutex, OnceLock};
+
+type AppFocusCallback = Box<dyn Fn(&str) + Send + Sync + 'static>;
+
+static APP_FOCUS_OBSERVER_CLASS: OnceLock<&'static Class> = OnceLock::new();
+/// 앱 전환 콜백 (bundle ID 전달)
+static APP_FOCUS_CALLBACK: OnceLock<AppFocusCallback> = OnceLock::new();
+static APP_FOCUS_OBSERVER: Mutex<Option<SendId>> = Mutex::new(None);
+
+#[allow(dead_code)] // ObjC observer 생명주기 유지를 위해 보관
+struct SendId(id);
+unsafe impl Send for SendId {}
+unsafe impl Sync for SendId {}
+
+/// NSString → String (nil이면 None)
+unsafe fn nsstring_to_string(ns: id) -> Option<String> {
+    if ns == nil {
+        return None;
+    }
+    let utf8: *const c_char = msg_send![ns, UTF8String];
+    if utf8.is_null() {
+        return None;
+    }
+    Some(CStr::from_ptr(utf8).to_string_lossy().into_owned())
+}
+
+/// NSRunningApplication의 bundle ID
+unsafe fn bundle_id_of(app: id) -> Option<String> {
+    if app == nil {
+        return None;
+    }
+    let bundle_id: id = msg_send![app, bundleIdentifier];
+    nsstring_to_string(bundle_id)
+}
+
+/// 현재 포커스(최전면) 앱의 bundle ID
+pub fn frontmost_bundle_id() -> Option<String> {
+    unsafe {
+        let workspace: id = msg_send![class!(NSWorkspace), sharedWorkspace];
+        let app: id = msg_send![workspace, frontmostApplication];
+        bundle_id_of(app)
+    }
+}
+
+extern "C" fn handle_app_activation(_: &Object, _: Sel, notification: id) {
+    let Some(callback) = APP_FOCUS_CALLBACK.get() else {
+        return;
+    };
+    let bundle_id = unsafe {
+        let user_info: id = msg_send![notification, userInfo];
+        if user_info == nil {
+            return;
+        }
+        let key = NSString::alloc(nil).init_str("NSWorkspaceApplicationKey");
+        let app: id = msg_send![user_info, objectForKey: key];
+        let _: () = msg_send![key, release];
+        bundle_id_of(app)
+    };
+    if let Some(bundle_id) = bundle_id {
+        callback(&bundle_id);
+    }
+}
+
+fn observer_class() -> &'static Class {
+    APP_FOCUS_OBSERVER_CLASS.get_or_init(|| {
+        let superclass = class!(NSObject);
+        let mut decl = ClassDecl::new("KoingAppFocusObserver", superclass).unwrap();
+        type ActionFn = extern "C" fn(&Object, Sel, id);
+        unsafe {
+            decl.add_method(
+                sel!(handleAppActivation:),
+                handle_app_activation as ActionFn,
+            );
+        }
+        decl.register()
+    })
+}
+
+/// 앱 전환 옵저버 등록 (메인 스레드에서 호출, 최초 1회만 유효)
+///
+/// 등록 시점의 최전면 앱에 대해서도 콜백을 한 번 호출
+pub fn start_app_focus_observer<F>(callback: F)
+where
+    F: Fn(&str) + Send + Sync + 'static,
+{
+    if APP_FOCUS_CALLBACK.set(Box::new(callback)).is_err() {
+        return;
+    }
+
+    unsafe {
+        let observer: id = msg_send![observer_class(), new];
+        let workspace: id = msg_send![class!(NSWorkspace), sharedWorkspace];
+        let workspace_center: id = msg_send![workspace, notificationCenter];
+        let name = NSString::alloc(nil).init_str("NSWorkspaceDidActivateApplicationNotification");
+        let _: () = msg_send![
+            workspace_center,
+            addObserver: observer
+            selector: sel!(handleAppActivation:)
+            name: name
+            object: nil
+        ];
+        let _: () = msg_send![name, release];
+
+        let mut guard = APP_FOCUS_OBSERVER.lock().unwrap_or_else(|e| e.into_inner());
+        *guard = Some(SendId(observer));
+    }
+
+    if let (Some(bundle_id), Some(callback)) = (frontmost_bundle_id(), APP_FOCUS_CALLBACK.get()) {
+        callback(&bundle_id);
+    }
+}
@@ -1,16 +1,40 @@
 //! CGEventTap을 사용한 키보드 이벤트 감지
 
-use crate::detection::AutoDetector;
+use crate::config::{
+    resolve_app_profile, AppProfile, NgramSettings, ProfileParams, DEFAULT_RESET_KEYCODES,
+};
+use crate::core::converter::is_conversion_boundary;
+use crate::core::expansion::ExpansionTable;
+use crate::core::pipeline::{ConversionGate, ConversionValidator, Converter, TwoSetConverter};
+use crate::detection::{
+    has_excessive_uppercase, has_url_pattern, is_reverse_candidate, AutoDetector,
+    ConversionFeedback, ConversionOutcome, ShadowEvaluator, ShadowVariantStats,
+};
+use crate::logging::LogEvent;
+use crate::ngram::{korean_to_eng, KoreanValidator};
+use crate::platform::app_focus::frontmost_bundle_id;
+use crate::platform::focused_element::is_text_input_focused;
 use crate::platform::input_source::{
     cached_input_source_snapshot, invalidate_input_source_cache, schedule_async_refresh,
-    switch_to_korean_on_main, InputSourceState,
+    switch_to_korean_on_main, InputSourceSnapshot, InputSourceState,
 };
-use crate::platform::text_replacer::KOING_SYNTHETIC_EVENT_MARKER;
-use core_foundation::runloop::{kCFRunLoopCommonModes, CFRunLoop};
+use crate::platform::permissions::check_accessibility_permission;
+use crate::platform::text_replacer::{simulate_return, KOING_SYNTHETIC_EVENT_MARKER};
+use crate::platform::{set_thread_qos, ThreadQos};
+use crate::snapshot::ConfigSnapshot;
+use crate::stats::{local_hour, ConversionStats, StatKind};
+use crate::supervisor::{spawn_supervised, RestartPolicy};
+use core_foundation::date::CFDate;
+use core_foundation::runloop::{
+    kCFRunLoopCommonModes, kCFRunLoopDefaultMode, CFRunLoop, CFRunLoopSource,
+};
+use core_foundation::timezone::CFTimeZone;
 use core_graphics::event::{
-    CGEvent, CGEventFlags, CGEventTap, CGEventTapLocation, CGEventTapOptions, CGEventTapPlacement,
-    CGEventType, EventField,
+    CGEvent, CGEventFlags, CGEventRef, CGEventTap, CGEventTapLocation, CGEventTapOptions,
+    CGEventTapPlacement, CGEventType, EventField,
 };
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex, MutexGuard};
 use std::thread;
@@ -28,11 +52,126 @@ extern "C" {
     fn CGEventTapIsEnabled(tap: *mut std::ffi::c_void) -> bool;
     /// macOS CoreFoundation: CFRunLoop 정지
     fn CFRunLoopStop(rl: *mut std::ffi::c_void);
+    /// macOS CoreFoundation: mach port 무효화 (폐기할 이벤트 탭 정리)
+    fn CFMachPortInvalidate(port: *mut std::ffi::c_void);
+    /// macOS CoreGraphics: 키 이벤트가 입력하는 유니코드 문자열 (IME/합성 이벤트가 넣은 문자 포함)
+    fn CGEventKeyboardGetUnicodeString(
+        event: *const std::ffi::c_void,
+        max_len: usize,
+        actual_len: *mut usize,
+        buf: *mut u16,
+    );
+}
+
+/// 이벤트 탭 재생성 기본 백오프 (ms) — 연속 실패마다 2배
+const RECREATE_BASE_BACKOFF_MS: u64 = 2_000;
+/// 이벤트 탭 재생성 최대 백오프 (ms)
+const RECREATE_MAX_BACKOFF_MS: u64 = 60_000;
+/// RunLoop 1회 실행 시간 — 재생성 요청 확인 주기
+const RUN_LOOP_SLICE: Duration = Duration::from_millis(500);
+/// 시작 실패 후 권한 획득 확인 주기
+const PERMISSION_POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// 실행 중 권한 회수/재허용 확인 주기 (부담을 줄이도록 길게)
+const PERMISSION_WATCH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// 이벤트 탭 실행 상태 (메뉴바 경고 표시/자동 복구용)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TapStatus {
+    /// 시작 중 (최초 실행 또는 권한 획득 후 재시작)
+    Starting,
+    /// 정상 동작
+    Running,
+    /// 시작 실패 — 권한 안내 표시 후 권한 획득 대기
+    PermissionRequired,
+    /// 실행 중 권한 회수 — 재허용되면 탭 재생성
+    PermissionRevoked,
+}
+
+/// 이벤트 탭 상태 전이 원인
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TapEvent {
+    /// 탭 설치 성공
+    Started,
+    /// 탭 생성 실패
+    StartFailed,
+    /// 실패/회수 상태에서 권한 획득 확인
+    PermissionGranted,
+    /// 실행 중 권한 회수 확인
+    PermissionRevoked,
+}
+
+impl TapStatus {
+    /// 다음 상태 (실패/회수 상태가 아니면 권한 획득은, 시작 실패 상태에서는 회수는 무시)
+    pub fn next(self, event: TapEvent) -> TapStatus {
+        match (self, event) {
+            (_, TapEvent::Started) => TapStatus::Running,
+            (_, TapEvent::StartFailed) => TapStatus::PermissionRequired,
+            (
+                TapStatus::PermissionRequired | TapStatus::PermissionRevoked,
+                TapEvent::PermissionGranted,
+            ) => TapStatus::Starting,
+            (status, TapEvent::PermissionGranted) => status,
+            (TapStatus::Running | TapStatus::Starting, TapEvent::PermissionRevoked) => {
+                TapStatus::PermissionRevoked
+            }
+            (status, TapEvent::PermissionRevoked) => status,
+        }
+    }
+
+    /// 실행 중 주기 확인한 권한 상태로 보낼 전이 (시작 실패 상태는 run_event_tap이 처리)
+    pub fn permission_event(self, has_permission: bool) -> Option<TapEvent> {
+        match (self, has_permission) {
+            (TapStatus::Running | TapStatus::Starting, false) => Some(TapEvent::PermissionRevoked),
+            (TapStatus::PermissionRevoked, true) => Some(TapEvent::PermissionGranted),
+            _ => None,
+        }
+    }
+
+    /// 메뉴바에 경고를 표시할 상태인지 (최초 시작 중에는 표시하지 않음)
+    pub fn needs_attention(self, previous_failed: bool) -> bool {
+        match self {
+            TapStatus::Running => false,
+            TapStatus::PermissionRequired | TapStatus::PermissionRevoked => true,
+            TapStatus::Starting => previous_failed,
+        }
+    }
+}
+
+/// 시작 실패 후 대기 루프의 다음 동작
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RecoveryStep {
+    /// 권한 대기 계속
+    Wait,
+    /// 권한 획득 — 탭 재시작
+    Retry,
+    /// 앱 종료 중
+    Stop,
+}
+
+fn recovery_step(running: bool, has_permission: bool) -> RecoveryStep {
+    if !running {
+        RecoveryStep::Stop
+    } else if has_permission {
+        RecoveryStep::Retry
+    } else {
+        RecoveryStep::Wait
+    }
 }
 
 /// 키 버퍼 - 입력된 영문 키를 누적
+///
+/// 원문 버퍼와 한글 전용 버퍼를 함께 관리합니다.
+/// - 원문 버퍼: 입력된 모든 문자 (숫자/기호 포함) — 교체할 backspace 개수 계산용
+/// - 한글 전용 버퍼: 두벌식 자모로 매핑되는 키만 누적 — 변환 판정용
+///
+/// 비한글 키(숫자, 기호)는 원문 버퍼에만 남아 판정을 오염시키지 않고,
+/// 변환 시에는 원문 그대로 통과되어(`"rk1sk"` → `"가1나"`) 위치가 보존됩니다.
+///
+/// 원문 버퍼가 URL/이메일/경로로 보이면(`"github.com/user"`, `"id@naver"`)
+/// 다음 공백 경계까지 해당 구간 전체를 판정 대상에서 제외합니다.
 pub struct KeyBuffer {
     buffer: String,
+    hangul_keys: String,
     max_size: usize,
 }
 
@@ -40,26 +179,59 @@ impl KeyBuffer {
     pub fn new(max_size: usize) -> Self {
         Self {
             buffer: String::with_capacity(max_size),
+            hangul_keys: String::with_capacity(max_size),
             max_size,
         }
     }
 
     pub fn push(&mut self, c: char) {
+        // 공백류는 변환 경계, 비ASCII 문자는 두벌식 매핑 밖 — 이전 구간을 버리고 새로 시작
+        if is_conversion_boundary(c) || !c.is_ascii() {
+            self.clear();
+            return;
+        }
         if self.buffer.chars().count() >= self.max_size {
             // 오래된 문자 제거
-            self.buffer.remove(0);
+            let removed = self.buffer.remove(0);
+            if is_hangul_key(removed) && !self.hangul_keys.is_empty() {
+                self.hangul_keys.remove(0);
+            }
         }
         self.buffer.push(c);
+        if is_hangul_key(c) {
+            self.hangul_keys.push(c);
+        }
     }
 
     pub fn clear(&mut self) {
         self.buffer.clear();
+        self.hangul_keys.clear();
     }
 
+    /// 원문 버퍼 (비한글 키 포함)
     pub fn get(&self) -> &str {
         &self.buffer
     }
 
+    /// 한글 전용 버퍼 (두벌식 자모 키만)
+    pub fn hangul_keys(&self) -> &str {
+        &self.hangul_keys
+    }
+
+    /// 변환 판정 대상(한글 전용 버퍼)과 교체할 backspace 개수(원문 기준)
+    /// URL/이메일 구간이면 판정 대상은 빈 문자열
+    pub fn conversion_target(&self) -> (&str, usize) {
+        if self.is_url_like() {
+            return ("", self.len());
+        }
+        (&self.hangul_keys, self.len())
+    }
+
+    /// 원문 버퍼가 URL/이메일/경로 형태인지
+    pub fn is_url_like(&self) -> bool {
+        has_url_pattern(&self.buffer)
+    }
+
     pub fn len(&self) -> usize {
         self.buffer.chars().count()
     }
@@ -70,13 +242,17 @@ impl KeyBuffer {
 
     /// 마지막 문자 제거 (Backspace 처리용)
     pub fn pop(&mut self) -> Option<char> {
-        self.buffer.pop()
+        let popped = self.buffer.pop();
+        if popped.is_some_and(is_hangul_key) {
+            self.hangul_keys.pop();
+        }
+        popped
     }
 
     /// 마지막 n개의 문자 삭제 후 새 문자열 추가
     pub fn replace_last(&mut self, remove_count: usize, new_text: &str) {
         for _ in 0..remove_count {
-            self.buffer.pop();
+            self.pop();
         }
         for c in new_text.chars() {
             self.push(c);
@@ -84,6 +260,144 @@ impl KeyBuffer {
     }
 }
 
+/// 수동 변환용 누적 버퍼 - Space를 넘어 여러 단어를 누적
+///
+/// 자동 변환 버퍼([`KeyBuffer`])는 Space마다 비워져 단어 단위로 판정하지만,
+/// 수동 변환(Option+Space)은 공백을 포함한 여러 단어(`"dkssud gktpdy"`)를 한 번에 변환합니다.
+/// 공백도 화면에 입력된 문자이므로 교체할 backspace 개수에 포함됩니다.
+pub struct ManualBuffer {
+    buffer: String,
+    max_size: usize,
+}
+
+impl ManualBuffer {
+    pub fn new(max_size: usize) -> Self {
+        Self {
+            buffer: String::with_capacity(max_size),
+            max_size,
+        }
+    }
+
+    pub fn push(&mut self, c: char) {
+        if self.buffer.chars().count() >= self.max_size {
+            // 오래된 문자 제거 (앞쪽 단어가 잘려도 교체 구간은 화면 끝과 일치)
+            self.buffer.remove(0);
+        }
+        self.buffer.push(c);
+    }
+
+    /// 단어 사이 Space (앞에 누적된 단어가 없으면 무시)
+    pub fn push_space(&mut self) {
+        if !self.buffer.is_empty() {
+            self.push(' ');
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<char> {
+        self.buffer.pop()
+    }
+
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+    }
+
+    pub fn get(&self) -> &str {
+        &self.buffer
+    }
+
+    /// 공백을 포함한 누적 구간을 꺼냄 (한 단어뿐이면 None — 자동 버퍼 경로 사용)
+    pub fn take_multi_word(&mut self) -> Option<String> {
+        let text = std::mem::take(&mut self.buffer);
+        text.contains(' ').then_some(text)
+    }
+}
+
+/// 키 버퍼 최대 길이
+pub const KEY_BUFFER_CAPACITY: usize = 100;
+
+/// 공백 없이 이 길이까지 이어 치면 debounce를 기다리지 않고 변환 판정 (한 번에 지우는 양 제한)
+pub const LONG_BUFFER_KEYS: usize = 30;
+
+/// 한 번의 교체에서 지울 수 있는 최대 문자 수 — 버퍼보다 많이 지우면 앞 텍스트가 손상됨
+pub const MAX_BACKSPACE_COUNT: usize = KEY_BUFFER_CAPACITY;
+
+/// 교체 대상 구간: 앞쪽 비한글 키(직전 변환 후 남은 숫자/기호 등)는 화면에 이미 확정된 텍스트이므로 제외
+///
+/// 중간/뒤쪽 비한글 키는 한글 사이에 입력된 것이므로 함께 지우고 다시 붙여넣음
+pub fn convertible_segment(buffer: &str) -> &str {
+    buffer.trim_start_matches(|c: char| !is_hangul_key(c))
+}
+
+/// 교체 구간의 backspace 개수 (빈 구간이거나 상한 초과 시 None)
+pub fn backspace_count_for(segment: &str) -> Option<usize> {
+    let count = segment.chars().count();
+    (count > 0 && count <= MAX_BACKSPACE_COUNT).then_some(count)
+}
+
+/// 변환 결과 기준 backspace 개수
+///
+/// 변환 결과를 역변환해 실제 소비된 키 수를 구하고, 교체 구간 길이와 다르면
+/// (낱자모 겹자음/이중모음 합성 등) 지울 개수를 확정할 수 없으므로 None
+pub fn backspace_count_for_conversion(segment: &str, hangul: &str) -> Option<usize> {
+    let typed = segment.chars().count();
+    let consumed = korean_to_eng(hangul).chars().count();
+    if consumed != typed {
+        log::warn!(
+            "역변환 길이 불일치: 입력 {}자, 역변환 {}자 ('{}' → '{}')",
+            typed,
+            consumed,
+            segment,
+            hangul
+        );
+        return None;
+    }
+    backspace_count_for(segment)
+}
+
+/// adaptive debounce 하한 (ms) — 빠른 타이핑에서도 입력 도중 변환되지 않도록
+pub const ADAPTIVE_DEBOUNCE_MIN_MS: u64 = 150;
+/// adaptive debounce 상한 (ms) — 느린 타이핑에서도 변환이 지나치게 늦지 않도록
+pub const ADAPTIVE_DEBOUNCE_MAX_MS: u64 = 800;
+/// 이동평균에 반영하는 최대 키 간격 (ms) — 더 긴 간격은 타이핑 중단으로 보고 제외
+const MAX_TYPING_INTERVAL_MS: u64 = 1_000;
+
+/// 현재 시각 (epoch ms)
+fn epoch_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+/// 현재 로컬 시각의 시 (시스템 시간대 기준, 통계 시간대 버킷용)
+fn current_local_hour() -> usize {
+    let offset = CFTimeZone::system().seconds_from_gmt(CFDate::now());
+    local_hour(epoch_ms() / 1000, offset as i64)
+}
+
+/// 키 간격 이동평균 갱신 (새 간격 가중치 1/4, 첫 측정은 그대로 사용)
+pub fn update_key_interval_avg(avg_ms: u64, interval_ms: u64) -> u64 {
+    if interval_ms == 0 || interval_ms > MAX_TYPING_INTERVAL_MS {
+        return avg_ms;
+    }
+    if avg_ms == 0 {
+        return interval_ms;
+    }
+    (avg_ms * 3 + interval_ms) / 4
+}
+
+/// 평균 키 간격 기준 debounce (간격의 2.5배, 상·하한 적용, 측정 전이면 None)
+pub fn adaptive_debounce_ms(avg_interval_ms: u64) -> Option<u64> {
+    (avg_interval_ms > 0).then(|| {
+        (avg_interval_ms * 5 / 2).clamp(ADAPTIVE_DEBOUNCE_MIN_MS, ADAPTIVE_DEBOUNCE_MAX_MS)
+    })
+}
+
+/// 텍스트에서 두벌식 자모로 매핑되는 키만 추출 (한글 전용 버퍼와 동일한 규칙)
+pub fn extract_hangul_keys(text: &str) -> String {
+    text.chars().filter(|&c| is_hangul_key(c)).collect()
+}
+
 /// Debounce 타이머 명령
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum DebounceCommand {
@@ -119,7 +433,7 @@ struct SwitchTimerState {
 }
 
 /// 변환 이력 (Undo용)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConversionHistory {
     /// 원본 영문 텍스트
     pub original: String,
@@ -189,11 +503,198 @@ fn keycode_to_char(keycode: u16, shift: bool) -> Option<char> {
     })
 }
 
+/// 문자를 입력하는 키코드와 Shift 필요 여부 (keycode_to_char의 역방향, self-test 합성 입력용)
+pub fn char_to_keycode(c: char) -> Option<(u16, bool)> {
+    (0..=50u16).find_map(|keycode| {
+        [false, true]
+            .into_iter()
+            .find(|&shift| keycode_to_char(keycode, shift) == Some(c))
+            .map(|shift| (keycode, shift))
+    })
+}
+
 /// 두벌식 자판에서 자음/모음으로 매핑되는 키인지 확인
+/// (자판 정의는 jamo_mapper 한 곳에서만 관리)
 fn is_hangul_key(c: char) -> bool {
     crate::core::jamo_mapper::map_to_jamo(c).is_some()
 }
 
+/// 키 버퍼에 넣을 수 없는 문자 (CJK IME 합성 문자, Option 조합 문자 등 비ASCII 텍스트)
+///
+/// 제어 문자와 방향키/기능키가 내는 사설 영역 문자(U+F700~)는 텍스트가 아니므로 제외
+fn is_foreign_text_char(c: char) -> bool {
+    !c.is_ascii() && !c.is_control() && !('\u{E000}'..='\u{F8FF}').contains(&c)
+}
+
+/// 문자 키 입력의 버퍼 처리
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeyInput {
+    /// ASCII 문자 — 버퍼에 추가
+    Buffer(char),
+    /// 비ASCII 문자 입력 — 버퍼를 변환 없이 비움
+    Reset,
+    /// 문자를 만들지 않는 키 — 버퍼 유지
+    Ignore,
+}
+
+/// 자동 변환 직후 Enter 처리
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EnterAction {
+    /// 대기 중인 교체가 없음 — 그대로 통과
+    PassThrough,
+    /// 교체 전 — 지금은 소비하고 교체가 끝난 뒤 재전송
+    Defer,
+}
+
+/// 보류한 Enter를 포기하는 교체 대기 시간 (워커 재시작 등으로 완료 통지가 유실된 경우)
+const NEWLINE_DEFER_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// 자동 변환 교체와 Enter의 순서 조정
+///
+/// 교체가 끝나기 전에 Enter를 통과시키면 줄바꿈 뒤 커서에서 Backspace가 실행되므로,
+/// 교체 대기 중인 Enter는 보류했다가 교체 완료 후 재전송 (순서: 교체 → 줄바꿈)
+#[derive(Debug, Default)]
+struct NewlineGate {
+    /// 워커가 아직 처리하지 않은 자동 변환 요청 수
+    in_flight: u64,
+    /// 보류한 Enter 수
+    deferred: u32,
+    /// 마지막 자동 변환 요청 시각
+    last_request: Option<Instant>,
+}
+
+impl NewlineGate {
+    fn conversion_requested(&mut self, now: Instant) {
+        self.in_flight += 1;
+        self.last_request = Some(now);
+    }
+
+    /// Enter 입력 시 처리 결정 (교체 대기 중이면 보류)
+    fn on_enter(&mut self, now: Instant) -> EnterAction {
+        let stale = self
+            .last_request
+            .is_some_and(|at| now.duration_since(at) >= NEWLINE_DEFER_TIMEOUT);
+        if self.in_flight == 0 || stale {
+            *self = Self::default();
+            return EnterAction::PassThrough;
+        }
+        self.deferred += 1;
+        EnterAction::Defer
+    }
+
+    /// 워커가 자동 변환 count건을 처리함
+    /// 반환: 지금 재전송할 Enter 수 (대기 중인 교체가 모두 끝났을 때만)
+    fn conversions_finished(&mut self, count: u64) -> u32 {
+        self.in_flight = self.in_flight.saturating_sub(count);
+        if self.in_flight > 0 {
+            return 0;
+        }
+        std::mem::take(&mut self.deferred)
+    }
+}
+
+/// 이모지 피커/후보 창을 연 뒤 변환을 보류하는 최대 시간 (닫힘을 감지하지 못한 경우)
+const CANDIDATE_HOLD_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 이모지 피커를 여는 단축키인지 (Control+Command+Space, fn(🌐)+E)
+///
+/// 한자 후보 창(Option+Return)은 한글 입력 소스에서만 열리고, 그때는 영문 버퍼를 쌓지 않으므로 제외
+fn opens_candidate_window(keycode: u16, flags: CGEventFlags) -> bool {
+    match keycode {
+        49 => flags.contains(CGEventFlags::CGEventFlagControl | CGEventFlags::CGEventFlagCommand),
+        14 => flags.contains(CGEventFlags::CGEventFlagSecondaryFn),
+        _ => false,
+    }
+}
+
+/// 후보 창을 닫는 키인지 (Escape, Return)
+fn closes_candidate_window(keycode: u16) -> bool {
+    matches!(keycode, 53 | 36)
+}
+
+/// 이모지 피커/후보 창 표시 중 변환 보류
+///
+/// 창이 떠 있는 동안 입력한 키(검색어 등)는 편집 중인 텍스트에 들어가지 않으므로,
+/// 버퍼에 쌓아 변환하면 합성 Backspace가 엉뚱한 텍스트를 지움. 창 표시 여부를 직접 알 수 없어
+/// 여는 단축키 후 닫는 키/클릭 또는 제한 시간까지 보류
+#[derive(Debug, Default)]
+struct CandidateHold {
+    /// 보류 만료 시각 (None: 보류 아님)
+    until: Option<Instant>,
+}
+
+impl CandidateHold {
+    /// 키 입력 반영, 이 키를 보류 상태로 처리해야 하면 true (버퍼에 넣지 않고 통과)
+    fn on_key(&mut self, keycode: u16, flags: CGEventFlags, now: Instant) -> bool {
+        if opens_candidate_window(keycode, flags) {
+            self.until = Some(now + CANDIDATE_HOLD_TIMEOUT);
+            return true;
+        }
+        if !self.is_active(now) {
+            return false;
+        }
+        if closes_candidate_window(keycode) {
+            self.until = None;
+        }
+        true
+    }
+
+    /// 클릭(후보 선택/창 밖 클릭)으로 창이 닫힘
+    fn release(&mut self) {
+        self.until = None;
+    }
+
+    fn is_active(&mut self, now: Instant) -> bool {
+        match self.until {
+            Some(until) if now < until => true,
+            Some(_) => {
+                self.until = None;
+                false
+            }
+            None => false,
+        }
+    }
+}
+
+/// 보류한 Enter 재전송 (합성 이벤트로 표시되어 탭에서 다시 처리하지 않음)
+fn send_deferred_newline() {
+    if let Err(e) = simulate_return() {
+        log::error!("Enter 재전송 실패: {}", e);
+    }
+}
+
+/// 키코드 문자(key_char)와 이벤트가 실제로 입력하는 문자열(produced)로 버퍼 처리 결정
+///
+/// 버퍼에는 두벌식 매핑 대상인 ASCII 키만 넣고, 이벤트가 비ASCII 문자를 입력하면
+/// 화면 텍스트와 버퍼가 어긋나므로 키코드와 무관하게 초기화
+fn classify_key_input(key_char: Option<char>, produced: &str) -> KeyInput {
+    if produced.chars().any(is_foreign_text_char) {
+        return KeyInput::Reset;
+    }
+    match key_char {
+        Some(c) if c.is_ascii() => KeyInput::Buffer(c),
+        Some(_) => KeyInput::Reset,
+        None => KeyInput::Ignore,
+    }
+}
+
+/// 키 이벤트가 입력하는 유니코드 문자열
+fn event_unicode_string(event: &CGEvent) -> String {
+    let mut buf = [0u16; 8];
+    let mut len = 0usize;
+    // &CGEventRef는 CGEventRef 포인터 자체 (foreign-types 참조 타입)
+    let event_ref: &CGEventRef = event;
+    unsafe {
+        CGEventKeyboardGetUnicodeString(
+            event_ref as *const CGEventRef as *const std::ffi::c_void,
+            buf.len(),
+            &mut len,
+            buf.as_mut_ptr(),
+        );
+    }
+    String::from_utf16_lossy(&buf[..len.min(buf.len())])
+}
+
 /// 단축키 설정
 #[derive(Clone, Copy)]
 pub struct HotkeyConfig {
@@ -201,17 +702,149 @@ pub struct HotkeyConfig {
     pub require_option: bool,
     /// Space 키코드 (49)
     pub trigger_keycode: u16,
+    /// Koing 활성화 토글 키코드 (Option+Shift와 함께 입력, None이면 사용 안 함)
+    pub toggle_keycode: Option<u16>,
 }
 
 impl Default for HotkeyConfig {
     fn default() -> Self {
         Self {
             require_option: true,
-            trigger_keycode: 49, // Space
+            trigger_keycode: 49,      // Space
+            toggle_keycode: Some(49), // Option+Shift+Space
+        }
+    }
+}
+
+impl HotkeyConfig {
+    /// 활성화 토글 단축키인지 확인
+    fn is_toggle(&self, keycode: u16, option_pressed: bool, shift_pressed: bool) -> bool {
+        self.toggle_keycode == Some(keycode) && option_pressed && shift_pressed
+    }
+}
+
+/// 입력 소스 상태별 문자 키 처리 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyHandling {
+    /// 버퍼링 + 자동 변환 (debounce/실시간)
+    Convert,
+    /// 버퍼링만 (Option+Space 수동 변환만)
+    BufferOnly,
+    /// 버퍼를 비우고 그대로 통과
+    PassThrough,
+}
+
+/// 입력 소스 상태별 처리 정책
+///
+/// 상태를 아직 모르면(캐시 갱신 대기) 키를 pending 버퍼에 보관했다가 확인된 상태의 정책을 적용.
+/// 한글 입력 소스에서 버퍼링하면 화면 글자 수와 키 수가 달라질 수 있으므로 기본값은 패스스루
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputSourcePolicy {
+    /// 영문 입력 소스 (한글 IME 영문 서브모드 포함)
+    pub english: KeyHandling,
+    /// 한글 등 비영문 입력 소스
+    pub non_english: KeyHandling,
+}
+
+impl Default for InputSourcePolicy {
+    fn default() -> Self {
+        Self {
+            english: KeyHandling::Convert,
+            non_english: KeyHandling::PassThrough,
+        }
+    }
+}
+
+impl InputSourcePolicy {
+    /// 입력 소스 상태의 처리 방식 (Unknown이면 None — pending 보관)
+    pub fn handling_for(&self, state: InputSourceState) -> Option<KeyHandling> {
+        match state {
+            InputSourceState::English => Some(self.english),
+            InputSourceState::NonEnglish => Some(self.non_english),
+            InputSourceState::Unknown => None,
+        }
+    }
+}
+
+/// 포커스 요소 검사 결과 캐시 유지 시간 — AX 조회 비용 절감용
+const FOCUS_CHECK_TTL: Duration = Duration::from_millis(500);
+
+/// 전환 제외 앱 검사 결과 캐시 유지 시간 — 최전면 앱 조회 비용 절감용
+const SWITCH_APP_CHECK_TTL: Duration = Duration::from_millis(500);
+
+/// 포커스 요소/앱 검사 결과 캐시
+#[derive(Debug, Clone, Copy)]
+struct FocusCheck {
+    checked_at: Instant,
+    allowed: bool,
+}
+
+/// 포커스 앱 기준 입력 소스 전환 허용 여부 (목록에 있는 앱이면 false, 앱을 모르면 허용)
+fn switch_allowed_for(bundle_id: Option<&str>, no_switch_bundle_ids: &[String]) -> bool {
+    !bundle_id.is_some_and(|id| no_switch_bundle_ids.iter().any(|b| b == id))
+}
+
+/// 이벤트 탭이 사용하는 입력 소스(TIS) 접근 함수 (테스트에서 교체)
+#[derive(Clone, Copy)]
+struct InputSourceAccess {
+    /// 캐시된 현재 입력 소스 상태
+    snapshot: fn() -> InputSourceSnapshot,
+    /// 캐시 무효화
+    invalidate: fn(),
+    /// 비동기 캐시 갱신 예약
+    refresh: fn(),
+    /// 한글 입력 소스로 전환 (메인 스레드에서 실행)
+    switch_to_korean: fn(),
+}
+
+impl Default for InputSourceAccess {
+    fn default() -> Self {
+        Self {
+            snapshot: cached_input_source_snapshot,
+            invalidate: invalidate_input_source_cache,
+            refresh: schedule_async_refresh,
+            switch_to_korean: switch_to_korean_on_main,
         }
     }
 }
 
+/// 앱별 프로필 매핑과 프로필 파라미터
+#[derive(Default)]
+struct AppProfileTable {
+    apps: BTreeMap<String, AppProfile>,
+    params: BTreeMap<AppProfile, ProfileParams>,
+    /// 매핑에 없는 앱에 적용할 변환 민감도
+    fallback: AppProfile,
+    /// 마지막으로 포커스된 앱 bundle ID
+    active_app: Option<String>,
+}
+
+impl AppProfileTable {
+    fn profile_for(&self, bundle_id: Option<&str>) -> AppProfile {
+        bundle_id
+            .and_then(|id| resolve_app_profile(&self.apps, id))
+            .unwrap_or(self.fallback)
+    }
+
+    fn params_for(&self, profile: AppProfile) -> ProfileParams {
+        self.params
+            .get(&profile)
+            .copied()
+            .unwrap_or_else(|| profile.default_params())
+    }
+}
+
+/// 버퍼 변경 콜백 (버퍼, 변환 후보, 신뢰도)
+type BufferChangeCallback = Box<dyn Fn(String, String, Option<f32>) + Send + 'static>;
+/// 선택 텍스트 변환 콜백
+type ConvertSelectionCallback = Box<dyn Fn() + Send + 'static>;
+/// 역변환 콜백 (한글 모드에서 입력된 영단어 키 시퀀스)
+type ReverseCallback = Box<dyn Fn(String) + Send + 'static>;
+/// 단축키 활성화 토글 콜백 (바뀐 활성화 상태)
+type ToggleCallback = Box<dyn Fn(bool) + Send + 'static>;
+/// 이벤트 탭 상태 변경 콜백
+type TapStatusCallback = Box<dyn Fn(TapStatus) + Send + 'static>;
+
 /// 이벤트 탭 핸들러에서 사용할 공유 상태
 pub struct EventTapState {
     pub buffer: Mutex<KeyBuffer>,
@@ -221,9 +854,67 @@ pub struct EventTapState {
     /// Koing 활성화 여부 (false이면 모든 이벤트를 그대로 통과)
     pub enabled: AtomicBool,
     pub auto_detector: Mutex<AutoDetector>,
+    /// 포커스 앱별 감지 프로필 (앱 전환 시 auto_detector에 적용)
+    app_profiles: Mutex<AppProfileTable>,
+    /// 포커스 요소가 텍스트 입력인지 판별 (None: 판별 불가)
+    focus_probe: Mutex<fn() -> Option<bool>>,
+    /// Accessibility 권한 확인 (테스트에서 교체)
+    permission_probe: Mutex<fn() -> bool>,
+    /// 마지막 포커스 검사 결과
+    focus_check: Mutex<Option<FocusCheck>>,
+    /// 변환 후 입력 소스 전환을 건너뛸 앱 bundle ID
+    no_switch_bundle_ids: Mutex<Vec<String>>,
+    /// 최전면 앱 bundle ID 조회 함수
+    frontmost_app_probe: Mutex<fn() -> Option<String>>,
+    /// 마지막 전환 허용 검사 결과
+    switch_app_check: Mutex<Option<FocusCheck>>,
+    /// 변환 거부 시 입력 소스만 한글로 전환 (soft switch)
+    soft_switch_on_reject: AtomicBool,
+    /// 입력 소스(TIS) 조회/전환 함수
+    input_source: Mutex<InputSourceAccess>,
+    /// 입력 소스 상태별 문자 키 처리 정책
+    input_source_policy: Mutex<InputSourcePolicy>,
+    /// 한글 모드에서 친 영단어를 영문으로 되돌리기 (양방향 모드)
+    reverse_to_english: AtomicBool,
+    /// 한글 입력 소스에서 입력된 키 (역변환 판정용)
+    korean_keys: Mutex<KeyBuffer>,
+    /// 버퍼/타이머를 초기화하는 경계 키코드
+    reset_keycodes: Mutex<Vec<u16>>,
+    /// 사용자 치환 규칙 (일치하면 자동 감지 없이 변환)
+    expansions: Mutex<ExpansionTable>,
+    /// 실시간 변환 판정에 쓰는 영→한 변환기 (기본: 두벌식)
+    converter: Mutex<Arc<dyn Converter>>,
+    /// 주입된 자동 변환 판정기 (None: 설정이 반영되는 auto_detector)
+    gate: Mutex<Option<Arc<dyn ConversionGate>>>,
+    /// 느린 변환(2단계) 구조 검사기 (기본: 모델 없는 KoreanValidator)
+    validator: Mutex<Arc<dyn ConversionValidator>>,
     pub on_convert: Mutex<Option<Box<dyn Fn(String, bool) + Send + 'static>>>,
     /// Undo 콜백 (한글 텍스트, 원본 영문 텍스트)
     pub on_undo: Mutex<Option<Box<dyn Fn(String, String) + Send + 'static>>>,
+    /// 선택 텍스트 변환 콜백 (버퍼가 빈 상태에서 단축키 입력 시)
+    pub on_convert_selection: Mutex<Option<ConvertSelectionCallback>>,
+    /// 역변환 콜백 (한글 모드에서 입력된 영단어 키 시퀀스)
+    on_reverse: Mutex<Option<ReverseCallback>>,
+    /// 단축키로 활성화 상태를 바꾼 뒤 호출할 콜백 (메뉴바 갱신용)
+    on_toggle: Mutex<Option<ToggleCallback>>,
+    /// 이벤트 탭 실행 상태
+    tap_status: Mutex<TapStatus>,
+    /// 이벤트 탭 상태 변경 콜백 (메뉴바 경고 표시용)
+    on_tap_status: Mutex<Option<TapStatusCallback>>,
+    /// 디버그 오버레이 사용 여부 (false면 버퍼 변경 콜백을 호출하지 않음)
+    debug_overlay: AtomicBool,
+    /// 버퍼 변경 콜백 (버퍼, 변환 후보, 신뢰도) — 디버그 오버레이 갱신용
+    on_buffer_change: Mutex<Option<BufferChangeCallback>>,
+    /// 마지막으로 알린 버퍼 내용 (변경 시에만 콜백 호출)
+    last_notified_buffer: Mutex<String>,
+    /// 수동 변환용 누적 버퍼 (Space를 넘어 여러 단어 누적)
+    manual_buffer: Mutex<ManualBuffer>,
+    /// 대안 신뢰도 기준 shadow 판정기 (None이면 사용 안 함)
+    shadow_eval: Mutex<Option<ShadowEvaluator>>,
+    /// 변환 워커의 N-gram 설정 (스냅샷 기록용, 워커 시작 시 적용)
+    ngram_settings: Mutex<NgramSettings>,
+    /// 최근 변환 결과 피드백 (세션 단위 신뢰도 기준 조정)
+    feedback: Mutex<ConversionFeedback>,
     /// 실시간 모드 활성화 여부
     pub realtime_mode: AtomicBool,
     /// Debounce 타이머 Condvar 기반 상태
@@ -232,26 +923,46 @@ pub struct EventTapState {
     switch_cv: Arc<(Mutex<SwitchTimerState>, std::sync::Condvar)>,
     /// 마지막 키 입력 시간 (ms 단위 epoch)
     pub last_key_time: AtomicU64,
+    /// 버퍼에 들어간 문자 키 입력 횟수 (한글 전환 타이머 만료 시 재검사용)
+    typed_keys: AtomicU64,
     /// 변환 이력 (Undo용)
     pub conversion_history: Mutex<Option<ConversionHistory>>,
+    /// 누적 변환 통계 (횟수만 집계, 종료 시 저장)
+    stats: Mutex<ConversionStats>,
     /// 텍스트 교체 중 여부 (레이스 컨디션 방지)
     pub is_replacing: AtomicBool,
     /// debounce/실시간 변환이 버퍼를 소비한 직후 true로 설정.
-    /// Space/Enter가 뒤따라 올 때 이벤트를 소비하여 race condition 방지.
+    /// Space가 뒤따라 올 때 이벤트를 소비하여 race condition 방지 (Enter는 newline_gate가 처리).
     /// 새 문자 입력 시 false로 리셋.
     conversion_just_triggered: AtomicBool,
+    /// 자동 변환 교체와 Enter 순서 조정 상태
+    newline_gate: Mutex<NewlineGate>,
+    /// 보류한 Enter 재전송 함수 (테스트에서 교체)
+    newline_sender: Mutex<fn()>,
+    /// 이모지 피커/후보 창 표시 중 변환 보류 상태
+    candidate_hold: Mutex<CandidateHold>,
     /// 변환 감지 debounce 시간 (ms)
     pub debounce_ms: AtomicU64,
+    /// 입력 속도 기반 debounce 사용 여부 (true면 debounce_ms 대신 추정값 사용)
+    adaptive_debounce: AtomicBool,
+    /// 최근 키 간격 이동평균 (ms, 0이면 측정 전)
+    key_interval_avg_ms: AtomicU64,
     /// 한글 자판 전환 지연 시간 (ms)
     pub switch_delay_ms: AtomicU64,
     /// 느린 변환 대기 시간 (ms) — 유효하지만 확신 낮은 한글용
     pub slow_debounce_ms: AtomicU64,
+    /// 1단계 실패 시 2단계(느린 구조적) 변환 시도 여부
+    two_stage_conversion: AtomicBool,
     /// CGEventTap mach port (이벤트 탭 재활성화용)
     tap_port: AtomicPtr<std::ffi::c_void>,
     /// 이벤트 탭 스레드의 CFRunLoop (정상 종료용)
     run_loop: AtomicPtr<std::ffi::c_void>,
     /// 재활성화 필요 플래그 (콜백에서 빠르게 반환 후 감시 스레드가 처리)
     needs_reenable: AtomicBool,
+    /// 재생성 필요 플래그 (재활성화 최종 실패 시 설정, RunLoop 스레드가 처리)
+    needs_recreate: AtomicBool,
+    /// 재생성 백오프 상태 (반복 재생성 루프 방지)
+    recreate_backoff: Mutex<RecreateBackoff>,
     /// 마지막 이벤트 수신 시간 (epoch ms, 헬스 모니터링용)
     last_event_time: AtomicU64,
 }
@@ -259,14 +970,43 @@ pub struct EventTapState {
 impl EventTapState {
     pub fn new(hotkey: HotkeyConfig) -> Self {
         Self {
-            buffer: Mutex::new(KeyBuffer::new(100)),
-            pending_buffer: Mutex::new(KeyBuffer::new(100)),
+            buffer: Mutex::new(KeyBuffer::new(KEY_BUFFER_CAPACITY)),
+            pending_buffer: Mutex::new(KeyBuffer::new(KEY_BUFFER_CAPACITY)),
             hotkey,
             running: AtomicBool::new(true),
             enabled: AtomicBool::new(true),
             auto_detector: Mutex::new(AutoDetector::default()),
+            app_profiles: Mutex::new(AppProfileTable::default()),
+            focus_probe: Mutex::new(is_text_input_focused),
+            permission_probe: Mutex::new(check_accessibility_permission),
+            focus_check: Mutex::new(None),
+            no_switch_bundle_ids: Mutex::new(Vec::new()),
+            frontmost_app_probe: Mutex::new(frontmost_bundle_id),
+            switch_app_check: Mutex::new(None),
+            soft_switch_on_reject: AtomicBool::new(false),
+            input_source: Mutex::new(InputSourceAccess::default()),
+            input_source_policy: Mutex::new(InputSourcePolicy::default()),
+            reverse_to_english: AtomicBool::new(false),
+            korean_keys: Mutex::new(KeyBuffer::new(KEY_BUFFER_CAPACITY)),
+            reset_keycodes: Mutex::new(DEFAULT_RESET_KEYCODES.to_vec()),
+            expansions: Mutex::new(ExpansionTable::default()),
+            converter: Mutex::new(Arc::new(TwoSetConverter)),
+            gate: Mutex::new(None),
+            validator: Mutex::new(Arc::new(KoreanValidator::new())),
             on_convert: Mutex::new(None),
             on_undo: Mutex::new(None),
+            on_convert_selection: Mutex::new(None),
+            on_reverse: Mutex::new(None),
+            on_toggle: Mutex::new(None),
+            tap_status: Mutex::new(TapStatus::Starting),
+            on_tap_status: Mutex::new(None),
+            debug_overlay: AtomicBool::new(false),
+            on_buffer_change: Mutex::new(None),
+            last_notified_buffer: Mutex::new(String::new()),
+            manual_buffer: Mutex::new(ManualBuffer::new(KEY_BUFFER_CAPACITY)),
+            shadow_eval: Mutex::new(None),
+            ngram_settings: Mutex::new(NgramSettings::default()),
+            feedback: Mutex::new(ConversionFeedback::default()),
             realtime_mode: AtomicBool::new(true), // 기본 활성화
             debounce_cv: Arc::new((
                 Mutex::new(DebounceTimerState { command: None }),
@@ -277,15 +1017,25 @@ impl EventTapState {
                 std::sync::Condvar::new(),
             )),
             last_key_time: AtomicU64::new(0),
+            typed_keys: AtomicU64::new(0),
             conversion_history: Mutex::new(None),
+            stats: Mutex::new(ConversionStats::default()),
             is_replacing: AtomicBool::new(false),
             conversion_just_triggered: AtomicBool::new(false),
+            newline_gate: Mutex::new(NewlineGate::default()),
+            newline_sender: Mutex::new(send_deferred_newline),
+            candidate_hold: Mutex::new(CandidateHold::default()),
             slow_debounce_ms: AtomicU64::new(1500),
+            two_stage_conversion: AtomicBool::new(true),
             debounce_ms: AtomicU64::new(300),
+            adaptive_debounce: AtomicBool::new(false),
+            key_interval_avg_ms: AtomicU64::new(0),
             switch_delay_ms: AtomicU64::new(0),
             tap_port: AtomicPtr::new(std::ptr::null_mut()),
             run_loop: AtomicPtr::new(std::ptr::null_mut()),
             needs_reenable: AtomicBool::new(false),
+            needs_recreate: AtomicBool::new(false),
+            recreate_backoff: Mutex::new(RecreateBackoff::default()),
             last_event_time: AtomicU64::new(0),
         }
     }
@@ -306,77 +1056,661 @@ impl EventTapState {
         *on_undo = Some(Box::new(callback));
     }
 
-    /// Koing 활성화/비활성화
-    pub fn set_enabled(&self, enabled: bool) {
-        self.enabled.store(enabled, Ordering::Release);
+    pub fn set_convert_selection_callback<F>(&self, callback: F)
+    where
+        F: Fn() + Send + 'static,
+    {
+        let mut on_convert_selection = lock_or_recover(&self.on_convert_selection);
+        *on_convert_selection = Some(Box::new(callback));
     }
 
-    /// Koing 활성화 여부
-    pub fn is_enabled(&self) -> bool {
-        self.enabled.load(Ordering::Acquire)
+    pub fn set_toggle_callback<F>(&self, callback: F)
+    where
+        F: Fn(bool) + Send + 'static,
+    {
+        let mut on_toggle = lock_or_recover(&self.on_toggle);
+        *on_toggle = Some(Box::new(callback));
     }
 
-    /// 자동 감지 활성화/비활성화
-    pub fn set_auto_detect_enabled(&self, enabled: bool) {
-        if let Ok(mut detector) = self.auto_detector.lock() {
-            detector.set_enabled(enabled);
-        }
+    pub fn set_reverse_callback<F>(&self, callback: F)
+    where
+        F: Fn(String) + Send + 'static,
+    {
+        let mut on_reverse = lock_or_recover(&self.on_reverse);
+        *on_reverse = Some(Box::new(callback));
     }
 
-    /// 자동 감지 활성화 여부
-    pub fn is_auto_detect_enabled(&self) -> bool {
-        self.auto_detector
-            .lock()
-            .map(|d| d.is_enabled())
-            .unwrap_or(false)
+    pub fn set_tap_status_callback<F>(&self, callback: F)
+    where
+        F: Fn(TapStatus) + Send + 'static,
+    {
+        let mut on_tap_status = lock_or_recover(&self.on_tap_status);
+        *on_tap_status = Some(Box::new(callback));
     }
 
-    /// 실시간 모드 활성화/비활성화
-    pub fn set_realtime_mode(&self, enabled: bool) {
-        self.realtime_mode.store(enabled, Ordering::Relaxed);
+    /// 버퍼 변경 콜백 등록 (디버그 오버레이가 켜져 있을 때만 호출)
+    pub fn set_buffer_change_callback<F>(&self, callback: F)
+    where
+        F: Fn(String, String, Option<f32>) + Send + 'static,
+    {
+        let mut on_buffer_change = lock_or_recover(&self.on_buffer_change);
+        *on_buffer_change = Some(Box::new(callback));
     }
 
-    /// 실시간 모드 활성화 여부
-    pub fn is_realtime_mode(&self) -> bool {
-        self.realtime_mode.load(Ordering::Relaxed)
+    /// 디버그 오버레이 사용 여부 설정
+    pub fn set_debug_overlay(&self, enabled: bool) {
+        self.debug_overlay.store(enabled, Ordering::Relaxed);
+        lock_or_recover(&self.last_notified_buffer).clear();
     }
 
-    /// 변환 감지 debounce 시간 설정
-    pub fn set_debounce_ms(&self, ms: u64) {
-        self.debounce_ms.store(ms, Ordering::Relaxed);
+    pub fn is_debug_overlay(&self) -> bool {
+        self.debug_overlay.load(Ordering::Relaxed)
     }
 
-    /// 변환 감지 debounce 시간 읽기
-    pub fn get_debounce_ms(&self) -> u64 {
-        self.debounce_ms.load(Ordering::Relaxed)
+    /// shadow 판정 사용 여부 설정 (끄면 누적 집계도 초기화)
+    pub fn set_shadow_eval(&self, enabled: bool) {
+        *lock_or_recover(&self.shadow_eval) = enabled.then(ShadowEvaluator::with_defaults);
     }
 
-    /// 느린 변환 대기 시간 설정
-    pub fn set_slow_debounce_ms(&self, ms: u64) {
-        self.slow_debounce_ms.store(ms, Ordering::Relaxed);
+    /// 대안 기준별 shadow 판정 누적 집계 (꺼져 있으면 빈 목록)
+    pub fn shadow_stats(&self) -> Vec<ShadowVariantStats> {
+        lock_or_recover(&self.shadow_eval)
+            .as_ref()
+            .map(|evaluator| evaluator.stats().to_vec())
+            .unwrap_or_default()
     }
 
-    /// 느린 변환 대기 시간 읽기
-    pub fn get_slow_debounce_ms(&self) -> u64 {
-        self.slow_debounce_ms.load(Ordering::Relaxed)
+    /// 변환 워커가 사용하는 N-gram 설정 기록 (스냅샷에 포함)
+    pub fn set_ngram_settings(&self, settings: NgramSettings) {
+        *lock_or_recover(&self.ngram_settings) = settings;
     }
 
-    /// 한글 자판 전환 지연 시간 설정
-    pub fn set_switch_delay_ms(&self, ms: u64) {
-        self.switch_delay_ms.store(ms, Ordering::Relaxed);
+    /// 현재 적용 중인 변환 설정 스냅샷 (버그 리포트 첨부용)
+    pub fn config_snapshot(&self) -> ConfigSnapshot {
+        let (app_profiles, profile_params, sensitivity, active_app) = {
+            let table = lock_or_recover(&self.app_profiles);
+            (
+                table.apps.clone(),
+                table.params.clone(),
+                table.fallback,
+                table.active_app.clone(),
+            )
+        };
+        let (auto_detect_enabled, detector_config, never_convert_words, pass_through_jamo_only) = {
+            let detector = lock_or_recover(&self.auto_detector);
+            (
+                detector.is_enabled(),
+                detector.config().clone(),
+                detector.never_convert_words(),
+                detector.is_pass_through_jamo_only(),
+            )
+        };
+        ConfigSnapshot {
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            enabled: self.is_enabled(),
+            auto_detect_enabled,
+            realtime_mode: self.is_realtime_mode(),
+            debounce_ms: self.get_debounce_ms(),
+            adaptive_debounce: self.is_adaptive_debounce(),
+            slow_debounce_ms: self.get_slow_debounce_ms(),
+            two_stage_conversion: self.is_two_stage_conversion(),
+            switch_delay_ms: self.get_switch_delay_ms(),
+            sensitivity,
+            app_profiles,
+            profile_params,
+            active_app,
+            threshold: detector_config.threshold,
+            realtime_threshold: detector_config.realtime_threshold,
+            min_length: detector_config.min_length,
+            never_convert_words,
+            pass_through_jamo_only,
+            soft_switch_on_reject: self.soft_switch_on_reject.load(Ordering::Relaxed),
+            reverse_to_english: self.reverse_to_english.load(Ordering::Relaxed),
+            reset_keycodes: lock_or_recover(&self.reset_keycodes).clone(),
+            ngram: lock_or_recover(&self.ngram_settings).clone(),
+        }
     }
 
-    /// 한글 자판 전환 지연 시간 읽기
-    pub fn get_switch_delay_ms(&self) -> u64 {
-        self.switch_delay_ms.load(Ordering::Relaxed)
+    /// 스냅샷의 설정을 그대로 적용 (N-gram 설정은 기록만, 워커 재시작 시 반영)
+    ///
+    /// 감지기 기준은 앱 프로필 적용 후 마지막에 덮어써 스냅샷 시점 값과 같게 맞춤
+    pub fn apply_snapshot(&self, snapshot: &ConfigSnapshot) {
+        self.set_enabled(snapshot.enabled);
+        self.set_realtime_mode(snapshot.realtime_mode);
+        self.set_debounce_ms(snapshot.debounce_ms);
+        self.set_adaptive_debounce(snapshot.adaptive_debounce);
+        self.set_slow_debounce_ms(snapshot.slow_debounce_ms);
+        self.set_two_stage_conversion(snapshot.two_stage_conversion);
+        self.set_switch_delay_ms(snapshot.switch_delay_ms);
+        {
+            let mut table = lock_or_recover(&self.app_profiles);
+            table.apps = snapshot.app_profiles.clone();
+            table.params = snapshot.profile_params.clone();
+            table.fallback = snapshot.sensitivity;
+            table.active_app = snapshot.active_app.clone();
+        }
+        {
+            let mut detector = lock_or_recover(&self.auto_detector);
+            detector.set_enabled(snapshot.auto_detect_enabled);
+            detector.set_sensitivity(
+                snapshot.threshold,
+                snapshot.realtime_threshold,
+                snapshot.min_length,
+            );
+            detector.set_never_convert_words(snapshot.never_convert_words.clone());
+            detector.set_pass_through_jamo_only(snapshot.pass_through_jamo_only);
+        }
+        // 스냅샷 기준에 이미 반영된 조정값이 다시 더해지지 않도록
+        lock_or_recover(&self.feedback).reset();
+        self.set_soft_switch_on_reject(snapshot.soft_switch_on_reject);
+        self.set_reverse_to_english(snapshot.reverse_to_english);
+        self.set_reset_keycodes(snapshot.reset_keycodes.clone());
+        self.set_ngram_settings(snapshot.ngram.clone());
+        log::info!("설정 스냅샷 적용 (v{})", snapshot.app_version);
     }
 
-    /// Debounce 타이머에 명령 전송 (Condvar로 즉시 깨움)
-    fn send_debounce_command(&self, cmd: DebounceCommand) {
-        let (ref mutex, ref cvar) = *self.debounce_cv;
-        if let Ok(mut state) = mutex.lock() {
-            state.command = Some(cmd);
-            cvar.notify_one();
+    /// 실시간 판정을 대안 기준으로 다시 평가해 불일치만 로그 (shadow 판정이 꺼져 있으면 무시)
+    fn shadow_evaluate(&self, detector: &AutoDetector, judge: &str, actual: bool) {
+        let mut shadow_eval = lock_or_recover(&self.shadow_eval);
+        let Some(evaluator) = shadow_eval.as_mut() else {
+            return;
+        };
+        for mismatch in evaluator.evaluate_realtime(detector, judge, actual) {
+            LogEvent::new(log::Level::Debug, "shadow_decision", "shadow 판정 불일치")
+                .field("variant", mismatch.variant)
+                .field("threshold", mismatch.threshold)
+                .field("actual", mismatch.actual)
+                .field("shadow", mismatch.shadow)
+                .field("mismatches", mismatch.mismatches)
+                .field("evaluated", mismatch.evaluated)
+                .sensitive("text", judge)
+                .emit();
+        }
+    }
+
+    /// 버퍼가 마지막 알림 이후 바뀌었으면 콜백 호출 (디버그 오버레이가 꺼져 있으면 무시)
+    ///
+    /// 변환 후보/신뢰도는 이 시점에 계산하고, 콜백은 버퍼 잠금 없이 호출
+    fn notify_buffer_change(&self) {
+        if !self.is_debug_overlay() {
+            return;
+        }
+        let buffer = lock_or_recover(&self.buffer).get().to_string();
+        {
+            let mut last = lock_or_recover(&self.last_notified_buffer);
+            if *last == buffer {
+                return;
+            }
+            last.clone_from(&buffer);
+        }
+        let preview = self
+            .judge_snapshot()
+            .map(|keys| self.convert_keys(&keys))
+            .unwrap_or_default();
+        let confidence = self.buffer_confidence();
+        if let Some(callback) = lock_or_recover(&self.on_buffer_change).as_ref() {
+            callback(buffer, preview, confidence);
+        }
+    }
+
+    /// 이벤트 탭 실행 상태
+    pub fn tap_status(&self) -> TapStatus {
+        *lock_or_recover(&self.tap_status)
+    }
+
+    /// 비활성화된 탭의 재활성화/재생성이 진행 중인지 (진단 표시용)
+    pub fn is_tap_recovering(&self) -> bool {
+        self.needs_reenable.load(Ordering::Acquire) || self.needs_recreate.load(Ordering::Acquire)
+    }
+
+    /// 상태 전이 적용, 상태가 바뀌었으면 콜백 호출 후 true
+    pub fn set_permission_probe(&self, probe: fn() -> bool) {
+        *lock_or_recover(&self.permission_probe) = probe;
+    }
+
+    /// 실행 중 권한 확인 1회: 회수되면 입력 상태를 버리고 경고, 재허용되면 탭 재생성 요청
+    fn watch_permission(&self) {
+        let has_permission = (*lock_or_recover(&self.permission_probe))();
+        let Some(event) = self.tap_status().permission_event(has_permission) else {
+            return;
+        };
+        if !self.apply_tap_event(event) {
+            return;
+        }
+        match event {
+            TapEvent::PermissionRevoked => {
+                log::error!("Accessibility 권한 회수 감지, 재허용 대기");
+                self.reset_input_state();
+            }
+            _ => {
+                log::warn!("Accessibility 권한 재허용 감지, 이벤트 탭 재생성");
+                self.request_recreate();
+            }
+        }
+    }
+
+    fn apply_tap_event(&self, event: TapEvent) -> bool {
+        let next = {
+            let mut status = lock_or_recover(&self.tap_status);
+            let next = status.next(event);
+            if next == *status {
+                return false;
+            }
+            *status = next;
+            next
+        };
+        if let Some(callback) = lock_or_recover(&self.on_tap_status).as_ref() {
+            callback(next);
+        }
+        true
+    }
+
+    /// Koing 활성화/비활성화 (비활성화 시 버퍼를 비우고 대기 중인 타이머 취소)
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Release);
+        if !enabled {
+            self.reset_input_state();
+            self.reset_feedback();
+        }
+    }
+
+    /// 토글 단축키 처리: 활성화 상태를 뒤집고 콜백 호출, 새 상태 반환
+    fn toggle_enabled(&self) -> bool {
+        let enabled = !self.is_enabled();
+        self.set_enabled(enabled);
+        log::info!(
+            "단축키로 Koing {}",
+            if enabled { "활성화" } else { "비활성화" }
+        );
+        if let Some(callback) = lock_or_recover(&self.on_toggle).as_ref() {
+            callback(enabled);
+        }
+        enabled
+    }
+
+    /// Koing 활성화 여부
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Acquire)
+    }
+
+    /// 자동 감지 활성화/비활성화
+    pub fn set_auto_detect_enabled(&self, enabled: bool) {
+        if let Ok(mut detector) = self.auto_detector.lock() {
+            detector.set_enabled(enabled);
+        }
+    }
+
+    /// 자동 감지 활성화 여부
+    pub fn is_auto_detect_enabled(&self) -> bool {
+        self.auto_detector
+            .lock()
+            .map(|d| d.is_enabled())
+            .unwrap_or(false)
+    }
+
+    /// 앱별 프로필 매핑/파라미터 설정
+    pub fn set_app_profiles(
+        &self,
+        apps: BTreeMap<String, AppProfile>,
+        params: BTreeMap<AppProfile, ProfileParams>,
+    ) {
+        let mut table = lock_or_recover(&self.app_profiles);
+        table.apps = apps;
+        table.params = params;
+    }
+
+    /// 포커스 앱의 프로필을 감지기 threshold/min_length에 적용
+    pub fn apply_profile_for_app(&self, bundle_id: &str) -> AppProfile {
+        let (profile, params) = {
+            let mut table = lock_or_recover(&self.app_profiles);
+            table.active_app = Some(bundle_id.to_string());
+            let profile = table.profile_for(Some(bundle_id));
+            (profile, table.params_for(profile))
+        };
+
+        self.apply_profile_params(params);
+        log::debug!("앱 프로필 적용: {} → {:?}", bundle_id, profile);
+        profile
+    }
+
+    /// 변환 민감도 설정 (앱 매핑이 없는 앱에 적용) — 현재 포커스 앱 기준으로 즉시 반영
+    pub fn set_sensitivity(&self, sensitivity: AppProfile) -> AppProfile {
+        let (profile, params) = {
+            let mut table = lock_or_recover(&self.app_profiles);
+            table.fallback = sensitivity;
+            let profile = table.profile_for(table.active_app.as_deref());
+            (profile, table.params_for(profile))
+        };
+
+        self.apply_profile_params(params);
+        profile
+    }
+
+    /// 변환 민감도 읽기
+    pub fn get_sensitivity(&self) -> AppProfile {
+        lock_or_recover(&self.app_profiles).fallback
+    }
+
+    /// 프로필 파라미터에 세션 피드백 조정값을 더해 감지기에 적용
+    fn apply_profile_params(&self, params: ProfileParams) {
+        let offset = self.feedback_offset();
+        lock_or_recover(&self.auto_detector).set_sensitivity(
+            (params.threshold + offset).clamp(0.0, 100.0),
+            (params.realtime_threshold + offset).clamp(0.0, 100.0),
+            params.min_length,
+        );
+    }
+
+    /// 현재 포커스 앱 프로필을 다시 적용 (피드백 조정값 변경 시)
+    fn reapply_active_profile(&self) {
+        let params = {
+            let table = lock_or_recover(&self.app_profiles);
+            table.params_for(table.profile_for(table.active_app.as_deref()))
+        };
+        self.apply_profile_params(params);
+    }
+
+    /// 세션 피드백에 의한 신뢰도 기준 조정값 (±5 이내)
+    pub fn feedback_offset(&self) -> f32 {
+        lock_or_recover(&self.feedback).threshold_offset()
+    }
+
+    /// 변환 결과를 피드백에 기록하고 조정값이 바뀌면 감지기 기준 갱신
+    fn record_feedback(&self, outcome: ConversionOutcome) {
+        let (before, after) = {
+            let mut feedback = lock_or_recover(&self.feedback);
+            let before = feedback.threshold_offset();
+            feedback.record(outcome);
+            (before, feedback.threshold_offset())
+        };
+        if before != after {
+            log::debug!(
+                "변환 피드백: 신뢰도 기준 조정 {:+.1} → {:+.1}",
+                before,
+                after
+            );
+            self.reapply_active_profile();
+        }
+    }
+
+    /// 세션 피드백 초기화 (기본 기준으로 복귀)
+    pub fn reset_feedback(&self) {
+        let before = {
+            let mut feedback = lock_or_recover(&self.feedback);
+            let before = feedback.threshold_offset();
+            feedback.reset();
+            before
+        };
+        if before != 0.0 {
+            self.reapply_active_profile();
+        }
+    }
+
+    /// 포커스 판별 함수 교체 (기본: AX role 조회)
+    pub fn set_focus_probe(&self, probe: fn() -> Option<bool>) {
+        *lock_or_recover(&self.focus_probe) = probe;
+        *lock_or_recover(&self.focus_check) = None;
+    }
+
+    /// 포커스 요소 기준 변환 허용 여부 (변환 트리거 직전에만 호출)
+    ///
+    /// 비텍스트 요소로 확인된 경우에만 false — 판별 불가 시 허용.
+    /// AX 조회 비용 때문에 결과를 짧게 캐싱합니다.
+    fn focus_allows_conversion(&self) -> bool {
+        self.focus_allows_conversion_at(Instant::now())
+    }
+
+    fn focus_allows_conversion_at(&self, now: Instant) -> bool {
+        if let Some(check) = *lock_or_recover(&self.focus_check) {
+            if now.saturating_duration_since(check.checked_at) < FOCUS_CHECK_TTL {
+                return check.allowed;
+            }
+        }
+
+        let probe = *lock_or_recover(&self.focus_probe);
+        let allowed = probe().unwrap_or(true);
+        *lock_or_recover(&self.focus_check) = Some(FocusCheck {
+            checked_at: now,
+            allowed,
+        });
+        allowed
+    }
+
+    /// 변환 후 입력 소스 전환을 건너뛸 앱 목록 설정
+    pub fn set_no_switch_bundle_ids(&self, bundle_ids: Vec<String>) {
+        *lock_or_recover(&self.no_switch_bundle_ids) = bundle_ids;
+        *lock_or_recover(&self.switch_app_check) = None;
+    }
+
+    /// 최전면 앱 조회 함수 교체 (기본: NSWorkspace 조회)
+    pub fn set_frontmost_app_probe(&self, probe: fn() -> Option<String>) {
+        *lock_or_recover(&self.frontmost_app_probe) = probe;
+        *lock_or_recover(&self.switch_app_check) = None;
+    }
+
+    /// 포커스 앱 기준 한글 입력 소스 전환 허용 여부 (결과를 짧게 캐싱)
+    pub fn switch_allowed_for_focused_app(&self) -> bool {
+        self.switch_allowed_at(Instant::now())
+    }
+
+    fn switch_allowed_at(&self, now: Instant) -> bool {
+        let no_switch = lock_or_recover(&self.no_switch_bundle_ids);
+        if no_switch.is_empty() {
+            return true;
+        }
+        if let Some(check) = *lock_or_recover(&self.switch_app_check) {
+            if now.saturating_duration_since(check.checked_at) < SWITCH_APP_CHECK_TTL {
+                return check.allowed;
+            }
+        }
+
+        let probe = *lock_or_recover(&self.frontmost_app_probe);
+        let bundle_id = probe();
+        let allowed = switch_allowed_for(bundle_id.as_deref(), &no_switch);
+        if !allowed {
+            log::debug!("입력 소스 전환 제외 앱: {:?}", bundle_id);
+        }
+        *lock_or_recover(&self.switch_app_check) = Some(FocusCheck {
+            checked_at: now,
+            allowed,
+        });
+        allowed
+    }
+
+    /// 비텍스트 요소에 포커스가 있으면 버퍼를 비우고 true
+    fn suppress_for_non_text_focus(&self) -> bool {
+        if self.focus_allows_conversion() {
+            return false;
+        }
+        log::debug!("변환 억제: 포커스 요소가 텍스트 입력이 아님");
+        lock_or_recover(&self.buffer).clear();
+        lock_or_recover(&self.manual_buffer).clear();
+        true
+    }
+
+    /// 입력 소스 접근 함수
+    fn input_source(&self) -> InputSourceAccess {
+        *lock_or_recover(&self.input_source)
+    }
+
+    /// 입력 소스 상태별 처리 정책 설정
+    pub fn set_input_source_policy(&self, policy: InputSourcePolicy) {
+        *lock_or_recover(&self.input_source_policy) = policy;
+    }
+
+    /// 입력 소스 상태별 처리 정책
+    pub fn input_source_policy(&self) -> InputSourcePolicy {
+        *lock_or_recover(&self.input_source_policy)
+    }
+
+    /// 한글 입력 소스로 전환 (비활성 상태이면 입력 소스를 건드리지 않고 false)
+    fn switch_to_korean(&self) -> bool {
+        if !self.is_enabled() {
+            log::debug!("한글 전환 생략: Koing 비활성");
+            return false;
+        }
+        (self.input_source().switch_to_korean)();
+        true
+    }
+
+    /// soft switch 설정 (변환 거부 시 입력 소스만 한글로 전환)
+    pub fn set_soft_switch_on_reject(&self, enabled: bool) {
+        self.soft_switch_on_reject.store(enabled, Ordering::Relaxed);
+    }
+
+    /// 양방향 모드 설정 (한글 모드에서 친 영단어를 영문으로 역변환)
+    pub fn set_reverse_to_english(&self, enabled: bool) {
+        self.reverse_to_english.store(enabled, Ordering::Relaxed);
+        lock_or_recover(&self.korean_keys).clear();
+    }
+
+    /// 한글 입력 소스에서 입력된 키 기록 (양방향 모드일 때만)
+    fn push_korean_key(&self, c: char) {
+        if self.reverse_to_english.load(Ordering::Relaxed) {
+            lock_or_recover(&self.korean_keys).push(c);
+        }
+    }
+
+    /// 한글 모드 키를 꺼내 역변환 후보(영단어)이면 반환
+    fn take_reverse_candidate(&self) -> Option<String> {
+        let keys = {
+            let mut korean_keys = lock_or_recover(&self.korean_keys);
+            let keys = korean_keys.get().to_string();
+            korean_keys.clear();
+            keys
+        };
+        if !self.reverse_to_english.load(Ordering::Relaxed) || !is_reverse_candidate(&keys) {
+            return None;
+        }
+        lock_or_recover(&self.auto_detector)
+            .is_blocked_english_word(&keys)
+            .then_some(keys)
+    }
+
+    /// 실시간 모드 활성화/비활성화
+    pub fn set_realtime_mode(&self, enabled: bool) {
+        self.realtime_mode.store(enabled, Ordering::Relaxed);
+    }
+
+    /// 실시간 모드 활성화 여부
+    pub fn is_realtime_mode(&self) -> bool {
+        self.realtime_mode.load(Ordering::Relaxed)
+    }
+
+    /// 변환 감지 debounce 시간 설정
+    pub fn set_debounce_ms(&self, ms: u64) {
+        self.debounce_ms.store(ms, Ordering::Relaxed);
+    }
+
+    /// 변환 감지 debounce 시간 읽기
+    pub fn get_debounce_ms(&self) -> u64 {
+        self.debounce_ms.load(Ordering::Relaxed)
+    }
+
+    /// 통계 복원 (앱 시작 시 저장된 통계)
+    pub fn set_stats(&self, stats: ConversionStats) {
+        *lock_or_recover(&self.stats) = stats;
+    }
+
+    /// 현재 시간대 버킷에 통계 이벤트 기록
+    pub fn record_stat(&self, kind: StatKind) {
+        self.record_stat_at(kind, current_local_hour());
+    }
+
+    fn record_stat_at(&self, kind: StatKind, hour: usize) {
+        lock_or_recover(&self.stats).record(kind, hour);
+        self.record_feedback(match kind {
+            StatKind::Conversion => ConversionOutcome::Kept,
+            StatKind::Undo => ConversionOutcome::Undone,
+            StatKind::Rejection => ConversionOutcome::Rejected,
+        });
+    }
+
+    /// 누적 통계 스냅샷
+    pub fn stats_snapshot(&self) -> ConversionStats {
+        lock_or_recover(&self.stats).clone()
+    }
+
+    /// 누적 통계를 JSON 파일로 내보내기
+    pub fn export_stats(&self, path: &std::path::Path) -> Result<(), String> {
+        crate::stats::export_stats(path, &self.stats_snapshot())
+    }
+
+    /// 입력 속도 기반 debounce 사용 설정
+    pub fn set_adaptive_debounce(&self, enabled: bool) {
+        self.adaptive_debounce.store(enabled, Ordering::Relaxed);
+    }
+
+    /// 입력 속도 기반 debounce 사용 여부
+    pub fn is_adaptive_debounce(&self) -> bool {
+        self.adaptive_debounce.load(Ordering::Relaxed)
+    }
+
+    /// 최근 키 간격 이동평균 (ms, 0이면 측정 전)
+    pub fn key_interval_avg_ms(&self) -> u64 {
+        self.key_interval_avg_ms.load(Ordering::Relaxed)
+    }
+
+    /// 실제 적용할 debounce 시간 (adaptive 모드에서 측정값이 있으면 추정값)
+    pub fn effective_debounce_ms(&self) -> u64 {
+        if self.is_adaptive_debounce() {
+            if let Some(ms) = adaptive_debounce_ms(self.key_interval_avg_ms()) {
+                return ms;
+            }
+        }
+        self.get_debounce_ms()
+    }
+
+    /// 키 입력 시각 기록 및 키 간격 이동평균 갱신
+    fn record_key_time(&self, now_ms: u64) {
+        let prev = self.last_key_time.swap(now_ms, Ordering::AcqRel);
+        if prev == 0 {
+            return;
+        }
+        let interval = now_ms.saturating_sub(prev);
+        let _ = self
+            .key_interval_avg_ms
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |avg| {
+                Some(update_key_interval_avg(avg, interval))
+            });
+    }
+
+    /// 느린 변환 대기 시간 설정
+    pub fn set_slow_debounce_ms(&self, ms: u64) {
+        self.slow_debounce_ms.store(ms, Ordering::Relaxed);
+    }
+
+    /// 느린 변환 대기 시간 읽기
+    pub fn get_slow_debounce_ms(&self) -> u64 {
+        self.slow_debounce_ms.load(Ordering::Relaxed)
+    }
+
+    /// 2단계 변환 설정 (false면 1단계 실패가 최종 거부, 대기 중인 2단계도 건너뜀)
+    pub fn set_two_stage_conversion(&self, enabled: bool) {
+        self.two_stage_conversion.store(enabled, Ordering::Relaxed);
+    }
+
+    /// 2단계 변환 사용 여부
+    pub fn is_two_stage_conversion(&self) -> bool {
+        self.two_stage_conversion.load(Ordering::Relaxed)
+    }
+
+    /// 한글 자판 전환 지연 시간 설정
+    pub fn set_switch_delay_ms(&self, ms: u64) {
+        self.switch_delay_ms.store(ms, Ordering::Relaxed);
+    }
+
+    /// 한글 자판 전환 지연 시간 읽기
+    pub fn get_switch_delay_ms(&self) -> u64 {
+        self.switch_delay_ms.load(Ordering::Relaxed)
+    }
+
+    /// Debounce 타이머에 명령 전송 (Condvar로 즉시 깨움)
+    fn send_debounce_command(&self, cmd: DebounceCommand) {
+        let (ref mutex, ref cvar) = *self.debounce_cv;
+        if let Ok(mut state) = mutex.lock() {
+            state.command = Some(cmd);
+            cvar.notify_one();
         }
     }
 
@@ -409,15 +1743,30 @@ impl EventTapState {
         self.needs_reenable.store(true, Ordering::Release);
     }
 
+    /// 재생성 필요 플래그 설정 후 RunLoop을 깨워 즉시 처리하게 함
+    fn request_recreate(&self) {
+        self.needs_recreate.store(true, Ordering::Release);
+        let rl = self.run_loop.load(Ordering::Acquire);
+        if !rl.is_null() {
+            // run_in_mode를 반환시켜 RunLoop 스레드가 플래그를 확인하게 함
+            unsafe {
+                CFRunLoopStop(rl);
+            }
+        }
+    }
+
     /// 재시도 + 검증 로직이 포함된 이벤트 탭 재활성화
     /// Sonoma/Sequoia에서는 더 많은 재시도와 딜레이를 사용
     /// 재시도 전 main RunLoop을 사전 깨워 성공 확률 증가
-    fn reenable_tap_with_retry(&self) {
+    /// 반환: 재활성화 성공 여부 (false면 재생성 필요)
+    fn reenable_tap_with_retry(&self) -> bool {
         use crate::platform::os_version::{is_sequoia_or_later, is_sonoma_or_later};
 
         let port = self.tap_port.load(Ordering::Acquire);
         if port.is_null() {
-            return;
+            // 탭 재생성 중 — 새 탭은 생성 직후 활성화되므로 재활성화 불필요
+            self.needs_reenable.store(false, Ordering::Release);
+            return true;
         }
 
         // main RunLoop 사전 깨우기 — 유휴 상태에서 깨어나는 시간 단축
@@ -452,23 +1801,42 @@ impl EventTapState {
             thread::sleep(Duration::from_millis(10));
             let enabled = unsafe { CGEventTapIsEnabled(port) };
             if enabled {
-                log::warn!(
-                    "이벤트 탭 재활성화 성공 (시도 {}/{})",
-                    attempt + 1,
-                    max_retries
-                );
+                LogEvent::new(log::Level::Warn, "tap_reenable", "이벤트 탭 재활성화 성공")
+                    .field("result", "success")
+                    .field("attempt", attempt + 1)
+                    .field("max_retries", max_retries)
+                    .emit();
                 self.needs_reenable.store(false, Ordering::Release);
-                return;
+                return true;
             }
 
-            log::warn!(
-                "이벤트 탭 재활성화 실패, 재시도 {}/{}",
-                attempt + 1,
-                max_retries
-            );
+            LogEvent::new(
+                log::Level::Warn,
+                "tap_reenable",
+                "이벤트 탭 재활성화 실패, 재시도",
+            )
+            .field("result", "retry")
+            .field("attempt", attempt + 1)
+            .field("max_retries", max_retries)
+            .emit();
         }
 
-        log::error!("이벤트 탭 재활성화 최종 실패 ({}회 시도)", max_retries);
+        LogEvent::new(
+            log::Level::Error,
+            "tap_reenable",
+            "이벤트 탭 재활성화 최종 실패",
+        )
+        .field("result", "failed")
+        .field("max_retries", max_retries)
+        .emit();
+        false
+    }
+
+    /// 마지막 변환 결과 (이력은 유지, Undo 후에는 None)
+    pub fn last_converted(&self) -> Option<String> {
+        lock_or_recover(&self.conversion_history)
+            .as_ref()
+            .map(|history| history.converted.clone())
     }
 
     /// 변환 이력 가져오기 (Undo용)
@@ -485,64 +1853,287 @@ impl EventTapState {
         self.run_loop.store(rl, Ordering::Release);
     }
 
-    /// 버퍼 초기화 및 타이머 취소 (설정 윈도우 등 외부에서 사용)
-    /// 합성 이벤트가 의도하지 않은 윈도우에 전송되는 것을 방지
-    pub fn cancel_pending_conversion(&self) {
+    /// 입력 상태 초기화: 버퍼 비우기 + debounce/전환 타이머 취소 + 직전 변환 플래그 리셋
+    ///
+    /// 포커스 변경/앱 전환/명시적 취소 등 입력 위치가 바뀌었을 수 있을 때 사용 (외부에서도 호출 가능)
+    /// 합성 이벤트가 의도하지 않은 위치에 전송되는 것을 방지
+    pub fn reset_input_state(&self) {
+        self.conversion_just_triggered
+            .store(false, Ordering::Release);
         lock_or_recover(&self.buffer).clear();
+        lock_or_recover(&self.manual_buffer).clear();
         lock_or_recover(&self.pending_buffer).clear();
+        lock_or_recover(&self.korean_keys).clear();
         self.send_debounce_command(DebounceCommand::Cancel);
         self.send_switch_command(SwitchCommand::Cancel);
     }
 
-    /// 이벤트 탭 종료 — 타이머 스레드 정지 + CFRunLoop 정지
-    pub fn stop(&self) {
-        self.running.store(false, Ordering::Release);
-        self.send_debounce_command(DebounceCommand::Shutdown);
-        self.send_switch_command(SwitchCommand::Shutdown);
-        let rl = self.run_loop.load(Ordering::Acquire);
-        if !rl.is_null() {
-            unsafe {
-                CFRunLoopStop(rl);
-            }
+    /// 이모지 피커/후보 창 보류 처리, 보류 중이면 버퍼/타이머를 비우고 true (이벤트는 그대로 통과)
+    fn hold_for_candidate_window(&self, keycode: u16, flags: CGEventFlags, now: Instant) -> bool {
+        if !lock_or_recover(&self.candidate_hold).on_key(keycode, flags, now) {
+            return false;
         }
+        self.reset_input_state();
+        true
     }
 
-    fn push_pending_char(&self, c: char) {
-        lock_or_recover(&self.pending_buffer).push(c);
+    /// 마우스 클릭 시 버퍼 초기화 및 타이머 취소 (커서가 옮겨졌을 수 있음)
+    /// 텍스트 교체 중이면 무시, 초기화했으면 true
+    fn reset_on_mouse_down(&self) -> bool {
+        if self.is_replacing.load(Ordering::Acquire) {
+            return false;
+        }
+        lock_or_recover(&self.candidate_hold).release();
+        self.reset_input_state();
+        true
     }
 
-    fn pop_pending_char(&self) -> Option<char> {
-        lock_or_recover(&self.pending_buffer).pop()
+    /// 사용자 치환 규칙 설정 (설정 윈도우에서 편집 시 즉시 반영)
+    pub fn set_expansions(&self, rules: &[(String, String)]) {
+        *lock_or_recover(&self.expansions) = ExpansionTable::new(rules);
     }
 
-    fn has_pending_buffer(&self) -> bool {
-        !lock_or_recover(&self.pending_buffer).is_empty()
+    /// 실시간 변환 판정용 변환기 교체 (테스트/확장용)
+    pub fn set_converter(&self, converter: Arc<dyn Converter>) {
+        *lock_or_recover(&self.converter) = converter;
     }
 
-    fn resolve_pending_buffer(&self, input_source: InputSourceState) {
-        match input_source {
-            InputSourceState::English => {
-                let pending = {
-                    let mut pending = lock_or_recover(&self.pending_buffer);
-                    let staged = pending.get().to_string();
-                    pending.clear();
-                    staged
-                };
-                if pending.is_empty() {
+    /// 현재 변환기로 키 시퀀스 변환 (변환 중에는 잠금을 잡지 않음)
+    fn convert_keys(&self, keys: &str) -> String {
+        let converter = Arc::clone(&lock_or_recover(&self.converter));
+        converter.convert(keys)
+    }
+
+    /// 자동 변환 판정기 교체 (테스트/확장용, 감도/예외어 설정은 내장 감지기에만 반영)
+    pub fn set_gate(&self, gate: Arc<dyn ConversionGate>) {
+        *lock_or_recover(&self.gate) = Some(gate);
+    }
+
+    /// 느린 변환 구조 검사기 교체 (테스트/확장용)
+    pub fn set_validator(&self, validator: Arc<dyn ConversionValidator>) {
+        *lock_or_recover(&self.validator) = validator;
+    }
+
+    /// 현재 판정기로 판정 (주입된 판정기가 없으면 내장 감지기)
+    fn with_gate<R>(&self, f: impl FnOnce(&dyn ConversionGate) -> R) -> R {
+        let injected = lock_or_recover(&self.gate).clone();
+        match injected {
+            Some(gate) => f(gate.as_ref()),
+            None => f(&*lock_or_recover(&self.auto_detector)),
+        }
+    }
+
+    /// 실시간(1단계) 변환 판정 — 내장 감지기로 판정한 경우에만 shadow 평가
+    fn decide_realtime(&self, judge: &str) -> bool {
+        let injected = lock_or_recover(&self.gate).clone();
+        if let Some(gate) = injected {
+            return gate.should_convert_realtime(judge);
+        }
+        let detector = lock_or_recover(&self.auto_detector);
+        let should_convert = detector.should_convert_realtime(judge);
+        self.shadow_evaluate(&detector, judge, should_convert);
+        should_convert
+    }
+
+    /// 치환 규칙 적용 (일치하는 트리거가 없으면 None)
+    pub fn expand<F>(&self, text: &str, convert_word: F) -> Option<String>
+    where
+        F: Fn(&str) -> String,
+    {
+        lock_or_recover(&self.expansions).expand(text, convert_word)
+    }
+
+    /// 현재 버퍼의 판정 대상(한글 키) 스냅샷 — 비었거나 URL 구간이면 None
+    ///
+    /// 버퍼 잠금은 복사하는 동안만 유지 (UI 스레드에서 호출)
+    pub fn judge_snapshot(&self) -> Option<String> {
+        let buffer = lock_or_recover(&self.buffer);
+        let (judge, _) = buffer.conversion_target();
+        (!judge.is_empty()).then(|| judge.to_string())
+    }
+
+    /// 현재 버퍼의 변환 신뢰도 (0-100, 판정 대상이 없으면 None)
+    pub fn buffer_confidence(&self) -> Option<f32> {
+        let judge = self.judge_snapshot()?;
+        Some(lock_or_recover(&self.auto_detector).get_confidence(&judge))
+    }
+
+    /// 경계 키코드 목록 설정 (Backspace/Space/Enter 등 별도 처리되는 키는 영향 없음)
+    pub fn set_reset_keycodes(&self, keycodes: Vec<u16>) {
+        *lock_or_recover(&self.reset_keycodes) = keycodes;
+    }
+
+    /// 버퍼를 비우는 경계 키인지
+    fn is_reset_keycode(&self, keycode: u16) -> bool {
+        lock_or_recover(&self.reset_keycodes).contains(&keycode)
+    }
+
+    /// 경계 키 입력 시 버퍼 초기화 및 타이머 취소, 초기화했으면 true
+    fn reset_on_boundary_key(&self, keycode: u16) -> bool {
+        if !self.is_reset_keycode(keycode) {
+            return false;
+        }
+        self.reset_input_state();
+        true
+    }
+
+    /// 문자 키 입력을 버퍼에 반영 (비ASCII 입력이면 변환 없이 버퍼/타이머 초기화)
+    /// 반환: 이후 영문 키 처리를 이어갈 문자 (Buffer일 때만)
+    fn apply_key_input(&self, input: KeyInput) -> Option<char> {
+        match input {
+            KeyInput::Buffer(c) => Some(c),
+            KeyInput::Reset => {
+                self.reset_input_state();
+                None
+            }
+            KeyInput::Ignore => None,
+        }
+    }
+
+    /// 자동 변환 요청 전달 (Space/Enter 처리를 위해 직후 플래그와 교체 대기 수 기록)
+    fn dispatch_auto_conversion(&self, content: String) {
+        self.conversion_just_triggered
+            .store(true, Ordering::Release);
+        // 교체 후 화면 텍스트가 달라지므로 수동 누적도 새로 시작
+        lock_or_recover(&self.manual_buffer).clear();
+        lock_or_recover(&self.newline_gate).conversion_requested(Instant::now());
+        if let Some(callback) = lock_or_recover(&self.on_convert).as_ref() {
+            callback(content, false);
+        }
+    }
+
+    /// Enter 입력 처리 결정 (교체 대기 중이면 보류)
+    fn enter_action(&self) -> EnterAction {
+        lock_or_recover(&self.newline_gate).on_enter(Instant::now())
+    }
+
+    /// 워커가 자동 변환 요청 count건 처리를 마쳤을 때 호출 (교체 성공 여부 무관)
+    /// 대기 중인 교체가 모두 끝났으면 보류한 Enter를 재전송
+    pub fn finish_auto_conversions(&self, count: usize) {
+        if count == 0 {
+            return;
+        }
+        let newlines = lock_or_recover(&self.newline_gate).conversions_finished(count as u64);
+        if newlines == 0 {
+            return;
+        }
+        let send = *lock_or_recover(&self.newline_sender);
+        for _ in 0..newlines {
+            send();
+        }
+    }
+
+    /// 이벤트 탭 종료 — 타이머 스레드 정지 + CFRunLoop 정지
+    pub fn stop(&self) {
+        self.running.store(false, Ordering::Release);
+        self.send_debounce_command(DebounceCommand::Shutdown);
+        self.send_switch_command(SwitchCommand::Shutdown);
+        let rl = self.run_loop.load(Ordering::Acquire);
+        if !rl.is_null() {
+            unsafe {
+                CFRunLoopStop(rl);
+            }
+        }
+    }
+
+    fn push_pending_char(&self, c: char) {
+        self.typed_keys.fetch_add(1, Ordering::AcqRel);
+        self.record_key_time(epoch_ms());
+        lock_or_recover(&self.pending_buffer).push(c);
+    }
+
+    /// 입력된 문자를 버퍼에 추가
+    fn push_typed_char(&self, c: char) {
+        self.typed_keys.fetch_add(1, Ordering::AcqRel);
+        self.record_key_time(epoch_ms());
+        lock_or_recover(&self.buffer).push(c);
+        lock_or_recover(&self.manual_buffer).push(c);
+    }
+
+    /// 워커가 화면에 원문으로 남긴 키를 버퍼 앞에 되돌림 (긴 구간 분할 변환의 뒷부분)
+    ///
+    /// 교체 중 입력된 키는 그 뒤에 이어지며, debounce를 다시 걸어 함께 판정
+    pub fn requeue_keys(&self, keys: &str) {
+        {
+            let mut buffer = lock_or_recover(&self.buffer);
+            let typed = buffer.get().to_string();
+            buffer.clear();
+            for c in keys.chars().chain(typed.chars()) {
+                buffer.push(c);
+            }
+        }
+        {
+            let mut manual_buffer = lock_or_recover(&self.manual_buffer);
+            let typed = manual_buffer.get().to_string();
+            manual_buffer.clear();
+            for c in keys.chars().chain(typed.chars()) {
+                manual_buffer.push(c);
+            }
+        }
+        if self.is_realtime_mode() {
+            self.send_debounce_command(DebounceCommand::Reset);
+        }
+        self.notify_buffer_change();
+    }
+
+    /// 수동 변환 대상 꺼내기: 공백을 포함해 누적된 여러 단어가 있으면 그 구간,
+    /// 없으면 자동 버퍼 (두 버퍼 모두 비움)
+    fn take_manual_conversion_target(&self) -> String {
+        let mut buffer = lock_or_recover(&self.buffer);
+        let content = lock_or_recover(&self.manual_buffer)
+            .take_multi_word()
+            .unwrap_or_else(|| buffer.get().to_string());
+        buffer.clear();
+        content
+    }
+
+    /// 한글 전환 타이머 시작 시점 스냅샷 (지금까지의 키 입력 횟수)
+    fn switch_guard_snapshot(&self) -> u64 {
+        self.typed_keys.load(Ordering::Acquire)
+    }
+
+    /// 타이머 만료 시 한글 전환을 진행해도 되는지 재검사
+    /// 타이머 시작 이후 키 입력이 없었고 버퍼가 비어 있어야 함 (Cancel 명령과의 race 방지)
+    fn switch_still_wanted(&self, armed_keys: u64) -> bool {
+        self.typed_keys.load(Ordering::Acquire) == armed_keys
+            && lock_or_recover(&self.buffer).is_empty()
+            && !self.has_pending_buffer()
+    }
+
+    fn pop_pending_char(&self) -> Option<char> {
+        lock_or_recover(&self.pending_buffer).pop()
+    }
+
+    fn has_pending_buffer(&self) -> bool {
+        !lock_or_recover(&self.pending_buffer).is_empty()
+    }
+
+    fn resolve_pending_buffer(&self, input_source: InputSourceState) {
+        match self.input_source_policy().handling_for(input_source) {
+            Some(KeyHandling::Convert | KeyHandling::BufferOnly) => {
+                let pending = {
+                    let mut pending = lock_or_recover(&self.pending_buffer);
+                    let staged = pending.get().to_string();
+                    pending.clear();
+                    staged
+                };
+                if pending.is_empty() {
                     return;
                 }
 
                 let mut buffer = lock_or_recover(&self.buffer);
+                let mut manual_buffer = lock_or_recover(&self.manual_buffer);
                 for c in pending.chars() {
                     buffer.push(c);
+                    manual_buffer.push(c);
                 }
             }
-            InputSourceState::NonEnglish | InputSourceState::Unknown => {
+            Some(KeyHandling::PassThrough) => {
                 lock_or_recover(&self.pending_buffer).clear();
-                if input_source == InputSourceState::NonEnglish {
-                    lock_or_recover(&self.buffer).clear();
-                }
+                lock_or_recover(&self.buffer).clear();
+                lock_or_recover(&self.manual_buffer).clear();
             }
+            None => lock_or_recover(&self.pending_buffer).clear(),
         }
     }
 }
@@ -552,7 +2143,10 @@ fn start_debounce_timer(state: Arc<EventTapState>) {
     let cv = Arc::clone(&state.debounce_cv);
     let state_for_timer = Arc::clone(&state);
 
-    thread::spawn(move || {
+    // 패닉 시 감시 스레드가 재시작 (대기 중이던 deadline은 초기화)
+    spawn_supervised("koing-debounce", RestartPolicy::default(), move || {
+        // 만료 시 변환을 요청하므로 타이머 지연이 곧 변환 지연
+        set_thread_qos(ThreadQos::UserInitiated);
         let (ref mutex, ref cvar) = *cv;
         let mut deadline: Option<Instant> = None;
         // 1단계(빠른 변환) 시도 후 실패했는지 추적
@@ -602,7 +2196,7 @@ fn start_debounce_timer(state: Arc<EventTapState>) {
                             state_for_timer.slow_debounce_ms.load(Ordering::Relaxed),
                         )
                     } else {
-                        Duration::from_millis(state_for_timer.debounce_ms.load(Ordering::Relaxed))
+                        Duration::from_millis(state_for_timer.effective_debounce_ms())
                     };
 
                     if elapsed >= target_duration {
@@ -633,36 +2227,48 @@ fn start_debounce_timer(state: Arc<EventTapState>) {
             }
 
             // 타이머 만료 — 변환 시도
-            if !fast_triggered {
-                // 1단계: 높은 confidence 변환 시도
-                if trigger_realtime_conversion(&state_for_timer) {
-                    deadline = None;
-                    fast_triggered = false;
-                } else {
-                    fast_triggered = true; // 1단계 실패, 2단계 대기
-                                           // deadline을 현재 시점으로 갱신 — slow_debounce_ms만큼 추가 대기
-                    deadline = Some(Instant::now());
-                }
-            } else {
-                // 2단계: 유효한 한글 구조이면 변환
-                trigger_slow_conversion(&state_for_timer);
-                deadline = None;
-                fast_triggered = false;
-            }
+            fast_triggered = on_debounce_expired(&state_for_timer, fast_triggered);
+            state_for_timer.notify_buffer_change();
+            // 2단계 대기: deadline을 현재 시점으로 갱신 — slow_debounce_ms만큼 추가 대기
+            deadline = fast_triggered.then(Instant::now);
         }
     });
 }
 
+/// debounce 만료 시 변환 단계 실행
+/// fast_triggered: 1단계 실패 후 2단계 대기 중이었는지
+/// 반환: 2단계 대기로 넘어가면 true
+fn on_debounce_expired(state: &EventTapState, fast_triggered: bool) -> bool {
+    let two_stage = state.is_two_stage_conversion();
+    if !fast_triggered {
+        // 1단계: 높은 confidence 변환 시도
+        if trigger_realtime_conversion(state) {
+            return false;
+        }
+        if two_stage {
+            return true; // 1단계 실패, 2단계 대기
+        }
+    } else if two_stage && trigger_slow_conversion(state) {
+        // 2단계: 유효한 한글 구조이면 변환
+        return false;
+    }
+    // 최종 거부 (2단계 실패 또는 2단계 비활성) 시 soft switch
+    trigger_soft_switch(state);
+    false
+}
+
 /// 한글 전환 타이머 스레드 시작 (Condvar 기반 — 정확한 타이밍)
 /// 자동 변환 후 switch_delay_ms간 추가 입력이 없으면 한글 입력 소스로 전환
 fn start_switch_timer(state: Arc<EventTapState>) {
     let cv = Arc::clone(&state.switch_cv);
     let state_for_timer = Arc::clone(&state);
 
-    thread::spawn(move || {
+    spawn_supervised("koing-switch", RestartPolicy::default(), move || {
+        set_thread_qos(ThreadQos::UserInitiated);
         let (ref mutex, ref cvar) = *cv;
         let mut deadline: Option<Instant> = None;
         let mut switch_fired = false;
+        let mut armed_keys = 0;
 
         loop {
             let mut guard = lock_or_recover(mutex);
@@ -674,6 +2280,7 @@ fn start_switch_timer(state: Arc<EventTapState>) {
                         SwitchCommand::Reset => {
                             deadline = Some(Instant::now());
                             switch_fired = false;
+                            armed_keys = state_for_timer.switch_guard_snapshot();
                         }
                         SwitchCommand::Cancel => {
                             deadline = None;
@@ -715,8 +2322,14 @@ fn start_switch_timer(state: Arc<EventTapState>) {
             }
 
             // 타이머 만료 — 한글 전환 (메인 스레드에서 실행)
+            // Cancel이 도착하기 전에 만료될 수 있으므로 전환 직전 상태를 재검사
             if !switch_fired {
-                switch_to_korean_on_main();
+                drop(guard);
+                if !state_for_timer.switch_still_wanted(armed_keys) {
+                    log::debug!("한글 전환 취소: 타이머 시작 후 키 입력 있음");
+                } else if state_for_timer.switch_allowed_for_focused_app() {
+                    state_for_timer.switch_to_korean();
+                }
                 switch_fired = true;
             }
             deadline = None;
@@ -724,6 +2337,44 @@ fn start_switch_timer(state: Arc<EventTapState>) {
     });
 }
 
+/// soft switch 최소 한글 키 수 (짧은 영단어/약어에서 전환 방지)
+const SOFT_SWITCH_MIN_LENGTH: usize = 4;
+/// soft switch 최소 한글 신뢰도
+const SOFT_SWITCH_MIN_CONFIDENCE: f32 = 60.0;
+
+/// 변환이 거부된 입력에 대해 입력 소스만 한글로 전환할지 판정
+fn should_soft_switch(detector: &AutoDetector, judge: &str) -> bool {
+    judge.chars().count() >= SOFT_SWITCH_MIN_LENGTH
+        && !detector.looks_like_english_word(judge)
+        && detector.get_confidence(judge) >= SOFT_SWITCH_MIN_CONFIDENCE
+}
+
+/// soft switch (2단계 변환 거부 후): 텍스트는 교체하지 않고 입력 소스만 한글로 전환
+fn trigger_soft_switch(state: &EventTapState) -> bool {
+    if !state.soft_switch_on_reject.load(Ordering::Relaxed)
+        || state.is_replacing.load(Ordering::Acquire)
+    {
+        return false;
+    }
+
+    {
+        let mut buffer = lock_or_recover(&state.buffer);
+        let (judge, _) = buffer.conversion_target();
+        if judge.is_empty() {
+            return false;
+        }
+        let detector = lock_or_recover(&state.auto_detector);
+        if !should_soft_switch(&detector, judge) || !state.switch_allowed_for_focused_app() {
+            return false;
+        }
+        log::debug!("변환 거부, 입력 소스만 한글로 전환: {}", judge);
+        // 거부된 입력은 이후 변환 대상에서 제외
+        buffer.clear();
+    }
+
+    state.switch_to_korean()
+}
+
 /// 실시간 변환 트리거 (1단계: 높은 confidence)
 /// 반환값: true이면 변환 성공, false이면 변환 조건 미충족
 fn trigger_realtime_conversion(state: &EventTapState) -> bool {
@@ -735,38 +2386,49 @@ fn trigger_realtime_conversion(state: &EventTapState) -> bool {
         return false;
     }
 
+    if lock_or_recover(&state.buffer).is_empty() || state.suppress_for_non_text_focus() {
+        return false;
+    }
+
     // 버퍼 검증 + 소비를 단일 lock 범위에서 수행하여
     // 검증과 소비 사이에 새 키 입력이 끼어드는 race condition 방지
     let buffer_content = {
         let mut buffer = lock_or_recover(&state.buffer);
-        if buffer.is_empty() {
-            return false;
-        }
-        let detector = lock_or_recover(&state.auto_detector);
-        if !detector.should_convert_realtime(buffer.get()) {
-            return false;
-        }
-        // 구조적 유효성 검사 — 실패 시 버퍼를 유지하여 Stage 2로 폴백
-        let content = buffer.get().to_string();
-        let converted = crate::core::converter::convert(&content);
-        if converted == content
-            || crate::detection::validator::has_incomplete_jamo(&converted)
-            || !crate::ngram::check_syllable_structure(&converted)
-            || converted.chars().count() <= 1
-        {
-            return false;
+        // 치환 규칙 트리거는 자동 감지 없이 변환 (영단어 트리거 포함)
+        let is_expansion = state
+            .expand(buffer.get(), |word| state.convert_keys(word))
+            .is_some();
+        if !is_expansion {
+            let (judge, _) = buffer.conversion_target();
+            // 한글 키가 부족하면 변환/스코어링 없이 거부
+            if !state.with_gate(|gate| gate.has_enough_keys(judge)) {
+                return false;
+            }
+            // 비한글 대문자는 판정 대상에서 빠지므로 원문 버퍼로 검사
+            if has_excessive_uppercase(buffer.get()) {
+                return false;
+            }
+            if !state.decide_realtime(judge) {
+                return false;
+            }
+            // 구조적 유효성 검사 — 실패 시 버퍼를 유지하여 Stage 2로 폴백
+            let converted = state.convert_keys(judge);
+            if converted == judge
+                || crate::detection::validator::has_incomplete_jamo(&converted)
+                || !crate::ngram::check_syllable_structure(&converted)
+                || converted.chars().count() <= 1
+            {
+                return false;
+            }
         }
 
+        // 교체 대상은 원문 버퍼 (비한글 키는 변환 시 그대로 통과)
+        let content = buffer.get().to_string();
         buffer.clear();
         content
     };
 
-    state
-        .conversion_just_triggered
-        .store(true, Ordering::SeqCst);
-    if let Some(callback) = lock_or_recover(&state.on_convert).as_ref() {
-        callback(buffer_content, false);
-    }
+    state.dispatch_auto_conversion(buffer_content);
     true
 }
 
@@ -780,58 +2442,96 @@ fn trigger_slow_conversion(state: &EventTapState) -> bool {
         return false;
     }
 
+    if lock_or_recover(&state.buffer).is_empty() || state.suppress_for_non_text_focus() {
+        return false;
+    }
+
     // 버퍼 검증 + 소비를 단일 lock 범위에서 수행하여
     // 검증과 소비 사이에 새 키 입력이 끼어드는 race condition 방지
     let buffer_content = {
         let mut buffer = lock_or_recover(&state.buffer);
-        if buffer.is_empty() {
-            return false;
-        }
-        let content = buffer.get().to_string();
-
-        // 한글로 변환
-        let converted = crate::core::converter::convert(&content);
-        if converted == content {
-            return false;
-        }
-
-        // 낱자모(미완성 자모) 포함 시 거부
-        if crate::detection::validator::has_incomplete_jamo(&converted) {
-            return false;
-        }
-
-        // 음절 구조 검사
-        if !crate::ngram::check_syllable_structure(&converted) {
+        let (judge, _) = buffer.conversion_target();
+        if !state.with_gate(|gate| gate.has_enough_keys(judge)) {
             return false;
         }
 
-        // 한 글자 변환은 오탐 방지
-        if converted.chars().count() <= 1 {
+        // 한글 전용 버퍼 기준 구조 검사 (낱자모/음절 구조/1글자)
+        // 점수 미달(빈도 휴리스틱)은 2단계가 구제하려는 입력이므로 통과
+        let validator = Arc::clone(&lock_or_recover(&state.validator));
+        if let Err(reason) = validator.check_structure(judge) {
+            log::debug!("느린 변환 스킵: {} ({})", reason, judge);
+            state.record_stat(StatKind::Rejection);
             return false;
         }
 
-        // 모든 검증 통과 — 버퍼 소비
+        // 모든 검증 통과 — 원문 버퍼 소비
+        let content = buffer.get().to_string();
         buffer.clear();
         content
     };
 
-    state
-        .conversion_just_triggered
-        .store(true, Ordering::Release);
-    if let Some(callback) = lock_or_recover(&state.on_convert).as_ref() {
-        callback(buffer_content, false);
-    }
+    state.dispatch_auto_conversion(buffer_content);
     true
 }
 
+/// 재활성화 최종 실패 후 탭 재생성이 필요한지 판단
+/// 종료 중에는 재생성하지 않음
+fn should_recreate_after_reenable(reenabled: bool, running: bool) -> bool {
+    !reenabled && running
+}
+
+/// 이벤트 탭 재생성 백오프 상태
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct RecreateBackoff {
+    /// 연속 재생성 실패 횟수
+    failures: u32,
+    /// 마지막 재생성 시도 시간 (epoch ms, 0이면 시도 없음)
+    last_attempt_ms: u64,
+}
+
+impl RecreateBackoff {
+    /// 다음 재생성까지 대기 시간: 2s, 4s, 8s, ... 최대 60s
+    /// 성공 직후에도 기본 간격을 두어 재생성 루프를 방지
+    fn delay_ms(&self) -> u64 {
+        RECREATE_BASE_BACKOFF_MS
+            .saturating_mul(1u64 << self.failures.min(16))
+            .min(RECREATE_MAX_BACKOFF_MS)
+    }
+
+    /// 지금 재생성을 시도해도 되는지
+    fn is_ready(&self, now_ms: u64) -> bool {
+        self.last_attempt_ms == 0 || now_ms.saturating_sub(self.last_attempt_ms) >= self.delay_ms()
+    }
+
+    fn record_success(&mut self, now_ms: u64) {
+        self.failures = 0;
+        self.last_attempt_ms = now_ms;
+    }
+
+    fn record_failure(&mut self, now_ms: u64) {
+        self.failures = self.failures.saturating_add(1);
+        self.last_attempt_ms = now_ms;
+    }
+}
+
 /// 재활성화 감시 스레드 시작
 /// needs_reenable 플래그를 폴링하여 재활성화 수행
+/// 재활성화 최종 실패 시 탭 재생성을 요청
 fn start_reenable_watcher(state: Arc<EventTapState>) {
     let state_for_watcher = Arc::clone(&state);
     thread::spawn(move || {
+        set_thread_qos(ThreadQos::Utility);
         while state_for_watcher.running.load(Ordering::Acquire) {
             if state_for_watcher.needs_reenable.load(Ordering::Acquire) {
-                state_for_watcher.reenable_tap_with_retry();
+                let reenabled = state_for_watcher.reenable_tap_with_retry();
+                let running = state_for_watcher.running.load(Ordering::Acquire);
+                if should_recreate_after_reenable(reenabled, running) {
+                    // 재활성화로 복구 불가 — RunLoop 스레드에 탭 재생성 위임
+                    state_for_watcher
+                        .needs_reenable
+                        .store(false, Ordering::Release);
+                    state_for_watcher.request_recreate();
+                }
             }
             thread::sleep(Duration::from_millis(50));
         }
@@ -844,12 +2544,18 @@ fn start_reenable_watcher(state: Arc<EventTapState>) {
 fn start_health_monitor(state: Arc<EventTapState>) {
     let state_for_monitor = Arc::clone(&state);
     thread::spawn(move || {
+        set_thread_qos(ThreadQos::Utility);
         // 초기 15초 대기 (앱 시작 직후 이벤트 없는 것은 정상)
         thread::sleep(Duration::from_secs(15));
 
         while state_for_monitor.running.load(Ordering::Acquire) {
             thread::sleep(Duration::from_secs(15));
 
+            // 권한 회수 중에는 재활성화해도 소용없음 — 권한 감시 스레드가 재생성
+            if state_for_monitor.tap_status() == TapStatus::PermissionRevoked {
+                continue;
+            }
+
             let last = state_for_monitor.last_event_time.load(Ordering::Acquire);
             if last == 0 {
                 // 아직 이벤트를 한 번도 받지 못함 — 스킵
@@ -891,9 +2597,29 @@ fn start_health_monitor(state: Arc<EventTapState>) {
     });
 }
 
+/// 권한 감시 스레드 시작
+/// 실행 중 Accessibility 권한이 회수되면 탭이 조용히 멈추므로 주기적으로 확인해
+/// 메뉴바 경고를 띄우고, 재허용되면 탭을 재생성
+fn start_permission_watcher(state: Arc<EventTapState>) {
+    thread::spawn(move || {
+        set_thread_qos(ThreadQos::Utility);
+        while state.running.load(Ordering::Acquire) {
+            thread::sleep(PERMISSION_WATCH_INTERVAL);
+            state.watch_permission();
+        }
+    });
+}
+
 /// 이벤트 탭 시작
 /// 반환: 성공 시 EventTapState의 Arc, 실패 시 에러 메시지
 pub fn start_event_tap(state: Arc<EventTapState>) -> Result<(), String> {
+    // 모든 키 입력이 이 스레드의 RunLoop 콜백을 거치므로 가장 높은 QoS
+    set_thread_qos(ThreadQos::UserInteractive);
+    // 탭 생성이 실패하면 보조 스레드 없이 반환 (권한 복구 후 재시도 가능)
+    let current_loop = CFRunLoop::get_current();
+    let mut installed = Some(install_tap(&state, &current_loop)?);
+    state.apply_tap_event(TapEvent::Started);
+
     // Debounce 타이머 시작
     start_debounce_timer(Arc::clone(&state));
     // 한글 전환 타이머 시작
@@ -902,43 +2628,146 @@ pub fn start_event_tap(state: Arc<EventTapState>) -> Result<(), String> {
     start_reenable_watcher(Arc::clone(&state));
     // 헬스 모니터링 스레드 시작
     start_health_monitor(Arc::clone(&state));
+    // 권한 감시 스레드 시작
+    start_permission_watcher(Arc::clone(&state));
+
+    // CFRunLoop 참조 저장 (stop()/재생성 요청에서 사용)
+    use core_foundation::base::TCFType;
+    state.set_run_loop(current_loop.as_concrete_TypeRef() as *mut std::ffi::c_void);
+
+    // 런루프 실행 (stop() 호출 시 종료됨)
+    // 짧은 단위로 실행하며 사이사이 재생성 요청을 처리
+    while state.running.load(Ordering::Acquire) {
+        CFRunLoop::run_in_mode(unsafe { kCFRunLoopDefaultMode }, RUN_LOOP_SLICE, false);
+
+        if state.needs_recreate.load(Ordering::Acquire) {
+            recreate_tap(&state, &current_loop, &mut installed);
+        }
+    }
 
-    let state_clone = Arc::clone(&state);
+    Ok(())
+}
+
+/// 이벤트 탭 실행 (종료 시까지 블록)
+///
+/// 시작 실패 시 권한 안내 상태로 전환하고, 권한 획득을 폴링하여 자동으로 재시작합니다.
+pub fn run_event_tap(state: Arc<EventTapState>) {
+    loop {
+        let Err(e) = start_event_tap(Arc::clone(&state)) else {
+            return;
+        };
+        if state.apply_tap_event(TapEvent::StartFailed) {
+            log::error!("Event tap 시작 실패, 권한 대기: {}", e);
+        } else {
+            log::debug!("Event tap 재시작 실패: {}", e);
+        }
+
+        loop {
+            thread::sleep(PERMISSION_POLL_INTERVAL);
+            match recovery_step(
+                state.running.load(Ordering::Acquire),
+                check_accessibility_permission(),
+            ) {
+                RecoveryStep::Wait => continue,
+                RecoveryStep::Retry => break,
+                RecoveryStep::Stop => return,
+            }
+        }
+        state.apply_tap_event(TapEvent::PermissionGranted);
+    }
+}
+
+/// RunLoop에 연결된 이벤트 탭
+struct InstalledTap {
+    tap: CGEventTap<'static>,
+    source: CFRunLoopSource,
+}
+
+/// 이벤트 탭 생성 후 RunLoop에 연결하고 활성화
+fn install_tap(state: &Arc<EventTapState>, run_loop: &CFRunLoop) -> Result<InstalledTap, String> {
+    let state_clone = Arc::clone(state);
 
     let tap = CGEventTap::new(
         CGEventTapLocation::HID,
         CGEventTapPlacement::HeadInsertEventTap,
         CGEventTapOptions::Default,
-        vec![CGEventType::KeyDown, CGEventType::FlagsChanged],
-        move |_proxy, event_type, event| handle_event(&state_clone, event_type, event),
+        vec![
+            CGEventType::KeyDown,
+            CGEventType::FlagsChanged,
+            CGEventType::LeftMouseDown,
+        ],
+        move |_proxy, event_type, event| {
+            let result = handle_event(&state_clone, event_type, event);
+            state_clone.notify_buffer_change();
+            result
+        },
     )
     .map_err(|_| "CGEventTap 생성 실패. Accessibility 권한을 확인하세요.")?;
 
     // mach port 포인터 저장 (TapDisabledByTimeout 시 재활성화용)
     use core_foundation::base::TCFType;
     let raw_port = tap.mach_port.as_concrete_TypeRef() as *mut std::ffi::c_void;
-    state.set_tap_port(raw_port);
 
-    unsafe {
-        let loop_source = tap
-            .mach_port
-            .create_runloop_source(0)
-            .map_err(|_| "RunLoop source 생성 실패")?;
+    let source = tap
+        .mach_port
+        .create_runloop_source(0)
+        .map_err(|_| "RunLoop source 생성 실패")?;
 
-        let current_loop = CFRunLoop::get_current();
-        current_loop.add_source(&loop_source, kCFRunLoopCommonModes);
+    unsafe {
+        run_loop.add_source(&source, kCFRunLoopCommonModes);
+    }
+    tap.enable();
+    state.set_tap_port(raw_port);
 
-        // CFRunLoop 참조 저장 (stop()에서 종료용)
-        use core_foundation::base::TCFType as _;
-        state.set_run_loop(current_loop.as_concrete_TypeRef() as *mut std::ffi::c_void);
+    Ok(InstalledTap { tap, source })
+}
 
-        tap.enable();
+/// 기존 탭을 폐기하고 새로 생성 (RunLoop 스레드에서만 호출)
+/// 백오프 간격이 지나지 않았으면 플래그를 유지한 채 다음 주기로 미룸
+fn recreate_tap(
+    state: &Arc<EventTapState>,
+    run_loop: &CFRunLoop,
+    installed: &mut Option<InstalledTap>,
+) {
+    let now_ms = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
 
-        // 런루프 실행 (stop() 호출 시 종료됨)
-        CFRunLoop::run_current();
+    let mut backoff = lock_or_recover(&state.recreate_backoff);
+    if !backoff.is_ready(now_ms) {
+        return;
+    }
+    state.needs_recreate.store(false, Ordering::Release);
+
+    // 기존 탭 폐기: 다른 스레드가 죽은 port를 쓰지 않도록 먼저 포인터 해제
+    state.set_tap_port(std::ptr::null_mut());
+    if let Some(old) = installed.take() {
+        use core_foundation::base::TCFType;
+        unsafe {
+            run_loop.remove_source(&old.source, kCFRunLoopCommonModes);
+            CFMachPortInvalidate(old.tap.mach_port.as_concrete_TypeRef() as *mut std::ffi::c_void);
+        }
     }
 
-    Ok(())
+    match install_tap(state, run_loop) {
+        Ok(new_tap) => {
+            *installed = Some(new_tap);
+            backoff.record_success(now_ms);
+            state.apply_tap_event(TapEvent::Started);
+            log::warn!("이벤트 탭 재생성 성공");
+        }
+        Err(e) => {
+            backoff.record_failure(now_ms);
+            log::error!(
+                "이벤트 탭 재생성 실패 ({}회 연속): {} — {}ms 후 재시도",
+                backoff.failures,
+                e,
+                backoff.delay_ms()
+            );
+            state.needs_recreate.store(true, Ordering::Release);
+        }
+    }
 }
 
 /// 이벤트 처리
@@ -951,11 +2780,39 @@ fn handle_event(
         return Some(event.clone());
     }
 
+    // 활성화 토글 단축키 (Option+Shift+Space) — 비활성화 상태에서도 동작해야 하므로 먼저 확인
+    if matches!(event_type, CGEventType::KeyDown) {
+        let keycode = event.get_integer_value_field(EventField::KEYBOARD_EVENT_KEYCODE) as u16;
+        let flags = event.get_flags();
+        if state.hotkey.is_toggle(
+            keycode,
+            flags.contains(CGEventFlags::CGEventFlagAlternate),
+            flags.contains(CGEventFlags::CGEventFlagShift),
+        ) {
+            state.toggle_enabled();
+            return None;
+        }
+    }
+
+    // macOS가 이벤트 탭을 비활성화했으면 재활성화 요청 (비활성화 상태에서도 토글 단축키를 받아야 함)
+    // 콜백에서 직접 재시도하지 않고, 감시 스레드가 처리하도록 플래그만 설정
+    if matches!(
+        event_type,
+        CGEventType::TapDisabledByTimeout | CGEventType::TapDisabledByUserInput
+    ) {
+        log::warn!("이벤트 탭 비활성화 감지: {:?}", event_type);
+        state.request_reenable();
+        return Some(event.clone());
+    }
+
     // Koing 비활성화 상태이면 모든 이벤트를 그대로 통과
+    // 입력 소스 조회/캐시 갱신도 하지 않음 (다른 입력 전환 도구와 간섭 방지)
     if !state.is_enabled() {
         return Some(event.clone());
     }
 
+    let input_source = state.input_source();
+
     // 마지막 이벤트 수신 시간 업데이트 (헬스 모니터링용)
     let now_ms = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
@@ -965,24 +2822,16 @@ fn handle_event(
 
     // 유휴→활성 전환 감지: 5초 이상 무입력 후 첫 이벤트에서 캐시 선제 갱신
     if prev_event_time > 0 && now_ms.saturating_sub(prev_event_time) >= 5000 {
-        schedule_async_refresh();
-    }
-
-    // macOS가 이벤트 탭을 비활성화했으면 재활성화 요청
-    // 콜백에서 직접 재시도하지 않고, 감시 스레드가 처리하도록 플래그만 설정
-    if matches!(
-        event_type,
-        CGEventType::TapDisabledByTimeout | CGEventType::TapDisabledByUserInput
-    ) {
-        log::warn!("이벤트 탭 비활성화 감지: {:?}", event_type);
-        state.request_reenable();
-        return Some(event.clone());
+        (input_source.refresh)();
     }
 
     // Koing이 생성한 합성 이벤트는 처리하지 않고 통과
     // KeyDown뿐 아니라 FlagsChanged(simulate_paste의 Cmd 키 이벤트 등)도 필터링하여
     // 텍스트 교체 중 불필요한 캐시 무효화/버퍼 클리어 방지
-    if matches!(event_type, CGEventType::KeyDown | CGEventType::FlagsChanged) {
+    if matches!(
+        event_type,
+        CGEventType::KeyDown | CGEventType::FlagsChanged | CGEventType::LeftMouseDown
+    ) {
         let user_data = event.get_integer_value_field(EventField::EVENT_SOURCE_USER_DATA);
         if user_data == KOING_SYNTHETIC_EVENT_MARKER {
             return Some(event.clone());
@@ -995,6 +2844,12 @@ fn handle_event(
             let flags = event.get_flags();
             let option_pressed = flags.contains(CGEventFlags::CGEventFlagAlternate);
 
+            // 이모지 피커/후보 창이 떠 있는 동안은 변환하지 않음 (합성 Backspace 충돌 방지)
+            if state.hold_for_candidate_window(keycode, flags, Instant::now()) {
+                log::debug!("후보 창 표시 중: 버퍼 초기화, 변환 보류");
+                return Some(event.clone());
+            }
+
             // Option + Z = Undo (마지막 변환 되돌리기)
             // 텍스트 교체 중이면 연타 방지
             if keycode == 6 && option_pressed && !state.is_replacing.load(Ordering::Acquire) {
@@ -1020,17 +2875,19 @@ fn handle_event(
                 state.send_debounce_command(DebounceCommand::Cancel);
                 state.send_switch_command(SwitchCommand::Cancel);
 
-                // 변환 트리거
-                let buffer_content = {
-                    let mut buffer = lock_or_recover(&state.buffer);
-                    let content = buffer.get().to_string();
-                    buffer.clear();
-                    content
-                };
+                // 변환 트리거 (Space로 이어진 여러 단어가 있으면 공백 포함 전체)
+                let buffer_content = state.take_manual_conversion_target();
 
-                if !buffer_content.is_empty() {
-                    if let Some(callback) = lock_or_recover(&state.on_convert).as_ref() {
-                        callback(buffer_content, true); // 수동 단축키
+                if state.focus_allows_conversion() {
+                    if !buffer_content.is_empty() {
+                        if let Some(callback) = lock_or_recover(&state.on_convert).as_ref() {
+                            callback(buffer_content, true); // 수동 단축키
+                        }
+                    } else if let Some(callback) =
+                        lock_or_recover(&state.on_convert_selection).as_ref()
+                    {
+                        // 버퍼가 비어 있으면 선택 텍스트 일괄 변환 (워커에서 AX 조회)
+                        callback();
                     }
                 }
 
@@ -1044,14 +2901,17 @@ fn handle_event(
             // Backspace: 버퍼에서 마지막 문자 제거
             if keycode == 51 {
                 // 비문자 키에서도 conversion_just_triggered 리셋
-                // (Space/Enter만 swap으로 이전 값을 확인하므로 여기서는 단순 store)
+                // (Space만 swap으로 이전 값을 확인하므로 여기서는 단순 store)
                 state
                     .conversion_just_triggered
                     .store(false, Ordering::Release);
                 if state.pop_pending_char().is_none() {
                     let mut buffer = lock_or_recover(&state.buffer);
                     buffer.pop();
+                    lock_or_recover(&state.manual_buffer).pop();
                 }
+                // 한글 모드의 Backspace는 조합 단위로 지워져 키와 화면이 어긋남
+                lock_or_recover(&state.korean_keys).clear();
                 if !state.has_pending_buffer() && lock_or_recover(&state.buffer).is_empty() {
                     state.send_debounce_command(DebounceCommand::Cancel);
                 } else if state.is_realtime_mode() && !state.has_pending_buffer() {
@@ -1060,22 +2920,24 @@ fn handle_event(
                 return Some(event.clone());
             }
 
-            // 버퍼 초기화 조건: Tab, Escape, 방향키
-            if matches!(keycode, 48 | 53 | 123..=126) {
-                // 비문자 키에서도 conversion_just_triggered 리셋
-                state
-                    .conversion_just_triggered
-                    .store(false, Ordering::Release);
-                lock_or_recover(&state.buffer).clear();
-                lock_or_recover(&state.pending_buffer).clear();
-                state.send_debounce_command(DebounceCommand::Cancel);
-                state.send_switch_command(SwitchCommand::Cancel);
+            // 버퍼 초기화 조건: 경계 키 (기본: Tab, Escape, 방향키)
+            if state.reset_on_boundary_key(keycode) {
                 return Some(event.clone());
             }
 
             // Space 입력 시: 버퍼 초기화 (변환 트리거 없이 통과)
             if keycode == 49 {
-                let snapshot = cached_input_source_snapshot();
+                // 양방향 모드: 한글 모드에서 친 영단어는 Space까지 입력된 뒤 워커에서 역변환
+                if let Some(keys) = state.take_reverse_candidate() {
+                    if !state.is_replacing.load(Ordering::Acquire)
+                        && state.focus_allows_conversion()
+                    {
+                        if let Some(callback) = lock_or_recover(&state.on_reverse).as_ref() {
+                            callback(keys);
+                        }
+                    }
+                }
+                let snapshot = (input_source.snapshot)();
                 if state.has_pending_buffer() {
                     if snapshot.is_fresh {
                         state.resolve_pending_buffer(snapshot.state);
@@ -1092,13 +2954,23 @@ fn handle_event(
                     lock_or_recover(&state.buffer).clear();
                     return None;
                 }
+                // 자동 변환은 단어 단위 — 자동 감지가 꺼진 수동 변환 모드에서만
+                // 누적 버퍼에 공백을 유지해 여러 단어를 한 번에 변환
+                let accumulate = !state.is_auto_detect_enabled();
                 lock_or_recover(&state.buffer).clear();
+                let mut manual_buffer = lock_or_recover(&state.manual_buffer);
+                if accumulate {
+                    manual_buffer.push_space();
+                } else {
+                    manual_buffer.clear();
+                }
                 return Some(event.clone());
             }
 
             // Enter 입력 시 버퍼 초기화 (자동 변환 비활성화)
             if keycode == 36 {
-                let snapshot = cached_input_source_snapshot();
+                lock_or_recover(&state.korean_keys).clear();
+                let snapshot = (input_source.snapshot)();
                 if state.has_pending_buffer() {
                     if snapshot.is_fresh {
                         state.resolve_pending_buffer(snapshot.state);
@@ -1108,22 +2980,34 @@ fn handle_event(
                 }
                 state.send_debounce_command(DebounceCommand::Cancel);
 
-                // debounce가 직전에 버퍼를 소비했다면 Enter 소비
-                if state
+                state
                     .conversion_just_triggered
-                    .swap(false, Ordering::AcqRel)
-                {
-                    lock_or_recover(&state.buffer).clear();
-                    return None;
-                }
-
+                    .store(false, Ordering::Release);
                 lock_or_recover(&state.buffer).clear();
-                return Some(event.clone());
+                lock_or_recover(&state.manual_buffer).clear();
+
+                // 변환 교체 전이면 Enter를 보류했다가 교체 후 재전송 (줄바꿈 보존)
+                return match state.enter_action() {
+                    EnterAction::PassThrough => Some(event.clone()),
+                    EnterAction::Defer => {
+                        log::debug!("Enter 보류: 변환 교체 후 재전송");
+                        None
+                    }
+                };
+            }
+
+            // 비ASCII 입력(CJK IME 합성 문자 등)은 버퍼를 비우고 통과
+            let key_input = classify_key_input(
+                keycode_to_char(keycode, shift_pressed),
+                &event_unicode_string(event),
+            );
+            if key_input == KeyInput::Reset {
+                log::debug!("비ASCII 문자 입력: 버퍼 초기화, 변환 타이머 취소");
             }
 
             // 문자 키 처리 - 영문 입력 모드일 때만 버퍼링
-            if let Some(c) = keycode_to_char(keycode, shift_pressed) {
-                let snapshot = cached_input_source_snapshot();
+            if let Some(c) = state.apply_key_input(key_input) {
+                let snapshot = (input_source.snapshot)();
                 if snapshot.is_fresh {
                     state.resolve_pending_buffer(snapshot.state);
                 }
@@ -1136,18 +3020,27 @@ fn handle_event(
                     return Some(event.clone());
                 }
 
-                // 현재 입력 소스 확인 (TIS API)
+                // 현재 입력 소스 확인 (TIS API) 후 상태별 정책 적용
                 // 한글 IME 영문 서브모드(A 모드)도 is_english_input_source()에서 감지됨
                 // 주의: CGEvent 유니코드(event_produces_latin_char)는 HID 레벨에서
                 //       IME 처리 전 raw 문자를 반환하므로 한글 모드에서도 true가 될 수 있음
-                if snapshot.state != InputSourceState::English {
-                    // 한글 입력 모드: 버퍼 클리어하고 패스스루
+                let handling = state
+                    .input_source_policy()
+                    .handling_for(snapshot.state)
+                    .unwrap_or(KeyHandling::PassThrough);
+                if handling == KeyHandling::PassThrough {
+                    // 버퍼 클리어하고 패스스루 (한글 모드이고 양방향 모드면 역변환용으로 키 기록)
                     lock_or_recover(&state.buffer).clear();
                     lock_or_recover(&state.pending_buffer).clear();
+                    lock_or_recover(&state.manual_buffer).clear();
                     state.send_debounce_command(DebounceCommand::Cancel);
                     state.send_switch_command(SwitchCommand::Cancel);
+                    if snapshot.state == InputSourceState::NonEnglish {
+                        state.push_korean_key(c);
+                    }
                     return Some(event.clone());
                 }
+                lock_or_recover(&state.korean_keys).clear();
 
                 // 한글 키인지 확인
                 let is_hangul = is_hangul_key(c);
@@ -1155,51 +3048,55 @@ fn handle_event(
                 state
                     .conversion_just_triggered
                     .store(false, Ordering::SeqCst);
-                lock_or_recover(&state.buffer).push(c);
+                state.push_typed_char(c);
 
                 // 타이핑 중이므로 한글 전환 타이머 취소
                 state.send_switch_command(SwitchCommand::Cancel);
 
+                // 버퍼링만: 수동 변환(Option+Space)까지 자동 변환 타이머를 돌리지 않음
+                if handling == KeyHandling::BufferOnly {
+                    state.send_debounce_command(DebounceCommand::Cancel);
+                    return Some(event.clone());
+                }
+
                 // 실시간 모드에서 debounce 처리
                 if state.is_realtime_mode() {
                     if is_hangul {
-                        // 한글 키: debounce 타이머 리셋
-                        state.send_debounce_command(DebounceCommand::Reset);
+                        // 한글 키: debounce 타이머 리셋, 길게 이어 치면 즉시 판정
+                        let long = lock_or_recover(&state.buffer).len() == LONG_BUFFER_KEYS;
+                        state.send_debounce_command(if long {
+                            DebounceCommand::Trigger
+                        } else {
+                            DebounceCommand::Reset
+                        });
                     } else {
                         // 비한글 키 (숫자, 특수문자 등): 즉시 변환 체크 후 버퍼 유지
-                        // 단, 버퍼에 한글 패턴이 있을 때만
-                        let buffer_before = {
+                        // 판정은 한글 전용 버퍼 기준 (비한글 키는 한글 전용 버퍼에 쌓이지 않음)
+                        // URL/이메일 구간이면 판정 대상이 비어 변환하지 않음
+                        // 교체 대상은 방금 입력된 비한글 키까지 포함한 원문 버퍼
+                        // (키 이벤트는 그대로 통과해 교체 전에 화면에 입력되므로
+                        //  커서 직전 텍스트와 일치해야 교체 시 커서 검사를 통과함)
+                        let (content, judge) = {
                             let buffer = lock_or_recover(&state.buffer);
-                            // 마지막 문자(비한글 키) 제외한 버퍼
-                            let s = buffer.get();
-                            if s.len() > 1 {
-                                s[..s.len() - 1].to_string()
-                            } else {
-                                String::new()
-                            }
+                            (
+                                buffer.get().to_string(),
+                                buffer.conversion_target().0.to_string(),
+                            )
                         };
 
-                        if !buffer_before.is_empty() {
-                            let should_convert = {
-                                let detector = lock_or_recover(&state.auto_detector);
-                                detector.should_convert_realtime(&buffer_before)
-                            };
+                        if !judge.is_empty() && !has_excessive_uppercase(&content) {
+                            let should_convert = state.decide_realtime(&judge);
 
-                            if should_convert {
-                                // 비한글 키 직전까지 변환
+                            if should_convert && !state.suppress_for_non_text_focus() {
+                                // 비한글 키까지 변환 (비한글 키는 그대로 통과)
                                 {
                                     let mut buffer = lock_or_recover(&state.buffer);
                                     buffer.clear();
                                     buffer.push(c); // 비한글 키는 버퍼에 남김
                                 }
 
-                                state
-                                    .conversion_just_triggered
-                                    .store(true, Ordering::Release);
-                                if let Some(callback) = lock_or_recover(&state.on_convert).as_ref()
-                                {
-                                    callback(buffer_before, false); // 실시간 즉시
-                                }
+                                state.dispatch_auto_conversion(content); // 실시간 즉시
+                                lock_or_recover(&state.manual_buffer).push(c);
                             }
                         }
                     }
@@ -1211,20 +3108,24 @@ fn handle_event(
         CGEventType::FlagsChanged => {
             // 수정키 변경 시 입력 소스 캐시 무효화 + 비동기 사전 갱신
             // modifier 이벤트에서 미리 캐시를 갱신해두어 후속 KeyDown에서 캐시 히트 보장
-            invalidate_input_source_cache();
-            schedule_async_refresh();
+            (input_source.invalidate)();
+            (input_source.refresh)();
 
             // Cmd 키 감지: 앱 전환(Cmd+Tab) 등에 의한 버퍼 오염 방지
             let flags = event.get_flags();
             if flags.contains(CGEventFlags::CGEventFlagCommand) {
-                lock_or_recover(&state.buffer).clear();
-                lock_or_recover(&state.pending_buffer).clear();
-                state.send_debounce_command(DebounceCommand::Cancel);
-                state.send_switch_command(SwitchCommand::Cancel);
+                state.reset_input_state();
             }
 
             Some(event.clone())
         }
+        CGEventType::LeftMouseDown => {
+            // 클릭으로 커서가 옮겨지면 대기 중인 변환이 엉뚱한 위치에 적용되므로 취소
+            if state.reset_on_mouse_down() {
+                log::debug!("마우스 클릭: 버퍼 초기화, 변환 타이머 취소");
+            }
+            Some(event.clone())
+        }
         _ => Some(event.clone()),
     }
 }
@@ -1232,6 +3133,9 @@ fn handle_event(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::detection::{FEEDBACK_MAX_OFFSET, FEEDBACK_WINDOW};
+    use crate::ngram::RejectReason;
+    use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
 
     #[test]
     fn test_key_buffer() {
@@ -1245,6 +3149,69 @@ mod tests {
         assert!(buffer.is_empty());
     }
 
+    #[test]
+    fn test_manual_buffer_keeps_words_across_spaces() {
+        let mut buffer = ManualBuffer::new(20);
+        // 앞에 단어가 없으면 Space 무시
+        buffer.push_space();
+        for c in "dkssud".chars() {
+            buffer.push(c);
+        }
+        buffer.push_space();
+        for c in "gktpdy".chars() {
+            buffer.push(c);
+        }
+        assert_eq!(buffer.get(), "dkssud gktpdy");
+        assert_eq!(buffer.take_multi_word().as_deref(), Some("dkssud gktpdy"));
+        assert_eq!(buffer.get(), "");
+
+        // 한 단어뿐이면 자동 버퍼 경로
+        buffer.push('r');
+        buffer.push('k');
+        assert_eq!(buffer.take_multi_word(), None);
+        assert_eq!(buffer.get(), "");
+    }
+
+    #[test]
+    fn test_manual_multi_word_conversion_counts() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        for c in "dkssud".chars() {
+            state.push_typed_char(c);
+        }
+        // Space: 자동 버퍼는 비우고 수동 버퍼에만 공백 유지
+        lock_or_recover(&state.buffer).clear();
+        lock_or_recover(&state.manual_buffer).push_space();
+        for c in "gktpdz".chars() {
+            state.push_typed_char(c);
+        }
+        // Backspace는 두 버퍼에 모두 반영
+        lock_or_recover(&state.buffer).pop();
+        lock_or_recover(&state.manual_buffer).pop();
+        state.push_typed_char('y');
+        assert_eq!(lock_or_recover(&state.buffer).get(), "gktpdy");
+
+        let target = state.take_manual_conversion_target();
+        assert_eq!(target, "dkssud gktpdy");
+        assert!(lock_or_recover(&state.buffer).is_empty());
+
+        let hangul = crate::core::converter::convert(&target);
+        assert_eq!(hangul, "안녕 하세요");
+        // 공백도 화면의 한 글자 — 단어 키 12개 + 공백 1개
+        assert_eq!(backspace_count_for_conversion(&target, &hangul), Some(13));
+
+        // 자동 변환 후에는 수동 누적을 새로 시작 (한 단어면 자동 버퍼 그대로)
+        for c in "dkssud".chars() {
+            state.push_typed_char(c);
+        }
+        lock_or_recover(&state.buffer).clear();
+        state.dispatch_auto_conversion("dkssud".to_string());
+        lock_or_recover(&state.manual_buffer).push_space();
+        for c in "rk".chars() {
+            state.push_typed_char(c);
+        }
+        assert_eq!(state.take_manual_conversion_target(), "rk");
+    }
+
     #[test]
     fn test_key_buffer_overflow() {
         let mut buffer = KeyBuffer::new(3);
@@ -1255,6 +3222,111 @@ mod tests {
         assert_eq!(buffer.get(), "bcd");
     }
 
+    #[test]
+    fn test_key_buffer_separates_hangul_keys_from_raw_input() {
+        let mut buffer = KeyBuffer::new(10);
+        for c in "rk1sk".chars() {
+            buffer.push(c);
+        }
+        assert_eq!(buffer.get(), "rk1sk");
+        assert_eq!(buffer.hangul_keys(), "rksk");
+
+        // 판정은 한글 전용 버퍼, backspace는 원문 기준
+        let (judge, backspace_count) = buffer.conversion_target();
+        assert_eq!(judge, "rksk");
+        assert_eq!(backspace_count, 5);
+        assert_eq!(crate::core::converter::convert(buffer.get()), "가1나");
+    }
+
+    #[test]
+    fn test_key_buffer_pop_keeps_buffers_in_sync() {
+        let mut buffer = KeyBuffer::new(10);
+        for c in "rk1".chars() {
+            buffer.push(c);
+        }
+        assert_eq!(buffer.pop(), Some('1'));
+        assert_eq!(buffer.hangul_keys(), "rk");
+        assert_eq!(buffer.pop(), Some('k'));
+        assert_eq!(buffer.get(), "r");
+        assert_eq!(buffer.hangul_keys(), "r");
+    }
+
+    #[test]
+    fn test_key_buffer_overflow_drops_hangul_key_too() {
+        let mut buffer = KeyBuffer::new(3);
+        for c in "r1ks".chars() {
+            buffer.push(c);
+        }
+        assert_eq!(buffer.get(), "1ks");
+        assert_eq!(buffer.hangul_keys(), "ks");
+    }
+
+    #[test]
+    fn test_key_buffer_whitespace_starts_new_segment() {
+        for boundary in [' ', '\t', '\u{00A0}', '\u{3000}'] {
+            let mut buffer = KeyBuffer::new(20);
+            for c in "dkssud".chars() {
+                buffer.push(c);
+            }
+            buffer.push(boundary);
+            assert!(buffer.is_empty(), "{:?}", boundary);
+            for c in "gk".chars() {
+                buffer.push(c);
+            }
+            assert_eq!(buffer.get(), "gk");
+            assert_eq!(buffer.hangul_keys(), "gk");
+        }
+    }
+
+    #[test]
+    fn test_key_buffer_clears_on_non_ascii_char() {
+        // 전각 구두점/CJK 문자는 두벌식 매핑 밖 — 이전 구간을 버리고 새로 시작
+        for foreign in ['，', 'あ', '中'] {
+            let mut buffer = KeyBuffer::new(20);
+            for c in "dks".chars() {
+                buffer.push(c);
+            }
+            buffer.push(foreign);
+            assert!(buffer.is_empty(), "{:?}", foreign);
+            assert!(buffer.hangul_keys().is_empty());
+            buffer.push('k');
+            assert_eq!(buffer.get(), "k");
+        }
+        // 변환기는 여전히 전각 구두점을 음절 경계로 통과시킴
+        assert_eq!(crate::core::converter::convert("dks，k"), "안，ㅏ");
+        assert_eq!(convertible_segment("，dks"), "dks");
+    }
+
+    #[test]
+    fn test_key_buffer_skips_url_segment_until_boundary() {
+        let mut buffer = KeyBuffer::new(40);
+        for c in "github.com/dkssud".chars() {
+            buffer.push(c);
+        }
+        assert!(buffer.is_url_like());
+        assert_eq!(buffer.conversion_target(), ("", 17));
+
+        // 공백 경계 이후에는 다시 판정
+        buffer.push(' ');
+        for c in "dkssud".chars() {
+            buffer.push(c);
+        }
+        assert_eq!(buffer.conversion_target(), ("dkssud", 6));
+
+        // 한글 입력 중 점 하나는 제외하지 않음
+        let mut buffer = KeyBuffer::new(40);
+        for c in "dkssud.gk".chars() {
+            buffer.push(c);
+        }
+        assert_eq!(buffer.conversion_target().0, "dkssudgk");
+    }
+
+    #[test]
+    fn test_extract_hangul_keys() {
+        assert_eq!(extract_hangul_keys("rk1sk"), "rksk");
+        assert_eq!(extract_hangul_keys("123!"), "");
+    }
+
     #[test]
     fn test_keycode_to_char() {
         assert_eq!(keycode_to_char(0, false), Some('a'));
@@ -1263,6 +3335,797 @@ mod tests {
         assert_eq!(keycode_to_char(15, true), Some('R'));
     }
 
+    #[test]
+    fn test_char_to_keycode_round_trip() {
+        assert_eq!(char_to_keycode('d'), Some((2, false)));
+        assert_eq!(char_to_keycode('R'), Some((15, true)));
+        assert_eq!(char_to_keycode('1'), Some((18, false)));
+        assert_eq!(char_to_keycode('한'), None);
+        for c in "dkssudGKSRMF".chars() {
+            let (keycode, shift) = char_to_keycode(c).unwrap();
+            assert_eq!(keycode_to_char(keycode, shift), Some(c));
+        }
+    }
+
+    #[test]
+    fn test_convertible_segment_drops_leading_non_hangul_keys() {
+        assert_eq!(convertible_segment("dkssud"), "dkssud");
+        // 직전 변환 후 남은 숫자/기호는 교체 대상에서 제외
+        assert_eq!(convertible_segment("1dkssud"), "dkssud");
+        assert_eq!(convertible_segment("!?dkssud"), "dkssud");
+        // 한글 사이/뒤의 비한글 키는 유지
+        assert_eq!(convertible_segment("gks2rmf"), "gks2rmf");
+        assert_eq!(convertible_segment("dkssud!"), "dkssud!");
+        assert_eq!(convertible_segment("123"), "");
+    }
+
+    #[test]
+    fn test_backspace_count_for_segment() {
+        assert_eq!(backspace_count_for(convertible_segment("1dkssud")), Some(6));
+        assert_eq!(backspace_count_for(convertible_segment("gks2rmf")), Some(7));
+        assert_eq!(backspace_count_for(convertible_segment("dkssud!")), Some(7));
+        assert_eq!(backspace_count_for(convertible_segment("12#")), None);
+        assert_eq!(backspace_count_for(""), None);
+
+        // 상한 초과 시 교체하지 않음 (과삭제 방지)
+        let max = "r".repeat(MAX_BACKSPACE_COUNT);
+        assert_eq!(backspace_count_for(&max), Some(MAX_BACKSPACE_COUNT));
+        let over = "r".repeat(MAX_BACKSPACE_COUNT + 1);
+        assert_eq!(backspace_count_for(&over), None);
+    }
+
+    /// 키 간격 시퀀스를 이동평균에 반영
+    fn feed_intervals(avg: u64, intervals: &[u64]) -> u64 {
+        intervals
+            .iter()
+            .fold(avg, |avg, &interval| update_key_interval_avg(avg, interval))
+    }
+
+    #[test]
+    fn test_adaptive_debounce_converges() {
+        // 빠른 타이핑 (80ms 간격) → 200ms 근처
+        let fast = feed_intervals(0, &[80; 30]);
+        assert_eq!(fast, 80);
+        assert_eq!(adaptive_debounce_ms(fast), Some(200));
+
+        // 느린 타이핑 (250ms 간격) → 625ms 근처
+        let slow = feed_intervals(0, &[250; 30]);
+        assert_eq!(adaptive_debounce_ms(slow), Some(625));
+
+        // 빠른 → 느린 전환 시 점진적으로 수렴
+        let mut avg = fast;
+        let mut prev = adaptive_debounce_ms(avg).unwrap();
+        for _ in 0..30 {
+            avg = update_key_interval_avg(avg, 250);
+            let next = adaptive_debounce_ms(avg).unwrap();
+            assert!(next >= prev);
+            prev = next;
+        }
+        assert!((600..=625).contains(&prev), "{}", prev);
+
+        // 불규칙한 간격도 평균 범위 안에서 수렴
+        let mixed = feed_intervals(0, &[60, 140, 90, 110, 70, 130, 100, 100].repeat(5));
+        assert!((80..=120).contains(&mixed), "{}", mixed);
+    }
+
+    #[test]
+    fn test_adaptive_debounce_bounds() {
+        assert_eq!(adaptive_debounce_ms(0), None);
+        // 극단적으로 빠르거나 느린 입력은 상·하한으로 제한
+        assert_eq!(
+            adaptive_debounce_ms(feed_intervals(0, &[10; 20])),
+            Some(ADAPTIVE_DEBOUNCE_MIN_MS)
+        );
+        assert_eq!(
+            adaptive_debounce_ms(feed_intervals(0, &[900; 20])),
+            Some(ADAPTIVE_DEBOUNCE_MAX_MS)
+        );
+        // 타이핑 중단(긴 간격)과 동시 입력(0ms)은 평균에 반영하지 않음
+        assert_eq!(feed_intervals(100, &[5_000, 0, 60_000]), 100);
+    }
+
+    #[test]
+    fn test_reset_input_state_restores_initial_state() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        state.set_reverse_to_english(true);
+        for c in "dkssud".chars() {
+            state.push_typed_char(c);
+        }
+        state.push_pending_char('g');
+        state.push_korean_key('s');
+        state.send_debounce_command(DebounceCommand::Reset);
+        state.send_switch_command(SwitchCommand::Reset);
+        state
+            .conversion_just_triggered
+            .store(true, Ordering::Release);
+
+        state.reset_input_state();
+        assert!(lock_or_recover(&state.buffer).is_empty());
+        assert!(lock_or_recover(&state.manual_buffer).get().is_empty());
+        assert!(!state.has_pending_buffer());
+        assert!(lock_or_recover(&state.korean_keys).is_empty());
+        assert_eq!(
+            lock_or_recover(&state.debounce_cv.0).command,
+            Some(DebounceCommand::Cancel)
+        );
+        assert_eq!(
+            lock_or_recover(&state.switch_cv.0).command,
+            Some(SwitchCommand::Cancel)
+        );
+        assert!(!state.conversion_just_triggered.load(Ordering::Acquire));
+        // 설정은 그대로
+        assert!(state.is_enabled());
+    }
+
+    #[test]
+    fn test_mouse_down_resets_buffer_and_timers() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        for c in "dkssud".chars() {
+            state.push_typed_char(c);
+        }
+        state.push_pending_char('g');
+        state.send_debounce_command(DebounceCommand::Reset);
+        state.send_switch_command(SwitchCommand::Reset);
+        state
+            .conversion_just_triggered
+            .store(true, Ordering::Release);
+
+        assert!(state.reset_on_mouse_down());
+        assert!(lock_or_recover(&state.buffer).is_empty());
+        assert!(!state.has_pending_buffer());
+        assert_eq!(
+            lock_or_recover(&state.debounce_cv.0).command,
+            Some(DebounceCommand::Cancel)
+        );
+        assert_eq!(
+            lock_or_recover(&state.switch_cv.0).command,
+            Some(SwitchCommand::Cancel)
+        );
+        assert!(!state.conversion_just_triggered.load(Ordering::Acquire));
+    }
+
+    #[test]
+    fn test_candidate_hold_trigger_and_release() {
+        let ctrl_cmd = CGEventFlags::CGEventFlagControl | CGEventFlags::CGEventFlagCommand;
+        assert!(opens_candidate_window(49, ctrl_cmd));
+        assert!(opens_candidate_window(
+            14,
+            CGEventFlags::CGEventFlagSecondaryFn
+        ));
+        // Control 또는 Command 하나만, 수식키 없는 E는 해당 없음
+        assert!(!opens_candidate_window(
+            49,
+            CGEventFlags::CGEventFlagCommand
+        ));
+        assert!(!opens_candidate_window(
+            49,
+            CGEventFlags::CGEventFlagControl
+        ));
+        assert!(!opens_candidate_window(14, CGEventFlags::CGEventFlagNull));
+
+        let none = CGEventFlags::CGEventFlagNull;
+        let start = Instant::now();
+        let mut hold = CandidateHold::default();
+        assert!(!hold.on_key(0, none, start));
+
+        // 여는 단축키 → 이후 검색어 입력은 보류
+        assert!(hold.on_key(49, ctrl_cmd, start));
+        assert!(hold.on_key(1, none, start + Duration::from_millis(100)));
+        // Return/Escape로 닫으면 그 키까지만 보류
+        assert!(hold.on_key(36, none, start + Duration::from_millis(200)));
+        assert!(!hold.on_key(1, none, start + Duration::from_millis(300)));
+
+        // 클릭으로 닫힘
+        assert!(hold.on_key(49, ctrl_cmd, start));
+        hold.release();
+        assert!(!hold.on_key(1, none, start));
+
+        // 닫힘을 놓쳐도 제한 시간 후 해제
+        assert!(hold.on_key(14, CGEventFlags::CGEventFlagSecondaryFn, start));
+        assert!(hold.on_key(1, none, start + CANDIDATE_HOLD_TIMEOUT / 2));
+        assert!(!hold.on_key(1, none, start + CANDIDATE_HOLD_TIMEOUT));
+    }
+
+    #[test]
+    fn test_candidate_hold_clears_buffer() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        let ctrl_cmd = CGEventFlags::CGEventFlagControl | CGEventFlags::CGEventFlagCommand;
+        let none = CGEventFlags::CGEventFlagNull;
+        let now = Instant::now();
+        for c in "dkssud".chars() {
+            lock_or_recover(&state.buffer).push(c);
+        }
+        assert!(!state.hold_for_candidate_window(1, none, now));
+        assert_eq!(lock_or_recover(&state.buffer).get(), "dkssud");
+
+        assert!(state.hold_for_candidate_window(49, ctrl_cmd, now));
+        assert!(lock_or_recover(&state.buffer).is_empty());
+
+        // 보류 중 클릭하면 해제
+        assert!(state.reset_on_mouse_down());
+        assert!(!state.hold_for_candidate_window(1, none, now));
+    }
+
+    #[test]
+    fn test_newline_gate_orders_enter_after_replacement() {
+        let start = Instant::now();
+
+        // 대기 중인 교체가 없으면 통과
+        let mut gate = NewlineGate::default();
+        assert_eq!(gate.on_enter(start), EnterAction::PassThrough);
+        assert_eq!(gate.conversions_finished(1), 0);
+
+        // 교체 전 Enter는 보류 → 교체 완료 시 재전송
+        gate.conversion_requested(start);
+        assert_eq!(gate.on_enter(start), EnterAction::Defer);
+        assert_eq!(gate.conversions_finished(1), 1);
+        assert_eq!(gate.conversions_finished(1), 0);
+
+        // 교체가 이미 끝났으면 통과
+        gate.conversion_requested(start);
+        assert_eq!(gate.conversions_finished(1), 0);
+        assert_eq!(gate.on_enter(start), EnterAction::PassThrough);
+
+        // 요청이 여러 건이면 마지막 교체 후에 한꺼번에 재전송
+        gate.conversion_requested(start);
+        gate.conversion_requested(start);
+        assert_eq!(gate.on_enter(start), EnterAction::Defer);
+        assert_eq!(gate.on_enter(start), EnterAction::Defer);
+        assert_eq!(gate.conversions_finished(1), 0);
+        assert_eq!(gate.conversions_finished(1), 2);
+
+        // 완료 통지가 유실되면 대기 시간 후 보류하지 않음
+        gate.conversion_requested(start);
+        assert_eq!(
+            gate.on_enter(start + NEWLINE_DEFER_TIMEOUT),
+            EnterAction::PassThrough
+        );
+        assert_eq!(gate.conversions_finished(1), 0);
+    }
+
+    #[test]
+    fn test_enter_after_auto_conversion_is_resent_after_finish() {
+        static NEWLINES: AtomicU64 = AtomicU64::new(0);
+        fn counting_sender() {
+            NEWLINES.fetch_add(1, Ordering::SeqCst);
+        }
+
+        let state = EventTapState::new(HotkeyConfig::default());
+        *lock_or_recover(&state.newline_sender) = counting_sender;
+        let converted = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&converted);
+        state.set_convert_callback(move |buffer, is_manual| {
+            lock_or_recover(&sink).push((buffer, is_manual));
+        });
+
+        // 변환은 그대로 요청되고 Enter는 교체 완료까지 보류
+        state.dispatch_auto_conversion("dkssud".to_string());
+        assert_eq!(
+            *lock_or_recover(&converted),
+            vec![("dkssud".to_string(), false)]
+        );
+        assert_eq!(state.enter_action(), EnterAction::Defer);
+        assert_eq!(NEWLINES.load(Ordering::SeqCst), 0);
+
+        state.finish_auto_conversions(1);
+        assert_eq!(NEWLINES.load(Ordering::SeqCst), 1);
+
+        // 교체 후 Enter는 바로 통과, 재전송 없음
+        assert_eq!(state.enter_action(), EnterAction::PassThrough);
+        state.finish_auto_conversions(1);
+        assert_eq!(NEWLINES.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_last_converted_keeps_history_until_undo() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        assert_eq!(state.last_converted(), None);
+
+        state.save_conversion_history("dkssud".to_string(), "안녕".to_string());
+        assert_eq!(state.last_converted().as_deref(), Some("안녕"));
+        assert_eq!(state.last_converted().as_deref(), Some("안녕"));
+
+        assert!(state.take_conversion_history().is_some());
+        assert_eq!(state.last_converted(), None);
+    }
+
+    #[test]
+    fn test_boundary_keys_follow_configured_list() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        for keycode in DEFAULT_RESET_KEYCODES {
+            assert!(state.is_reset_keycode(keycode), "{}", keycode);
+        }
+        // Home/End는 기본 목록에 없음
+        assert!(!state.is_reset_keycode(115));
+
+        for c in "dkssud".chars() {
+            state.push_typed_char(c);
+        }
+        state.send_debounce_command(DebounceCommand::Reset);
+        assert!(!state.reset_on_boundary_key(115));
+        assert_eq!(lock_or_recover(&state.buffer).get(), "dkssud");
+        assert_eq!(
+            lock_or_recover(&state.debounce_cv.0).command,
+            Some(DebounceCommand::Reset)
+        );
+
+        state.set_reset_keycodes(vec![115, 119]);
+        assert!(!state.reset_on_boundary_key(48));
+        assert!(state.reset_on_boundary_key(115));
+        assert!(lock_or_recover(&state.buffer).is_empty());
+        assert_eq!(
+            lock_or_recover(&state.debounce_cv.0).command,
+            Some(DebounceCommand::Cancel)
+        );
+        assert_eq!(
+            lock_or_recover(&state.switch_cv.0).command,
+            Some(SwitchCommand::Cancel)
+        );
+    }
+
+    #[test]
+    fn test_mouse_down_ignored_while_replacing() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        for c in "gksrmf".chars() {
+            state.push_typed_char(c);
+        }
+        state.send_debounce_command(DebounceCommand::Reset);
+        state.is_replacing.store(true, Ordering::Release);
+
+        assert!(!state.reset_on_mouse_down());
+        assert_eq!(lock_or_recover(&state.buffer).get(), "gksrmf");
+        assert_eq!(
+            lock_or_recover(&state.debounce_cv.0).command,
+            Some(DebounceCommand::Reset)
+        );
+    }
+
+    #[test]
+    fn test_classify_key_input_resets_on_non_ascii() {
+        // ASCII 키는 이벤트 문자열과 무관하게 버퍼링
+        assert_eq!(classify_key_input(Some('r'), "r"), KeyInput::Buffer('r'));
+        assert_eq!(classify_key_input(Some('R'), ""), KeyInput::Buffer('R'));
+        // CJK IME 합성 문자 / Option 조합 문자 → 리셋
+        assert_eq!(classify_key_input(Some('k'), "か"), KeyInput::Reset);
+        assert_eq!(classify_key_input(Some('a'), "å"), KeyInput::Reset);
+        assert_eq!(classify_key_input(None, "你好"), KeyInput::Reset);
+        assert_eq!(classify_key_input(Some('あ'), ""), KeyInput::Reset);
+        // 문자를 만들지 않는 키 (방향키 사설 영역 문자, 제어 문자)
+        assert_eq!(classify_key_input(None, "\u{F702}"), KeyInput::Ignore);
+        assert_eq!(classify_key_input(None, "\u{1b}"), KeyInput::Ignore);
+        assert_eq!(classify_key_input(None, ""), KeyInput::Ignore);
+    }
+
+    #[test]
+    fn test_non_ascii_input_resets_buffer_without_conversion() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        let converted = Arc::new(AtomicBool::new(false));
+        let converted_for_callback = Arc::clone(&converted);
+        state.set_convert_callback(move |_, _| {
+            converted_for_callback.store(true, Ordering::Release);
+        });
+        for c in "dkssud".chars() {
+            state.push_typed_char(c);
+        }
+        state.push_pending_char('g');
+        state.send_debounce_command(DebounceCommand::Reset);
+
+        assert_eq!(
+            state.apply_key_input(classify_key_input(Some('k'), "か")),
+            None
+        );
+        assert!(lock_or_recover(&state.buffer).is_empty());
+        assert!(!state.has_pending_buffer());
+        assert_eq!(
+            lock_or_recover(&state.debounce_cv.0).command,
+            Some(DebounceCommand::Cancel)
+        );
+        assert!(!converted.load(Ordering::Acquire));
+
+        // ASCII 키는 그대로 이어서 처리
+        assert_eq!(
+            state.apply_key_input(classify_key_input(Some('r'), "r")),
+            Some('r')
+        );
+    }
+
+    #[test]
+    fn test_toggle_hotkey_matches_option_shift_only() {
+        let hotkey = HotkeyConfig::default();
+        assert!(hotkey.is_toggle(49, true, true));
+        // Option+Space는 수동 변환, Shift+Space는 일반 입력
+        assert!(!hotkey.is_toggle(49, true, false));
+        assert!(!hotkey.is_toggle(49, false, true));
+        assert!(!hotkey.is_toggle(6, true, true));
+
+        let disabled = HotkeyConfig {
+            toggle_keycode: None,
+            ..HotkeyConfig::default()
+        };
+        assert!(!disabled.is_toggle(49, true, true));
+    }
+
+    #[test]
+    fn test_toggle_enabled_clears_state_and_notifies() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        let notified = Arc::new(Mutex::new(Vec::new()));
+        let notified_for_callback = Arc::clone(&notified);
+        state.set_toggle_callback(move |enabled| {
+            notified_for_callback.lock().unwrap().push(enabled);
+        });
+
+        for c in "dkssud".chars() {
+            state.push_typed_char(c);
+        }
+        state.push_pending_char('g');
+        state.send_debounce_command(DebounceCommand::Reset);
+        state.send_switch_command(SwitchCommand::Reset);
+
+        // 활성 → 비활성: 버퍼/타이머 정리
+        assert!(!state.toggle_enabled());
+        assert!(!state.is_enabled());
+        assert!(lock_or_recover(&state.buffer).is_empty());
+        assert!(!state.has_pending_buffer());
+        assert_eq!(
+            lock_or_recover(&state.debounce_cv.0).command,
+            Some(DebounceCommand::Cancel)
+        );
+        assert_eq!(
+            lock_or_recover(&state.switch_cv.0).command,
+            Some(SwitchCommand::Cancel)
+        );
+
+        // 비활성 → 활성
+        assert!(state.toggle_enabled());
+        assert!(state.is_enabled());
+        assert_eq!(*notified.lock().unwrap(), vec![false, true]);
+    }
+
+    #[test]
+    fn test_stats_export_from_state() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        let mut restored = ConversionStats::default();
+        restored.record(StatKind::Conversion, 8);
+        state.set_stats(restored);
+
+        state.record_stat_at(StatKind::Conversion, 8);
+        state.record_stat_at(StatKind::Undo, 21);
+        let stats = state.stats_snapshot();
+        assert_eq!(stats.total.conversions, 2);
+        assert_eq!(stats.hourly[8].conversions, 2);
+        assert_eq!(stats.hourly[21].undos, 1);
+
+        let path =
+            std::env::temp_dir().join(format!("koing_stats_export_{}.json", std::process::id()));
+        state.export_stats(&path).unwrap();
+        assert_eq!(crate::stats::load_stats_from(&path), stats);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_feedback_moves_detector_threshold() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        state.set_sensitivity(AppProfile::Normal);
+        let base = AppProfile::Normal.default_params();
+        let threshold = || lock_or_recover(&state.auto_detector).config().threshold;
+        assert_eq!(threshold(), base.threshold);
+
+        // 거부가 이어지면 기준이 내려감 (하한: -5)
+        for _ in 0..10 {
+            state.record_stat_at(StatKind::Rejection, 9);
+        }
+        assert_eq!(threshold(), base.threshold - FEEDBACK_MAX_OFFSET);
+        assert_eq!(
+            lock_or_recover(&state.auto_detector)
+                .config()
+                .realtime_threshold,
+            base.realtime_threshold - FEEDBACK_MAX_OFFSET
+        );
+
+        // 변환 후 Undo가 이어지면 다시 올라감 (상한: +5)
+        for _ in 0..FEEDBACK_WINDOW {
+            state.record_stat_at(StatKind::Conversion, 9);
+            state.record_stat_at(StatKind::Undo, 9);
+        }
+        assert_eq!(threshold(), base.threshold + FEEDBACK_MAX_OFFSET);
+
+        // 앱 전환 후에도 조정 유지, 비활성화(세션 종료)하면 기본값
+        state.apply_profile_for_app("com.example.App");
+        assert_eq!(threshold(), base.threshold + FEEDBACK_MAX_OFFSET);
+        state.set_enabled(false);
+        assert_eq!(threshold(), base.threshold);
+        assert_eq!(state.feedback_offset(), 0.0);
+    }
+
+    #[test]
+    fn test_effective_debounce_uses_estimate_when_adaptive() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        state.set_debounce_ms(300);
+
+        // 측정 전에는 설정값 사용
+        state.set_adaptive_debounce(true);
+        assert_eq!(state.effective_debounce_ms(), 300);
+
+        let mut now = 1_000_000;
+        for _ in 0..20 {
+            state.record_key_time(now);
+            now += 100;
+        }
+        assert_eq!(state.key_interval_avg_ms(), 100);
+        assert_eq!(state.effective_debounce_ms(), 250);
+
+        // adaptive 끄면 설정값으로 복귀
+        state.set_adaptive_debounce(false);
+        assert_eq!(state.effective_debounce_ms(), 300);
+    }
+
+    #[test]
+    fn test_backspace_count_for_conversion() {
+        // 겹받침 분리(도깨비불)/낱자모 입력도 변환기는 키 수를 보존
+        for segment in [
+            "dkssud", "gks2rmf", "Rkrtn", "dkrtk", "rkqtdl", "rt", "hk", "qkfkq!",
+        ] {
+            let hangul = crate::convert(segment);
+            assert_eq!(
+                backspace_count_for_conversion(segment, &hangul),
+                Some(segment.chars().count()),
+                "{} → {}",
+                segment,
+                hangul
+            );
+        }
+
+        // 낱자 하나로 합쳐지거나 음절이 빠진 결과는 역변환 길이가 달라짐 — 교체하지 않음
+        for (segment, hangul) in [("rt", "ㄳ"), ("hk", "ㅘ"), ("dkrtk", "앇"), ("dk", "안")] {
+            assert_ne!(korean_to_eng(hangul).chars().count(), segment.len());
+            assert_eq!(backspace_count_for_conversion(segment, hangul), None);
+        }
+
+        // 교체 구간 자체가 비정상이면 None
+        assert_eq!(backspace_count_for_conversion("", ""), None);
+    }
+
+    #[test]
+    fn test_hangul_key_table_regression() {
+        // 소문자 26키는 모두 두벌식 자모
+        for c in 'a'..='z' {
+            assert!(is_hangul_key(c), "{}", c);
+        }
+        // 대문자는 Shift 쌍자음/모음 키만 자모 (나머지는 비한글 키로 취급)
+        for c in 'A'..='Z' {
+            assert_eq!(is_hangul_key(c), "QWERTOP".contains(c), "{}", c);
+        }
+        for c in ['0', '9', ' ', '!', '-', ';', '\'', '[', 'ㄱ', '가', 'é'] {
+            assert!(!is_hangul_key(c), "{:?}", c);
+        }
+    }
+
+    #[test]
+    fn test_hangul_key_matches_jamo_mapper_for_all_keycodes() {
+        use crate::core::jamo_mapper::{is_consonant, is_vowel};
+
+        // 키코드로 만들 수 있는 모든 문자에 대해 판정이 jamo_mapper와 일치
+        for keycode in 0..128u16 {
+            for shift in [false, true] {
+                if let Some(c) = keycode_to_char(keycode, shift) {
+                    assert_eq!(
+                        is_hangul_key(c),
+                        is_consonant(c) || is_vowel(c),
+                        "keycode {} shift {} ({:?})",
+                        keycode,
+                        shift,
+                        c
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_shift_vowel_keys_are_hangul_keys() {
+        // Shift+o → 'O'(ㅒ), Shift+p → 'P'(ㅖ)
+        assert_eq!(keycode_to_char(31, true), Some('O'));
+        assert_eq!(keycode_to_char(35, true), Some('P'));
+        assert!(is_hangul_key('O'));
+        assert!(is_hangul_key('P'));
+
+        let mut buffer = KeyBuffer::new(50);
+        for c in "rPtks".chars() {
+            buffer.push(c);
+        }
+        assert_eq!(buffer.hangul_keys(), "rPtks");
+        assert_eq!(
+            crate::core::converter::convert(buffer.hangul_keys()),
+            "계산"
+        );
+    }
+
+    #[test]
+    fn test_recreate_only_after_failed_reenable_while_running() {
+        assert!(should_recreate_after_reenable(false, true));
+        assert!(!should_recreate_after_reenable(true, true));
+        assert!(!should_recreate_after_reenable(false, false));
+    }
+
+    #[test]
+    fn test_switch_guard_blocks_after_new_typing() {
+        // 변환 직후 타이머 시작 → 만료 전 영문 입력 (Cancel 도착 전 만료 race)
+        let state = EventTapState::new(HotkeyConfig::default());
+        let armed = state.switch_guard_snapshot();
+        assert!(state.switch_still_wanted(armed));
+
+        state.push_typed_char('h');
+        assert!(!state.switch_still_wanted(armed));
+
+        // 버퍼가 비워져도 타이머 시작 이후 입력이 있었으면 전환하지 않음
+        lock_or_recover(&state.buffer).clear();
+        assert!(!state.switch_still_wanted(armed));
+
+        // 새로 시작한 타이머는 다시 전환 가능
+        let rearmed = state.switch_guard_snapshot();
+        assert!(state.switch_still_wanted(rearmed));
+    }
+
+    #[test]
+    fn test_switch_guard_blocks_with_pending_input() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        let armed = state.switch_guard_snapshot();
+        state.push_pending_char('a');
+        assert!(!state.switch_still_wanted(armed));
+    }
+
+    #[test]
+    fn test_tap_status_transitions() {
+        // 실패 → 안내 → 권한 획득 → 재시작 → 복구
+        let status = TapStatus::Starting.next(TapEvent::StartFailed);
+        assert_eq!(status, TapStatus::PermissionRequired);
+        let status = status.next(TapEvent::PermissionGranted);
+        assert_eq!(status, TapStatus::Starting);
+        assert_eq!(
+            status.next(TapEvent::StartFailed),
+            TapStatus::PermissionRequired
+        );
+        assert_eq!(status.next(TapEvent::Started), TapStatus::Running);
+
+        // 정상 동작 중 권한 확인은 무시
+        assert_eq!(
+            TapStatus::Running.next(TapEvent::PermissionGranted),
+            TapStatus::Running
+        );
+
+        // 허용 → 회수 → 재허용 → 재생성 성공
+        let status = TapStatus::Running.next(TapEvent::PermissionRevoked);
+        assert_eq!(status, TapStatus::PermissionRevoked);
+        let status = status.next(TapEvent::PermissionGranted);
+        assert_eq!(status, TapStatus::Starting);
+        assert_eq!(status.next(TapEvent::Started), TapStatus::Running);
+        // 시작 실패 상태의 회수 확인은 무시 (권한 대기 루프가 처리)
+        assert_eq!(
+            TapStatus::PermissionRequired.next(TapEvent::PermissionRevoked),
+            TapStatus::PermissionRequired
+        );
+    }
+
+    #[test]
+    fn test_permission_event_follows_status() {
+        assert_eq!(
+            TapStatus::Running.permission_event(false),
+            Some(TapEvent::PermissionRevoked)
+        );
+        assert_eq!(
+            TapStatus::Starting.permission_event(false),
+            Some(TapEvent::PermissionRevoked)
+        );
+        assert_eq!(
+            TapStatus::PermissionRevoked.permission_event(true),
+            Some(TapEvent::PermissionGranted)
+        );
+        // 변화 없음 / 시작 실패 상태는 전이 없음
+        assert_eq!(TapStatus::Running.permission_event(true), None);
+        assert_eq!(TapStatus::PermissionRevoked.permission_event(false), None);
+        assert_eq!(TapStatus::PermissionRequired.permission_event(true), None);
+        assert_eq!(TapStatus::PermissionRequired.permission_event(false), None);
+    }
+
+    #[test]
+    fn test_watch_permission_recovers_after_regrant() {
+        static GRANTED: AtomicBool = AtomicBool::new(true);
+        let state = EventTapState::new(HotkeyConfig::default());
+        state.set_permission_probe(|| GRANTED.load(Ordering::SeqCst));
+        state.apply_tap_event(TapEvent::Started);
+        lock_or_recover(&state.buffer).push('a');
+
+        state.watch_permission();
+        assert_eq!(state.tap_status(), TapStatus::Running);
+
+        // 회수: 경고 상태 + 입력 상태 초기화, 재생성은 요청하지 않음
+        GRANTED.store(false, Ordering::SeqCst);
+        state.watch_permission();
+        assert_eq!(state.tap_status(), TapStatus::PermissionRevoked);
+        assert!(state.tap_status().needs_attention(false));
+        assert!(lock_or_recover(&state.buffer).is_empty());
+        assert!(!state.needs_recreate.load(Ordering::Acquire));
+
+        // 재허용: 탭 재생성 요청
+        GRANTED.store(true, Ordering::SeqCst);
+        state.watch_permission();
+        assert_eq!(state.tap_status(), TapStatus::Starting);
+        assert!(state.needs_recreate.load(Ordering::Acquire));
+    }
+
+    #[test]
+    fn test_tap_status_needs_attention() {
+        assert!(!TapStatus::Starting.needs_attention(false));
+        // 권한 획득 후 재시작 중에는 복구 전까지 경고 유지 (아이콘 깜빡임 방지)
+        assert!(TapStatus::Starting.needs_attention(true));
+        assert!(TapStatus::PermissionRequired.needs_attention(false));
+        assert!(TapStatus::PermissionRevoked.needs_attention(false));
+        assert!(!TapStatus::Running.needs_attention(true));
+    }
+
+    #[test]
+    fn test_apply_tap_event_notifies_only_on_change() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        let notified = Arc::new(Mutex::new(Vec::new()));
+        let notified_cb = Arc::clone(&notified);
+        state.set_tap_status_callback(move |status| lock_or_recover(&notified_cb).push(status));
+
+        assert!(state.apply_tap_event(TapEvent::StartFailed));
+        assert!(!state.apply_tap_event(TapEvent::StartFailed));
+        assert!(state.apply_tap_event(TapEvent::PermissionGranted));
+        assert!(state.apply_tap_event(TapEvent::Started));
+        assert_eq!(state.tap_status(), TapStatus::Running);
+        assert_eq!(
+            *lock_or_recover(&notified),
+            vec![
+                TapStatus::PermissionRequired,
+                TapStatus::Starting,
+                TapStatus::Running
+            ]
+        );
+    }
+
+    #[test]
+    fn test_recovery_step() {
+        assert_eq!(recovery_step(true, false), RecoveryStep::Wait);
+        assert_eq!(recovery_step(true, true), RecoveryStep::Retry);
+        assert_eq!(recovery_step(false, true), RecoveryStep::Stop);
+        assert_eq!(recovery_step(false, false), RecoveryStep::Stop);
+    }
+
+    #[test]
+    fn test_recreate_backoff_grows_and_caps() {
+        let mut backoff = RecreateBackoff::default();
+        assert!(backoff.is_ready(1_000));
+        assert_eq!(backoff.delay_ms(), 2_000);
+
+        backoff.record_failure(10_000);
+        assert_eq!(backoff.delay_ms(), 4_000);
+        assert!(!backoff.is_ready(13_999));
+        assert!(backoff.is_ready(14_000));
+
+        backoff.record_failure(14_000);
+        assert_eq!(backoff.delay_ms(), 8_000);
+
+        for _ in 0..20 {
+            backoff.record_failure(14_000);
+        }
+        assert_eq!(backoff.delay_ms(), RECREATE_MAX_BACKOFF_MS);
+    }
+
+    #[test]
+    fn test_recreate_backoff_resets_on_success_but_keeps_min_interval() {
+        let mut backoff = RecreateBackoff::default();
+        backoff.record_failure(0);
+        backoff.record_failure(5_000);
+        backoff.record_success(20_000);
+
+        assert_eq!(backoff.failures, 0);
+        // 성공 직후 바로 다시 재생성하지 않음 (루프 방지)
+        assert!(!backoff.is_ready(21_000));
+        assert!(backoff.is_ready(22_000));
+    }
+
     #[test]
     fn test_hotkey_config_default() {
         let config = HotkeyConfig::default();
@@ -1295,4 +4158,773 @@ mod tests {
         let pending = lock_or_recover(&state.pending_buffer);
         assert!(pending.is_empty());
     }
+
+    #[test]
+    fn test_apply_profile_for_app_updates_detector_params() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        let config = crate::config::KoingConfig::default();
+        state.set_app_profiles(config.app_profiles.clone(), config.profile_params.clone());
+
+        let profile = state.apply_profile_for_app("com.microsoft.VSCode");
+        assert_eq!(profile, AppProfile::Conservative);
+        {
+            let detector = lock_or_recover(&state.auto_detector);
+            let expected = config.params_for_profile(AppProfile::Conservative);
+            assert_eq!(detector.config().threshold, expected.threshold);
+            assert_eq!(detector.config().min_length, expected.min_length);
+            // 코드 에디터에서는 짧은 한글도 변환하지 않음
+            assert!(!detector.should_convert("dkssud"));
+        }
+
+        let profile = state.apply_profile_for_app("com.kakao.KakaoTalkMac");
+        assert_eq!(profile, AppProfile::Aggressive);
+        {
+            let detector = lock_or_recover(&state.auto_detector);
+            let expected = config.params_for_profile(AppProfile::Aggressive);
+            assert_eq!(
+                detector.config().realtime_threshold,
+                expected.realtime_threshold
+            );
+            assert_eq!(detector.config().min_length, expected.min_length);
+        }
+
+        // 매핑에 없는 앱은 normal로 복귀
+        assert_eq!(
+            state.apply_profile_for_app("com.apple.Safari"),
+            AppProfile::Normal
+        );
+        let detector = lock_or_recover(&state.auto_detector);
+        assert_eq!(detector.config().threshold, 70.0);
+        assert_eq!(detector.config().min_length, 3);
+    }
+
+    #[test]
+    fn test_apply_profile_uses_custom_params() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        let custom = ProfileParams {
+            threshold: 99.0,
+            realtime_threshold: 100.0,
+            min_length: 8,
+        };
+        state.set_app_profiles(
+            BTreeMap::from([("com.example.Editor".to_string(), AppProfile::Conservative)]),
+            BTreeMap::from([(AppProfile::Conservative, custom)]),
+        );
+
+        state.apply_profile_for_app("com.example.Editor");
+        let detector = lock_or_recover(&state.auto_detector);
+        assert_eq!(detector.config().threshold, 99.0);
+        assert_eq!(detector.config().min_length, 8);
+    }
+
+    #[test]
+    fn test_sensitivity_changes_boundary_word() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        let config = crate::config::KoingConfig::default();
+        state.set_app_profiles(config.app_profiles.clone(), config.profile_params.clone());
+
+        // "dkssud"(안녕)은 기본 임계값은 넘지만 보수적 임계값에는 못 미침
+        assert_eq!(
+            state.set_sensitivity(AppProfile::Conservative),
+            AppProfile::Conservative
+        );
+        assert!(!lock_or_recover(&state.auto_detector).should_convert("dkssud"));
+
+        state.set_sensitivity(AppProfile::Normal);
+        assert!(lock_or_recover(&state.auto_detector).should_convert("dkssud"));
+        assert_eq!(state.get_sensitivity(), AppProfile::Normal);
+    }
+
+    #[test]
+    fn test_sensitivity_does_not_override_app_profile() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        let config = crate::config::KoingConfig::default();
+        state.set_app_profiles(config.app_profiles.clone(), config.profile_params.clone());
+
+        // 매핑된 앱은 앱 프로필 유지
+        state.apply_profile_for_app("com.microsoft.VSCode");
+        assert_eq!(
+            state.set_sensitivity(AppProfile::Aggressive),
+            AppProfile::Conservative
+        );
+        assert_eq!(
+            lock_or_recover(&state.auto_detector).config().threshold,
+            85.0
+        );
+
+        // 매핑 없는 앱은 민감도 설정을 따름
+        assert_eq!(
+            state.apply_profile_for_app("com.apple.Safari"),
+            AppProfile::Aggressive
+        );
+        assert_eq!(
+            lock_or_recover(&state.auto_detector).config().threshold,
+            60.0
+        );
+    }
+
+    #[test]
+    fn test_non_text_focus_suppresses_conversion() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        state.set_focus_probe(|| Some(false));
+        let converted = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&converted);
+        state.set_convert_callback(move |buffer, _| sink.lock().unwrap().push(buffer));
+        for c in "dkssudgktpdy".chars() {
+            lock_or_recover(&state.buffer).push(c);
+        }
+
+        assert!(!trigger_realtime_conversion(&state));
+        assert!(!trigger_slow_conversion(&state));
+        assert!(lock_or_recover(&state.buffer).is_empty());
+        assert!(converted.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_expansion_trigger_bypasses_detection() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        state.set_focus_probe(|| Some(true));
+        let converted = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&converted);
+        state.set_convert_callback(move |buffer, _| sink.lock().unwrap().push(buffer));
+
+        // 영단어는 자동 감지에서 거부
+        for c in "brb".chars() {
+            lock_or_recover(&state.buffer).push(c);
+        }
+        assert!(!trigger_realtime_conversion(&state));
+        assert_eq!(lock_or_recover(&state.buffer).get(), "brb");
+
+        state.set_expansions(&[("brb".to_string(), "금방 올게".to_string())]);
+        assert!(trigger_realtime_conversion(&state));
+        assert!(lock_or_recover(&state.buffer).is_empty());
+        assert_eq!(*converted.lock().unwrap(), vec!["brb".to_string()]);
+    }
+
+    #[test]
+    fn test_buffer_confidence_uses_judge_snapshot() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        assert_eq!(state.judge_snapshot(), None);
+        assert_eq!(state.buffer_confidence(), None);
+
+        for c in "rk1sk".chars() {
+            lock_or_recover(&state.buffer).push(c);
+        }
+        // 비한글 키는 판정 대상에서 제외
+        assert_eq!(state.judge_snapshot().as_deref(), Some("rksk"));
+        assert_eq!(
+            state.buffer_confidence(),
+            Some(AutoDetector::default().get_confidence("rksk"))
+        );
+
+        // URL 구간은 판정하지 않음
+        lock_or_recover(&state.buffer).clear();
+        for c in "naver.com/".chars() {
+            lock_or_recover(&state.buffer).push(c);
+        }
+        assert_eq!(state.buffer_confidence(), None);
+    }
+
+    #[test]
+    fn test_buffer_change_notified_only_with_debug_overlay() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let calls_clone = Arc::clone(&calls);
+        state.set_buffer_change_callback(move |buffer, preview, confidence| {
+            lock_or_recover(&calls_clone).push((buffer, preview, confidence));
+        });
+
+        // 오버레이가 꺼져 있으면 호출하지 않음
+        lock_or_recover(&state.buffer).push('r');
+        state.notify_buffer_change();
+        assert!(lock_or_recover(&calls).is_empty());
+
+        state.set_debug_overlay(true);
+        lock_or_recover(&state.buffer).push('k');
+        state.notify_buffer_change();
+        // 버퍼가 그대로면 다시 호출하지 않음
+        state.notify_buffer_change();
+        lock_or_recover(&state.buffer).clear();
+        state.notify_buffer_change();
+
+        let calls = lock_or_recover(&calls);
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].0, "rk");
+        assert_eq!(calls[0].1, "가");
+        assert_eq!(
+            calls[0].2,
+            Some(AutoDetector::default().get_confidence("rk"))
+        );
+        assert_eq!(calls[1], (String::new(), String::new(), None));
+    }
+
+    #[test]
+    fn test_shadow_eval_counts_only_when_enabled() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        let detector = AutoDetector::default();
+        state.shadow_evaluate(&detector, "dkssudgktpdy", true);
+        assert!(state.shadow_stats().is_empty());
+
+        state.set_shadow_eval(true);
+        state.shadow_evaluate(&detector, "dkssudgktpdy", true);
+        let stats = state.shadow_stats();
+        assert_eq!(stats.len(), 2);
+        assert!(stats.iter().all(|variant| variant.evaluated == 1));
+
+        // 끄면 집계 초기화
+        state.set_shadow_eval(false);
+        assert!(state.shadow_stats().is_empty());
+    }
+
+    #[test]
+    fn test_apply_snapshot_reproduces_state() {
+        let source = EventTapState::new(HotkeyConfig::default());
+        source.set_debounce_ms(450);
+        source.set_adaptive_debounce(true);
+        source.set_two_stage_conversion(false);
+        source.set_realtime_mode(false);
+        source.set_app_profiles(
+            BTreeMap::from([("com.microsoft.VSCode".to_string(), AppProfile::Conservative)]),
+            BTreeMap::new(),
+        );
+        source.set_sensitivity(AppProfile::Aggressive);
+        source.apply_profile_for_app("com.microsoft.VSCode");
+        lock_or_recover(&source.auto_detector)
+            .set_never_convert_words(vec!["kotlin".to_string(), "Deno".to_string()]);
+        source.set_reverse_to_english(true);
+        source.set_reset_keycodes(vec![48, 53]);
+        source.set_ngram_settings(NgramSettings {
+            threshold: -9.0,
+            ..NgramSettings::default()
+        });
+        let snapshot = source.config_snapshot();
+        assert_eq!(snapshot.never_convert_words, vec!["deno", "kotlin"]);
+        assert_eq!(
+            snapshot.threshold,
+            AppProfile::Conservative.default_params().threshold
+        );
+
+        let target = EventTapState::new(HotkeyConfig::default());
+        target.apply_snapshot(&snapshot);
+        assert_eq!(target.config_snapshot(), snapshot);
+        assert!(lock_or_recover(&target.auto_detector).is_blocked_english_word("kotlin"));
+        // 포커스 앱 프로필도 그대로 — 민감도 변경 시 같은 앱 기준으로 다시 계산
+        assert_eq!(
+            target.set_sensitivity(AppProfile::Normal),
+            AppProfile::Conservative
+        );
+    }
+
+    #[test]
+    fn test_realtime_conversion_uses_injected_converter() {
+        /// 변환하지 못하는 변환기 (입력 그대로 반환)
+        struct IdentityConverter;
+        impl Converter for IdentityConverter {
+            fn convert(&self, keys: &str) -> String {
+                keys.to_string()
+            }
+        }
+
+        let state = EventTapState::new(HotkeyConfig::default());
+        state.set_focus_probe(|| Some(true));
+        let push = |text: &str| {
+            let mut buffer = lock_or_recover(&state.buffer);
+            buffer.clear();
+            text.chars().for_each(|c| buffer.push(c));
+        };
+
+        // 감지기가 통과시켜도 변환 결과가 원본과 같으면 구조 검사에서 거부
+        state.set_converter(Arc::new(IdentityConverter));
+        push("dkssudgktpdy");
+        assert!(!trigger_realtime_conversion(&state));
+        assert_eq!(lock_or_recover(&state.buffer).get(), "dkssudgktpdy");
+
+        state.set_converter(Arc::new(TwoSetConverter));
+        assert!(trigger_realtime_conversion(&state));
+        assert!(lock_or_recover(&state.buffer).is_empty());
+    }
+
+    #[test]
+    fn test_realtime_and_slow_stages_use_injected_gate_and_validator() {
+        /// 지정한 입력만 변환하는 판정기
+        struct OnlyGate(&'static str);
+        impl ConversionGate for OnlyGate {
+            fn check_input(&self, _judge: &str) -> Result<(), String> {
+                Ok(())
+            }
+            fn looks_like_english(&self, _judge: &str) -> bool {
+                false
+            }
+            fn confidence(&self, _keys: &str) -> f32 {
+                100.0
+            }
+            fn is_blocked_word(&self, _judge: &str) -> bool {
+                false
+            }
+            fn has_enough_keys(&self, _judge: &str) -> bool {
+                true
+            }
+            fn should_convert_realtime(&self, judge: &str) -> bool {
+                judge == self.0
+            }
+        }
+        /// 모든 입력의 구조 검사를 거부하는 검증기
+        struct RejectAll;
+        impl ConversionValidator for RejectAll {
+            fn validate(&self, _judge: &str, _english_like: bool) -> Result<(), String> {
+                Err("mock 거부".to_string())
+            }
+            fn check_structure(&self, _judge: &str) -> Result<(), String> {
+                Err("mock 거부".to_string())
+            }
+        }
+
+        let state = EventTapState::new(HotkeyConfig::default());
+        state.set_focus_probe(|| Some(true));
+        let push = |text: &str| {
+            let mut buffer = lock_or_recover(&state.buffer);
+            buffer.clear();
+            text.chars().for_each(|c| buffer.push(c));
+        };
+
+        // 내장 감지기라면 변환할 입력도 판정기가 거부하면 버퍼 유지
+        state.set_gate(Arc::new(OnlyGate("gksrmf")));
+        push("dkssudgktpdy");
+        assert!(!trigger_realtime_conversion(&state));
+        assert_eq!(lock_or_recover(&state.buffer).get(), "dkssudgktpdy");
+        push("gksrmf");
+        assert!(trigger_realtime_conversion(&state));
+        assert!(lock_or_recover(&state.buffer).is_empty());
+
+        // 느린 변환은 주입된 검증기의 구조 검사를 따름
+        push("dkssudgktpdy");
+        assert!(trigger_slow_conversion(&state));
+        state.set_validator(Arc::new(RejectAll));
+        push("dkssudgktpdy");
+        assert!(!trigger_slow_conversion(&state));
+        assert_eq!(lock_or_recover(&state.buffer).get(), "dkssudgktpdy");
+    }
+
+    #[test]
+    fn test_unknown_focus_allows_conversion() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        state.set_focus_probe(|| None);
+        let converted = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&converted);
+        state.set_convert_callback(move |buffer, _| sink.lock().unwrap().push(buffer));
+        for c in "dkssudgktpdy".chars() {
+            lock_or_recover(&state.buffer).push(c);
+        }
+
+        assert!(trigger_slow_conversion(&state));
+        assert_eq!(*converted.lock().unwrap(), vec!["dkssudgktpdy".to_string()]);
+    }
+
+    #[test]
+    fn test_slow_conversion_ignores_heuristic_low_score() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        state.set_focus_probe(|| Some(true));
+        let converted = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&converted);
+        state.set_convert_callback(move |buffer, _| sink.lock().unwrap().push(buffer));
+        // 괘씸: 구조는 정상이지만 드문 음절이라 빈도 휴리스틱 점수 미달
+        assert_eq!(
+            KoreanValidator::new().classify("rhoTla"),
+            Err(RejectReason::LowScore)
+        );
+        for c in "rhoTla".chars() {
+            lock_or_recover(&state.buffer).push(c);
+        }
+
+        assert!(trigger_slow_conversion(&state));
+        assert_eq!(*converted.lock().unwrap(), vec!["rhoTla".to_string()]);
+    }
+
+    #[test]
+    fn test_debounce_expiry_follows_two_stage_setting() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        state.set_focus_probe(|| Some(true));
+        let converted = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&converted);
+        state.set_convert_callback(move |buffer, _| sink.lock().unwrap().push(buffer));
+        // 절벽: 1단계(신뢰도)는 거부, 2단계(구조)는 통과
+        for c in "wjfqur".chars() {
+            lock_or_recover(&state.buffer).push(c);
+        }
+
+        // 2단계 비활성: 1단계 실패가 최종 — 대기 없이 종료, 변환 없음
+        state.set_two_stage_conversion(false);
+        assert!(!on_debounce_expired(&state, false));
+        // 2단계 대기 중에 꺼져도 2단계는 건너뜀
+        assert!(!on_debounce_expired(&state, true));
+        assert!(converted.lock().unwrap().is_empty());
+        assert_eq!(lock_or_recover(&state.buffer).get(), "wjfqur");
+
+        // 2단계 활성: 1단계 실패 후 2단계 대기 → 2단계에서 변환
+        state.set_two_stage_conversion(true);
+        assert!(on_debounce_expired(&state, false));
+        assert!(converted.lock().unwrap().is_empty());
+        assert!(!on_debounce_expired(&state, true));
+        assert_eq!(*converted.lock().unwrap(), vec!["wjfqur".to_string()]);
+    }
+
+    #[test]
+    fn test_should_soft_switch_thresholds() {
+        let detector = AutoDetector::default();
+        // 낱자모가 남아 변환은 거부되지만 한글 입력 의도가 보이는 경우
+        assert!(should_soft_switch(&detector, "rkqt"));
+        // 짧은 입력, 영어 단어, 낮은 신뢰도는 전환하지 않음
+        assert!(!should_soft_switch(&detector, "rkq"));
+        assert!(!should_soft_switch(&detector, "world"));
+        assert!(!should_soft_switch(&detector, "rustc"));
+        assert!(!should_soft_switch(&detector, "asdf"));
+    }
+
+    #[test]
+    fn test_soft_switch_changes_input_source_without_replacing_text() {
+        static SWITCH_CALLS: AtomicU64 = AtomicU64::new(0);
+        fn counting_switch() {
+            SWITCH_CALLS.fetch_add(1, Ordering::SeqCst);
+        }
+
+        let state = EventTapState::new(HotkeyConfig::default());
+        state.set_focus_probe(|| Some(true));
+        lock_or_recover(&state.input_source).switch_to_korean = counting_switch;
+        let converted = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&converted);
+        state.set_convert_callback(move |buffer, _| sink.lock().unwrap().push(buffer));
+        let type_buffer = |text: &str| {
+            for c in text.chars() {
+                lock_or_recover(&state.buffer).push(c);
+            }
+        };
+
+        // 옵션 꺼짐: 거부 후에도 전환 없음
+        type_buffer("rkqt");
+        assert!(!trigger_slow_conversion(&state));
+        assert!(!trigger_soft_switch(&state));
+        assert_eq!(SWITCH_CALLS.load(Ordering::SeqCst), 0);
+
+        // 옵션 켜짐: 입력 소스만 전환, 텍스트 교체 콜백은 호출되지 않음
+        state.set_soft_switch_on_reject(true);
+        assert!(trigger_soft_switch(&state));
+        assert_eq!(SWITCH_CALLS.load(Ordering::SeqCst), 1);
+        assert!(lock_or_recover(&state.buffer).is_empty());
+        assert!(converted.lock().unwrap().is_empty());
+
+        // 하한 미달(영어 단어)은 전환하지 않고 버퍼 유지
+        type_buffer("rustc");
+        assert!(!trigger_soft_switch(&state));
+        assert_eq!(SWITCH_CALLS.load(Ordering::SeqCst), 1);
+        assert_eq!(lock_or_recover(&state.buffer).get(), "rustc");
+    }
+
+    #[test]
+    fn test_disabled_state_never_touches_input_source() {
+        static TIS_CALLS: AtomicU64 = AtomicU64::new(0);
+        fn counting_snapshot() -> InputSourceSnapshot {
+            TIS_CALLS.fetch_add(1, Ordering::SeqCst);
+            InputSourceSnapshot {
+                state: InputSourceState::English,
+                is_fresh: true,
+            }
+        }
+        fn counting_call() {
+            TIS_CALLS.fetch_add(1, Ordering::SeqCst);
+        }
+
+        let state = EventTapState::new(HotkeyConfig::default());
+        state.set_focus_probe(|| Some(true));
+        *lock_or_recover(&state.input_source) = InputSourceAccess {
+            snapshot: counting_snapshot,
+            invalidate: counting_call,
+            refresh: counting_call,
+            switch_to_korean: counting_call,
+        };
+        state.set_enabled(false);
+
+        let source = CGEventSource::new(CGEventSourceStateID::Private).unwrap();
+        let key = CGEvent::new_keyboard_event(source.clone(), 0, true).unwrap(); // a
+        let space = CGEvent::new_keyboard_event(source.clone(), 49, true).unwrap();
+        let enter = CGEvent::new_keyboard_event(source, 36, true).unwrap();
+        for (event_type, event) in [
+            (CGEventType::KeyDown, &key),
+            (CGEventType::KeyDown, &space),
+            (CGEventType::KeyDown, &enter),
+            (CGEventType::FlagsChanged, &key),
+            (CGEventType::LeftMouseDown, &key),
+        ] {
+            assert!(handle_event(&state, event_type, event).is_some());
+        }
+        // 비활성 상태에서는 전환 타이머/soft switch도 입력 소스를 건드리지 않음
+        assert!(!state.switch_to_korean());
+        assert_eq!(TIS_CALLS.load(Ordering::SeqCst), 0);
+        assert!(lock_or_recover(&state.buffer).is_empty());
+
+        // 활성 상태에서는 수정키 변경 시 캐시 무효화 + 갱신 예약
+        state.set_enabled(true);
+        assert!(handle_event(&state, CGEventType::FlagsChanged, &key).is_some());
+        assert_eq!(TIS_CALLS.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_input_source_policy_controls_buffering() {
+        static SOURCE_STATE: AtomicU64 = AtomicU64::new(0);
+        fn injected_snapshot() -> InputSourceSnapshot {
+            let state = match SOURCE_STATE.load(Ordering::SeqCst) {
+                1 => InputSourceState::English,
+                2 => InputSourceState::NonEnglish,
+                _ => InputSourceState::Unknown,
+            };
+            InputSourceSnapshot {
+                state,
+                is_fresh: true,
+            }
+        }
+        fn no_op() {}
+
+        let state = EventTapState::new(HotkeyConfig::default());
+        *lock_or_recover(&state.input_source) = InputSourceAccess {
+            snapshot: injected_snapshot,
+            invalidate: no_op,
+            refresh: no_op,
+            switch_to_korean: no_op,
+        };
+        let source = CGEventSource::new(CGEventSourceStateID::Private).unwrap();
+        let key = CGEvent::new_keyboard_event(source, 0, true).unwrap(); // a (ㅁ)
+        let type_key = |source_state: InputSourceState| {
+            SOURCE_STATE.store(source_state as u64, Ordering::SeqCst);
+            state.reset_input_state();
+            assert!(handle_event(&state, CGEventType::KeyDown, &key).is_some());
+            (
+                lock_or_recover(&state.buffer).get().to_string(),
+                lock_or_recover(&state.debounce_cv.0).command,
+            )
+        };
+
+        // 기본 정책: 영문이면 버퍼링 + 자동 변환 타이머, 한글이면 패스스루
+        assert_eq!(state.input_source_policy(), InputSourcePolicy::default());
+        assert_eq!(
+            type_key(InputSourceState::English),
+            ("a".to_string(), Some(DebounceCommand::Reset))
+        );
+        assert_eq!(
+            type_key(InputSourceState::NonEnglish),
+            (String::new(), Some(DebounceCommand::Cancel))
+        );
+
+        // 한글 입력 소스에서도 버퍼링만 (자동 변환 타이머 없음)
+        state.set_input_source_policy(InputSourcePolicy {
+            english: KeyHandling::PassThrough,
+            non_english: KeyHandling::BufferOnly,
+        });
+        assert_eq!(
+            type_key(InputSourceState::NonEnglish),
+            ("a".to_string(), Some(DebounceCommand::Cancel))
+        );
+        assert_eq!(lock_or_recover(&state.manual_buffer).get(), "a");
+        assert_eq!(
+            type_key(InputSourceState::English),
+            (String::new(), Some(DebounceCommand::Cancel))
+        );
+
+        // 확인 전 입력은 pending 보관 후 확인된 상태의 정책 적용
+        type_key(InputSourceState::Unknown);
+        assert!(state.has_pending_buffer());
+        state.resolve_pending_buffer(InputSourceState::NonEnglish);
+        assert_eq!(lock_or_recover(&state.buffer).get(), "a");
+        assert_eq!(
+            state
+                .input_source_policy()
+                .handling_for(InputSourceState::Unknown),
+            None
+        );
+    }
+
+    #[test]
+    fn test_manual_hotkey_joins_words_only_without_auto_detect() {
+        fn english_snapshot() -> InputSourceSnapshot {
+            InputSourceSnapshot {
+                state: InputSourceState::English,
+                is_fresh: true,
+            }
+        }
+        fn no_op() {}
+
+        let state = EventTapState::new(HotkeyConfig::default());
+        state.set_focus_probe(|| Some(true));
+        *lock_or_recover(&state.input_source) = InputSourceAccess {
+            snapshot: english_snapshot,
+            invalidate: no_op,
+            refresh: no_op,
+            switch_to_korean: no_op,
+        };
+        let converted = Arc::new(Mutex::new(Vec::new()));
+        let converted_clone = Arc::clone(&converted);
+        state.set_convert_callback(move |text, is_manual| {
+            lock_or_recover(&converted_clone).push((text, is_manual));
+        });
+
+        let source = CGEventSource::new(CGEventSourceStateID::Private).unwrap();
+        let type_text = |text: &str| {
+            for c in text.chars() {
+                let keycode = if c == ' ' {
+                    49
+                } else {
+                    char_to_keycode(c).unwrap().0
+                };
+                let key = CGEvent::new_keyboard_event(source.clone(), keycode, true).unwrap();
+                assert!(handle_event(&state, CGEventType::KeyDown, &key).is_some());
+            }
+        };
+        let press_hotkey = || {
+            let key = CGEvent::new_keyboard_event(source.clone(), 49, true).unwrap();
+            key.set_flags(CGEventFlags::CGEventFlagAlternate);
+            assert!(handle_event(&state, CGEventType::KeyDown, &key).is_none());
+            lock_or_recover(&converted).pop()
+        };
+
+        // 자동 감지 모드: Space에서 끊고 마지막 단어만 변환
+        assert!(state.is_auto_detect_enabled());
+        type_text("hello dkssud");
+        assert_eq!(press_hotkey(), Some(("dkssud".to_string(), true)));
+
+        // 수동 변환 모드: Space로 이어진 단어 전체를 공백 포함 변환
+        state.set_auto_detect_enabled(false);
+        type_text("dkssud gktpdy");
+        assert_eq!(press_hotkey(), Some(("dkssud gktpdy".to_string(), true)));
+    }
+
+    #[test]
+    fn test_long_buffer_triggers_conversion_and_requeue() {
+        fn english_snapshot() -> InputSourceSnapshot {
+            InputSourceSnapshot {
+                state: InputSourceState::English,
+                is_fresh: true,
+            }
+        }
+        fn no_op() {}
+
+        let state = EventTapState::new(HotkeyConfig::default());
+        state.set_realtime_mode(true);
+        *lock_or_recover(&state.input_source) = InputSourceAccess {
+            snapshot: english_snapshot,
+            invalidate: no_op,
+            refresh: no_op,
+            switch_to_korean: no_op,
+        };
+        let source = CGEventSource::new(CGEventSourceStateID::Private).unwrap();
+        let key = CGEvent::new_keyboard_event(source, 0, true).unwrap(); // a (ㅁ)
+        let type_key = || {
+            assert!(handle_event(&state, CGEventType::KeyDown, &key).is_some());
+            lock_or_recover(&state.debounce_cv.0).command.take()
+        };
+
+        for _ in 0..LONG_BUFFER_KEYS - 1 {
+            assert_eq!(type_key(), Some(DebounceCommand::Reset));
+        }
+        // 길이 상한에 닿으면 debounce 만료를 기다리지 않음
+        assert_eq!(type_key(), Some(DebounceCommand::Trigger));
+        assert_eq!(type_key(), Some(DebounceCommand::Reset));
+
+        // 분할 변환의 뒷부분은 교체 중 입력된 키 앞에 되돌아감
+        state.reset_input_state();
+        type_key();
+        state.requeue_keys("qksrkqtmqslek");
+        assert_eq!(lock_or_recover(&state.buffer).get(), "qksrkqtmqsleka");
+        assert_eq!(
+            lock_or_recover(&state.manual_buffer).get(),
+            "qksrkqtmqsleka"
+        );
+        assert_eq!(
+            lock_or_recover(&state.debounce_cv.0).command,
+            Some(DebounceCommand::Reset)
+        );
+    }
+
+    #[test]
+    fn test_reverse_candidate_only_when_enabled() {
+        let state = EventTapState::new(HotkeyConfig::default());
+        let type_korean = |text: &str| text.chars().for_each(|c| state.push_korean_key(c));
+
+        // 옵션 꺼짐: 키를 기록하지 않음
+        type_korean("hello");
+        assert_eq!(state.take_reverse_candidate(), None);
+
+        state.set_reverse_to_english(true);
+        type_korean("hello");
+        assert_eq!(state.take_reverse_candidate(), Some("hello".to_string()));
+        // 꺼낸 뒤에는 비어 있음
+        assert_eq!(state.take_reverse_candidate(), None);
+
+        // 사전에 없는 한글 입력, 짧은 단어는 후보가 아님
+        type_korean("dkssud");
+        assert_eq!(state.take_reverse_candidate(), None);
+        type_korean("go");
+        assert_eq!(state.take_reverse_candidate(), None);
+
+        // 끄면 기록 중인 키도 버림
+        type_korean("hel");
+        state.set_reverse_to_english(false);
+        type_korean("lo");
+        assert_eq!(state.take_reverse_candidate(), None);
+    }
+
+    #[test]
+    fn test_switch_allowed_only_outside_listed_apps() {
+        let no_switch = vec!["com.example.Game".to_string()];
+        assert!(!switch_allowed_for(Some("com.example.Game"), &no_switch));
+        assert!(switch_allowed_for(Some("com.apple.TextEdit"), &no_switch));
+        // 하위 ID/접두사는 일치로 보지 않음
+        assert!(switch_allowed_for(
+            Some("com.example.Game.Helper"),
+            &no_switch
+        ));
+        // 최전면 앱을 모르면 기존처럼 전환
+        assert!(switch_allowed_for(None, &no_switch));
+        assert!(switch_allowed_for(Some("com.example.Game"), &[]));
+    }
+
+    #[test]
+    fn test_switch_app_check_is_cached_briefly() {
+        static FRONTMOST: Mutex<Option<&str>> = Mutex::new(None);
+        fn probe() -> Option<String> {
+            FRONTMOST.lock().unwrap().map(str::to_string)
+        }
+
+        let state = EventTapState::new(HotkeyConfig::default());
+        state.set_frontmost_app_probe(probe);
+        *FRONTMOST.lock().unwrap() = Some("com.example.Game");
+        let start = Instant::now();
+        // 목록이 비어 있으면 조회 없이 허용
+        assert!(state.switch_allowed_at(start));
+
+        state.set_no_switch_bundle_ids(vec!["com.example.Game".to_string()]);
+        assert!(!state.switch_allowed_at(start));
+
+        // 캐시 유지 시간 안에는 앱이 바뀌어도 이전 결과
+        *FRONTMOST.lock().unwrap() = Some("com.apple.TextEdit");
+        assert!(!state.switch_allowed_at(start + Duration::from_millis(100)));
+        assert!(state.switch_allowed_at(start + SWITCH_APP_CHECK_TTL));
+    }
+
+    #[test]
+    fn test_focus_check_is_cached_briefly() {
+        static PROBE_CALLS: AtomicU64 = AtomicU64::new(0);
+        fn counting_probe() -> Option<bool> {
+            PROBE_CALLS.fetch_add(1, Ordering::SeqCst);
+            Some(true)
+        }
+
+        let state = EventTapState::new(HotkeyConfig::default());
+        state.set_focus_probe(counting_probe);
+        let start = Instant::now();
+
+        assert!(state.focus_allows_conversion_at(start));
+        assert!(state.focus_allows_conversion_at(start + Duration::from_millis(100)));
+        assert_eq!(PROBE_CALLS.load(Ordering::SeqCst), 1);
+
+        assert!(state.focus_allows_conversion_at(start + FOCUS_CHECK_TTL));
+        assert_eq!(PROBE_CALLS.load(Ordering::SeqCst), 2);
+    }
 }
@@ -0,0 +1,628 @@
+//! 포커스된 UI 요소 확인 (Accessibility API)
+//!
+//! 버튼/목록 등 비텍스트 요소에 포커스가 있을 때 변환을 억제하기 위해
+//! 시스템 전역 포커스 요소의 `AXRole`을 조회합니다.
+//! 선택 텍스트 일괄 변환을 위해 `AXSelectedText` 읽기/교체도 제공합니다.
+//! 변환 전후 커서 위치 확인/복원을 위해 `AXSelectedTextRange`도 다룹니다.
+//! 실험적으로 변환 결과를 paste 대신 AX 텍스트 입력으로 커밋하는 경로도 제공합니다.
+
+use core_foundation::base::{CFGetTypeID, CFIndex, CFRange, CFRelease, CFTypeRef, TCFType};
+use core_foundation::string::{CFString, CFStringRef};
+use std::ffi::c_void;
+use std::ptr;
+
+type AXUIElementRef = *const c_void;
+type AXValueRef = *const c_void;
+type AXError = i32;
+type AXValueType = u32;
+
+const AX_ERROR_SUCCESS: AXError = 0;
+/// `kAXValueCFRangeType`
+const AX_VALUE_CF_RANGE_TYPE: AXValueType = 4;
+/// AX 조회 타임아웃 (초) — 응답 없는 앱 때문에 변환 경로가 막히지 않도록 짧게 설정
+const AX_MESSAGING_TIMEOUT_SECS: f32 = 0.1;
+
+#[link(name = "ApplicationServices", kind = "framework")]
+extern "C" {
+    fn AXUIElementCreateSystemWide() -> AXUIElementRef;
+    fn AXUIElementCopyAttributeValue(
+        element: AXUIElementRef,
+        attribute: CFStringRef,
+        value: *mut CFTypeRef,
+    ) -> AXError;
+    fn AXUIElementSetAttributeValue(
+        element: AXUIElementRef,
+        attribute: CFStringRef,
+        value: CFTypeRef,
+    ) -> AXError;
+    fn AXUIElementCopyParameterizedAttributeValue(
+        element: AXUIElementRef,
+        parameterized_attribute: CFStringRef,
+        parameter: CFTypeRef,
+        result: *mut CFTypeRef,
+    ) -> AXError;
+    fn AXUIElementIsAttributeSettable(
+        element: AXUIElementRef,
+        attribute: CFStringRef,
+        settable: *mut u8,
+    ) -> AXError;
+    fn AXUIElementSetMessagingTimeout(element: AXUIElementRef, timeout: f32) -> AXError;
+    fn AXValueCreate(value_type: AXValueType, value: *const c_void) -> AXValueRef;
+    fn AXValueGetValue(value: AXValueRef, value_type: AXValueType, value_out: *mut c_void) -> bool;
+}
+
+/// 텍스트 입력 요소 role
+const TEXT_INPUT_ROLES: [&str; 4] = ["AXTextField", "AXTextArea", "AXComboBox", "AXSearchField"];
+
+/// 텍스트 입력이 불가능한 것이 확실한 role
+const NON_TEXT_ROLES: [&str; 14] = [
+    "AXButton",
+    "AXCheckBox",
+    "AXRadioButton",
+    "AXPopUpButton",
+    "AXMenuButton",
+    "AXMenuItem",
+    "AXMenuBarItem",
+    "AXSlider",
+    "AXImage",
+    "AXLink",
+    "AXTabGroup",
+    "AXDisclosureTriangle",
+    "AXList",
+    "AXOutline",
+];
+
+/// 포커스 요소 분류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusKind {
+    /// 텍스트 입력 요소
+    TextInput,
+    /// 비텍스트 요소 (변환 억제)
+    NonText,
+    /// 판별 불가 (조회 실패, 웹 콘텐츠의 AXGroup 등)
+    Unknown,
+}
+
+impl FocusKind {
+    /// 변환 허용 여부 — 판별 불가 시에도 허용 (텍스트 입력 누락 방지)
+    pub fn allows_conversion(self) -> bool {
+        self != FocusKind::NonText
+    }
+}
+
+/// role 문자열로 포커스 요소 분류
+pub fn classify_role(role: Option<&str>) -> FocusKind {
+    match role {
+        Some(role) if TEXT_INPUT_ROLES.contains(&role) => FocusKind::TextInput,
+        Some(role) if NON_TEXT_ROLES.contains(&role) => FocusKind::NonText,
+        _ => FocusKind::Unknown,
+    }
+}
+
+/// 텍스트 범위 (UTF-16 단위, `AXSelectedTextRange`와 동일)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextRange {
+    pub location: usize,
+    pub length: usize,
+}
+
+/// 교체 전 커서 검사 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorCheck {
+    /// 커서가 변환 구간 바로 뒤에 있음 (교체 후 복원 기준 위치)
+    AtSegmentEnd(TextRange),
+    /// 선택 영역이 있거나 커서가 다른 곳으로 이동함 — backspace가 다른 텍스트를 지움
+    Moved,
+    /// AX로 커서를 조회할 수 없음 (터미널/일부 웹 편집기)
+    Unknown,
+}
+
+fn utf16_len(text: &str) -> usize {
+    text.encode_utf16().count()
+}
+
+/// 커서 직전 텍스트가 변환 구간과 일치하는지 검사
+///
+/// - `range`: 교체 전 `AXSelectedTextRange`
+/// - `before_cursor`: 커서 직전 변환 구간 길이만큼의 텍스트
+pub fn check_cursor(
+    range: Option<TextRange>,
+    before_cursor: Option<&str>,
+    segment: &str,
+) -> CursorCheck {
+    let Some(range) = range else {
+        return CursorCheck::Unknown;
+    };
+    if range.length > 0 || range.location < utf16_len(segment) {
+        return CursorCheck::Moved;
+    }
+    match before_cursor {
+        Some(text) if text == segment => CursorCheck::AtSegmentEnd(range),
+        Some(_) => CursorCheck::Moved,
+        None => CursorCheck::Unknown,
+    }
+}
+
+/// 교체 후 커서 위치: 변환 구간 시작 + 새 텍스트 길이
+pub fn cursor_after_replace(saved: TextRange, segment: &str, new_text: &str) -> TextRange {
+    TextRange {
+        location: saved.location.saturating_sub(utf16_len(segment)) + utf16_len(new_text),
+        length: 0,
+    }
+}
+
+/// 텍스트 입력 커밋 경로 판단에 필요한 포커스 요소 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextInputCapability {
+    pub focus: FocusKind,
+    /// `AXSelectedText` 설정 가능 여부
+    pub selected_text_settable: bool,
+    /// `AXSelectedTextRange` 설정 가능 여부
+    pub range_settable: bool,
+    pub cursor: CursorCheck,
+}
+
+/// 변환 결과 커밋 경로
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitPath {
+    /// 변환 구간을 선택한 뒤 `AXSelectedText`로 교체 (선택할 범위)
+    TextInput(TextRange),
+    /// 기존 backspace + paste
+    Paste,
+}
+
+/// 커밋 경로 결정 — 텍스트 입력 요소에서 선택/교체가 모두 가능하고
+/// 커서가 변환 구간 바로 뒤에 있을 때만 텍스트 입력 경로 사용
+///
+/// 웹 편집기(AXGroup 등 판별 불가 role)는 AX 교체가 무시되거나 엉뚱한 위치에
+/// 반영되는 경우가 있어 paste를 유지합니다.
+pub fn choose_commit_path(capability: &TextInputCapability, segment: &str) -> CommitPath {
+    if capability.focus != FocusKind::TextInput
+        || !capability.selected_text_settable
+        || !capability.range_settable
+        || segment.is_empty()
+    {
+        return CommitPath::Paste;
+    }
+    match capability.cursor {
+        CursorCheck::AtSegmentEnd(cursor) => CommitPath::TextInput(segment_range(cursor, segment)),
+        CursorCheck::Moved | CursorCheck::Unknown => CommitPath::Paste,
+    }
+}
+
+/// 변환 구간 바로 앞 위치부터 커서까지의 범위
+fn segment_range(cursor: TextRange, segment: &str) -> TextRange {
+    let length = utf16_len(segment);
+    TextRange {
+        location: cursor.location.saturating_sub(length),
+        length,
+    }
+}
+
+unsafe fn create_range_value(range: TextRange) -> AXValueRef {
+    let cf_range = CFRange::init(range.location as CFIndex, range.length as CFIndex);
+    AXValueCreate(
+        AX_VALUE_CF_RANGE_TYPE,
+        &cf_range as *const CFRange as *const c_void,
+    )
+}
+
+/// 요소의 `AXSelectedTextRange` 조회
+unsafe fn copy_selected_range(element: AXUIElementRef) -> Option<TextRange> {
+    let attr = CFString::new("AXSelectedTextRange");
+    let mut value: CFTypeRef = ptr::null();
+    let err = AXUIElementCopyAttributeValue(element, attr.as_concrete_TypeRef(), &mut value);
+    if err != AX_ERROR_SUCCESS || value.is_null() {
+        return None;
+    }
+    let mut cf_range = CFRange::init(0, 0);
+    let ok = AXValueGetValue(
+        value as AXValueRef,
+        AX_VALUE_CF_RANGE_TYPE,
+        &mut cf_range as *mut CFRange as *mut c_void,
+    );
+    CFRelease(value);
+    if !ok || cf_range.location < 0 || cf_range.length < 0 {
+        return None;
+    }
+    Some(TextRange {
+        location: cf_range.location as usize,
+        length: cf_range.length as usize,
+    })
+}
+
+/// 요소 속성이 설정 가능한지 (조회 실패 시 false)
+unsafe fn is_attribute_settable(element: AXUIElementRef, attribute: &str) -> bool {
+    let attr = CFString::new(attribute);
+    let mut settable: u8 = 0;
+    let err = AXUIElementIsAttributeSettable(element, attr.as_concrete_TypeRef(), &mut settable);
+    err == AX_ERROR_SUCCESS && settable != 0
+}
+
+/// 요소에 CFString 속성 설정
+unsafe fn set_string_attribute(
+    element: AXUIElementRef,
+    attribute: &str,
+    text: &str,
+) -> Result<(), String> {
+    let attr = CFString::new(attribute);
+    let value = CFString::new(text);
+    let err =
+        AXUIElementSetAttributeValue(element, attr.as_concrete_TypeRef(), value.as_CFTypeRef());
+    if err == AX_ERROR_SUCCESS {
+        Ok(())
+    } else {
+        Err(format!("{} 설정 실패: 오류 코드 {}", attribute, err))
+    }
+}
+
+/// 요소의 `AXSelectedTextRange` 설정
+unsafe fn set_selected_range(element: AXUIElementRef, range: TextRange) -> Result<(), String> {
+    let value = create_range_value(range);
+    if value.is_null() {
+        return Err("AXValue 생성 실패".to_string());
+    }
+    let attr = CFString::new("AXSelectedTextRange");
+    let err = AXUIElementSetAttributeValue(element, attr.as_concrete_TypeRef(), value as CFTypeRef);
+    CFRelease(value as CFTypeRef);
+    if err == AX_ERROR_SUCCESS {
+        Ok(())
+    } else {
+        Err(format!("AXSelectedTextRange 설정 실패: 오류 코드 {}", err))
+    }
+}
+
+/// 요소의 커서 위치 검사 (`check_focused_cursor`의 요소 지정 버전)
+unsafe fn element_cursor_check(element: AXUIElementRef, segment: &str) -> CursorCheck {
+    let range = copy_selected_range(element);
+    let before = range
+        .filter(|range| range.length == 0)
+        .and_then(|range| copy_string_for_range(element, segment_range(range, segment)));
+    check_cursor(range, before.as_deref(), segment)
+}
+
+/// 요소의 범위 텍스트 조회 (`AXStringForRange`)
+unsafe fn copy_string_for_range(element: AXUIElementRef, range: TextRange) -> Option<String> {
+    let param = create_range_value(range);
+    if param.is_null() {
+        return None;
+    }
+    let attr = CFString::new("AXStringForRange");
+    let mut value: CFTypeRef = ptr::null();
+    let err = AXUIElementCopyParameterizedAttributeValue(
+        element,
+        attr.as_concrete_TypeRef(),
+        param as CFTypeRef,
+        &mut value,
+    );
+    CFRelease(param as CFTypeRef);
+    if err != AX_ERROR_SUCCESS || value.is_null() {
+        return None;
+    }
+    if CFGetTypeID(value) != CFString::type_id() {
+        CFRelease(value);
+        return None;
+    }
+    Some(CFString::wrap_under_create_rule(value as CFStringRef).to_string())
+}
+
+/// 요소의 CFString 속성 조회
+unsafe fn copy_string_attribute(element: AXUIElementRef, attribute: &str) -> Option<String> {
+    let attr = CFString::new(attribute);
+    let mut value: CFTypeRef = ptr::null();
+    let err = AXUIElementCopyAttributeValue(element, attr.as_concrete_TypeRef(), &mut value);
+    if err != AX_ERROR_SUCCESS || value.is_null() {
+        return None;
+    }
+    if CFGetTypeID(value) != CFString::type_id() {
+        CFRelease(value);
+        return None;
+    }
+    // Copy 규칙: 소유권을 넘겨받아 drop 시 해제
+    Some(CFString::wrap_under_create_rule(value as CFStringRef).to_string())
+}
+
+/// 포커스된 UI 요소에 대해 `f` 실행 (포커스 요소 조회 실패 시 None)
+fn with_focused_element<T>(f: impl FnOnce(AXUIElementRef) -> Option<T>) -> Option<T> {
+    unsafe {
+        let system = AXUIElementCreateSystemWide();
+        if system.is_null() {
+            return None;
+        }
+        AXUIElementSetMessagingTimeout(system, AX_MESSAGING_TIMEOUT_SECS);
+
+        let attr = CFString::new("AXFocusedUIElement");
+        let mut focused: CFTypeRef = ptr::null();
+        let err = AXUIElementCopyAttributeValue(system, attr.as_concrete_TypeRef(), &mut focused);
+        CFRelease(system as CFTypeRef);
+        if err != AX_ERROR_SUCCESS || focused.is_null() {
+            return None;
+        }
+
+        let result = f(focused as AXUIElementRef);
+        CFRelease(focused);
+        result
+    }
+}
+
+/// 현재 포커스된 UI 요소의 `AXRole` (조회 실패 시 None)
+pub fn focused_element_role() -> Option<String> {
+    with_focused_element(|element| unsafe { copy_string_attribute(element, "AXRole") })
+}
+
+/// 포커스된 요소의 선택 텍스트 (선택 없으면 빈 문자열, 조회 실패 시 None)
+pub fn focused_selected_text() -> Option<String> {
+    with_focused_element(|element| unsafe { copy_string_attribute(element, "AXSelectedText") })
+}
+
+/// 포커스된 요소의 선택 텍스트를 교체
+pub fn replace_focused_selected_text(text: &str) -> Result<(), String> {
+    with_focused_element(|element| unsafe {
+        let attr = CFString::new("AXSelectedText");
+        let value = CFString::new(text);
+        let err =
+            AXUIElementSetAttributeValue(element, attr.as_concrete_TypeRef(), value.as_CFTypeRef());
+        Some(err)
+    })
+    .ok_or_else(|| "포커스 요소를 찾을 수 없습니다".to_string())
+    .and_then(|err| {
+        if err == AX_ERROR_SUCCESS {
+            Ok(())
+        } else {
+            Err(format!("AXSelectedText 설정 실패: 오류 코드 {}", err))
+        }
+    })
+}
+
+/// 포커스된 요소의 커서 범위 (조회 실패 시 None)
+pub fn focused_selected_range() -> Option<TextRange> {
+    with_focused_element(|element| unsafe { copy_selected_range(element) })
+}
+
+/// 교체 전 커서가 변환 구간 바로 뒤에 있는지 확인
+pub fn check_focused_cursor(segment: &str) -> CursorCheck {
+    with_focused_element(|element| unsafe { Some(element_cursor_check(element, segment)) })
+        .unwrap_or(CursorCheck::Unknown)
+}
+
+/// 변환 결과를 AX 텍스트 입력으로 커밋 (실험적)
+///
+/// 다른 앱의 `NSTextInputClient`(marked text)에는 외부에서 접근할 수 없으므로,
+/// 변환 구간을 `AXSelectedTextRange`로 선택한 뒤 `AXSelectedText`를 교체해
+/// 입력기의 insertText와 같은 효과를 냅니다. 클립보드와 합성 키 이벤트를 쓰지 않습니다.
+///
+/// - `Ok(true)`: 커밋 완료
+/// - `Ok(false)`: 미지원 또는 텍스트 변경 전 실패 — 호출자가 paste 경로로 폴백
+/// - `Err`: 텍스트가 바뀌었을 수 있어 폴백하면 안 되는 실패
+pub fn commit_via_text_input(segment: &str, new_text: &str) -> Result<bool, String> {
+    with_focused_element(|element| unsafe {
+        let capability = TextInputCapability {
+            focus: classify_role(copy_string_attribute(element, "AXRole").as_deref()),
+            selected_text_settable: is_attribute_settable(element, "AXSelectedText"),
+            range_settable: is_attribute_settable(element, "AXSelectedTextRange"),
+            cursor: element_cursor_check(element, segment),
+        };
+        let CommitPath::TextInput(selection) = choose_commit_path(&capability, segment) else {
+            log::debug!("텍스트 입력 커밋 미지원: {:?}", capability);
+            return Some(Ok(false));
+        };
+
+        if let Err(e) = set_selected_range(element, selection) {
+            log::debug!("텍스트 입력 커밋 구간 선택 실패: {}", e);
+            return Some(Ok(false));
+        }
+        if let Err(e) = set_string_attribute(element, "AXSelectedText", new_text) {
+            // 교체 전 실패 — 선택을 원래 커서로 되돌리고 paste로 폴백
+            log::debug!("텍스트 입력 커밋 실패: {}", e);
+            let cursor = TextRange {
+                location: selection.location + selection.length,
+                length: 0,
+            };
+            return Some(set_selected_range(element, cursor).map(|_| false));
+        }
+
+        // 교체 결과 검증: 커서 직전 텍스트가 새 텍스트와 같아야 함
+        match element_cursor_check(element, new_text) {
+            CursorCheck::AtSegmentEnd(_) => Some(Ok(true)),
+            check => Some(Err(format!("텍스트 입력 커밋 검증 실패: {:?}", check))),
+        }
+    })
+    .unwrap_or(Ok(false))
+}
+
+/// 포커스된 요소의 커서 범위 설정
+pub fn set_focused_selected_range(range: TextRange) -> Result<(), String> {
+    with_focused_element(|element| unsafe { Some(set_selected_range(element, range)) })
+        .unwrap_or_else(|| Err("포커스 요소를 찾을 수 없습니다".to_string()))
+}
+
+/// 포커스된 요소가 텍스트 입력인지 확인 (판별 불가 시 None)
+pub fn is_text_input_focused() -> Option<bool> {
+    match classify_role(focused_element_role().as_deref()) {
+        FocusKind::TextInput => Some(true),
+        FocusKind::NonText => Some(false),
+        FocusKind::Unknown => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_text_roles() {
+        assert_eq!(classify_role(Some("AXTextField")), FocusKind::TextInput);
+        assert_eq!(classify_role(Some("AXTextArea")), FocusKind::TextInput);
+        assert_eq!(classify_role(Some("AXComboBox")), FocusKind::TextInput);
+    }
+
+    #[test]
+    fn test_classify_non_text_and_unknown_roles() {
+        assert_eq!(classify_role(Some("AXButton")), FocusKind::NonText);
+        assert_eq!(classify_role(Some("AXList")), FocusKind::NonText);
+        // 웹 콘텐츠 편집 영역은 AXGroup/AXWebArea로 보고되므로 판별 불가로 취급
+        assert_eq!(classify_role(Some("AXGroup")), FocusKind::Unknown);
+        assert_eq!(classify_role(Some("AXWebArea")), FocusKind::Unknown);
+        assert_eq!(classify_role(None), FocusKind::Unknown);
+    }
+
+    #[test]
+    fn test_allows_conversion() {
+        assert!(FocusKind::TextInput.allows_conversion());
+        assert!(FocusKind::Unknown.allows_conversion());
+        assert!(!FocusKind::NonText.allows_conversion());
+    }
+
+    #[test]
+    fn test_check_cursor_at_segment_end() {
+        let range = TextRange {
+            location: 12,
+            length: 0,
+        };
+        assert_eq!(
+            check_cursor(Some(range), Some("dkssud"), "dkssud"),
+            CursorCheck::AtSegmentEnd(range)
+        );
+        // AX 미지원 앱은 판별 불가 (기존 방식대로 교체)
+        assert_eq!(check_cursor(None, None, "dkssud"), CursorCheck::Unknown);
+        assert_eq!(
+            check_cursor(Some(range), None, "dkssud"),
+            CursorCheck::Unknown
+        );
+    }
+
+    #[test]
+    fn test_check_cursor_detects_moved_cursor() {
+        // 커서가 다른 위치로 이동해 직전 텍스트가 다름
+        let range = TextRange {
+            location: 20,
+            length: 0,
+        };
+        assert_eq!(
+            check_cursor(Some(range), Some("hello "), "dkssud"),
+            CursorCheck::Moved
+        );
+        // 선택 영역이 있으면 backspace가 선택 영역을 지움
+        let selection = TextRange {
+            location: 6,
+            length: 3,
+        };
+        assert_eq!(
+            check_cursor(Some(selection), Some("dkssud"), "dkssud"),
+            CursorCheck::Moved
+        );
+        // 커서 앞 텍스트가 변환 구간보다 짧음
+        let start = TextRange {
+            location: 2,
+            length: 0,
+        };
+        assert_eq!(
+            check_cursor(Some(start), Some("dk"), "dkssud"),
+            CursorCheck::Moved
+        );
+    }
+
+    #[test]
+    fn test_cursor_after_replace() {
+        // "abc dkssud|xyz" → "abc 안녕|xyz": 커서는 한글 끝, 뒤 텍스트 앞
+        let saved = TextRange {
+            location: 10,
+            length: 0,
+        };
+        assert_eq!(
+            cursor_after_replace(saved, "dkssud", "안녕"),
+            TextRange {
+                location: 6,
+                length: 0
+            }
+        );
+        // NFD 출력은 UTF-16 길이 기준 (안녕 = 자모 6개)
+        assert_eq!(
+            cursor_after_replace(
+                saved,
+                "dkssud",
+                "\u{110B}\u{1161}\u{11AB}\u{1102}\u{1167}\u{11BC}"
+            ),
+            TextRange {
+                location: 10,
+                length: 0
+            }
+        );
+        assert_eq!(
+            segment_range(saved, "dkssud"),
+            TextRange {
+                location: 4,
+                length: 6
+            }
+        );
+    }
+
+    fn capability(cursor: CursorCheck) -> TextInputCapability {
+        TextInputCapability {
+            focus: FocusKind::TextInput,
+            selected_text_settable: true,
+            range_settable: true,
+            cursor,
+        }
+    }
+
+    #[test]
+    fn test_choose_commit_path_text_input() {
+        let cursor = TextRange {
+            location: 10,
+            length: 0,
+        };
+        assert_eq!(
+            choose_commit_path(&capability(CursorCheck::AtSegmentEnd(cursor)), "dkssud"),
+            CommitPath::TextInput(TextRange {
+                location: 4,
+                length: 6
+            })
+        );
+    }
+
+    #[test]
+    fn test_choose_commit_path_falls_back_to_paste() {
+        let at_end = CursorCheck::AtSegmentEnd(TextRange {
+            location: 6,
+            length: 0,
+        });
+        // 커서 위치를 모르거나 이동함
+        assert_eq!(
+            choose_commit_path(&capability(CursorCheck::Unknown), "dkssud"),
+            CommitPath::Paste
+        );
+        assert_eq!(
+            choose_commit_path(&capability(CursorCheck::Moved), "dkssud"),
+            CommitPath::Paste
+        );
+        // 텍스트 입력 role이 아님 (웹 편집기 AXGroup 등)
+        let unknown_role = TextInputCapability {
+            focus: FocusKind::Unknown,
+            ..capability(at_end)
+        };
+        assert_eq!(
+            choose_commit_path(&unknown_role, "dkssud"),
+            CommitPath::Paste
+        );
+        // 선택 텍스트/범위 중 하나라도 설정 불가
+        let read_only = TextInputCapability {
+            selected_text_settable: false,
+            ..capability(at_end)
+        };
+        assert_eq!(choose_commit_path(&read_only, "dkssud"), CommitPath::Paste);
+        let fixed_range = TextInputCapability {
+            range_settable: false,
+            ..capability(at_end)
+        };
+        assert_eq!(
+            choose_commit_path(&fixed_range, "dkssud"),
+            CommitPath::Paste
+        );
+        // 빈 구간
+        assert_eq!(
+            choose_commit_path(&capability(at_end), ""),
+            CommitPath::Paste
+        );
+    }
+}
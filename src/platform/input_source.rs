@@ -2,6 +2,8 @@
 //! Carbon API의 TIS (Text Input Source) 함수 사용
 #![allow(deprecated)] // cocoa 크레이트 deprecated API 사용
 
+use crate::ipc::IpcEvent;
+use crate::logging::LogEvent;
 use crate::platform::os_version::is_sonoma_or_later;
 use cocoa::base::{id, nil};
 use cocoa::foundation::NSString;
@@ -69,8 +71,11 @@ pub fn invalidate_input_source_cache() {
 type TISInputSourceRef = *mut std::ffi::c_void;
 type CFIndex = isize;
 
-/// 캐싱된 한글 입력 소스 (CFRetain으로 소유권 유지)
-static KOREAN_SOURCE_CACHE: OnceLock<usize> = OnceLock::new();
+/// 캐싱된 한글 입력 소스 (대상 ID, CFRetain으로 소유권 유지한 참조 — 못 찾으면 0)
+/// 사용자 지정 소스 변경 시 비워서 다음 전환 때 다시 검색
+static KOREAN_SOURCE_CACHE: Mutex<Option<(String, usize)>> = Mutex::new(None);
+/// 사용자 지정 한글 입력 소스 ID (None이면 기본 두벌식)
+static PREFERRED_KOREAN_SOURCE: Mutex<Option<String>> = Mutex::new(None);
 
 /// 캐싱된 영문 입력 소스 (CFRetain으로 소유권 유지)
 static ENGLISH_SOURCE_CACHE: OnceLock<usize> = OnceLock::new();
@@ -93,6 +98,7 @@ extern "C" {
 
     // 상수 키 (런타임에 가져와야 함)
     static kTISPropertyInputSourceID: CFStringRef;
+    static kTISPropertyLocalizedName: CFStringRef;
     static kTISNotifySelectedKeyboardInputSourceChanged: CFStringRef;
 }
 
@@ -184,6 +190,106 @@ fn is_korean_english_submode(id: &str) -> bool {
     is_korean_input_source_id(id) && contains_ascii_case_insensitive(id, "roman")
 }
 
+/// 한글 IME의 실제 한글 타이핑 모드인지 확인 (영문 서브모드 제외)
+fn is_korean_typing_mode(id: &str) -> bool {
+    is_korean_input_source_id(id) && !is_korean_english_submode(id)
+}
+
+/// 입력 소스 ID의 입력기 부분 (마지막 모드 이름 제외)
+/// e.g., com.apple.inputmethod.Korean.Roman → com.apple.inputmethod.Korean
+fn input_method_prefix(id: &str) -> &str {
+    id.rsplit_once('.').map_or(id, |(prefix, _)| prefix)
+}
+
+/// 한글 전환 경로
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KoreanSwitchPlan<'a> {
+    /// 이미 한글 타이핑 모드 (전환 불필요)
+    AlreadyKorean,
+    /// 한글 IME 영문 서브모드 → 같은 IME의 한글 모드로 토글
+    FromSubmode(&'a str),
+    /// 기본 한글 입력 소스(사용자 지정, 없으면 2SetKorean)로 전환
+    DefaultKorean,
+}
+
+/// 현재 입력 소스 ID로 한글 전환 경로 결정
+fn plan_korean_switch(current_id: Option<&str>) -> KoreanSwitchPlan<'_> {
+    match current_id {
+        Some(id) if is_korean_typing_mode(id) => KoreanSwitchPlan::AlreadyKorean,
+        Some(id) if is_korean_english_submode(id) => KoreanSwitchPlan::FromSubmode(id),
+        _ => KoreanSwitchPlan::DefaultKorean,
+    }
+}
+
+/// 영문 서브모드와 같은 IME의 한글 모드 선택 (활성화된 입력 소스 중, 두벌식 우선)
+fn hangul_mode_for_submode<'a>(submode_id: &str, enabled_ids: &'a [String]) -> Option<&'a str> {
+    let prefix = input_method_prefix(submode_id);
+    let modes: Vec<&str> = enabled_ids
+        .iter()
+        .map(String::as_str)
+        .filter(|id| input_method_prefix(id) == prefix && is_korean_typing_mode(id))
+        .collect();
+    modes
+        .iter()
+        .find(|&&id| id == KOREAN_INPUT_SOURCE_ID)
+        .or(modes.first())
+        .copied()
+}
+
+/// 사용자 지정 한글 입력 소스로 전환 대상 결정
+/// 비었거나, 한글 타이핑 모드가 아니거나, 활성화되지 않은 소스면 기본 두벌식으로 폴백
+fn korean_source_target<'a>(preferred: Option<&'a str>, enabled_ids: &[String]) -> &'a str {
+    match preferred.map(str::trim) {
+        Some(id)
+            if !id.is_empty()
+                && is_korean_typing_mode(id)
+                && enabled_ids.iter().any(|enabled| enabled == id) =>
+        {
+            id
+        }
+        _ => KOREAN_INPUT_SOURCE_ID,
+    }
+}
+
+/// 사용자 지정 한글 입력 소스 설정 (None/빈 값이면 기본 두벌식)
+/// 캐싱된 소스를 비워 다음 한글 전환부터 새 소스 사용
+pub fn set_preferred_korean_source(source_id: Option<&str>) {
+    let source_id = source_id
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(str::to_string);
+    *PREFERRED_KOREAN_SOURCE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner()) = source_id;
+    // 이전 참조는 해제하지 않음 (다른 스레드가 전환 중일 수 있음, 변경 시 1회 ~64바이트)
+    *KOREAN_SOURCE_CACHE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// 사용자 지정 한글 입력 소스 ID
+pub fn preferred_korean_source() -> Option<String> {
+    PREFERRED_KOREAN_SOURCE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+/// 전환 대상 한글 입력 소스 ID (사용자 지정 소스가 무효하면 기본 두벌식)
+fn resolve_korean_source_id() -> String {
+    let Some(preferred) = preferred_korean_source() else {
+        return KOREAN_INPUT_SOURCE_ID.to_string();
+    };
+    let target = korean_source_target(Some(&preferred), &enabled_input_source_ids());
+    if target != preferred {
+        log::warn!(
+            "사용자 지정 한글 입력 소스를 사용할 수 없음: {}, 기본 한글로 전환",
+            preferred
+        );
+    }
+    target.to_string()
+}
+
 /// 현재 스레드가 메인 스레드인지 확인
 fn is_main_thread() -> bool {
     extern "C" {
@@ -393,50 +499,155 @@ fn switch_to_input_source(target_id: &str) -> Result<(), String> {
     }
 }
 
-/// 한글 입력 소스 참조를 캐싱 (최초 1회만 검색)
-fn get_cached_korean_source() -> Option<TISInputSourceRef> {
-    let ptr = *KOREAN_SOURCE_CACHE.get_or_init(|| {
-        unsafe {
-            let source_list = TISCreateInputSourceList(ptr::null(), true);
-            if source_list.is_null() {
-                return 0;
-            }
+/// 입력 소스 정보 (설정 UI 표시용)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputSourceInfo {
+    /// 입력 소스 ID (e.g., com.apple.inputmethod.Korean.3SetKorean)
+    pub id: String,
+    /// 시스템 표시 이름 (없으면 ID)
+    pub name: String,
+}
 
-            let count = CFArrayGetCount(source_list);
-            let mut found: usize = 0;
+impl InputSourceInfo {
+    /// 실제 한글 타이핑 모드인지 (영문 서브모드 제외)
+    pub fn is_korean(&self) -> bool {
+        is_korean_typing_mode(&self.id)
+    }
+}
 
-            for i in 0..count {
-                let source_ptr = CFArrayGetValueAtIndex(source_list, i) as TISInputSourceRef;
-                if source_ptr.is_null() {
-                    continue;
-                }
+/// 활성화된 입력 소스(입력 모드 포함) 목록
+pub fn list_input_sources() -> Vec<InputSourceInfo> {
+    let mut sources = Vec::new();
+    unsafe {
+        let source_list = TISCreateInputSourceList(ptr::null(), false);
+        if source_list.is_null() {
+            return sources;
+        }
 
-                let source_id_ref =
-                    TISGetInputSourceProperty(source_ptr, kTISPropertyInputSourceID);
-                if source_id_ref.is_null() {
-                    continue;
-                }
+        let count = CFArrayGetCount(source_list);
+        for i in 0..count {
+            let source_ptr = CFArrayGetValueAtIndex(source_list, i) as TISInputSourceRef;
+            if source_ptr.is_null() {
+                continue;
+            }
 
-                let source_id = CFString::wrap_under_get_rule(source_id_ref as CFStringRef);
-                if source_id.to_string() == KOREAN_INPUT_SOURCE_ID {
-                    // 의도적 CFRetain: 앱 수명 동안 캐싱하며 CFRelease하지 않음.
-                    // ~64바이트 고정 할당으로 실질적 리크 영향 없음.
-                    CFRetain(source_ptr as CFTypeRef);
-                    found = source_ptr as usize;
-                    break;
-                }
+            let source_id_ref = TISGetInputSourceProperty(source_ptr, kTISPropertyInputSourceID);
+            if source_id_ref.is_null() {
+                continue;
             }
 
-            CFRelease(source_list as CFTypeRef);
-            found
+            let id = CFString::wrap_under_get_rule(source_id_ref as CFStringRef).to_string();
+            let name_ref = TISGetInputSourceProperty(source_ptr, kTISPropertyLocalizedName);
+            let name = if name_ref.is_null() {
+                id.clone()
+            } else {
+                CFString::wrap_under_get_rule(name_ref as CFStringRef).to_string()
+            };
+            sources.push(InputSourceInfo { id, name });
+        }
+
+        CFRelease(source_list as CFTypeRef);
+    }
+    sources
+}
+
+/// 활성화된 입력 소스(입력 모드 포함) ID 목록
+fn enabled_input_source_ids() -> Vec<String> {
+    list_input_sources()
+        .into_iter()
+        .map(|source| source.id)
+        .collect()
+}
+
+/// 영문 서브모드에서 같은 IME의 한글 모드로 토글
+///
+/// 입력 모드도 TIS 입력 소스로 노출되므로 단순 선택으로 전환되는지 확인하고,
+/// 전환되지 않으면 false를 반환해 기본 한글 입력 소스 전환으로 넘김
+fn switch_submode_to_hangul(submode_id: &str) -> bool {
+    let enabled_ids = enabled_input_source_ids();
+    let Some(target_id) = hangul_mode_for_submode(submode_id, &enabled_ids) else {
+        log::debug!("영문 서브모드의 한글 모드 없음: {}", submode_id);
+        return false;
+    };
+
+    match switch_to_input_source(target_id) {
+        Ok(()) if verify_switch(is_korean_typing_mode) => {
+            log::debug!(
+                "영문 서브모드 → 한글 모드 전환: {} → {}",
+                submode_id,
+                target_id
+            );
+            true
         }
+        Ok(()) => {
+            log::warn!(
+                "영문 서브모드 → 한글 모드 전환 미반영 ({} → {}), 기본 한글로 재시도",
+                submode_id,
+                target_id
+            );
+            false
+        }
+        Err(e) => {
+            log::warn!("영문 서브모드 → 한글 모드 전환 실패: {}", e);
+            false
+        }
+    }
+}
+
+/// 한글 입력 소스 참조를 캐싱 (대상 소스가 바뀔 때만 다시 검색)
+/// 반환: (전환 대상 ID, 캐싱된 참조)
+fn get_cached_korean_source() -> (String, Option<TISInputSourceRef>) {
+    let mut cache = KOREAN_SOURCE_CACHE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    let (target_id, ptr) = cache.get_or_insert_with(|| {
+        let target_id = resolve_korean_source_id();
+        let found = unsafe { find_retained_source(&target_id) };
+        (target_id, found)
     });
 
-    if ptr == 0 {
+    let source = if *ptr == 0 {
         None
     } else {
-        Some(ptr as TISInputSourceRef)
+        Some(*ptr as TISInputSourceRef)
+    };
+    (target_id.clone(), source)
+}
+
+/// 설치된 입력 소스에서 ID로 검색해 CFRetain한 참조 반환 (없으면 0)
+unsafe fn find_retained_source(target_id: &str) -> usize {
+    let source_list = TISCreateInputSourceList(ptr::null(), true);
+    if source_list.is_null() {
+        return 0;
     }
+
+    let count = CFArrayGetCount(source_list);
+    let mut found: usize = 0;
+    let target = CFString::new(target_id);
+
+    for i in 0..count {
+        let source_ptr = CFArrayGetValueAtIndex(source_list, i) as TISInputSourceRef;
+        if source_ptr.is_null() {
+            continue;
+        }
+
+        let source_id_ref = TISGetInputSourceProperty(source_ptr, kTISPropertyInputSourceID);
+        if source_id_ref.is_null() {
+            continue;
+        }
+
+        let source_id = CFString::wrap_under_get_rule(source_id_ref as CFStringRef);
+        if source_id == target {
+            // 의도적 CFRetain: 캐시 수명 동안 유지하며 CFRelease하지 않음.
+            // ~64바이트 고정 할당으로 실질적 리크 영향 없음.
+            CFRetain(source_ptr as CFTypeRef);
+            found = source_ptr as usize;
+            break;
+        }
+    }
+
+    CFRelease(source_list as CFTypeRef);
+    found
 }
 
 /// 입력 소스 전환 후 실제로 전환되었는지 검증
@@ -471,23 +682,26 @@ fn verify_switch(expected_check: impl Fn(&str) -> bool) -> bool {
 
 /// 한글 입력 소스로 전환 (캐시 실패 시 리스트 검색 폴백)
 pub fn switch_to_korean() -> Result<(), String> {
-    // 한글 타이핑 모드 검증 함수 (Korean.Roman 영문 서브모드 제외)
-    let is_korean_typing_mode =
-        |id: &str| is_korean_input_source_id(id) && !is_korean_english_submode(id);
-
-    // 이미 한글 타이핑 모드이면 전환 불필요
-    // Korean.Roman(영문 서브모드)은 영문으로 취급하여 전환 수행
-    if let Some(id) = get_current_input_source_id() {
-        if is_korean_typing_mode(&id) {
-            return Ok(());
+    let current_id = get_current_input_source_id();
+    match plan_korean_switch(current_id.as_deref()) {
+        // 이미 한글 타이핑 모드이면 전환 불필요
+        KoreanSwitchPlan::AlreadyKorean => return Ok(()),
+        // Korean.Roman(영문 서브모드): 사용 중인 IME의 한글 모드로 토글
+        KoreanSwitchPlan::FromSubmode(submode_id) => {
+            if switch_submode_to_hangul(submode_id) {
+                switched("korean");
+                return Ok(());
+            }
         }
+        KoreanSwitchPlan::DefaultKorean => {}
     }
 
     // 1차 시도: 캐싱된 소스로 빠른 전환
-    if let Some(source) = get_cached_korean_source() {
+    let (target_id, cached) = get_cached_korean_source();
+    if let Some(source) = cached {
         let ret = unsafe { TISSelectInputSource(source) };
-        if ret == 0 && verify_switch(&is_korean_typing_mode) {
-            invalidate_input_source_cache();
+        if ret == 0 && verify_switch(is_korean_typing_mode) {
+            switched("korean");
             return Ok(());
         }
         let current_id = get_current_input_source_id().unwrap_or_else(|| "unknown".to_string());
@@ -500,9 +714,9 @@ pub fn switch_to_korean() -> Result<(), String> {
 
     // 2차 시도: 입력 소스 리스트에서 직접 검색 (캐시 stale 대응)
     thread::sleep(Duration::from_millis(50));
-    if let Ok(()) = switch_to_input_source(KOREAN_INPUT_SOURCE_ID) {
-        if verify_switch(&is_korean_typing_mode) {
-            invalidate_input_source_cache();
+    if let Ok(()) = switch_to_input_source(&target_id) {
+        if verify_switch(is_korean_typing_mode) {
+            switched("korean");
             return Ok(());
         }
     }
@@ -512,6 +726,18 @@ pub fn switch_to_korean() -> Result<(), String> {
     Err("한글 전환 최종 실패: 캐시 및 리스트 검색 모두 실패".to_string())
 }
 
+/// 입력 소스 전환 실패 로그 이벤트 (target: "korean" / "english")
+pub fn switch_failure_event(target: &'static str, error: &str) -> LogEvent {
+    LogEvent::new(
+        log::Level::Warn,
+        "switch_failure",
+        "입력 소스 전환 실패 (main thread)",
+    )
+    .field("target", target)
+    .field("reason", error)
+    .field("current", get_current_input_source_id().unwrap_or_default())
+}
+
 /// 메인 스레드에서 한글 입력 소스로 전환 (비동기)
 /// TISSelectInputSource()는 메인 RunLoop이 있는 스레드에서 호출해야
 /// 포커스된 앱의 실제 입력 모드가 변경됨.
@@ -519,32 +745,95 @@ pub fn switch_to_korean() -> Result<(), String> {
 pub fn switch_to_korean_on_main() {
     crate::platform::dispatch_to_main(|| {
         if let Err(e) = switch_to_korean() {
-            log::warn!("한글 전환 실패 (main thread): {}", e);
+            switch_failure_event("korean", &e).emit();
         }
     });
 }
 
+/// 메인 스레드 한글 전환 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwitchOutcome {
+    /// 전환 후 한글 타이핑 모드임을 확인 (verify_switch 통과)
+    Switched,
+    /// 메인 스레드에서 실행했지만 전환 검증 실패
+    Failed,
+    /// 메인 스레드가 제한 시간 안에 응답하지 않음
+    TimedOut,
+}
+
 /// 메인 스레드에서 한글 입력 소스로 전환 (타임아웃 포함)
 /// 변환 직후 is_replacing 해제 전에 사용하여, 전환 완료 전 키 입력이
 /// 영문으로 처리되는 레이스 컨디션을 방지합니다.
 ///
-/// dispatch_to_main + Condvar 기반 타임아웃으로 구현하여,
+/// 메인 스레드의 전환 검증(verify_switch)이 끝날 때까지 기다리며,
+/// dispatch_to_main + Condvar 기반 타임아웃으로 구현하여
 /// 메인 스레드가 응답 없어도 worker가 영원히 블로킹되지 않습니다.
-///
-/// 반환값: true이면 전환 완료, false이면 타임아웃
-pub fn switch_to_korean_on_main_with_timeout(timeout: std::time::Duration) -> bool {
+pub fn switch_to_korean_on_main_with_timeout(timeout: std::time::Duration) -> SwitchOutcome {
+    switch_on_main_with_timeout("korean", switch_to_korean, timeout)
+}
+
+/// 메인 스레드에서 영문 입력 소스로 전환 (타임아웃 포함)
+/// 이미 한글 모드인 상태에서 교체하기 전에 사용 (Backspace/paste가 한글 조합과 섞이지 않도록)
+pub fn switch_to_english_on_main_with_timeout(timeout: std::time::Duration) -> SwitchOutcome {
+    switch_on_main_with_timeout("english", switch_to_english, timeout)
+}
+
+/// 메인 스레드에서 현재 입력 소스 상태 확인 (캐시도 갱신, 응답이 없으면 Unknown)
+pub fn current_input_source_state_on_main(timeout: std::time::Duration) -> InputSourceState {
+    run_on_main_with_timeout(
+        || {
+            refresh_input_source_cache();
+            cached_input_source_snapshot().state
+        },
+        timeout,
+    )
+    .unwrap_or(InputSourceState::Unknown)
+}
+
+fn switch_on_main_with_timeout(
+    target: &'static str,
+    switch: fn() -> Result<(), String>,
+    timeout: std::time::Duration,
+) -> SwitchOutcome {
+    let outcome = run_on_main_with_timeout(
+        move || match switch() {
+            Ok(()) => SwitchOutcome::Switched,
+            Err(e) => {
+                switch_failure_event(target, &e).emit();
+                SwitchOutcome::Failed
+            }
+        },
+        timeout,
+    );
+    outcome.unwrap_or_else(|| {
+        LogEvent::new(
+            log::Level::Warn,
+            "switch_failure",
+            "입력 소스 전환 타임아웃, 강제 진행",
+        )
+        .field("target", target)
+        .field("reason", "timeout")
+        .field("timeout_ms", timeout.as_millis() as u64)
+        .emit();
+        SwitchOutcome::TimedOut
+    })
+}
+
+/// 메인 스레드에서 실행하고 결과를 기다림 (timeout 안에 끝나지 않으면 None)
+fn run_on_main_with_timeout<T: Send + 'static>(
+    f: impl FnOnce() -> T + Send + 'static,
+    timeout: std::time::Duration,
+) -> Option<T> {
     use std::sync::{Arc, Condvar, Mutex};
 
-    let pair = Arc::new((Mutex::new(false), Condvar::new()));
+    let pair = Arc::new((Mutex::new(None), Condvar::new()));
     let pair_clone = Arc::clone(&pair);
 
     crate::platform::dispatch_to_main(move || {
-        if let Err(e) = switch_to_korean() {
-            log::warn!("한글 전환 실패 (main thread): {}", e);
-        }
+        let value = f();
         let (lock, cvar) = &*pair_clone;
-        if let Ok(mut completed) = lock.lock() {
-            *completed = true;
+        if let Ok(mut result) = lock.lock() {
+            *result = Some(value);
             cvar.notify_one();
         }
     });
@@ -554,15 +843,10 @@ pub fn switch_to_korean_on_main_with_timeout(timeout: std::time::Duration) -> bo
         Ok(g) => g,
         Err(e) => e.into_inner(),
     };
-    let (guard, timeout_result) = cvar
-        .wait_timeout_while(guard, timeout, |completed| !*completed)
+    let (mut guard, _) = cvar
+        .wait_timeout_while(guard, timeout, |result| result.is_none())
         .unwrap_or_else(|e| e.into_inner());
-    let completed = *guard;
-    if timeout_result.timed_out() && !completed {
-        log::warn!("한글 전환 타임아웃 ({:?}), 강제 진행", timeout);
-        return false;
-    }
-    true
+    guard.take()
 }
 
 /// 영문 입력 소스 참조를 캐싱 (최초 1회만 검색, ABC 또는 US)
@@ -647,11 +931,21 @@ pub fn switch_to_english() -> Result<(), String> {
     }
 
     // 전환 후 캐시 무효화
-    invalidate_input_source_cache();
+    if result.is_ok() {
+        switched("english");
+    } else {
+        invalidate_input_source_cache();
+    }
 
     result
 }
 
+/// 전환 성공 처리 — 캐시 무효화 + 구독자에게 전환 이벤트 전송
+fn switched(target: &'static str) {
+    invalidate_input_source_cache();
+    crate::ipc::publish(IpcEvent::Switch { target });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -712,4 +1006,118 @@ mod tests {
         // 영문 입력기 → false (korean이 아니므로)
         assert!(!is_korean_english_submode("com.apple.keylayout.ABC"));
     }
+
+    #[test]
+    fn test_plan_korean_switch() {
+        assert_eq!(
+            plan_korean_switch(Some("com.apple.inputmethod.Korean.2SetKorean")),
+            KoreanSwitchPlan::AlreadyKorean
+        );
+        assert_eq!(
+            plan_korean_switch(Some("com.apple.inputmethod.Korean.Roman")),
+            KoreanSwitchPlan::FromSubmode("com.apple.inputmethod.Korean.Roman")
+        );
+        assert_eq!(
+            plan_korean_switch(Some("com.apple.keylayout.ABC")),
+            KoreanSwitchPlan::DefaultKorean
+        );
+        assert_eq!(plan_korean_switch(None), KoreanSwitchPlan::DefaultKorean);
+    }
+
+    #[test]
+    fn test_hangul_mode_for_submode() {
+        let ids = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let submode = "com.apple.inputmethod.Korean.Roman";
+
+        // 두벌식이 활성화되어 있으면 우선 선택
+        let enabled = ids(&[
+            "com.apple.keylayout.ABC",
+            "com.apple.inputmethod.Korean.3SetKorean",
+            "com.apple.inputmethod.Korean.Roman",
+            "com.apple.inputmethod.Korean.2SetKorean",
+        ]);
+        assert_eq!(
+            hangul_mode_for_submode(submode, &enabled),
+            Some("com.apple.inputmethod.Korean.2SetKorean")
+        );
+
+        // 같은 IME의 다른 한글 모드만 있으면 그 모드 선택
+        let enabled = ids(&[
+            "com.apple.inputmethod.Korean.Roman",
+            "com.apple.inputmethod.Korean.3SetKorean",
+        ]);
+        assert_eq!(
+            hangul_mode_for_submode(submode, &enabled),
+            Some("com.apple.inputmethod.Korean.3SetKorean")
+        );
+
+        // 다른 IME의 한글 모드는 선택하지 않음
+        let enabled = ids(&[
+            "com.apple.inputmethod.Korean.Roman",
+            "org.youknowone.inputmethod.Gureum.han2",
+        ]);
+        assert_eq!(hangul_mode_for_submode(submode, &enabled), None);
+        assert_eq!(
+            hangul_mode_for_submode("org.youknowone.inputmethod.Gureum.roman", &enabled),
+            Some("org.youknowone.inputmethod.Gureum.han2")
+        );
+    }
+
+    #[test]
+    fn test_korean_source_target_prefers_configured_source() {
+        let enabled: Vec<String> = [
+            "com.apple.keylayout.ABC",
+            "com.apple.inputmethod.Korean.2SetKorean",
+            "com.apple.inputmethod.Korean.3SetKorean",
+            "com.apple.inputmethod.Korean.Roman",
+            "org.youknowone.inputmethod.Gureum.han2",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+
+        // 활성화된 한글 입력 소스면 그대로 사용
+        assert_eq!(
+            korean_source_target(Some("com.apple.inputmethod.Korean.3SetKorean"), &enabled),
+            "com.apple.inputmethod.Korean.3SetKorean"
+        );
+        assert_eq!(
+            korean_source_target(Some(" org.youknowone.inputmethod.Gureum.han2 "), &enabled),
+            "org.youknowone.inputmethod.Gureum.han2"
+        );
+
+        // 미지정/빈 값/비활성/한글 아님/영문 서브모드는 기본 두벌식
+        for preferred in [
+            None,
+            Some(""),
+            Some("  "),
+            Some("com.apple.inputmethod.Korean.390Sebulshik"),
+            Some("com.apple.keylayout.ABC"),
+            Some("com.apple.inputmethod.Korean.Roman"),
+        ] {
+            assert_eq!(
+                korean_source_target(preferred, &enabled),
+                KOREAN_INPUT_SOURCE_ID,
+                "{:?}",
+                preferred
+            );
+        }
+    }
+
+    #[test]
+    fn test_set_preferred_korean_source_normalizes_and_clears_cache() {
+        *KOREAN_SOURCE_CACHE.lock().unwrap() = Some((KOREAN_INPUT_SOURCE_ID.to_string(), 0));
+
+        set_preferred_korean_source(Some(" com.apple.inputmethod.Korean.3SetKorean "));
+        assert_eq!(
+            preferred_korean_source().as_deref(),
+            Some("com.apple.inputmethod.Korean.3SetKorean")
+        );
+        assert!(KOREAN_SOURCE_CACHE.lock().unwrap().is_none());
+
+        set_preferred_korean_source(Some(""));
+        assert_eq!(preferred_korean_source(), None);
+        set_preferred_korean_source(None);
+        assert_eq!(preferred_korean_source(), None);
+    }
 }
@@ -1,8 +1,16 @@
+pub mod accessibility;
+pub mod app_focus;
 pub mod event_tap;
+pub mod focused_element;
 pub mod input_source;
 pub mod os_version;
 pub mod permissions;
+pub mod speech;
 pub mod text_replacer;
+pub mod thread_qos;
+
+pub use permissions::open_accessibility_settings;
+pub use thread_qos::{set_thread_qos, ThreadQos};
 
 /// GCD를 사용하여 클로저를 메인 스레드에서 비동기 실행합니다.
 pub fn dispatch_to_main<F: FnOnce() + Send + 'static>(f: F) {
@@ -81,6 +81,23 @@ pub fn reset_accessibility_permission() {
     }
 }
 
+/// 시스템 설정 > 개인 정보 보호 및 보안 > 손쉬운 사용 패널 URL
+const ACCESSIBILITY_SETTINGS_URL: &str =
+    "x-apple.systempreferences:com.apple.preference.security?Privacy_Accessibility";
+
+/// 시스템 설정의 손쉬운 사용 패널 열기
+pub fn open_accessibility_settings() -> Result<(), String> {
+    let status = std::process::Command::new("open")
+        .arg(ACCESSIBILITY_SETTINGS_URL)
+        .status()
+        .map_err(|e| format!("시스템 설정 열기 실패: {}", e))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("시스템 설정 열기 실패: {}", status))
+    }
+}
+
 /// 권한 상태를 사람이 읽을 수 있는 문자열로 반환
 pub fn permission_status_string() -> &'static str {
     if check_accessibility_permission() {
@@ -100,6 +117,12 @@ mod tests {
         let _ = check_accessibility_permission();
     }
 
+    #[test]
+    fn test_accessibility_settings_url() {
+        assert!(ACCESSIBILITY_SETTINGS_URL.starts_with("x-apple.systempreferences:"));
+        assert!(ACCESSIBILITY_SETTINGS_URL.ends_with("Privacy_Accessibility"));
+    }
+
     #[test]
     fn test_permission_status_string() {
         let status = permission_status_string();
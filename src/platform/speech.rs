@@ -0,0 +1,154 @@
+//! 변환 결과 음성 읽기 (NSSpeechSynthesizer)
+//!
+//! 한국어 음성이 설치되어 있지 않으면 아무것도 하지 않습니다.
+//! 음성 확인은 첫 발화 때 한 번만 하므로, 이후 설치한 음성은 재시작해야 적용됩니다.
+#![allow(deprecated)] // cocoa 크레이트 deprecated API 사용
+
+use cocoa::base::{id, nil, BOOL, NO};
+use cocoa::foundation::NSString;
+use objc::{class, msg_send, sel, sel_impl};
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// 발화 최대 길이 (글자) — 더 긴 변환 결과는 읽지 않음
+pub const SPEECH_MAX_CHARS: usize = 30;
+/// 발화 최소 간격 — 연속 변환 시 앞 발화가 끝나기 전에 겹쳐 읽지 않도록
+pub const SPEECH_DEBOUNCE: Duration = Duration::from_millis(800);
+
+#[link(name = "AppKit", kind = "framework")]
+extern "C" {
+    static NSVoiceLocaleIdentifier: id;
+}
+
+struct SendId(id);
+unsafe impl Send for SendId {}
+unsafe impl Sync for SendId {}
+
+/// 한국어 음성 합성기 (None: 한국어 음성 없음, 메인 스레드에서만 접근)
+static SYNTHESIZER: OnceLock<Option<SendId>> = OnceLock::new();
+static SPEECH_GATE: Mutex<SpeechGate> = Mutex::new(SpeechGate::new());
+
+/// 발화 여부 판정 (길이 제한 + 최소 간격)
+#[derive(Debug, Default)]
+pub struct SpeechGate {
+    last_spoken: Option<Instant>,
+}
+
+impl SpeechGate {
+    pub const fn new() -> Self {
+        Self { last_spoken: None }
+    }
+
+    /// 읽을지 판정하고, 읽기로 했으면 시각 기록
+    pub fn should_speak(&mut self, text: &str, now: Instant) -> bool {
+        let text = text.trim();
+        if text.is_empty() || text.chars().count() > SPEECH_MAX_CHARS {
+            return false;
+        }
+        if self
+            .last_spoken
+            .is_some_and(|at| now.saturating_duration_since(at) < SPEECH_DEBOUNCE)
+        {
+            return false;
+        }
+        self.last_spoken = Some(now);
+        true
+    }
+}
+
+/// 변환 결과 읽기 (길이/간격 조건을 만족할 때만, 어느 스레드에서든 호출 가능)
+pub fn speak_conversion(text: &str) {
+    let allowed = SPEECH_GATE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .should_speak(text, Instant::now());
+    if allowed {
+        speak(text);
+    }
+}
+
+/// 한국어 음성으로 텍스트 읽기 (진행 중인 발화는 중단, 메인 스레드에서 실행)
+pub fn speak(text: &str) {
+    let text = text.to_string();
+    super::dispatch_to_main(move || unsafe {
+        let Some(synthesizer) = SYNTHESIZER.get_or_init(|| create_korean_synthesizer().map(SendId))
+        else {
+            return;
+        };
+        let synthesizer = synthesizer.0;
+        let speaking: BOOL = msg_send![synthesizer, isSpeaking];
+        if speaking != NO {
+            let _: () = msg_send![synthesizer, stopSpeaking];
+        }
+        let string = NSString::alloc(nil).init_str(&text);
+        let started: BOOL = msg_send![synthesizer, startSpeakingString: string];
+        if started == NO {
+            log::debug!("음성 읽기 시작 실패");
+        }
+        let _: () = msg_send![string, release];
+    });
+}
+
+/// 설치된 한국어 음성으로 합성기 생성 (없으면 None)
+unsafe fn create_korean_synthesizer() -> Option<id> {
+    let voices: id = msg_send![class!(NSSpeechSynthesizer), availableVoices];
+    if voices == nil {
+        return None;
+    }
+    let count: usize = msg_send![voices, count];
+    for i in 0..count {
+        let voice: id = msg_send![voices, objectAtIndex: i];
+        let attributes: id = msg_send![class!(NSSpeechSynthesizer), attributesForVoice: voice];
+        if attributes == nil {
+            continue;
+        }
+        let locale: id = msg_send![attributes, objectForKey: NSVoiceLocaleIdentifier];
+        if !nsstring_starts_with(locale, "ko") {
+            continue;
+        }
+        let synthesizer: id = msg_send![class!(NSSpeechSynthesizer), alloc];
+        let synthesizer: id = msg_send![synthesizer, initWithVoice: voice];
+        if synthesizer != nil {
+            return Some(synthesizer);
+        }
+    }
+    log::info!("한국어 음성이 없어 변환 결과 읽기를 건너뜀");
+    None
+}
+
+unsafe fn nsstring_starts_with(string: id, prefix: &str) -> bool {
+    if string == nil {
+        return false;
+    }
+    let utf8: *const c_char = msg_send![string, UTF8String];
+    !utf8.is_null() && CStr::from_ptr(utf8).to_string_lossy().starts_with(prefix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_speech_gate_debounces_consecutive_conversions() {
+        let mut gate = SpeechGate::new();
+        let start = Instant::now();
+        assert!(gate.should_speak("안녕", start));
+        // 간격 안의 연속 변환은 읽지 않음
+        assert!(!gate.should_speak("하세요", start + Duration::from_millis(300)));
+        assert!(!gate.should_speak("안녕", start + Duration::from_millis(799)));
+        assert!(gate.should_speak("하세요", start + SPEECH_DEBOUNCE));
+    }
+
+    #[test]
+    fn test_speech_gate_skips_empty_and_long_text() {
+        let mut gate = SpeechGate::new();
+        let now = Instant::now();
+        assert!(!gate.should_speak("", now));
+        assert!(!gate.should_speak("   ", now));
+        assert!(!gate.should_speak(&"가".repeat(SPEECH_MAX_CHARS + 1), now));
+        // 건너뛴 텍스트는 간격 계산에 포함되지 않음
+        assert!(gate.should_speak(&"가".repeat(SPEECH_MAX_CHARS), now));
+    }
+}
@@ -10,13 +10,22 @@ use objc::{msg_send, sel, sel_impl};
 use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
 use std::sync::Mutex;
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
+use crate::platform::event_tap::char_to_keycode;
+use crate::platform::focused_element::{
+    check_focused_cursor, commit_via_text_input, cursor_after_replace, focused_selected_range,
+    set_focused_selected_range, CursorCheck, TextRange,
+};
 use crate::platform::os_version::{is_sequoia_or_later, is_sonoma_or_later};
+use crate::platform::{set_thread_qos, ThreadQos};
 
 /// Koing이 생성한 합성 이벤트를 식별하는 마커 값
 pub const KOING_SYNTHETIC_EVENT_MARKER: i64 = 0x4B4F494E47; // "KOING"
 
+/// self-test 사용자 입력 재현 시 키 이벤트 사이 딜레이 (ms)
+const USER_TYPING_DELAY_MS: u64 = 20;
+
 /// 버전별 타이밍 프로파일
 /// Sonoma/Sequoia에서 보안 정책이 강화되어 더 긴 딜레이가 필요
 struct TimingProfile {
@@ -24,7 +33,7 @@ struct TimingProfile {
     backspace_key_delay_ms: u64,
     /// Paste 키 이벤트 사이 딜레이 (ms)
     paste_key_delay_ms: u64,
-    /// Paste 완료 후 딜레이 (ms)
+    /// Paste 완료 후 딜레이 (ms, 측정값이 없을 때의 기본값)
     paste_finish_delay_ms: u64,
     /// Backspace 완료 → 클립보드 복사 사이 딜레이 (ms)
     post_backspace_delay_ms: u64,
@@ -65,6 +74,77 @@ fn timing() -> &'static TimingProfile {
     TIMING.get_or_init(TimingProfile::for_current_os)
 }
 
+/// 측정 기반 paste 대기 하한 (ms)
+const ADAPTIVE_PASTE_MIN_MS: u64 = 5;
+/// 측정 기반 paste 대기 상한 (ms) — 측정 실패 시 이 값으로 후퇴
+const ADAPTIVE_PASTE_MAX_MS: u64 = 120;
+/// paste 완료(커서 이동) 대기 최대 시간 (ms)
+const PASTE_COMPLETION_TIMEOUT_MS: u64 = 150;
+/// paste 완료 폴링 간격 (ms)
+const PASTE_POLL_INTERVAL_MS: u64 = 5;
+
+/// 실제 paste 완료 시간 측정값으로 조정하는 대기 시간
+///
+/// 같은 OS라도 기기/앱마다 paste 처리 속도가 달라, 커서 이동으로 확인한 완료 시간의
+/// 이동평균으로 다음 변환의 대기 시간을 정합니다. 측정 전에는 OS별 기본값을 사용합니다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct AdaptivePasteTiming {
+    /// paste 완료까지 걸린 시간 이동평균 (ms, 0이면 측정 전)
+    avg_ms: u64,
+}
+
+impl AdaptivePasteTiming {
+    const fn new() -> Self {
+        Self { avg_ms: 0 }
+    }
+
+    /// paste 완료 측정값 반영 (최근 값 1/4 가중 이동평균)
+    fn record(&mut self, elapsed_ms: u64) {
+        let elapsed_ms = elapsed_ms.clamp(ADAPTIVE_PASTE_MIN_MS, ADAPTIVE_PASTE_MAX_MS);
+        self.avg_ms = if self.avg_ms == 0 {
+            elapsed_ms
+        } else {
+            (self.avg_ms * 3 + elapsed_ms) / 4
+        };
+    }
+
+    /// 측정 실패(타임아웃): 보수적인 상한값으로 후퇴
+    fn record_timeout(&mut self) {
+        self.avg_ms = ADAPTIVE_PASTE_MAX_MS;
+    }
+
+    /// 완료 확인 없이 기다릴 paste 후 딜레이 (평균의 1.25배)
+    fn finish_delay_ms(&self, base_ms: u64) -> u64 {
+        if self.avg_ms == 0 {
+            return base_ms;
+        }
+        (self.avg_ms * 5 / 4).clamp(ADAPTIVE_PASTE_MIN_MS, ADAPTIVE_PASTE_MAX_MS)
+    }
+
+    /// 완료 폴링 전 최소 대기 (평균의 3/4, 측정값이 대기 시간에 끌려가지 않도록 평균보다 짧게)
+    fn min_paste_wait_ms(&self, base_ms: u64) -> u64 {
+        if self.avg_ms == 0 {
+            return base_ms;
+        }
+        (self.avg_ms * 3 / 4).clamp(ADAPTIVE_PASTE_MIN_MS, ADAPTIVE_PASTE_MAX_MS)
+    }
+}
+
+/// 앱 수명 동안 갱신되는 paste 대기 측정 상태
+static ADAPTIVE_PASTE: Mutex<AdaptivePasteTiming> = Mutex::new(AdaptivePasteTiming::new());
+
+fn adaptive_paste() -> std::sync::MutexGuard<'static, AdaptivePasteTiming> {
+    ADAPTIVE_PASTE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// 일반 텍스트 pasteboard 타입
+const PLAIN_TEXT_TYPE: &str = "public.utf8-plain-text";
+/// 클립보드 매니저가 기록하지 않도록 함께 선언하는 마커 타입 (nspasteboard.org 규약)
+const TRANSIENT_MARKER_TYPES: [&str; 2] = [
+    "org.nspasteboard.TransientType",
+    "org.nspasteboard.ConcealedType",
+];
+
 /// 클립보드 작업 직렬화를 위한 글로벌 Mutex
 static CLIPBOARD_MUTEX: Mutex<()> = Mutex::new(());
 
@@ -74,6 +154,9 @@ static RESTORE_GENERATION: AtomicU64 = AtomicU64::new(0);
 /// 클립보드 복원 지연 시간 (ms) — 대상 앱이 paste를 처리할 충분한 시간
 const DEFERRED_RESTORE_DELAY_MS: u64 = 1500;
 
+/// 아직 복원하지 않은 원래 클립보드 내용 (교체 진행 중이거나 지연 복원 대기 중이면 Some)
+static PENDING_RESTORE: Mutex<Option<String>> = Mutex::new(None);
+
 /// 클립보드 내용을 백업하고 복원하는 구조체
 pub struct ClipboardBackup {
     content: Option<String>,
@@ -86,19 +169,96 @@ impl ClipboardBackup {
         Self { content }
     }
 
-    /// 백업한 내용으로 클립보드 복원
+    /// 백업한 내용으로 클립보드 복원 (원래 내용은 이미 기록되어 있으므로 임시 쓰기로 표시)
     pub fn restore(self) {
         if let Some(content) = self.content {
-            set_clipboard_string(&content);
+            set_transient_clipboard_string(&content);
+        }
+    }
+}
+
+/// 교체 중 클립보드 백업을 전역으로 등록하고, 복원 전에 Drop되면 즉시 복원하는 가드
+///
+/// 교체 도중 오류/패닉으로 빠져나가도 클립보드가 변환 텍스트로 남지 않게 합니다.
+/// 정상 경로에서는 [`defer`](Self::defer)로 지연 복원에 넘기고, 복원은 보관소에서
+/// 꺼낸 쪽만 수행하므로 중복 복원되지 않습니다.
+pub struct ClipboardRestoreGuard {
+    slot: &'static Mutex<Option<String>>,
+    write: fn(&str),
+    armed: bool,
+}
+
+impl ClipboardRestoreGuard {
+    /// 백업을 미복원 보관소에 등록
+    pub fn new(backup: ClipboardBackup) -> Self {
+        Self::register(
+            &PENDING_RESTORE,
+            backup.content,
+            set_transient_clipboard_string,
+        )
+    }
+
+    /// 보관소에 등록 (이전 교체의 복원이 대기 중이면 그 내용이 원래 클립보드이므로 유지)
+    fn register(
+        slot: &'static Mutex<Option<String>>,
+        content: Option<String>,
+        write: fn(&str),
+    ) -> Self {
+        let mut pending = slot.lock().unwrap_or_else(|e| e.into_inner());
+        if pending.is_none() {
+            *pending = content;
+        }
+        Self {
+            slot,
+            write,
+            armed: true,
+        }
+    }
+
+    /// 붙여넣기 완료 — 즉시 복원하지 않고 지연 복원에 맡김
+    pub fn defer(mut self) {
+        self.armed = false;
+    }
+}
+
+impl Drop for ClipboardRestoreGuard {
+    fn drop(&mut self) {
+        if self.armed {
+            restore_from(self.slot, self.write);
         }
     }
 }
 
+/// 보관소의 미복원 내용을 꺼내 복원, 복원했으면 true
+fn restore_from(slot: &Mutex<Option<String>>, write: fn(&str)) -> bool {
+    let content = slot.lock().unwrap_or_else(|e| e.into_inner()).take();
+    match content {
+        Some(content) => {
+            write(&content);
+            true
+        }
+        None => false,
+    }
+}
+
+/// 교체 중이거나 지연 복원 대기 중인 클립보드가 있는지
+pub fn has_pending_clipboard_restore() -> bool {
+    PENDING_RESTORE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .is_some()
+}
+
+/// 미복원 클립보드를 즉시 복원 (앱 종료 시 호출), 복원했으면 true
+pub fn restore_pending_clipboard() -> bool {
+    restore_from(&PENDING_RESTORE, set_transient_clipboard_string)
+}
+
 /// 클립보드에서 문자열 가져오기
 pub fn get_clipboard_string() -> Option<String> {
     unsafe {
         let pasteboard: id = NSPasteboard::generalPasteboard(nil);
-        let type_str: id = NSString::alloc(nil).init_str("public.utf8-plain-text");
+        let type_str: id = NSString::alloc(nil).init_str(PLAIN_TEXT_TYPE);
         let types: id = NSArray::arrayWithObject(nil, type_str);
         let available: id = msg_send![pasteboard, availableTypeFromArray: types];
         let _: () = msg_send![type_str, release];
@@ -125,22 +285,49 @@ pub fn get_clipboard_string() -> Option<String> {
     }
 }
 
-/// 클립보드에 문자열 설정
+/// 클립보드에 문자열 설정 (사용자가 요청한 복사 — 클립보드 매니저에 기록됨)
 pub fn set_clipboard_string(content: &str) {
+    write_clipboard(content, false);
+}
+
+/// 교체용 임시 클립보드 쓰기 (클립보드 매니저가 무시하도록 마커 타입을 함께 선언)
+pub fn set_transient_clipboard_string(content: &str) {
+    write_clipboard(content, true);
+}
+
+/// 선언할 pasteboard 타입 (텍스트 타입이 항상 첫 번째)
+fn clipboard_types(transient: bool) -> Vec<&'static str> {
+    let mut types = vec![PLAIN_TEXT_TYPE];
+    if transient {
+        types.extend(TRANSIENT_MARKER_TYPES);
+    }
+    types
+}
+
+fn write_clipboard(content: &str, transient: bool) {
     unsafe {
         let pasteboard: id = NSPasteboard::generalPasteboard(nil);
         let _: () = msg_send![pasteboard, clearContents];
 
-        let ns_string: id = NSString::alloc(nil).init_str(content);
-        let type_str: id = NSString::alloc(nil).init_str("public.utf8-plain-text");
-        let types: id = NSArray::arrayWithObject(nil, type_str);
-
+        let type_strs: Vec<id> = clipboard_types(transient)
+            .into_iter()
+            .map(|t| NSString::alloc(nil).init_str(t))
+            .collect();
+        let types: id = NSArray::arrayWithObjects(nil, &type_strs);
         let _: () = msg_send![pasteboard, declareTypes: types owner: nil];
-        let for_type: id = NSString::alloc(nil).init_str("public.utf8-plain-text");
-        let _: () = msg_send![pasteboard, setString: ns_string forType: for_type];
-        let _: () = msg_send![for_type, release];
-        let _: () = msg_send![type_str, release];
+
+        let ns_string: id = NSString::alloc(nil).init_str(content);
+        let _: () = msg_send![pasteboard, setString: ns_string forType: type_strs[0]];
+        // 마커 타입은 내용 없이 존재만 표시
+        let empty: id = NSString::alloc(nil).init_str("");
+        for &marker in &type_strs[1..] {
+            let _: () = msg_send![pasteboard, setString: empty forType: marker];
+        }
+        let _: () = msg_send![empty, release];
         let _: () = msg_send![ns_string, release];
+        for type_str in type_strs {
+            let _: () = msg_send![type_str, release];
+        }
     }
 }
 
@@ -177,8 +364,18 @@ fn event_source_state_id() -> CGEventSourceStateID {
     }
 }
 
-/// 키 이벤트 시뮬레이션
+/// 키 이벤트 시뮬레이션 (Koing 마커 포함 — 이벤트 탭이 무시)
 fn simulate_key(keycode: CGKeyCode, key_down: bool, flags: CGEventFlags) -> Result<(), String> {
+    post_key(keycode, key_down, flags, true)
+}
+
+/// 키 이벤트 전송 (marked가 false면 이벤트 탭이 사용자 입력으로 처리)
+fn post_key(
+    keycode: CGKeyCode,
+    key_down: bool,
+    flags: CGEventFlags,
+    marked: bool,
+) -> Result<(), String> {
     let source =
         CGEventSource::new(event_source_state_id()).map_err(|_| "CGEventSource 생성 실패")?;
 
@@ -186,15 +383,47 @@ fn simulate_key(keycode: CGKeyCode, key_down: bool, flags: CGEventFlags) -> Resu
         CGEvent::new_keyboard_event(source, keycode, key_down).map_err(|_| "CGEvent 생성 실패")?;
 
     event.set_flags(flags);
-    event.set_integer_value_field(
-        EventField::EVENT_SOURCE_USER_DATA,
-        KOING_SYNTHETIC_EVENT_MARKER,
-    );
+    if marked {
+        event.set_integer_value_field(
+            EventField::EVENT_SOURCE_USER_DATA,
+            KOING_SYNTHETIC_EVENT_MARKER,
+        );
+    }
     event.post(core_graphics::event::CGEventTapLocation::HID);
 
     Ok(())
 }
 
+/// 사용자 키 입력 재현 (self-test 전용, 마커 없이 보내 자동 변환 경로를 그대로 거침)
+fn press_user_key(keycode: CGKeyCode, flags: CGEventFlags) -> Result<(), String> {
+    post_key(keycode, true, flags, false)?;
+    thread::sleep(Duration::from_millis(USER_TYPING_DELAY_MS));
+    post_key(keycode, false, flags, false)?;
+    thread::sleep(Duration::from_millis(USER_TYPING_DELAY_MS));
+    Ok(())
+}
+
+/// 영문 자판 기준으로 text를 한 글자씩 입력 (self-test 전용)
+pub fn simulate_user_typing(text: &str) -> Result<(), String> {
+    for c in text.chars() {
+        let (keycode, shift) =
+            char_to_keycode(c).ok_or_else(|| format!("입력할 수 없는 문자: {:?}", c))?;
+        let flags = if shift {
+            CGEventFlags::CGEventFlagShift
+        } else {
+            CGEventFlags::empty()
+        };
+        press_user_key(keycode, flags)?;
+    }
+    Ok(())
+}
+
+/// Option+Z (Undo 단축키) 입력 재현 (self-test 전용)
+pub fn simulate_user_undo() -> Result<(), String> {
+    const Z_KEYCODE: CGKeyCode = 6;
+    press_user_key(Z_KEYCODE, CGEventFlags::CGEventFlagAlternate)
+}
+
 /// Backspace 키 시뮬레이션
 fn simulate_backspace() -> Result<(), String> {
     let t = timing();
@@ -206,6 +435,16 @@ fn simulate_backspace() -> Result<(), String> {
     Ok(())
 }
 
+/// Return 키 시뮬레이션 (자동 변환 교체 전에 보류한 Enter 재전송용)
+pub fn simulate_return() -> Result<(), String> {
+    let t = timing();
+    const RETURN_KEYCODE: CGKeyCode = 36;
+    simulate_key(RETURN_KEYCODE, true, CGEventFlags::empty())?;
+    thread::sleep(Duration::from_millis(t.backspace_key_delay_ms));
+    simulate_key(RETURN_KEYCODE, false, CGEventFlags::empty())?;
+    Ok(())
+}
+
 /// Cmd+V (붙여넣기) 시뮬레이션
 fn simulate_paste() -> Result<(), String> {
     let t = timing();
@@ -226,17 +465,52 @@ fn simulate_paste() -> Result<(), String> {
 
     // 4. Command 키 업
     simulate_key(COMMAND_KEYCODE, false, CGEventFlags::empty())?;
-    thread::sleep(Duration::from_millis(t.paste_finish_delay_ms));
 
     Ok(())
 }
 
+/// paste 완료 대기: 커서가 예상 위치로 옮겨지면 소요 시간을 측정값에 반영
+///
+/// 반환: 제한 시간 안에 완료를 확인했는지 여부
+fn wait_for_paste_completion(expected: TextRange) -> bool {
+    let started = Instant::now();
+    let min_wait = adaptive_paste().min_paste_wait_ms(timing().paste_finish_delay_ms);
+    thread::sleep(Duration::from_millis(min_wait));
+
+    let timeout = Duration::from_millis(PASTE_COMPLETION_TIMEOUT_MS);
+    loop {
+        if focused_selected_range() == Some(expected) {
+            let elapsed_ms = started.elapsed().as_millis() as u64;
+            adaptive_paste().record(elapsed_ms);
+            log::debug!("paste 완료 {}ms", elapsed_ms);
+            return true;
+        }
+        if started.elapsed() >= timeout {
+            adaptive_paste().record_timeout();
+            log::debug!("paste 완료 확인 실패, 대기 시간을 보수적으로 조정");
+            return false;
+        }
+        thread::sleep(Duration::from_millis(PASTE_POLL_INTERVAL_MS));
+    }
+}
+
 /// 텍스트 교체 실행
 /// - backspace_count: 삭제할 문자 수
 /// - new_text: 새로 입력할 텍스트
 pub fn replace_text(backspace_count: usize, new_text: &str) -> Result<(), String> {
+    replace_text_with(backspace_count, new_text, None).map(|_| ())
+}
+
+/// 텍스트 교체 공통부
+/// - expected_cursor: 교체 후 예상 커서 위치 (있으면 paste 완료를 확인하며 대기)
+/// - 반환: paste 완료를 확인했는지 여부 (확인 대상이 없으면 false)
+fn replace_text_with(
+    backspace_count: usize,
+    new_text: &str,
+    expected_cursor: Option<TextRange>,
+) -> Result<bool, String> {
     if new_text.is_empty() {
-        return Ok(());
+        return Ok(false);
     }
 
     // 클립보드 작업 직렬화 — 동시 변환 요청 방지
@@ -244,8 +518,8 @@ pub fn replace_text(backspace_count: usize, new_text: &str) -> Result<(), String
         .lock()
         .map_err(|e| format!("클립보드 Mutex 획득 실패: {}", e))?;
 
-    // 1. 클립보드 백업
-    let backup = ClipboardBackup::save();
+    // 1. 클립보드 백업 (중간에 실패하면 가드가 즉시 복원)
+    let backup = ClipboardRestoreGuard::new(ClipboardBackup::save());
 
     let t = timing();
 
@@ -258,21 +532,81 @@ pub fn replace_text(backspace_count: usize, new_text: &str) -> Result<(), String
     thread::sleep(Duration::from_millis(t.post_backspace_delay_ms));
 
     // 3. 새 텍스트를 클립보드에 복사
-    set_clipboard_string(new_text);
+    set_transient_clipboard_string(new_text);
 
     // 4. 클립보드 설정 완료 대기 (폴링 방식, 최대 100ms)
     if !wait_for_clipboard(new_text, 100) {
         log::warn!("클립보드 설정 확인 실패, 계속 진행");
     }
 
-    // 5. Cmd+V로 붙여넣기
+    // 5. Cmd+V로 붙여넣기 후 완료 대기 (측정 가능하면 커서 이동 확인)
     simulate_paste()?;
+    let completed = match expected_cursor {
+        Some(expected) => wait_for_paste_completion(expected),
+        None => {
+            wait_paste_finish_delay(t);
+            false
+        }
+    };
 
     // 6. 클립보드 복원을 지연 처리
     // 대상 앱이 paste를 처리하기 전에 복원하면 이전 클립보드 내용이 출력됨
-    schedule_deferred_restore(backup.content);
+    backup.defer();
+    schedule_deferred_restore();
 
-    Ok(())
+    Ok(completed)
+}
+
+/// 완료 확인 없이 paste 후 딜레이 (측정값이 있으면 측정 기반)
+fn wait_paste_finish_delay(t: &TimingProfile) {
+    let delay = adaptive_paste().finish_delay_ms(t.paste_finish_delay_ms);
+    thread::sleep(Duration::from_millis(delay));
+}
+
+/// 커서 위치를 확인하고 텍스트 교체 (자동 변환용)
+///
+/// - 커서가 변환 구간(`segment`) 바로 뒤가 아니면 교체하지 않음 (다른 텍스트 삭제 방지)
+/// - 교체 후 커서가 새 텍스트 끝이 아니면 AX로 위치 복원
+/// - AX로 커서를 알 수 없는 앱은 기존 방식대로 교체
+/// - 반환: paste 완료(커서 이동)를 확인했는지 여부
+pub fn replace_text_at_cursor(
+    backspace_count: usize,
+    segment: &str,
+    new_text: &str,
+) -> Result<bool, String> {
+    let saved = match check_focused_cursor(segment) {
+        CursorCheck::AtSegmentEnd(range) => Some(range),
+        CursorCheck::Moved => {
+            return Err("커서가 변환 구간 끝에 있지 않아 교체하지 않음".to_string());
+        }
+        CursorCheck::Unknown => None,
+    };
+
+    let expected = saved.map(|saved| cursor_after_replace(saved, segment, new_text));
+    let completed = replace_text_with(backspace_count, new_text, expected)?;
+
+    // 제한 시간 안에 커서가 옮겨지지 않았으면 AX로 위치 복원
+    if let (Some(expected), false) = (expected, completed) {
+        if let Err(e) = set_focused_selected_range(expected) {
+            log::debug!("커서 위치 복원 실패: {}", e);
+        }
+    }
+
+    Ok(completed)
+}
+
+/// 텍스트 입력 커밋을 먼저 시도하고, 미지원이면 paste로 교체 (실험적, 자동 변환용)
+///
+/// 반환: 교체 반영을 확인했는지 여부 (AX 커밋은 동기 반영)
+pub fn replace_text_via_text_input(
+    backspace_count: usize,
+    segment: &str,
+    new_text: &str,
+) -> Result<bool, String> {
+    if commit_via_text_input(segment, new_text)? {
+        return Ok(true);
+    }
+    replace_text_at_cursor(backspace_count, segment, new_text)
 }
 
 /// Undo 텍스트 교체 실행 (한글 → 원본 영문 복원)
@@ -291,8 +625,8 @@ pub fn undo_replace_text(hangul_text: &str, original_text: &str) -> Result<(), S
     // 한글은 조합 문자이므로 chars().count()로 정확한 문자 수 계산
     let backspace_count = hangul_text.chars().count();
 
-    // 1. 클립보드 백업
-    let backup = ClipboardBackup::save();
+    // 1. 클립보드 백업 (중간에 실패하면 가드가 즉시 복원)
+    let backup = ClipboardRestoreGuard::new(ClipboardBackup::save());
 
     let t = timing();
 
@@ -305,7 +639,7 @@ pub fn undo_replace_text(hangul_text: &str, original_text: &str) -> Result<(), S
     thread::sleep(Duration::from_millis(t.post_backspace_delay_ms));
 
     // 3. 원본 영문 텍스트를 클립보드에 복사
-    set_clipboard_string(original_text);
+    set_transient_clipboard_string(original_text);
 
     // 4. 클립보드 설정 완료 대기 (폴링 방식, 최대 100ms)
     if !wait_for_clipboard(original_text, 100) {
@@ -314,33 +648,144 @@ pub fn undo_replace_text(hangul_text: &str, original_text: &str) -> Result<(), S
 
     // 5. Cmd+V로 붙여넣기
     simulate_paste()?;
+    wait_paste_finish_delay(t);
 
     // 6. 클립보드 복원을 지연 처리
-    schedule_deferred_restore(backup.content);
+    backup.defer();
+    schedule_deferred_restore();
 
     Ok(())
 }
 
-/// 클립보드 복원을 백그라운드 스레드에서 지연 실행
+/// 미복원 클립보드 복원을 백그라운드 스레드에서 지연 실행
 /// 세대 카운터로 최신 변환만 복원하고, 이전 변환의 복원은 자동 취소
-fn schedule_deferred_restore(content: Option<String>) {
+/// (그 사이 종료 처리에서 먼저 복원했으면 보관소가 비어 있어 다시 복원하지 않음)
+fn schedule_deferred_restore() {
     let gen = RESTORE_GENERATION.fetch_add(1, AtomicOrdering::SeqCst);
-    if let Some(content) = content {
-        thread::spawn(move || {
-            thread::sleep(Duration::from_millis(DEFERRED_RESTORE_DELAY_MS));
-            // 이후 새 변환이 없었을 때만 복원
-            if RESTORE_GENERATION.load(AtomicOrdering::SeqCst) == gen + 1 {
-                let _lock = CLIPBOARD_MUTEX.lock();
-                set_clipboard_string(&content);
-            }
-        });
+    if !has_pending_clipboard_restore() {
+        return;
     }
+    thread::spawn(move || {
+        set_thread_qos(ThreadQos::Utility);
+        thread::sleep(Duration::from_millis(DEFERRED_RESTORE_DELAY_MS));
+        // 이후 새 변환이 없었을 때만 복원
+        if RESTORE_GENERATION.load(AtomicOrdering::SeqCst) == gen + 1 {
+            let _lock = CLIPBOARD_MUTEX.lock();
+            restore_pending_clipboard();
+        }
+    });
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_adaptive_paste_uses_base_until_measured() {
+        let timing = AdaptivePasteTiming::new();
+        assert_eq!(timing.finish_delay_ms(40), 40);
+        assert_eq!(timing.min_paste_wait_ms(40), 40);
+    }
+
+    #[test]
+    fn test_adaptive_paste_converges_to_measurement() {
+        let mut timing = AdaptivePasteTiming::new();
+        timing.record(80);
+        for _ in 0..20 {
+            timing.record(16);
+        }
+        assert_eq!(timing.avg_ms, 16);
+        assert_eq!(timing.finish_delay_ms(40), 20);
+        assert_eq!(timing.min_paste_wait_ms(40), 12);
+
+        // 점진 조정: 느려진 앱은 한 번에 따라가지 않고 평균이 서서히 증가
+        timing.record(60);
+        assert_eq!(timing.avg_ms, 27);
+        for _ in 0..20 {
+            timing.record(60);
+        }
+        assert!((55..=60).contains(&timing.avg_ms), "{}", timing.avg_ms);
+    }
+
+    #[test]
+    fn test_adaptive_paste_bounds_and_timeout() {
+        let mut timing = AdaptivePasteTiming::new();
+        for _ in 0..10 {
+            timing.record(0);
+        }
+        assert_eq!(timing.avg_ms, ADAPTIVE_PASTE_MIN_MS);
+        assert_eq!(timing.min_paste_wait_ms(40), ADAPTIVE_PASTE_MIN_MS);
+
+        for _ in 0..10 {
+            timing.record(10_000);
+        }
+        // 측정값이 아무리 커도 상한을 넘지 않음
+        assert!(timing.avg_ms <= ADAPTIVE_PASTE_MAX_MS);
+        assert_eq!(timing.finish_delay_ms(40), ADAPTIVE_PASTE_MAX_MS);
+
+        // 타임아웃 시 상한으로 후퇴 후 다시 측정값으로 수렴
+        let mut timing = AdaptivePasteTiming::new();
+        timing.record(10);
+        timing.record_timeout();
+        assert_eq!(timing.avg_ms, ADAPTIVE_PASTE_MAX_MS);
+        for _ in 0..30 {
+            timing.record(10);
+        }
+        assert!(timing.avg_ms <= 12, "{}", timing.avg_ms);
+    }
+
+    #[test]
+    fn test_min_wait_stays_below_measurement() {
+        // 폴링 전 대기가 측정값보다 짧아야 측정이 대기 시간에 끌려가지 않음
+        let mut timing = AdaptivePasteTiming::new();
+        for measured in [8, 20, 50, 100] {
+            timing.record(measured);
+            assert!(timing.min_paste_wait_ms(40) <= timing.avg_ms);
+        }
+    }
+
+    static WRITES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    fn record_write(content: &str) {
+        WRITES.lock().unwrap().push(content.to_string());
+    }
+
+    fn take_writes() -> Vec<String> {
+        std::mem::take(&mut *WRITES.lock().unwrap())
+    }
+
+    #[test]
+    fn test_restore_guard_paths() {
+        static SLOT: Mutex<Option<String>> = Mutex::new(None);
+
+        // 교체 도중 빠져나가면 (오류/패닉) Drop에서 즉시 복원
+        let result = std::panic::catch_unwind(|| {
+            let _guard =
+                ClipboardRestoreGuard::register(&SLOT, Some("원래 내용".to_string()), record_write);
+            panic!("교체 중 패닉");
+        });
+        assert!(result.is_err());
+        assert_eq!(take_writes(), vec!["원래 내용"]);
+        assert!(SLOT.lock().unwrap().is_none());
+
+        // 정상 경로: defer 후에는 Drop에서 복원하지 않고, 지연 복원/종료 처리 중 한 번만 복원
+        ClipboardRestoreGuard::register(&SLOT, Some("원래 내용".to_string()), record_write).defer();
+        assert!(take_writes().is_empty());
+        assert!(restore_from(&SLOT, record_write));
+        assert!(!restore_from(&SLOT, record_write));
+        assert_eq!(take_writes(), vec!["원래 내용"]);
+
+        // 복원 대기 중 이어진 교체는 (변환 텍스트가 든) 새 백업 대신 원래 내용을 유지
+        ClipboardRestoreGuard::register(&SLOT, Some("원래 내용".to_string()), record_write).defer();
+        ClipboardRestoreGuard::register(&SLOT, Some("안녕".to_string()), record_write).defer();
+        assert!(restore_from(&SLOT, record_write));
+        assert_eq!(take_writes(), vec!["원래 내용"]);
+
+        // 백업할 문자열이 없었으면 복원하지 않음
+        drop(ClipboardRestoreGuard::register(&SLOT, None, record_write));
+        assert!(take_writes().is_empty());
+    }
+
     #[test]
     #[ignore] // GUI 환경에서만 테스트 가능
     fn test_clipboard_operations() {
@@ -356,6 +801,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_clipboard_types_mark_transient_writes() {
+        assert_eq!(clipboard_types(false), vec![PLAIN_TEXT_TYPE]);
+        let types = clipboard_types(true);
+        assert_eq!(types[0], PLAIN_TEXT_TYPE);
+        assert!(types.contains(&"org.nspasteboard.TransientType"));
+        assert!(types.contains(&"org.nspasteboard.ConcealedType"));
+    }
+
+    #[test]
+    #[ignore] // GUI 환경에서만 테스트 가능
+    fn test_transient_clipboard_declares_markers() {
+        let original = get_clipboard_string();
+
+        set_transient_clipboard_string("임시 변환 텍스트");
+        // 마커가 있어도 텍스트는 그대로 읽힘
+        assert_eq!(get_clipboard_string(), Some("임시 변환 텍스트".to_string()));
+        let declared = unsafe {
+            let pasteboard: id = NSPasteboard::generalPasteboard(nil);
+            let types: id = msg_send![pasteboard, types];
+            (0..types.count())
+                .filter_map(|i| {
+                    let utf8: *const std::os::raw::c_char =
+                        msg_send![types.objectAtIndex(i), UTF8String];
+                    (!utf8.is_null()).then(|| {
+                        std::ffi::CStr::from_ptr(utf8)
+                            .to_string_lossy()
+                            .into_owned()
+                    })
+                })
+                .collect::<Vec<_>>()
+        };
+        for marker in TRANSIENT_MARKER_TYPES {
+            assert!(declared.iter().any(|t| t == marker), "{:?}", declared);
+        }
+
+        // 사용자 복사에는 마커 없음
+        set_clipboard_string("사용자 복사");
+        let has_marker: bool = unsafe {
+            let pasteboard: id = NSPasteboard::generalPasteboard(nil);
+            let marker = NSString::alloc(nil).init_str(TRANSIENT_MARKER_TYPES[0]);
+            let types = NSArray::arrayWithObject(nil, marker);
+            let available: id = msg_send![pasteboard, availableTypeFromArray: types];
+            let _: () = msg_send![marker, release];
+            available != nil
+        };
+        assert!(!has_marker);
+
+        if let Some(orig) = original {
+            set_clipboard_string(&orig);
+        }
+    }
+
     #[test]
     #[ignore] // GUI 환경에서만 테스트 가능
     fn test_clipboard_backup() {
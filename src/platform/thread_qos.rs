@@ -0,0 +1,122 @@
+//! 스레드 QoS(Quality of Service) 클래스 지정
+//!
+//! 입력 경로(이벤트 탭, 변환 워커)는 높은 QoS로, 감시/주기 작업은 낮은 QoS로 두어
+//! 변환 지연을 줄이면서 CPU/전력을 균형 있게 사용
+
+use std::cell::Cell;
+
+/// 스레드 QoS 클래스 (`<sys/qos.h>`의 qos_class_t)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThreadQos {
+    /// 사용자 입력을 직접 처리 (이벤트 탭 RunLoop)
+    UserInteractive,
+    /// 사용자가 결과를 기다리는 작업 (변환 워커, debounce/전환 타이머)
+    UserInitiated,
+    /// 결과를 당장 기다리지 않는 주기 작업 (감시/헬스 모니터, 통계 로그)
+    Utility,
+    /// 사용자에게 보이지 않는 작업
+    Background,
+}
+
+impl ThreadQos {
+    /// qos_class_t 값
+    pub fn qos_class(self) -> u32 {
+        match self {
+            ThreadQos::UserInteractive => 0x21, // QOS_CLASS_USER_INTERACTIVE
+            ThreadQos::UserInitiated => 0x19,   // QOS_CLASS_USER_INITIATED
+            ThreadQos::Utility => 0x11,         // QOS_CLASS_UTILITY
+            ThreadQos::Background => 0x09,      // QOS_CLASS_BACKGROUND
+        }
+    }
+}
+
+extern "C" {
+    /// 현재 스레드의 QoS 클래스 지정 (성공 시 0, 실패 시 errno)
+    fn pthread_set_qos_class_self_np(qos_class: u32, relative_priority: i32) -> i32;
+}
+
+thread_local! {
+    /// 현재 스레드에 지정된 QoS (지정 전이거나 실패 시 None)
+    static APPLIED_QOS: Cell<Option<ThreadQos>> = const { Cell::new(None) };
+}
+
+/// 현재 스레드의 QoS 지정 (실패해도 동작에는 지장 없으므로 경고만 기록)
+pub fn set_thread_qos(qos: ThreadQos) {
+    let result = apply_qos(qos, |qos_class, relative_priority| unsafe {
+        pthread_set_qos_class_self_np(qos_class, relative_priority)
+    });
+    if let Err(e) = result {
+        log::warn!(
+            "{} (스레드: {})",
+            e,
+            std::thread::current().name().unwrap_or("이름 없음")
+        );
+    }
+}
+
+/// 현재 스레드에 지정된 QoS
+pub fn current_thread_qos() -> Option<ThreadQos> {
+    APPLIED_QOS.with(Cell::get)
+}
+
+/// QoS 클래스를 qos_class_t로 변환해 set으로 지정하고 결과를 기록 (FFI 분리)
+fn apply_qos(qos: ThreadQos, set: impl FnOnce(u32, i32) -> i32) -> Result<(), String> {
+    // 같은 클래스 안의 상대 우선순위는 조정하지 않음
+    let ret = set(qos.qos_class(), 0);
+    if ret != 0 {
+        return Err(format!("스레드 QoS 설정 실패: {:?} (errno {})", qos, ret));
+    }
+    APPLIED_QOS.with(|applied| applied.set(Some(qos)));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_apply_qos_maps_class_values() {
+        for (qos, expected) in [
+            (ThreadQos::UserInteractive, 0x21),
+            (ThreadQos::UserInitiated, 0x19),
+            (ThreadQos::Utility, 0x11),
+            (ThreadQos::Background, 0x09),
+        ] {
+            let mut called_with = None;
+            let result = apply_qos(qos, |qos_class, relative_priority| {
+                called_with = Some((qos_class, relative_priority));
+                0
+            });
+            assert!(result.is_ok());
+            assert_eq!(called_with, Some((expected, 0)), "{:?}", qos);
+        }
+    }
+
+    #[test]
+    fn test_apply_qos_failure_is_reported_and_not_recorded() {
+        thread::spawn(|| {
+            let result = apply_qos(ThreadQos::UserInteractive, |_, _| 22);
+            assert!(result.unwrap_err().contains("errno 22"));
+            assert_eq!(current_thread_qos(), None);
+        })
+        .join()
+        .unwrap();
+    }
+
+    #[test]
+    fn test_qos_is_recorded_per_thread() {
+        let spawned = thread::spawn(|| {
+            apply_qos(ThreadQos::Utility, |_, _| 0).unwrap();
+            current_thread_qos()
+        })
+        .join()
+        .unwrap();
+        assert_eq!(spawned, Some(ThreadQos::Utility));
+
+        // 다른 스레드의 지정은 영향 없음
+        thread::spawn(|| assert_eq!(current_thread_qos(), None))
+            .join()
+            .unwrap();
+    }
+}
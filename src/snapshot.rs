@@ -0,0 +1,175 @@
+//! 변환 설정 스냅샷 (버그 리포트 재현용)
+//!
+//! 현재 적용 중인 변환 관련 설정(debounce, 감지 기준, 앱 프로필, 경계 키, N-gram 설정)을
+//! 하나의 JSON으로 내보내고, 같은 스냅샷을 다시 적용해 동일한 조건을 복원합니다.
+//! 치환 규칙처럼 사용자 문구가 담긴 설정은 포함하지 않습니다.
+
+use crate::config::{AppProfile, KoingConfig, NgramSettings, ProfileParams};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// 스냅샷 파일 기본 이름
+pub const SNAPSHOT_FILE_NAME: &str = "koing-snapshot.json";
+
+/// 변환 설정 스냅샷
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ConfigSnapshot {
+    /// 스냅샷을 만든 Koing 버전
+    pub app_version: String,
+    pub enabled: bool,
+    /// 자동 감지 활성화 여부
+    pub auto_detect_enabled: bool,
+    /// 실시간(debounce) 변환 사용 여부
+    pub realtime_mode: bool,
+    pub debounce_ms: u64,
+    pub adaptive_debounce: bool,
+    pub slow_debounce_ms: u64,
+    pub two_stage_conversion: bool,
+    pub switch_delay_ms: u64,
+    /// 앱 매핑이 없는 앱에 적용할 변환 민감도
+    pub sensitivity: AppProfile,
+    pub app_profiles: BTreeMap<String, AppProfile>,
+    pub profile_params: BTreeMap<AppProfile, ProfileParams>,
+    /// 스냅샷 시점의 포커스 앱 (프로필 선택 기준)
+    pub active_app: Option<String>,
+    /// 스냅샷 시점에 감지기에 적용된 기준 (포커스 앱 프로필 반영)
+    pub threshold: f32,
+    pub realtime_threshold: f32,
+    pub min_length: usize,
+    pub never_convert_words: Vec<String>,
+    pub pass_through_jamo_only: bool,
+    pub soft_switch_on_reject: bool,
+    pub reverse_to_english: bool,
+    pub reset_keycodes: Vec<u16>,
+    pub ngram: NgramSettings,
+}
+
+impl ConfigSnapshot {
+    /// 리포트 첨부용 JSON
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("스냅샷 직렬화 실패: {}", e))
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("스냅샷 파싱 실패: {}", e))
+    }
+
+    /// JSON 파일로 저장
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("스냅샷 디렉토리 생성 실패: {}", e))?;
+        }
+        fs::write(path, self.to_json()?).map_err(|e| format!("스냅샷 파일 저장 실패: {}", e))
+    }
+
+    /// JSON 파일에서 읽기
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let json = fs::read_to_string(path).map_err(|e| format!("스냅샷 파일 읽기 실패: {}", e))?;
+        Self::from_json(&json)
+    }
+
+    /// 설정 파일 값에 반영 (재시작 후에도 같은 조건, N-gram 설정은 재시작 시 적용)
+    ///
+    /// 포커스 앱/감지기 기준처럼 실행 중에만 의미 있는 값은 제외
+    pub fn apply_to_config(&self, config: &mut KoingConfig) {
+        config.enabled = self.enabled;
+        config.debounce_ms = self.debounce_ms;
+        config.adaptive_debounce = self.adaptive_debounce;
+        config.slow_debounce_ms = self.slow_debounce_ms;
+        config.two_stage_conversion = self.two_stage_conversion;
+        config.switch_delay_ms = self.switch_delay_ms;
+        config.sensitivity = self.sensitivity;
+        config.app_profiles = self.app_profiles.clone();
+        config.profile_params = self.profile_params.clone();
+        config.never_convert_words = self.never_convert_words.clone();
+        config.pass_through_jamo_only = self.pass_through_jamo_only;
+        config.soft_switch_on_reject = self.soft_switch_on_reject;
+        config.reverse_to_english = self.reverse_to_english;
+        config.reset_keycodes = self.reset_keycodes.clone();
+        config.ngram = self.ngram.clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> ConfigSnapshot {
+        ConfigSnapshot {
+            app_version: "0.3.11".to_string(),
+            enabled: true,
+            auto_detect_enabled: true,
+            realtime_mode: true,
+            debounce_ms: 300,
+            adaptive_debounce: true,
+            slow_debounce_ms: 1500,
+            two_stage_conversion: false,
+            switch_delay_ms: 10,
+            sensitivity: AppProfile::Aggressive,
+            app_profiles: BTreeMap::from([(
+                "com.microsoft.VSCode".to_string(),
+                AppProfile::Conservative,
+            )]),
+            profile_params: BTreeMap::from([(
+                AppProfile::Conservative,
+                ProfileParams {
+                    threshold: 85.0,
+                    realtime_threshold: 95.0,
+                    min_length: 4,
+                },
+            )]),
+            active_app: Some("com.microsoft.VSCode".to_string()),
+            threshold: 85.0,
+            realtime_threshold: 95.0,
+            min_length: 4,
+            never_convert_words: vec!["kotlin".to_string()],
+            pass_through_jamo_only: false,
+            soft_switch_on_reject: true,
+            reverse_to_english: false,
+            reset_keycodes: vec![48, 53, 115],
+            ngram: NgramSettings {
+                threshold: -9.5,
+                smoothing_k: 0.5,
+                model_path: Some("/tmp/model.bin".to_string()),
+            },
+        }
+    }
+
+    #[test]
+    fn test_snapshot_json_round_trip() {
+        let snapshot = sample();
+        let json = snapshot.to_json().unwrap();
+        assert!(json.contains("\"sensitivity\": \"aggressive\""));
+        assert_eq!(ConfigSnapshot::from_json(&json).unwrap(), snapshot);
+        assert!(ConfigSnapshot::from_json("{}").is_err());
+    }
+
+    #[test]
+    fn test_snapshot_file_round_trip() {
+        let path = std::env::temp_dir()
+            .join(format!("koing_snapshot_test_{}", std::process::id()))
+            .join(SNAPSHOT_FILE_NAME);
+        let snapshot = sample();
+        snapshot.save(&path).unwrap();
+        assert_eq!(ConfigSnapshot::load(&path).unwrap(), snapshot);
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_apply_to_config() {
+        let snapshot = sample();
+        let mut config = KoingConfig::default();
+        snapshot.apply_to_config(&mut config);
+        assert_eq!(config.debounce_ms, 300);
+        assert!(config.adaptive_debounce);
+        assert!(!config.two_stage_conversion);
+        assert_eq!(config.sensitivity, AppProfile::Aggressive);
+        assert_eq!(config.app_profiles, snapshot.app_profiles);
+        assert_eq!(config.profile_params, snapshot.profile_params);
+        assert_eq!(config.never_convert_words, vec!["kotlin".to_string()]);
+        assert_eq!(config.reset_keycodes, vec![48, 53, 115]);
+        assert_eq!(config.ngram, snapshot.ngram);
+    }
+}
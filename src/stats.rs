@@ -0,0 +1,215 @@
+//! 변환 통계 집계/저장 (텍스트 내용 없이 횟수만 기록)
+
+use crate::config::config_path;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// 시간대 버킷 수 (0~23시)
+pub const HOURS_PER_DAY: usize = 24;
+
+/// 집계 대상 이벤트
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatKind {
+    /// 변환 완료 (자동/수동/선택 텍스트)
+    Conversion,
+    /// 변환 되돌리기
+    Undo,
+    /// 자동 변환 검증 거부
+    Rejection,
+}
+
+/// 이벤트별 횟수
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct StatCounts {
+    pub conversions: u64,
+    pub undos: u64,
+    pub rejections: u64,
+}
+
+impl StatCounts {
+    fn add(&mut self, kind: StatKind) {
+        let counter = match kind {
+            StatKind::Conversion => &mut self.conversions,
+            StatKind::Undo => &mut self.undos,
+            StatKind::Rejection => &mut self.rejections,
+        };
+        *counter = counter.saturating_add(1);
+    }
+}
+
+/// 누적 변환 통계 (전체 합계 + 로컬 시각 기준 시간대별 분포)
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct ConversionStats {
+    /// 전체 합계
+    pub total: StatCounts,
+    /// 시간대별 횟수 (인덱스 = 시)
+    pub hourly: [StatCounts; HOURS_PER_DAY],
+}
+
+impl ConversionStats {
+    /// 이벤트 기록 (hour: 로컬 시각의 시, 범위 밖이면 23시로 보정)
+    pub fn record(&mut self, kind: StatKind, hour: usize) {
+        self.total.add(kind);
+        self.hourly[hour.min(HOURS_PER_DAY - 1)].add(kind);
+    }
+}
+
+/// epoch 초와 UTC 오프셋(초)으로 로컬 시각의 시 계산
+pub fn local_hour(unix_secs: u64, utc_offset_secs: i64) -> usize {
+    let local = unix_secs as i64 + utc_offset_secs;
+    (local.rem_euclid(86_400) / 3_600) as usize
+}
+
+/// 통계 파일 경로: ~/Library/Application Support/koing/stats.json
+pub fn stats_path() -> PathBuf {
+    config_path().with_file_name("stats.json")
+}
+
+/// 통계 로드 (파일 없거나 손상 시 빈 통계)
+pub fn load_stats() -> ConversionStats {
+    load_stats_from(&stats_path())
+}
+
+/// 지정 경로에서 통계 로드
+pub fn load_stats_from(path: &Path) -> ConversionStats {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) => {
+            if e.kind() != ErrorKind::NotFound {
+                log::warn!("변환 통계 읽기 실패: {}", e);
+            }
+            return ConversionStats::default();
+        }
+    };
+    serde_json::from_str(&content).unwrap_or_else(|e| {
+        log::warn!("변환 통계 파일 손상, 무시: {}", e);
+        ConversionStats::default()
+    })
+}
+
+/// 통계 저장 (앱 종료 시)
+pub fn save_stats(stats: &ConversionStats) -> Result<(), String> {
+    export_stats(&stats_path(), stats)
+}
+
+/// 지정 경로에 통계를 JSON으로 내보내기
+pub fn export_stats(path: &Path, stats: &ConversionStats) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("통계 디렉토리 생성 실패: {}", e))?;
+    }
+    let json = serde_json::to_string_pretty(stats).map_err(|e| format!("직렬화 실패: {}", e))?;
+    fs::write(path, json).map_err(|e| format!("통계 파일 저장 실패: {}", e))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("koing_stats_test_{}", std::process::id()))
+            .join(name)
+    }
+
+    #[test]
+    fn test_record_accumulates_total_and_hourly() {
+        let mut stats = ConversionStats::default();
+        stats.record(StatKind::Conversion, 9);
+        stats.record(StatKind::Conversion, 9);
+        stats.record(StatKind::Undo, 9);
+        stats.record(StatKind::Rejection, 22);
+        stats.record(StatKind::Conversion, 99);
+
+        assert_eq!(
+            stats.total,
+            StatCounts {
+                conversions: 3,
+                undos: 1,
+                rejections: 1
+            }
+        );
+        assert_eq!(
+            stats.hourly[9],
+            StatCounts {
+                conversions: 2,
+                undos: 1,
+                rejections: 0
+            }
+        );
+        assert_eq!(stats.hourly[22].rejections, 1);
+        assert_eq!(stats.hourly[23].conversions, 1);
+
+        // 버킷 합계 = 전체 합계
+        let summed = stats.hourly.iter().map(|h| h.conversions).sum::<u64>();
+        assert_eq!(summed, stats.total.conversions);
+    }
+
+    #[test]
+    fn test_local_hour_applies_offset() {
+        // 2024-01-01 00:30:00 UTC
+        let t = 1_704_069_000;
+        assert_eq!(local_hour(t, 0), 0);
+        // KST (+9h)
+        assert_eq!(local_hour(t, 9 * 3_600), 9);
+        // 음수 오프셋은 전날로 넘어감 (-5h → 19시)
+        assert_eq!(local_hour(t, -5 * 3_600), 19);
+        // 30분 단위 오프셋 (+5:30)
+        assert_eq!(local_hour(t, 5 * 3_600 + 1_800), 6);
+    }
+
+    #[test]
+    fn test_serialized_format_has_only_counts() {
+        let mut stats = ConversionStats::default();
+        stats.record(StatKind::Conversion, 0);
+        let value = serde_json::to_value(&stats).unwrap();
+
+        assert_eq!(
+            value["total"],
+            serde_json::json!({"conversions": 1, "undos": 0, "rejections": 0})
+        );
+        let hourly = value["hourly"].as_array().unwrap();
+        assert_eq!(hourly.len(), HOURS_PER_DAY);
+        assert_eq!(hourly[0]["conversions"], 1);
+
+        let mut keys: Vec<_> = value.as_object().unwrap().keys().cloned().collect();
+        keys.sort();
+        assert_eq!(keys, vec!["hourly", "total"]);
+    }
+
+    #[test]
+    fn test_export_and_load_roundtrip() {
+        let path = temp_path("roundtrip.json");
+        let mut stats = ConversionStats::default();
+        stats.record(StatKind::Conversion, 13);
+        stats.record(StatKind::Undo, 14);
+
+        export_stats(&path, &stats).unwrap();
+        assert_eq!(load_stats_from(&path), stats);
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_load_missing_or_corrupt_returns_empty() {
+        assert_eq!(
+            load_stats_from(&temp_path("missing.json")),
+            ConversionStats::default()
+        );
+
+        let path = temp_path("corrupt.json");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "{not json").unwrap();
+        assert_eq!(load_stats_from(&path), ConversionStats::default());
+
+        // 일부 필드만 있는 파일은 나머지를 0으로 채움
+        fs::write(&path, r#"{"total": {"conversions": 5}}"#).unwrap();
+        let stats = load_stats_from(&path);
+        assert_eq!(stats.total.conversions, 5);
+        assert_eq!(stats.total.undos, 0);
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,328 @@
+//! 패닉 기록 및 스레드 감시 (패닉 시 백오프 후 재시작)
+
+use crate::config::home_dir;
+use std::any::Any;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// 패닉 로그 경로: ~/Library/Logs/Koing/panic.log
+pub fn panic_log_path() -> PathBuf {
+    home_dir()
+        .join("Library")
+        .join("Logs")
+        .join("Koing")
+        .join("panic.log")
+}
+
+/// 패닉 정보 (스레드명, 발생 위치, 메시지)
+#[derive(Debug, Clone, PartialEq)]
+pub struct PanicReport {
+    pub thread: String,
+    pub location: String,
+    pub message: String,
+}
+
+impl PanicReport {
+    /// 패닉 페이로드에서 메시지 추출 (&str/String 외에는 고정 문구)
+    pub fn new(thread: Option<&str>, location: Option<String>, payload: &(dyn Any + Send)) -> Self {
+        let message = if let Some(s) = payload.downcast_ref::<&str>() {
+            (*s).to_string()
+        } else if let Some(s) = payload.downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "<알 수 없는 패닉 페이로드>".to_string()
+        };
+        Self {
+            thread: thread.unwrap_or("<unnamed>").to_string(),
+            location: location.unwrap_or_else(|| "<unknown>".to_string()),
+            message,
+        }
+    }
+
+    /// 로그 한 줄 (줄바꿈은 이스케이프하여 한 패닉이 한 줄을 유지)
+    pub fn to_log_line(&self, unix_secs: u64) -> String {
+        format!(
+            "[{}] thread={} location={} message={}",
+            unix_secs,
+            self.thread,
+            self.location,
+            self.message.replace('\n', "\\n")
+        )
+    }
+}
+
+/// 패닉 로그 파일에 한 줄 추가
+fn append_panic_log(path: &Path, line: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("로그 디렉토리 생성 실패: {}", e))?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| format!("패닉 로그 열기 실패: {}", e))?;
+    writeln!(file, "{}", line).map_err(|e| format!("패닉 로그 기록 실패: {}", e))
+}
+
+/// 패닉 훅 설치: 패닉을 로그 파일에 기록한 뒤 기존 훅(stderr 출력) 호출
+pub fn install_panic_hook() {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let report = PanicReport::new(
+            thread::current().name(),
+            info.location().map(|l| l.to_string()),
+            info.payload(),
+        );
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let line = report.to_log_line(now);
+        log::error!("패닉 발생: {}", line);
+        if let Err(e) = append_panic_log(&panic_log_path(), &line) {
+            log::warn!("{}", e);
+        }
+        previous(info);
+    }));
+}
+
+/// 재시작 정책
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RestartPolicy {
+    /// 첫 재시작 전 대기 시간
+    pub base_delay: Duration,
+    /// 최대 대기 시간 (연속 패닉 시 2배씩 증가하다 상한에서 고정)
+    pub max_delay: Duration,
+    /// 이 시간 이상 정상 동작 후 패닉하면 연속 카운터 초기화
+    pub healthy_after: Duration,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(30),
+            healthy_after: Duration::from_secs(60),
+        }
+    }
+}
+
+/// 재시작 카운터 및 백오프 계산
+#[derive(Debug, Clone)]
+pub struct RestartBackoff {
+    policy: RestartPolicy,
+    consecutive: u32,
+    restarts: u64,
+}
+
+impl RestartBackoff {
+    pub fn new(policy: RestartPolicy) -> Self {
+        Self {
+            policy,
+            consecutive: 0,
+            restarts: 0,
+        }
+    }
+
+    /// 패닉 기록 (ran_for: 이번 실행이 유지된 시간), 재시작 전 대기 시간 반환
+    pub fn record_panic(&mut self, ran_for: Duration) -> Duration {
+        if ran_for >= self.policy.healthy_after {
+            self.consecutive = 0;
+        }
+        self.consecutive = self.consecutive.saturating_add(1);
+        self.restarts = self.restarts.saturating_add(1);
+        let factor = 1u32 << (self.consecutive - 1).min(16);
+        self.policy
+            .base_delay
+            .saturating_mul(factor)
+            .min(self.policy.max_delay)
+    }
+
+    /// 연속 패닉 횟수 (정상 동작 구간 이후 초기화)
+    pub fn consecutive(&self) -> u32 {
+        self.consecutive
+    }
+
+    /// 누적 재시작 횟수
+    pub fn restarts(&self) -> u64 {
+        self.restarts
+    }
+}
+
+/// 감시 스레드와 함께 작업 스레드 시작
+///
+/// 작업이 패닉하면 백오프 후 같은 이름으로 다시 시작하고, 정상 반환하면 감시도 종료합니다.
+/// 반환된 핸들은 감시 종료 시 누적 재시작 횟수를 돌려줍니다.
+pub fn spawn_supervised<F>(name: &str, policy: RestartPolicy, work: F) -> JoinHandle<u64>
+where
+    F: Fn() + Send + Sync + 'static,
+{
+    let name = name.to_string();
+    let work = Arc::new(work);
+    thread::Builder::new()
+        .name(format!("{}-supervisor", name))
+        .spawn(move || {
+            let mut backoff = RestartBackoff::new(policy);
+            loop {
+                let started = Instant::now();
+                let work_for_thread = Arc::clone(&work);
+                let result = thread::Builder::new()
+                    .name(name.clone())
+                    .spawn(move || work_for_thread())
+                    .map_err(|e| format!("{} 스레드 생성 실패: {}", name, e))
+                    .and_then(|handle| handle.join().map_err(|_| format!("{} 패닉", name)));
+
+                match result {
+                    Ok(()) => return backoff.restarts(),
+                    Err(e) => {
+                        let delay = backoff.record_panic(started.elapsed());
+                        log::error!(
+                            "{}, {}ms 후 재시작 (연속 {}회, 누적 {}회)",
+                            e,
+                            delay.as_millis(),
+                            backoff.consecutive(),
+                            backoff.restarts()
+                        );
+                        thread::sleep(delay);
+                    }
+                }
+            }
+        })
+        .expect("감시 스레드 생성 실패")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn policy() -> RestartPolicy {
+        RestartPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(1000),
+            healthy_after: Duration::from_secs(10),
+        }
+    }
+
+    #[test]
+    fn test_backoff_doubles_until_max() {
+        let mut backoff = RestartBackoff::new(policy());
+        let delays: Vec<u128> = (0..6)
+            .map(|_| backoff.record_panic(Duration::ZERO).as_millis())
+            .collect();
+        assert_eq!(delays, vec![100, 200, 400, 800, 1000, 1000]);
+        assert_eq!(backoff.consecutive(), 6);
+        assert_eq!(backoff.restarts(), 6);
+    }
+
+    #[test]
+    fn test_backoff_resets_after_healthy_run() {
+        let mut backoff = RestartBackoff::new(policy());
+        backoff.record_panic(Duration::ZERO);
+        backoff.record_panic(Duration::ZERO);
+        assert_eq!(backoff.consecutive(), 2);
+
+        let delay = backoff.record_panic(Duration::from_secs(10));
+        assert_eq!(delay, Duration::from_millis(100));
+        assert_eq!(backoff.consecutive(), 1);
+        assert_eq!(backoff.restarts(), 3);
+    }
+
+    #[test]
+    fn test_backoff_does_not_overflow() {
+        let mut backoff = RestartBackoff::new(RestartPolicy {
+            max_delay: Duration::MAX,
+            ..policy()
+        });
+        for _ in 0..100 {
+            backoff.record_panic(Duration::ZERO);
+        }
+        assert_eq!(backoff.consecutive(), 100);
+    }
+
+    #[test]
+    fn test_panic_report_extracts_message() {
+        let payload: Box<dyn Any + Send> = Box::new("boom");
+        let report = PanicReport::new(Some("worker"), Some("src/main.rs:1:2".into()), &*payload);
+        assert_eq!(report.message, "boom");
+        assert_eq!(
+            report.to_log_line(42),
+            "[42] thread=worker location=src/main.rs:1:2 message=boom"
+        );
+
+        let payload: Box<dyn Any + Send> = Box::new(String::from("line1\nline2"));
+        let report = PanicReport::new(None, None, &*payload);
+        assert_eq!(
+            report.to_log_line(0),
+            "[0] thread=<unnamed> location=<unknown> message=line1\\nline2"
+        );
+
+        let payload: Box<dyn Any + Send> = Box::new(7u8);
+        assert!(PanicReport::new(None, None, &*payload)
+            .message
+            .contains("알 수 없는"));
+    }
+
+    #[test]
+    fn test_append_panic_log_creates_directory() {
+        let dir = std::env::temp_dir().join(format!("koing_panic_log_{}", std::process::id()));
+        let path = dir.join("Koing").join("panic.log");
+        let _ = fs::remove_dir_all(&dir);
+
+        append_panic_log(&path, "first").unwrap();
+        append_panic_log(&path, "second").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "first\nsecond\n");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_supervised_thread_restarts_after_panic() {
+        let runs = Arc::new(AtomicU32::new(0));
+        let runs_for_work = Arc::clone(&runs);
+        let names = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let names_for_work = Arc::clone(&names);
+        let handle = spawn_supervised(
+            "koing-test-worker",
+            RestartPolicy {
+                base_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(5),
+                healthy_after: Duration::from_secs(60),
+            },
+            move || {
+                names_for_work
+                    .lock()
+                    .unwrap()
+                    .push(thread::current().name().map(str::to_string));
+                // 처음 두 번은 의도적 패닉, 세 번째 실행에서 정상 종료
+                if runs_for_work.fetch_add(1, Ordering::SeqCst) < 2 {
+                    panic!("의도적 패닉");
+                }
+            },
+        );
+
+        assert_eq!(handle.join().unwrap(), 2);
+        assert_eq!(runs.load(Ordering::SeqCst), 3);
+        // 재시작된 스레드도 같은 이름 유지 (패닉 로그의 스레드명)
+        assert!(names
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|n| n.as_deref() == Some("koing-test-worker")));
+    }
+
+    #[test]
+    fn test_supervised_thread_without_panic_runs_once() {
+        let runs = Arc::new(AtomicU32::new(0));
+        let runs_for_work = Arc::clone(&runs);
+        let handle = spawn_supervised("koing-test-once", policy(), move || {
+            runs_for_work.fetch_add(1, Ordering::SeqCst);
+        });
+        assert_eq!(handle.join().unwrap(), 0);
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,211 @@
+//! 변환 단계별 소요 시간 집계 (성능 회귀 추적용)
+//!
+//! 디버그 빌드 또는 `debug_logging` 설정에서만 측정하며,
+//! 비활성화 상태에서는 `start()`가 시간을 읽지 않아 오버헤드가 없습니다.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// 변환 처리 단계
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    /// 영문→한글 변환 + N-gram 분석
+    Convert,
+    /// 자동 변환 판정 (음절 구조/N-gram/영어 패턴)
+    Validate,
+    /// 화면 텍스트 교체 (replace_text)
+    Replace,
+}
+
+impl Stage {
+    pub const ALL: [Stage; 3] = [Stage::Convert, Stage::Validate, Stage::Replace];
+
+    fn index(self) -> usize {
+        match self {
+            Stage::Convert => 0,
+            Stage::Validate => 1,
+            Stage::Replace => 2,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Stage::Convert => "convert",
+            Stage::Validate => "validate",
+            Stage::Replace => "replace",
+        }
+    }
+}
+
+/// 단계별 누적 값 (µs)
+struct StageCounter {
+    count: AtomicU64,
+    total_us: AtomicU64,
+    max_us: AtomicU64,
+}
+
+impl StageCounter {
+    const fn new() -> Self {
+        Self {
+            count: AtomicU64::new(0),
+            total_us: AtomicU64::new(0),
+            max_us: AtomicU64::new(0),
+        }
+    }
+}
+
+/// 단계별 집계 스냅샷
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StageTiming {
+    /// 측정 횟수
+    pub count: u64,
+    /// 평균 소요 시간 (µs)
+    pub avg_us: u64,
+    /// 최대 소요 시간 (µs)
+    pub max_us: u64,
+}
+
+/// 단계별 소요 시간 집계기
+pub struct TimingStats {
+    enabled: AtomicBool,
+    stages: [StageCounter; 3],
+}
+
+impl TimingStats {
+    pub const fn new() -> Self {
+        Self {
+            enabled: AtomicBool::new(false),
+            stages: [
+                StageCounter::new(),
+                StageCounter::new(),
+                StageCounter::new(),
+            ],
+        }
+    }
+
+    /// 측정 활성화/비활성화
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    /// 측정 시작 (비활성화 시 None)
+    pub fn start(&self) -> Option<Instant> {
+        self.is_enabled().then(Instant::now)
+    }
+
+    /// `start()` 이후 경과 시간 기록
+    pub fn finish(&self, stage: Stage, started: Option<Instant>) {
+        if let Some(started) = started {
+            self.record(stage, started.elapsed());
+        }
+    }
+
+    /// 소요 시간 기록
+    pub fn record(&self, stage: Stage, elapsed: Duration) {
+        let us = u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX);
+        let counter = &self.stages[stage.index()];
+        counter.count.fetch_add(1, Ordering::Relaxed);
+        counter.total_us.fetch_add(us, Ordering::Relaxed);
+        counter.max_us.fetch_max(us, Ordering::Relaxed);
+    }
+
+    /// 단계별 누적 평균/최대
+    pub fn snapshot(&self, stage: Stage) -> StageTiming {
+        let counter = &self.stages[stage.index()];
+        let count = counter.count.load(Ordering::Relaxed);
+        if count == 0 {
+            return StageTiming::default();
+        }
+        StageTiming {
+            count,
+            avg_us: counter.total_us.load(Ordering::Relaxed) / count,
+            max_us: counter.max_us.load(Ordering::Relaxed),
+        }
+    }
+
+    /// 로그 출력용 요약 (측정 기록이 없으면 None)
+    pub fn summary(&self) -> Option<String> {
+        let parts: Vec<String> = Stage::ALL
+            .iter()
+            .filter_map(|&stage| {
+                let timing = self.snapshot(stage);
+                (timing.count > 0).then(|| {
+                    format!(
+                        "{} n={} avg={}µs max={}µs",
+                        stage.label(),
+                        timing.count,
+                        timing.avg_us,
+                        timing.max_us
+                    )
+                })
+            })
+            .collect();
+        (!parts.is_empty()).then(|| format!("변환 단계 시간: {}", parts.join(", ")))
+    }
+}
+
+impl Default for TimingStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static TIMING_STATS: TimingStats = TimingStats::new();
+
+/// 전역 변환 단계 시간 집계기
+pub fn timing_stats() -> &'static TimingStats {
+    &TIMING_STATS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_accumulates_average_and_max() {
+        let stats = TimingStats::new();
+        stats.record(Stage::Convert, Duration::from_micros(100));
+        stats.record(Stage::Convert, Duration::from_micros(300));
+        stats.record(Stage::Convert, Duration::from_micros(200));
+        stats.record(Stage::Replace, Duration::from_millis(5));
+
+        assert_eq!(
+            stats.snapshot(Stage::Convert),
+            StageTiming {
+                count: 3,
+                avg_us: 200,
+                max_us: 300
+            }
+        );
+        assert_eq!(stats.snapshot(Stage::Replace).avg_us, 5_000);
+        assert_eq!(stats.snapshot(Stage::Validate), StageTiming::default());
+    }
+
+    #[test]
+    fn test_disabled_stats_do_not_measure() {
+        let stats = TimingStats::new();
+        assert!(stats.start().is_none());
+        stats.finish(Stage::Validate, stats.start());
+        assert_eq!(stats.snapshot(Stage::Validate).count, 0);
+
+        stats.set_enabled(true);
+        stats.finish(Stage::Validate, stats.start());
+        assert_eq!(stats.snapshot(Stage::Validate).count, 1);
+    }
+
+    #[test]
+    fn test_summary() {
+        let stats = TimingStats::new();
+        assert_eq!(stats.summary(), None);
+
+        stats.record(Stage::Validate, Duration::from_micros(40));
+        assert_eq!(
+            stats.summary().as_deref(),
+            Some("변환 단계 시간: validate n=1 avg=40µs max=40µs")
+        );
+    }
+}
@@ -0,0 +1,219 @@
+//! VoiceOver 라벨/공지 문자열
+
+use crate::platform::accessibility::preferred_language_code;
+use std::sync::OnceLock;
+
+/// 접근성 문자열 언어
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum A11yLanguage {
+    Korean,
+    English,
+}
+
+impl A11yLanguage {
+    /// 언어 코드에서 결정 ("ko", "ko-KR" → 한국어, 그 외 영어)
+    pub fn from_language_code(code: &str) -> Self {
+        let primary = code.split(['-', '_']).next().unwrap_or_default();
+        if primary.eq_ignore_ascii_case("ko") {
+            A11yLanguage::Korean
+        } else {
+            A11yLanguage::English
+        }
+    }
+
+    /// 시스템 선호 언어 (최초 1회 조회 후 캐싱, 조회 실패 시 한국어)
+    pub fn current() -> Self {
+        static CURRENT: OnceLock<A11yLanguage> = OnceLock::new();
+        *CURRENT.get_or_init(|| {
+            preferred_language_code()
+                .map(|code| Self::from_language_code(&code))
+                .unwrap_or(A11yLanguage::Korean)
+        })
+    }
+}
+
+/// 설정 윈도우 컨트롤 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsControl {
+    Enabled,
+    Debounce,
+    SlowDebounce,
+    SwitchDelay,
+    KoreanSource,
+    ExportStats,
+    EditExpansions,
+    RefreshDiagnostics,
+    CopyDiagnostics,
+    CopySnapshot,
+    SaveSnapshot,
+}
+
+/// 메뉴바 status item 라벨
+pub fn status_item_label(lang: A11yLanguage) -> &'static str {
+    match lang {
+        A11yLanguage::Korean => "Koing 한영 자동변환",
+        A11yLanguage::English => "Koing Korean-English converter",
+    }
+}
+
+/// 메뉴바 status item 값 (활성화 상태)
+pub fn status_item_value(enabled: bool, lang: A11yLanguage) -> &'static str {
+    match (lang, enabled) {
+        (A11yLanguage::Korean, true) => "활성화됨",
+        (A11yLanguage::Korean, false) => "비활성화됨",
+        (A11yLanguage::English, true) => "Enabled",
+        (A11yLanguage::English, false) => "Disabled",
+    }
+}
+
+/// 메뉴바 status item 값 (이벤트 탭 시작 실패, 권한 필요)
+pub fn permission_required_value(lang: A11yLanguage) -> &'static str {
+    match lang {
+        A11yLanguage::Korean => "손쉬운 사용 권한 필요",
+        A11yLanguage::English => "Accessibility permission required",
+    }
+}
+
+/// 설정 컨트롤 라벨
+pub fn settings_control_label(control: SettingsControl, lang: A11yLanguage) -> &'static str {
+    match (lang, control) {
+        (A11yLanguage::Korean, SettingsControl::Enabled) => "Koing 활성화",
+        (A11yLanguage::Korean, SettingsControl::Debounce) => "변환 속도",
+        (A11yLanguage::Korean, SettingsControl::SlowDebounce) => "느린 변환 속도",
+        (A11yLanguage::Korean, SettingsControl::SwitchDelay) => "자판 전환 지연",
+        (A11yLanguage::Korean, SettingsControl::KoreanSource) => "한글 입력 소스",
+        (A11yLanguage::Korean, SettingsControl::ExportStats) => "통계 내보내기",
+        (A11yLanguage::Korean, SettingsControl::EditExpansions) => "치환 규칙 편집",
+        (A11yLanguage::Korean, SettingsControl::RefreshDiagnostics) => "진단 정보 새로고침",
+        (A11yLanguage::Korean, SettingsControl::CopyDiagnostics) => "진단 정보 복사",
+        (A11yLanguage::Korean, SettingsControl::CopySnapshot) => "설정 스냅샷 복사",
+        (A11yLanguage::Korean, SettingsControl::SaveSnapshot) => "설정 스냅샷 파일로 저장",
+        (A11yLanguage::English, SettingsControl::Enabled) => "Enable Koing",
+        (A11yLanguage::English, SettingsControl::Debounce) => "Conversion speed",
+        (A11yLanguage::English, SettingsControl::SlowDebounce) => "Slow conversion speed",
+        (A11yLanguage::English, SettingsControl::SwitchDelay) => "Input source switch delay",
+        (A11yLanguage::English, SettingsControl::KoreanSource) => "Korean input source",
+        (A11yLanguage::English, SettingsControl::ExportStats) => "Export statistics",
+        (A11yLanguage::English, SettingsControl::EditExpansions) => "Edit expansion rules",
+        (A11yLanguage::English, SettingsControl::RefreshDiagnostics) => "Refresh diagnostics",
+        (A11yLanguage::English, SettingsControl::CopyDiagnostics) => "Copy diagnostics",
+        (A11yLanguage::English, SettingsControl::CopySnapshot) => "Copy settings snapshot",
+        (A11yLanguage::English, SettingsControl::SaveSnapshot) => "Save settings snapshot to file",
+    }
+}
+
+/// 활성화 토글 공지
+pub fn toggle_announcement(enabled: bool, lang: A11yLanguage) -> String {
+    format!("Koing {}", status_item_value(enabled, lang))
+}
+
+/// 변환 완료 공지
+pub fn conversion_announcement(hangul: &str, lang: A11yLanguage) -> String {
+    match lang {
+        A11yLanguage::Korean => format!("한글로 변환됨: {}", hangul),
+        A11yLanguage::English => format!("Converted to Korean: {}", hangul),
+    }
+}
+
+/// Undo 완료 공지
+pub fn undo_announcement(original: &str, lang: A11yLanguage) -> String {
+    match lang {
+        A11yLanguage::Korean => format!("변환 취소됨: {}", original),
+        A11yLanguage::English => format!("Conversion undone: {}", original),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_from_code() {
+        assert_eq!(A11yLanguage::from_language_code("ko"), A11yLanguage::Korean);
+        assert_eq!(
+            A11yLanguage::from_language_code("ko-KR"),
+            A11yLanguage::Korean
+        );
+        assert_eq!(
+            A11yLanguage::from_language_code("KO_kr"),
+            A11yLanguage::Korean
+        );
+        assert_eq!(
+            A11yLanguage::from_language_code("en-US"),
+            A11yLanguage::English
+        );
+        assert_eq!(
+            A11yLanguage::from_language_code("kok"),
+            A11yLanguage::English
+        );
+        assert_eq!(A11yLanguage::from_language_code(""), A11yLanguage::English);
+    }
+
+    #[test]
+    fn test_toggle_announcement() {
+        assert_eq!(
+            toggle_announcement(true, A11yLanguage::Korean),
+            "Koing 활성화됨"
+        );
+        assert_eq!(
+            toggle_announcement(false, A11yLanguage::Korean),
+            "Koing 비활성화됨"
+        );
+        assert_eq!(
+            toggle_announcement(true, A11yLanguage::English),
+            "Koing Enabled"
+        );
+        assert_eq!(
+            toggle_announcement(false, A11yLanguage::English),
+            "Koing Disabled"
+        );
+    }
+
+    #[test]
+    fn test_conversion_and_undo_announcements() {
+        assert_eq!(
+            conversion_announcement("안녕", A11yLanguage::Korean),
+            "한글로 변환됨: 안녕"
+        );
+        assert_eq!(
+            conversion_announcement("안녕", A11yLanguage::English),
+            "Converted to Korean: 안녕"
+        );
+        assert_eq!(
+            undo_announcement("dkssud", A11yLanguage::Korean),
+            "변환 취소됨: dkssud"
+        );
+        assert_eq!(
+            undo_announcement("dkssud", A11yLanguage::English),
+            "Conversion undone: dkssud"
+        );
+    }
+
+    #[test]
+    fn test_settings_labels_match_visible_text() {
+        // 한국어 라벨은 화면에 보이는 텍스트와 동일해야 함
+        assert_eq!(
+            settings_control_label(SettingsControl::Debounce, A11yLanguage::Korean),
+            "변환 속도"
+        );
+        assert_eq!(
+            settings_control_label(SettingsControl::SwitchDelay, A11yLanguage::Korean),
+            "자판 전환 지연"
+        );
+        for control in [
+            SettingsControl::Enabled,
+            SettingsControl::Debounce,
+            SettingsControl::SlowDebounce,
+            SettingsControl::SwitchDelay,
+            SettingsControl::KoreanSource,
+            SettingsControl::ExportStats,
+            SettingsControl::EditExpansions,
+            SettingsControl::RefreshDiagnostics,
+            SettingsControl::CopyDiagnostics,
+            SettingsControl::CopySnapshot,
+            SettingsControl::SaveSnapshot,
+        ] {
+            assert!(!settings_control_label(control, A11yLanguage::English).is_empty());
+        }
+    }
+}
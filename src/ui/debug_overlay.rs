@@ -0,0 +1,147 @@
+//! 디버그 오버레이 (현재 버퍼, 변환 후보, 신뢰도를 마우스 커서 근처에 표시)
+//!
+//! `KoingConfig.debug_overlay`가 켜져 있을 때만 만들어지는 개발용 창입니다.
+//! 포커스를 가져가지 않고 마우스 이벤트도 통과시키므로 입력 중인 앱에 영향이 없습니다.
+#![allow(deprecated)] // cocoa 크레이트 deprecated API 사용
+
+use crate::ui::settings::create_label;
+use cocoa::appkit::{NSWindow, NSWindowStyleMask};
+use cocoa::base::{id, nil, NO, YES};
+use cocoa::foundation::{NSPoint, NSRect, NSSize, NSString};
+use objc::{class, msg_send, sel, sel_impl};
+use std::sync::Mutex;
+
+/// 오버레이 크기
+const OVERLAY_SIZE: NSSize = NSSize {
+    width: 320.0,
+    height: 58.0,
+};
+/// 커서에서 오버레이까지 간격 (커서 오른쪽 아래에 표시)
+const CURSOR_OFFSET: f64 = 16.0;
+/// NSStatusWindowLevel (다른 앱 창 위에 표시)
+const STATUS_WINDOW_LEVEL: i64 = 25;
+
+struct SendId(id);
+unsafe impl Send for SendId {}
+
+/// 오버레이 창과 텍스트 라벨 (메인 스레드에서만 접근)
+struct OverlayUi {
+    window: SendId,
+    label: SendId,
+}
+
+static OVERLAY: Mutex<Option<OverlayUi>> = Mutex::new(None);
+
+/// 오버레이 표시 문자열 (버퍼가 비었으면 None — 오버레이 숨김)
+pub fn overlay_text(buffer: &str, preview: &str, confidence: Option<f32>) -> Option<String> {
+    if buffer.is_empty() {
+        return None;
+    }
+    let preview = if preview.is_empty() { "-" } else { preview };
+    let confidence = match confidence {
+        Some(confidence) => format!("{}/100", confidence.clamp(0.0, 100.0).round()),
+        None => "-".to_string(),
+    };
+    Some(format!(
+        "버퍼: {}\n후보: {}\n신뢰도: {}",
+        buffer, preview, confidence
+    ))
+}
+
+/// 오버레이 갱신 (메인 스레드에서 호출, 버퍼가 비었으면 숨김)
+pub fn show_debug_overlay(buffer: &str, preview: &str, confidence: Option<f32>) {
+    let Some(text) = overlay_text(buffer, preview, confidence) else {
+        hide_debug_overlay();
+        return;
+    };
+
+    let mut overlay = OVERLAY.lock().unwrap_or_else(|e| e.into_inner());
+    unsafe {
+        let ui = overlay.get_or_insert_with(|| create_overlay());
+        let _: () = msg_send![ui.label.0, setStringValue: NSString::alloc(nil).init_str(&text)];
+
+        let mouse: NSPoint = msg_send![class!(NSEvent), mouseLocation];
+        let origin = NSPoint::new(
+            mouse.x + CURSOR_OFFSET,
+            mouse.y - CURSOR_OFFSET - OVERLAY_SIZE.height,
+        );
+        let _: () = msg_send![ui.window.0, setFrameOrigin: origin];
+        let _: () = msg_send![ui.window.0, orderFrontRegardless];
+    }
+}
+
+/// 오버레이 숨김 (메인 스레드에서 호출)
+pub fn hide_debug_overlay() {
+    let overlay = OVERLAY.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(ref ui) = *overlay {
+        unsafe {
+            let _: () = msg_send![ui.window.0, orderOut: nil];
+        }
+    }
+}
+
+/// 테두리 없는 반투명 창 생성 (포커스/마우스 이벤트를 받지 않음)
+unsafe fn create_overlay() -> OverlayUi {
+    let rect = NSRect::new(NSPoint::new(0.0, 0.0), OVERLAY_SIZE);
+    let window = NSWindow::alloc(nil).initWithContentRect_styleMask_backing_defer_(
+        rect,
+        NSWindowStyleMask::NSBorderlessWindowMask,
+        cocoa::appkit::NSBackingStoreType::NSBackingStoreBuffered,
+        NO,
+    );
+    let _: () = msg_send![window, setReleasedWhenClosed: NO];
+    let _: () = msg_send![window, setLevel: STATUS_WINDOW_LEVEL];
+    let _: () = msg_send![window, setIgnoresMouseEvents: YES];
+    let _: () = msg_send![window, setHasShadow: YES];
+    let _: () = msg_send![window, setOpaque: NO];
+    let background: id = msg_send![class!(NSColor), colorWithWhite: 0.1f64 alpha: 0.85f64];
+    let _: () = msg_send![window, setBackgroundColor: background];
+
+    let label = create_label(
+        "",
+        NSRect::new(
+            NSPoint::new(8.0, 4.0),
+            NSSize::new(OVERLAY_SIZE.width - 16.0, OVERLAY_SIZE.height - 8.0),
+        ),
+    );
+    let font: id = msg_send![class!(NSFont), monospacedSystemFontOfSize: 11.0f64 weight: 0.0f64];
+    let _: () = msg_send![label, setFont: font];
+    let color: id = msg_send![class!(NSColor), whiteColor];
+    let _: () = msg_send![label, setTextColor: color];
+    let content_view: id = msg_send![window, contentView];
+    let _: () = msg_send![content_view, addSubview: label];
+
+    OverlayUi {
+        window: SendId(window),
+        label: SendId(label),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overlay_text_format() {
+        assert_eq!(
+            overlay_text("dkssud", "안녕", Some(87.4)).as_deref(),
+            Some("버퍼: dkssud\n후보: 안녕\n신뢰도: 87/100")
+        );
+        // 신뢰도 범위 밖 값은 잘라서 표시
+        assert_eq!(
+            overlay_text("rk", "가", Some(130.0)).as_deref(),
+            Some("버퍼: rk\n후보: 가\n신뢰도: 100/100")
+        );
+    }
+
+    #[test]
+    fn test_overlay_text_missing_values() {
+        // 빈 버퍼는 숨김
+        assert_eq!(overlay_text("", "", None), None);
+        // 판정 대상이 없는 버퍼 (URL 구간 등)
+        assert_eq!(
+            overlay_text("a.b/c", "", None).as_deref(),
+            Some("버퍼: a.b/c\n후보: -\n신뢰도: -")
+        );
+    }
+}
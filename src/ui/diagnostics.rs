@@ -0,0 +1,126 @@
+//! 설정 윈도우 진단 정보 (입력 소스, 권한, macOS 버전, 이벤트 탭 상태)
+
+use crate::platform::event_tap::{EventTapState, TapStatus};
+use crate::platform::input_source::get_current_input_source_id;
+use crate::platform::os_version::{get_macos_version, MacOSVersion};
+use crate::platform::permissions::permission_status_string;
+
+/// 진단 시점의 상태 스냅샷
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostics {
+    /// 현재 입력 소스 ID (조회 실패 시 None)
+    pub input_source_id: Option<String>,
+    /// Accessibility 권한 상태 문구
+    pub permission: &'static str,
+    pub macos_version: MacOSVersion,
+    /// 이벤트 탭 상태 (EventTapState 초기화 전이면 None)
+    pub tap_status: Option<TapStatus>,
+    /// 비활성화된 탭 복구 진행 중
+    pub tap_recovering: bool,
+}
+
+impl Diagnostics {
+    /// 현재 상태 조회 (TIS/AppKit API를 사용하므로 메인 스레드에서 호출)
+    pub fn collect(state: Option<&EventTapState>) -> Self {
+        Self {
+            input_source_id: get_current_input_source_id(),
+            permission: permission_status_string(),
+            macos_version: get_macos_version(),
+            tap_status: state.map(|s| s.tap_status()),
+            tap_recovering: state.is_some_and(|s| s.is_tap_recovering()),
+        }
+    }
+
+    /// 표시/복사용 줄 목록
+    pub fn lines(&self) -> Vec<String> {
+        vec![
+            format!(
+                "입력 소스: {}",
+                self.input_source_id.as_deref().unwrap_or("알 수 없음")
+            ),
+            self.permission.to_string(),
+            format!("macOS: {}", self.macos_version),
+            format!("이벤트 탭: {}", self.tap_label()),
+        ]
+    }
+
+    /// 클립보드 복사용 진단 문자열 (앱 버전 포함)
+    pub fn to_report(&self) -> String {
+        let mut report = format!("Koing {}\n", env!("CARGO_PKG_VERSION"));
+        report.push_str(&self.lines().join("\n"));
+        report
+    }
+
+    fn tap_label(&self) -> &'static str {
+        match (self.tap_status, self.tap_recovering) {
+            (None, _) => "초기화 전",
+            (Some(TapStatus::Running), true) => "복구 중 (비활성화 감지)",
+            (Some(TapStatus::Running), false) => "정상",
+            (Some(TapStatus::Starting), _) => "시작 중",
+            (Some(TapStatus::PermissionRequired), _) => "시작 실패 (권한 필요)",
+            (Some(TapStatus::PermissionRevoked), _) => "중단 (권한 회수됨)",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Diagnostics {
+        Diagnostics {
+            input_source_id: Some("com.apple.keylayout.ABC".to_string()),
+            permission: "Accessibility 권한: 허용됨",
+            macos_version: MacOSVersion {
+                major: 15,
+                minor: 2,
+                patch: 1,
+            },
+            tap_status: Some(TapStatus::Running),
+            tap_recovering: false,
+        }
+    }
+
+    #[test]
+    fn test_lines_format() {
+        assert_eq!(
+            sample().lines(),
+            vec![
+                "입력 소스: com.apple.keylayout.ABC",
+                "Accessibility 권한: 허용됨",
+                "macOS: 15.2.1",
+                "이벤트 탭: 정상",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_missing_values_and_tap_states() {
+        let mut diagnostics = sample();
+        diagnostics.input_source_id = None;
+        diagnostics.tap_status = None;
+        let lines = diagnostics.lines();
+        assert_eq!(lines[0], "입력 소스: 알 수 없음");
+        assert_eq!(lines[3], "이벤트 탭: 초기화 전");
+
+        // 복구 플래그는 정상 동작 중에만 별도 표시
+        diagnostics.tap_status = Some(TapStatus::Running);
+        diagnostics.tap_recovering = true;
+        assert_eq!(diagnostics.lines()[3], "이벤트 탭: 복구 중 (비활성화 감지)");
+        diagnostics.tap_status = Some(TapStatus::PermissionRequired);
+        assert_eq!(diagnostics.lines()[3], "이벤트 탭: 시작 실패 (권한 필요)");
+        diagnostics.tap_status = Some(TapStatus::PermissionRevoked);
+        assert_eq!(diagnostics.lines()[3], "이벤트 탭: 중단 (권한 회수됨)");
+    }
+
+    #[test]
+    fn test_report_has_version_header_and_all_lines() {
+        let report = sample().to_report();
+        let mut lines = report.lines();
+        assert_eq!(
+            lines.next(),
+            Some(format!("Koing {}", env!("CARGO_PKG_VERSION")).as_str())
+        );
+        assert_eq!(lines.collect::<Vec<_>>(), sample().lines());
+    }
+}
@@ -1,8 +1,19 @@
 //! macOS 메뉴바 앱 (NSStatusBar)
 #![allow(deprecated)] // cocoa 크레이트 deprecated API 사용
 
-use crate::config::{load_config, save_config, KoingConfig};
-use crate::platform::event_tap::EventTapState;
+use crate::config::{load_config, save_config, AppProfile, KoingConfig};
+use crate::platform::accessibility::{announce, set_accessibility_label, set_accessibility_value};
+use crate::platform::event_tap::{EventTapState, TapStatus};
+use crate::platform::input_source::{
+    is_english_input_source, preferred_korean_source, switch_to_english, switch_to_korean,
+};
+use crate::platform::text_replacer::{restore_pending_clipboard, set_clipboard_string};
+use crate::platform::{dispatch_to_main, open_accessibility_settings};
+use crate::stats::save_stats;
+use crate::ui::accessibility::{
+    permission_required_value, status_item_label, status_item_value, toggle_announcement,
+    A11yLanguage,
+};
 use cocoa::appkit::{
     NSApp, NSApplication, NSApplicationActivationPolicyAccessory, NSMenu, NSMenuItem, NSStatusBar,
     NSStatusItem, NSVariableStatusItemLength,
@@ -39,15 +50,32 @@ static SHOULD_QUIT: AtomicBool = AtomicBool::new(false);
 pub static EVENT_STATE: OnceLock<Arc<EventTapState>> = OnceLock::new();
 /// 메뉴바 status_item (아이콘 알파 변경용)
 static STATUS_ITEM: Mutex<SendId> = Mutex::new(SendId::NULL);
+/// 아이콘 이미지 로드 여부 (실패 시 텍스트 타이틀 사용)
+static ICON_LOADED: AtomicBool = AtomicBool::new(true);
+/// 이벤트 탭 시작 실패 경고 표시 중 여부
+static TAP_WARNING: AtomicBool = AtomicBool::new(false);
+/// "손쉬운 사용 권한 열기" 메뉴 아이템 (경고 상태에서만 표시)
+static PERMISSION_MENU_ITEM: Mutex<SendId> = Mutex::new(SendId::NULL);
+/// "현재 신뢰도" 표시 메뉴 아이템 (비활성, 메뉴가 열릴 때 갱신)
+static CONFIDENCE_MENU_ITEM: Mutex<SendId> = Mutex::new(SendId::NULL);
 /// "Koing 활성화" 토글 메뉴 아이템
 static TOGGLE_MENU_ITEM: Mutex<SendId> = Mutex::new(SendId::NULL);
+/// "마지막 변환 복사" 메뉴 아이템 (이력 유무에 따라 활성화)
+static LAST_CONVERSION_MENU_ITEM: Mutex<SendId> = Mutex::new(SendId::NULL);
+/// "한글로 전환" / "영문으로 전환" 메뉴 아이템 (현재 입력 소스에 체크)
+static INPUT_SOURCE_MENU_ITEMS: Mutex<[SendId; 2]> = Mutex::new([SendId::NULL; 2]);
 static DEBOUNCE_MENU_ITEMS: Mutex<[SendId; 4]> = Mutex::new([SendId::NULL; 4]);
 static SWITCH_MENU_ITEMS: Mutex<[SendId; 4]> = Mutex::new([SendId::NULL; 4]);
 static SLOW_DEBOUNCE_MENU_ITEMS: Mutex<[SendId; 4]> = Mutex::new([SendId::NULL; 4]);
+static SENSITIVITY_MENU_ITEMS: Mutex<[SendId; 3]> = Mutex::new([SendId::NULL; 3]);
+/// 프리셋과 다른 값일 때 표시되는 "사용자 지정" 메뉴 아이템
+static DEBOUNCE_CUSTOM_ITEM: Mutex<SendId> = Mutex::new(SendId::NULL);
+static SWITCH_CUSTOM_ITEM: Mutex<SendId> = Mutex::new(SendId::NULL);
+static SLOW_DEBOUNCE_CUSTOM_ITEM: Mutex<SendId> = Mutex::new(SendId::NULL);
 
 use super::{
-    DEBOUNCE_LABELS, DEBOUNCE_PRESETS, SLOW_DEBOUNCE_LABELS, SLOW_DEBOUNCE_PRESETS, SWITCH_LABELS,
-    SWITCH_PRESETS,
+    DEBOUNCE_LABELS, DEBOUNCE_PRESETS, SENSITIVITY_LABELS, SENSITIVITY_PRESETS,
+    SLOW_DEBOUNCE_LABELS, SLOW_DEBOUNCE_PRESETS, SWITCH_LABELS, SWITCH_PRESETS,
 };
 
 /// 현재 설정 읽어서 KoingConfig 구성
@@ -59,31 +87,186 @@ pub fn current_config() -> KoingConfig {
             config.debounce_ms = state.get_debounce_ms();
             config.switch_delay_ms = state.get_switch_delay_ms();
             config.slow_debounce_ms = state.get_slow_debounce_ms();
+            config.two_stage_conversion = state.is_two_stage_conversion();
+            config.sensitivity = state.get_sensitivity();
+            config.korean_source_id = preferred_korean_source();
             config
         }
         None => KoingConfig::default(),
     }
 }
 
-fn update_checkmarks(menu_items: &Mutex<[SendId; 4]>, presets: &[u64; 4], selected: u64) {
+/// 선택 값에 대한 체크 상태: (프리셋별 체크 여부, 사용자 지정 체크 여부)
+fn checkmark_states<T: PartialEq + Copy, const N: usize>(
+    presets: &[T; N],
+    selected: T,
+) -> ([bool; N], bool) {
+    let checks = presets.map(|preset| preset == selected);
+    (checks, !checks.contains(&true))
+}
+
+/// 입력 소스 메뉴 아이템 레이블 (한글, 영문 순)
+const INPUT_SOURCE_LABELS: [&str; 2] = ["한글로 전환", "영문으로 전환"];
+
+/// 현재 입력 소스에 대한 체크 상태 (한글, 영문 순)
+fn input_source_checks(is_english: bool) -> [bool; 2] {
+    [!is_english, is_english]
+}
+
+/// status item 타이틀 (아이콘 로드 실패 시 "코", 경고 상태면 ⚠︎ 추가)
+fn status_title(icon_loaded: bool, warning: bool) -> &'static str {
+    match (icon_loaded, warning) {
+        (true, false) => "",
+        (true, true) => "⚠︎",
+        (false, false) => "코",
+        (false, true) => "코⚠︎",
+    }
+}
+
+/// status item 접근성 값 (경고 상태면 권한 필요 안내)
+fn status_accessibility_value(enabled: bool, lang: A11yLanguage) -> &'static str {
+    if TAP_WARNING.load(Ordering::Acquire) {
+        permission_required_value(lang)
+    } else {
+        status_item_value(enabled, lang)
+    }
+}
+
+/// 현재 버퍼 신뢰도 메뉴 아이템 타이틀 (None: 입력 대기)
+fn confidence_title(confidence: Option<f32>) -> String {
+    match confidence {
+        Some(confidence) => format!("현재 신뢰도: {}/100", confidence.clamp(0.0, 100.0).round()),
+        None => "현재 신뢰도: 대기 중".to_string(),
+    }
+}
+
+/// 신뢰도 메뉴 아이템을 현재 버퍼 기준으로 갱신
+fn update_confidence_item(state: &EventTapState) {
+    let title = confidence_title(state.buffer_confidence());
+    let item = CONFIDENCE_MENU_ITEM
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    if !item.0.is_null() {
+        unsafe {
+            let title = NSString::alloc(nil).init_str(&title);
+            let _: () = msg_send![item.0, setTitle: title];
+        }
+    }
+}
+
+/// "마지막 변환 복사" 대상 (이력이 없거나 텍스트 교체 중이면 None)
+fn last_conversion_copy_text(last_converted: Option<&str>, replacing: bool) -> Option<&str> {
+    if replacing {
+        return None;
+    }
+    last_converted.filter(|text| !text.is_empty())
+}
+
+/// "마지막 변환 복사" 메뉴 아이템을 변환 이력 유무에 맞춰 활성화/비활성화
+fn update_last_conversion_item(state: &EventTapState) {
+    let enabled = last_conversion_copy_text(state.last_converted().as_deref(), false).is_some();
+    let item = LAST_CONVERSION_MENU_ITEM
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    if !item.0.is_null() {
+        unsafe {
+            let _: () = msg_send![item.0, setEnabled: if enabled { YES } else { NO }];
+        }
+    }
+}
+
+/// "사용자 지정" 메뉴 아이템 타이틀
+fn custom_item_title(ms: u64) -> String {
+    format!("사용자 지정 ({}ms)", ms)
+}
+
+fn update_checkmarks(
+    menu_items: &Mutex<[SendId; 4]>,
+    custom_item: &Mutex<SendId>,
+    presets: &[u64; 4],
+    selected: u64,
+) {
+    let (checks, is_custom) = checkmark_states(presets, selected);
+    set_item_checks(menu_items, &checks);
+
+    // 사용자 지정 항목: 프리셋과 다른 값일 때만 표시 + 체크
+    let custom = custom_item.lock().unwrap_or_else(|e| e.into_inner());
+    if !custom.0.is_null() {
+        let s: cocoa::foundation::NSInteger = if is_custom { 1 } else { 0 };
+        let hidden = if is_custom { NO } else { YES };
+        unsafe {
+            let title = NSString::alloc(nil).init_str(&custom_item_title(selected));
+            let _: () = msg_send![custom.0, setTitle: title];
+            let _: () = msg_send![custom.0, setState: s];
+            let _: () = msg_send![custom.0, setHidden: hidden];
+        }
+    }
+}
+
+/// 프리셋 메뉴 아이템 체크 상태 반영
+fn set_item_checks<const N: usize>(menu_items: &Mutex<[SendId; N]>, checks: &[bool; N]) {
     let items = menu_items.lock().unwrap_or_else(|e| e.into_inner());
-    for (i, &preset) in presets.iter().enumerate() {
-        let item = items[i].0;
-        if !item.is_null() {
-            let s: cocoa::foundation::NSInteger = if preset == selected { 1 } else { 0 };
+    for (item, &checked) in items.iter().zip(checks.iter()) {
+        if !item.0.is_null() {
+            let s: cocoa::foundation::NSInteger = if checked { 1 } else { 0 };
             unsafe {
-                let _: () = msg_send![item, setState: s];
+                let _: () = msg_send![item.0, setState: s];
             }
         }
     }
 }
 
+/// 변환 민감도 체크마크 (프리셋 외 값이 없으므로 사용자 지정 항목 없음)
+fn update_sensitivity_checkmarks(selected: AppProfile) {
+    let (checks, _) = checkmark_states(&SENSITIVITY_PRESETS, selected);
+    set_item_checks(&SENSITIVITY_MENU_ITEMS, &checks);
+}
+
+/// 현재 EventTapState 값으로 메뉴 체크마크 동기화
+/// (설정 윈도우나 설정 파일에서 값이 바뀐 경우 메뉴를 열 때 반영)
+fn sync_menu_state() {
+    let Some(state) = EVENT_STATE.get() else {
+        return;
+    };
+    update_toggle_state(state.is_enabled());
+    update_checkmarks(
+        &DEBOUNCE_MENU_ITEMS,
+        &DEBOUNCE_CUSTOM_ITEM,
+        &DEBOUNCE_PRESETS,
+        state.get_debounce_ms(),
+    );
+    update_checkmarks(
+        &SWITCH_MENU_ITEMS,
+        &SWITCH_CUSTOM_ITEM,
+        &SWITCH_PRESETS,
+        state.get_switch_delay_ms(),
+    );
+    update_checkmarks(
+        &SLOW_DEBOUNCE_MENU_ITEMS,
+        &SLOW_DEBOUNCE_CUSTOM_ITEM,
+        &SLOW_DEBOUNCE_PRESETS,
+        state.get_slow_debounce_ms(),
+    );
+    update_sensitivity_checkmarks(state.get_sensitivity());
+    update_confidence_item(state);
+    update_last_conversion_item(state);
+    set_item_checks(
+        &INPUT_SOURCE_MENU_ITEMS,
+        &input_source_checks(is_english_input_source()),
+    );
+}
+
 fn set_debounce(ms: u64) {
     let Some(state) = EVENT_STATE.get() else {
         return;
     };
     state.set_debounce_ms(ms);
-    update_checkmarks(&DEBOUNCE_MENU_ITEMS, &DEBOUNCE_PRESETS, ms);
+    update_checkmarks(
+        &DEBOUNCE_MENU_ITEMS,
+        &DEBOUNCE_CUSTOM_ITEM,
+        &DEBOUNCE_PRESETS,
+        ms,
+    );
 
     let config = current_config();
     if let Err(e) = save_config(&config) {
@@ -96,7 +279,7 @@ fn set_switch(ms: u64) {
         return;
     };
     state.set_switch_delay_ms(ms);
-    update_checkmarks(&SWITCH_MENU_ITEMS, &SWITCH_PRESETS, ms);
+    update_checkmarks(&SWITCH_MENU_ITEMS, &SWITCH_CUSTOM_ITEM, &SWITCH_PRESETS, ms);
 
     let config = current_config();
     if let Err(e) = save_config(&config) {
@@ -109,7 +292,25 @@ fn set_slow_debounce(ms: u64) {
         return;
     };
     state.set_slow_debounce_ms(ms);
-    update_checkmarks(&SLOW_DEBOUNCE_MENU_ITEMS, &SLOW_DEBOUNCE_PRESETS, ms);
+    update_checkmarks(
+        &SLOW_DEBOUNCE_MENU_ITEMS,
+        &SLOW_DEBOUNCE_CUSTOM_ITEM,
+        &SLOW_DEBOUNCE_PRESETS,
+        ms,
+    );
+
+    let config = current_config();
+    if let Err(e) = save_config(&config) {
+        log::error!("설정 저장 실패: {}", e);
+    }
+}
+
+fn set_sensitivity(sensitivity: AppProfile) {
+    let Some(state) = EVENT_STATE.get() else {
+        return;
+    };
+    state.set_sensitivity(sensitivity);
+    update_sensitivity_checkmarks(sensitivity);
 
     let config = current_config();
     if let Err(e) = save_config(&config) {
@@ -124,6 +325,14 @@ extern "C" fn quit_action(_this: &Object, _cmd: Sel, _sender: id) {
     // 이벤트 탭 CFRunLoop 정지
     if let Some(state) = EVENT_STATE.get() {
         state.stop();
+        // 누적 변환 통계 저장
+        if let Err(e) = save_stats(&state.stats_snapshot()) {
+            log::warn!("{}", e);
+        }
+    }
+    // 변환 직후 종료하면 지연 복원 전이므로 원래 클립보드를 바로 복원
+    if restore_pending_clipboard() {
+        log::info!("종료 전 클립보드 복원");
     }
     unsafe {
         let app: id = NSApp();
@@ -170,65 +379,160 @@ extern "C" fn set_slow_debounce_3000(_: &Object, _: Sel, _: id) {
     set_slow_debounce(3000);
 }
 
+// 변환 민감도
+extern "C" fn set_sensitivity_conservative(_: &Object, _: Sel, _: id) {
+    set_sensitivity(AppProfile::Conservative);
+}
+extern "C" fn set_sensitivity_normal(_: &Object, _: Sel, _: id) {
+    set_sensitivity(AppProfile::Normal);
+}
+extern "C" fn set_sensitivity_aggressive(_: &Object, _: Sel, _: id) {
+    set_sensitivity(AppProfile::Aggressive);
+}
+
 extern "C" fn toggle_enabled(_: &Object, _: Sel, _: id) {
     let Some(state) = EVENT_STATE.get() else {
         return;
     };
     let new_enabled = !state.is_enabled();
     state.set_enabled(new_enabled);
+    apply_enabled_change(new_enabled);
+}
+
+/// 활성화 상태 변경을 UI에 반영하고 설정 저장 (메인 스레드에서 호출, 단축키 토글 포함)
+pub fn apply_enabled_change(enabled: bool) {
+    // 체크마크/아이콘 알파값/접근성 상태 갱신
+    update_toggle_state(enabled);
+
+    // 설정 저장
+    let config = current_config();
+    if let Err(e) = save_config(&config) {
+        log::error!("설정 저장 실패: {}", e);
+    }
+}
+
+/// 입력 소스 전환 후 체크마크 갱신 (메뉴가 닫힌 뒤 메인 큐에서 실행)
+fn switch_input_source(to_english: bool) {
+    dispatch_to_main(move || {
+        let result = if to_english {
+            switch_to_english()
+        } else {
+            switch_to_korean()
+        };
+        if let Err(e) = result {
+            log::warn!("메뉴 입력 소스 전환 실패: {}", e);
+        }
+        set_item_checks(
+            &INPUT_SOURCE_MENU_ITEMS,
+            &input_source_checks(is_english_input_source()),
+        );
+    });
+}
+
+extern "C" fn switch_to_korean_action(_: &Object, _: Sel, _: id) {
+    switch_input_source(false);
+}
+extern "C" fn switch_to_english_action(_: &Object, _: Sel, _: id) {
+    switch_input_source(true);
+}
+
+extern "C" fn copy_last_conversion(_: &Object, _: Sel, _: id) {
+    let Some(state) = EVENT_STATE.get() else {
+        return;
+    };
+    // 교체 중에는 paste가 클립보드를 쓰고 있으므로 건드리지 않음
+    let last_converted = state.last_converted();
+    let replacing = state.is_replacing.load(Ordering::Acquire);
+    match last_conversion_copy_text(last_converted.as_deref(), replacing) {
+        Some(text) => set_clipboard_string(text),
+        None => log::debug!("마지막 변환 복사 스킵 (이력 없음 또는 교체 중)"),
+    }
+}
+
+extern "C" fn open_settings(_: &Object, _: Sel, _: id) {
+    crate::ui::settings::show_settings_window();
+}
+
+extern "C" fn run_self_test_action(_: &Object, _: Sel, _: id) {
+    crate::ui::self_test::run_self_test();
+}
+
+extern "C" fn open_permission_settings(_: &Object, _: Sel, _: id) {
+    if let Err(e) = open_accessibility_settings() {
+        log::error!("{}", e);
+    }
+}
+
+/// NSMenuDelegate: 메뉴가 열리기 직전 현재 설정과 동기화
+extern "C" fn menu_will_open(_: &Object, _: Sel, _menu: id) {
+    sync_menu_state();
+}
 
-    // 토글 메뉴 아이템 체크마크 업데이트
+/// 토글 상태를 메뉴/아이콘에 반영하고 VoiceOver로 공지 (메뉴바·설정 윈도우에서 호출)
+pub fn update_toggle_state(enabled: bool) {
     let toggle_item = TOGGLE_MENU_ITEM.lock().unwrap_or_else(|e| e.into_inner());
     if !toggle_item.0.is_null() {
-        let check: cocoa::foundation::NSInteger = if new_enabled { 1 } else { 0 };
+        let check: cocoa::foundation::NSInteger = if enabled { 1 } else { 0 };
         unsafe {
             let _: () = msg_send![toggle_item.0, setState: check];
         }
     }
 
-    // 메뉴바 아이콘 알파값 변경 (비활성화 시 흐리게)
     let status_item = STATUS_ITEM.lock().unwrap_or_else(|e| e.into_inner());
     if !status_item.0.is_null() {
         unsafe {
             let button: id = msg_send![status_item.0, button];
             if !button.is_null() {
-                let alpha: f64 = if new_enabled { 1.0 } else { 0.3 };
+                let alpha: f64 = if enabled { 1.0 } else { 0.3 };
                 let _: () = msg_send![button, setAlphaValue: alpha];
+                set_accessibility_value(
+                    button,
+                    status_accessibility_value(enabled, A11yLanguage::current()),
+                );
             }
         }
     }
 
-    // 설정 저장
-    let config = current_config();
-    if let Err(e) = save_config(&config) {
-        log::error!("설정 저장 실패: {}", e);
-    }
+    announce(&toggle_announcement(enabled, A11yLanguage::current()));
 }
 
-extern "C" fn open_settings(_: &Object, _: Sel, _: id) {
-    crate::ui::settings::show_settings_window();
-}
+/// 이벤트 탭 상태를 메뉴바에 반영 (메인 스레드에서 호출)
+///
+/// 시작 실패/권한 회수 시 아이콘에 경고 표시 + "권한 열기" 메뉴 노출, 복구되면 원래대로
+pub fn update_tap_status(status: TapStatus) {
+    let was_warning = TAP_WARNING.load(Ordering::Acquire);
+    let warning = status.needs_attention(was_warning);
+    TAP_WARNING.store(warning, Ordering::Release);
+    let enabled = EVENT_STATE.get().is_some_and(|state| state.is_enabled());
+    let lang = A11yLanguage::current();
 
-/// 외부에서 토글 상태를 업데이트할 때 사용 (설정 윈도우에서 호출)
-pub fn update_toggle_state(enabled: bool) {
-    let toggle_item = TOGGLE_MENU_ITEM.lock().unwrap_or_else(|e| e.into_inner());
-    if !toggle_item.0.is_null() {
-        let check: cocoa::foundation::NSInteger = if enabled { 1 } else { 0 };
+    let permission_item = PERMISSION_MENU_ITEM
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    if !permission_item.0.is_null() {
+        let hidden = if warning { NO } else { YES };
         unsafe {
-            let _: () = msg_send![toggle_item.0, setState: check];
+            let _: () = msg_send![permission_item.0, setHidden: hidden];
         }
     }
 
     let status_item = STATUS_ITEM.lock().unwrap_or_else(|e| e.into_inner());
     if !status_item.0.is_null() {
         unsafe {
+            let title = NSString::alloc(nil)
+                .init_str(status_title(ICON_LOADED.load(Ordering::Acquire), warning));
+            let _: () = msg_send![status_item.0, setTitle: title];
+            let _: () = msg_send![title, release];
             let button: id = msg_send![status_item.0, button];
             if !button.is_null() {
-                let alpha: f64 = if enabled { 1.0 } else { 0.3 };
-                let _: () = msg_send![button, setAlphaValue: alpha];
+                set_accessibility_value(button, status_accessibility_value(enabled, lang));
             }
         }
     }
+
+    if warning != was_warning {
+        announce(status_accessibility_value(enabled, lang));
+    }
 }
 
 fn create_app_delegate_class() -> &'static Class {
@@ -263,14 +567,39 @@ fn create_app_delegate_class() -> &'static Class {
             sel!(setSlowDebounce3000:),
             set_slow_debounce_3000 as ActionFn,
         );
+        decl.add_method(
+            sel!(setSensitivityConservative:),
+            set_sensitivity_conservative as ActionFn,
+        );
+        decl.add_method(
+            sel!(setSensitivityNormal:),
+            set_sensitivity_normal as ActionFn,
+        );
+        decl.add_method(
+            sel!(setSensitivityAggressive:),
+            set_sensitivity_aggressive as ActionFn,
+        );
         decl.add_method(sel!(toggleEnabled:), toggle_enabled as ActionFn);
+        decl.add_method(sel!(switchToKorean:), switch_to_korean_action as ActionFn);
+        decl.add_method(sel!(switchToEnglish:), switch_to_english_action as ActionFn);
+        decl.add_method(sel!(copyLastConversion:), copy_last_conversion as ActionFn);
         decl.add_method(sel!(openSettings:), open_settings as ActionFn);
+        decl.add_method(sel!(runSelfTest:), run_self_test_action as ActionFn);
+        decl.add_method(
+            sel!(openPermissionSettings:),
+            open_permission_settings as ActionFn,
+        );
+        decl.add_method(sel!(menuWillOpen:), menu_will_open as ActionFn);
     }
 
     decl.register()
 }
 
 /// 서브메뉴 생성 헬퍼
+///
+/// 프리셋 4개 뒤에 "사용자 지정 (Nms)" 항목을 두고,
+/// 현재 값이 프리셋과 다를 때만 표시합니다 (선택 시 설정 윈도우 열기).
+#[allow(clippy::too_many_arguments)]
 unsafe fn build_submenu(
     title: &str,
     labels: &[&str; 4],
@@ -278,6 +607,7 @@ unsafe fn build_submenu(
     presets: &[u64; 4],
     current: u64,
     items_out: &Mutex<[SendId; 4]>,
+    custom_item_out: &Mutex<SendId>,
     delegate: id,
 ) -> id {
     let menu_item = NSMenuItem::alloc(nil).initWithTitle_action_keyEquivalent_(
@@ -288,6 +618,31 @@ unsafe fn build_submenu(
     let submenu = NSMenu::new(nil).autorelease();
     let _: () = msg_send![submenu, setTitle: NSString::alloc(nil).init_str(title)];
 
+    add_preset_items(submenu, labels, selectors, items_out, delegate);
+
+    let custom_item = NSMenuItem::alloc(nil).initWithTitle_action_keyEquivalent_(
+        NSString::alloc(nil).init_str(&custom_item_title(current)),
+        sel!(openSettings:),
+        NSString::alloc(nil).init_str(""),
+    );
+    let _: () = msg_send![custom_item, setTarget: delegate];
+    *custom_item_out.lock().unwrap_or_else(|e| e.into_inner()) = SendId(custom_item);
+    submenu.addItem_(custom_item);
+
+    update_checkmarks(items_out, custom_item_out, presets, current);
+
+    let _: () = msg_send![menu_item, setSubmenu: submenu];
+    menu_item
+}
+
+/// 서브메뉴에 프리셋 항목 추가
+unsafe fn add_preset_items<const N: usize>(
+    submenu: id,
+    labels: &[&str; N],
+    selectors: [Sel; N],
+    items_out: &Mutex<[SendId; N]>,
+    delegate: id,
+) {
     let mut items_guard = items_out.lock().unwrap_or_else(|e| e.into_inner());
     for (i, (&label, &sel)) in labels.iter().zip(selectors.iter()).enumerate() {
         let item = NSMenuItem::alloc(nil).initWithTitle_action_keyEquivalent_(
@@ -296,12 +651,34 @@ unsafe fn build_submenu(
             NSString::alloc(nil).init_str(""),
         );
         let _: () = msg_send![item, setTarget: delegate];
-        if presets[i] == current {
-            let _: () = msg_send![item, setState: 1i64];
-        }
         items_guard[i] = SendId(item);
         submenu.addItem_(item);
     }
+}
+
+/// 변환 민감도 서브메뉴 (보수적/기본/적극적)
+unsafe fn build_sensitivity_submenu(current: AppProfile, delegate: id) -> id {
+    let title = "변환 민감도";
+    let menu_item = NSMenuItem::alloc(nil).initWithTitle_action_keyEquivalent_(
+        NSString::alloc(nil).init_str(title),
+        selector(""),
+        NSString::alloc(nil).init_str(""),
+    );
+    let submenu = NSMenu::new(nil).autorelease();
+    let _: () = msg_send![submenu, setTitle: NSString::alloc(nil).init_str(title)];
+
+    add_preset_items(
+        submenu,
+        &SENSITIVITY_LABELS,
+        [
+            sel!(setSensitivityConservative:),
+            sel!(setSensitivityNormal:),
+            sel!(setSensitivityAggressive:),
+        ],
+        &SENSITIVITY_MENU_ITEMS,
+        delegate,
+    );
+    update_sensitivity_checkmarks(current);
 
     let _: () = msg_send![menu_item, setSubmenu: submenu];
     menu_item
@@ -315,6 +692,7 @@ impl MenuBarApp {
         let cur_debounce = event_state.get_debounce_ms();
         let cur_switch = event_state.get_switch_delay_ms();
         let cur_slow_debounce = event_state.get_slow_debounce_ms();
+        let cur_sensitivity = event_state.get_sensitivity();
 
         unsafe {
             let _pool = NSAutoreleasePool::new(nil);
@@ -366,7 +744,8 @@ impl MenuBarApp {
                 true
             })();
             if !icon_loaded {
-                let title = NSString::alloc(nil).init_str("코");
+                ICON_LOADED.store(false, Ordering::Release);
+                let title = NSString::alloc(nil).init_str(status_title(false, false));
                 let _: () = msg_send![status_item, setTitle: title];
             }
 
@@ -377,11 +756,15 @@ impl MenuBarApp {
             }
 
             // 비활성화 상태면 아이콘 흐리게 표시
-            if !cur_enabled {
-                let button: id = msg_send![status_item, button];
-                if !button.is_null() {
+            let button: id = msg_send![status_item, button];
+            if !button.is_null() {
+                if !cur_enabled {
                     let _: () = msg_send![button, setAlphaValue: 0.3f64];
                 }
+                // VoiceOver: 아이콘만 있는 버튼에 이름/상태 부여
+                let lang = A11yLanguage::current();
+                set_accessibility_label(button, status_item_label(lang));
+                set_accessibility_value(button, status_item_value(cur_enabled, lang));
             }
 
             let menu = NSMenu::new(nil).autorelease();
@@ -407,6 +790,37 @@ impl MenuBarApp {
             let _: () = msg_send![hotkey_item, setEnabled: NO];
             menu.addItem_(hotkey_item);
 
+            // 현재 입력 버퍼의 변환 신뢰도 (비활성, menuWillOpen에서 갱신)
+            let confidence_item = NSMenuItem::alloc(nil).initWithTitle_action_keyEquivalent_(
+                NSString::alloc(nil).init_str(&confidence_title(None)),
+                selector(""),
+                NSString::alloc(nil).init_str(""),
+            );
+            let _: () = msg_send![confidence_item, setEnabled: NO];
+            {
+                let mut ci = CONFIDENCE_MENU_ITEM
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner());
+                *ci = SendId(confidence_item);
+            }
+            menu.addItem_(confidence_item);
+
+            // 손쉬운 사용 권한 열기 (이벤트 탭 시작 실패 시에만 표시)
+            let permission_item = NSMenuItem::alloc(nil).initWithTitle_action_keyEquivalent_(
+                NSString::alloc(nil).init_str("⚠️ 손쉬운 사용 권한 열기..."),
+                sel!(openPermissionSettings:),
+                NSString::alloc(nil).init_str(""),
+            );
+            let _: () = msg_send![permission_item, setTarget: delegate];
+            let _: () = msg_send![permission_item, setHidden: YES];
+            {
+                let mut pi = PERMISSION_MENU_ITEM
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner());
+                *pi = SendId(permission_item);
+            }
+            menu.addItem_(permission_item);
+
             menu.addItem_(NSMenuItem::separatorItem(nil));
 
             // "Koing 활성화" 토글 메뉴 아이템
@@ -425,6 +839,50 @@ impl MenuBarApp {
             }
             menu.addItem_(toggle_item);
 
+            // 마지막 변환 결과 복사 (이력이 없으면 비활성, menuWillOpen에서 갱신)
+            let last_conversion_item = NSMenuItem::alloc(nil).initWithTitle_action_keyEquivalent_(
+                NSString::alloc(nil).init_str("마지막 변환 복사"),
+                sel!(copyLastConversion:),
+                NSString::alloc(nil).init_str(""),
+            );
+            let _: () = msg_send![last_conversion_item, setTarget: delegate];
+            let _: () = msg_send![last_conversion_item, setEnabled: NO];
+            {
+                let mut li = LAST_CONVERSION_MENU_ITEM
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner());
+                *li = SendId(last_conversion_item);
+            }
+            menu.addItem_(last_conversion_item);
+
+            menu.addItem_(NSMenuItem::separatorItem(nil));
+
+            // 입력 소스 즉시 전환 (현재 입력 소스에 체크)
+            let source_checks = input_source_checks(is_english_input_source());
+            let mut source_items = [SendId::NULL; 2];
+            for (i, action) in [sel!(switchToKorean:), sel!(switchToEnglish:)]
+                .into_iter()
+                .enumerate()
+            {
+                let item = NSMenuItem::alloc(nil).initWithTitle_action_keyEquivalent_(
+                    NSString::alloc(nil).init_str(INPUT_SOURCE_LABELS[i]),
+                    action,
+                    NSString::alloc(nil).init_str(""),
+                );
+                let _: () = msg_send![item, setTarget: delegate];
+                if source_checks[i] {
+                    let _: () = msg_send![item, setState: 1i64];
+                }
+                source_items[i] = SendId(item);
+                menu.addItem_(item);
+            }
+            {
+                let mut items = INPUT_SOURCE_MENU_ITEMS
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner());
+                *items = source_items;
+            }
+
             menu.addItem_(NSMenuItem::separatorItem(nil));
 
             // 변환 속도 서브메뉴
@@ -440,6 +898,7 @@ impl MenuBarApp {
                 &DEBOUNCE_PRESETS,
                 cur_debounce,
                 &DEBOUNCE_MENU_ITEMS,
+                &DEBOUNCE_CUSTOM_ITEM,
                 delegate,
             );
             menu.addItem_(debounce_item);
@@ -457,6 +916,7 @@ impl MenuBarApp {
                 &SWITCH_PRESETS,
                 cur_switch,
                 &SWITCH_MENU_ITEMS,
+                &SWITCH_CUSTOM_ITEM,
                 delegate,
             );
             menu.addItem_(switch_item);
@@ -474,10 +934,15 @@ impl MenuBarApp {
                 &SLOW_DEBOUNCE_PRESETS,
                 cur_slow_debounce,
                 &SLOW_DEBOUNCE_MENU_ITEMS,
+                &SLOW_DEBOUNCE_CUSTOM_ITEM,
                 delegate,
             );
             menu.addItem_(slow_debounce_item);
 
+            // 변환 민감도 서브메뉴
+            let sensitivity_item = build_sensitivity_submenu(cur_sensitivity, delegate);
+            menu.addItem_(sensitivity_item);
+
             menu.addItem_(NSMenuItem::separatorItem(nil));
 
             // 설정...
@@ -489,6 +954,15 @@ impl MenuBarApp {
             let _: () = msg_send![settings_item, setTarget: delegate];
             menu.addItem_(settings_item);
 
+            // 변환 테스트 실행
+            let self_test_item = NSMenuItem::alloc(nil).initWithTitle_action_keyEquivalent_(
+                NSString::alloc(nil).init_str("변환 테스트 실행"),
+                sel!(runSelfTest:),
+                NSString::alloc(nil).init_str(""),
+            );
+            let _: () = msg_send![self_test_item, setTarget: delegate];
+            menu.addItem_(self_test_item);
+
             // 종료
             let quit_item = NSMenuItem::alloc(nil).initWithTitle_action_keyEquivalent_(
                 NSString::alloc(nil).init_str("종료"),
@@ -498,6 +972,10 @@ impl MenuBarApp {
             let _: () = msg_send![quit_item, setTarget: delegate];
             menu.addItem_(quit_item);
 
+            // 메뉴를 열 때마다 현재 값과 체크마크 동기화
+            // (자동 활성화를 끄고 setEnabled로 직접 관리 — 마지막 변환 복사 항목)
+            let _: () = msg_send![menu, setAutoenablesItems: NO];
+            let _: () = msg_send![menu, setDelegate: delegate];
             status_item.setMenu_(menu);
 
             Self {
@@ -524,3 +1002,79 @@ impl Drop for MenuBarApp {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checkmark_states_for_preset_value() {
+        let (checks, is_custom) = checkmark_states(&DEBOUNCE_PRESETS, 300);
+        assert_eq!(checks, [false, true, false, false]);
+        assert!(!is_custom);
+    }
+
+    #[test]
+    fn test_checkmark_moves_between_custom_and_preset() {
+        // 프리셋 → 사용자 지정: 프리셋 체크 해제, 사용자 지정 체크
+        let (checks, is_custom) = checkmark_states(&DEBOUNCE_PRESETS, 450);
+        assert_eq!(checks, [false; 4]);
+        assert!(is_custom);
+
+        // 사용자 지정 → 프리셋: 사용자 지정 해제, 해당 프리셋만 체크
+        let (checks, is_custom) = checkmark_states(&DEBOUNCE_PRESETS, 800);
+        assert_eq!(checks, [false, false, false, true]);
+        assert!(!is_custom);
+
+        let (checks, is_custom) = checkmark_states(&SWITCH_PRESETS, 0);
+        assert_eq!(checks, [true, false, false, false]);
+        assert!(!is_custom);
+    }
+
+    #[test]
+    fn test_sensitivity_checkmark_states() {
+        let (checks, is_custom) = checkmark_states(&SENSITIVITY_PRESETS, AppProfile::Aggressive);
+        assert_eq!(checks, [false, false, true]);
+        assert!(!is_custom);
+    }
+
+    #[test]
+    fn test_input_source_checks() {
+        assert_eq!(input_source_checks(false), [true, false]);
+        assert_eq!(input_source_checks(true), [false, true]);
+        assert_eq!(INPUT_SOURCE_LABELS, ["한글로 전환", "영문으로 전환"]);
+    }
+
+    #[test]
+    fn test_status_title() {
+        assert_eq!(status_title(true, false), "");
+        assert_eq!(status_title(true, true), "⚠︎");
+        // 아이콘 로드 실패 시 텍스트 폴백에 경고 추가
+        assert_eq!(status_title(false, false), "코");
+        assert_eq!(status_title(false, true), "코⚠︎");
+    }
+
+    #[test]
+    fn test_confidence_title() {
+        assert_eq!(confidence_title(None), "현재 신뢰도: 대기 중");
+        assert_eq!(confidence_title(Some(72.4)), "현재 신뢰도: 72/100");
+        assert_eq!(confidence_title(Some(0.0)), "현재 신뢰도: 0/100");
+        // 범위 밖 값은 0-100으로 보정
+        assert_eq!(confidence_title(Some(130.0)), "현재 신뢰도: 100/100");
+    }
+
+    #[test]
+    fn test_last_conversion_copy_text() {
+        assert_eq!(last_conversion_copy_text(Some("안녕"), false), Some("안녕"));
+        // 이력 없음 / 빈 결과 / 교체 중이면 복사하지 않음
+        assert_eq!(last_conversion_copy_text(None, false), None);
+        assert_eq!(last_conversion_copy_text(Some(""), false), None);
+        assert_eq!(last_conversion_copy_text(Some("안녕"), true), None);
+    }
+
+    #[test]
+    fn test_custom_item_title() {
+        assert_eq!(custom_item_title(450), "사용자 지정 (450ms)");
+        assert_eq!(custom_item_title(1200), "사용자 지정 (1200ms)");
+    }
+}
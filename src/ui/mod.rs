@@ -1,4 +1,11 @@
+use crate::config::AppProfile;
+
+pub mod accessibility;
+pub mod debug_overlay;
+pub mod diagnostics;
 pub mod menubar;
+pub mod onboarding;
+pub mod self_test;
 pub mod settings;
 
 // --- 공유 프리셋 상수 (menubar.rs, settings.rs에서 사용) ---
@@ -20,3 +27,11 @@ pub const SWITCH_LABELS: [&str; 4] = ["즉시 (0ms)", "빠름 (10ms)", "보통 (
 pub const SLOW_DEBOUNCE_PRESETS: [u64; 4] = [1000, 1500, 2000, 3000];
 pub const SLOW_DEBOUNCE_LABELS: [&str; 4] =
     ["빠름 (1초)", "보통 (1.5초)", "느림 (2초)", "여유 (3초)"];
+
+/// 변환 민감도 프리셋 (프로필별 threshold/min_length는 KoingConfig.profile_params)
+pub const SENSITIVITY_PRESETS: [AppProfile; 3] = [
+    AppProfile::Conservative,
+    AppProfile::Normal,
+    AppProfile::Aggressive,
+];
+pub const SENSITIVITY_LABELS: [&str; 3] = ["보수적", "기본", "적극적"];
@@ -0,0 +1,338 @@
+//! 첫 실행 온보딩 윈도우 (권한 허용 안내 → 단축키/기본 동작 안내)
+#![allow(deprecated)] // cocoa 크레이트 deprecated API 사용
+
+use crate::config::{save_config, KoingConfig};
+use crate::platform::open_accessibility_settings;
+use crate::platform::permissions::{check_accessibility_permission, permission_status_string};
+use crate::ui::menubar::current_config;
+use crate::ui::settings::{create_button, create_label};
+use cocoa::appkit::{NSApp, NSWindow, NSWindowStyleMask};
+use cocoa::base::{id, nil, NO, YES};
+use cocoa::foundation::{NSPoint, NSRect, NSSize, NSString};
+use objc::declare::ClassDecl;
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+use std::sync::{Mutex, OnceLock};
+
+/// 권한 상태 확인 주기 (초)
+const PERMISSION_POLL_SECS: f64 = 0.5;
+
+/// 온보딩 표시 여부: 설정 파일이 없는 최초 실행이고 아직 완료하지 않았을 때만
+pub fn needs_onboarding(config_exists: bool, config: &KoingConfig) -> bool {
+    !config_exists && !config.onboarded
+}
+
+/// 온보딩 단계
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnboardingStep {
+    /// 손쉬운 사용 권한 허용 대기
+    Permission,
+    /// 단축키/기본 동작 안내
+    Shortcuts,
+}
+
+impl OnboardingStep {
+    /// 시작 단계 (이미 권한이 있으면 안내 단계부터)
+    pub fn initial(permission_granted: bool) -> Self {
+        Self::Permission.advance(permission_granted)
+    }
+
+    /// 권한 상태 반영: 허용되면 안내 단계로 (되돌아가지 않음)
+    pub fn advance(self, permission_granted: bool) -> Self {
+        match self {
+            Self::Permission if permission_granted => Self::Shortcuts,
+            step => step,
+        }
+    }
+
+    /// 본문 안내 문구
+    pub fn message(self) -> &'static str {
+        match self {
+            Self::Permission => {
+                "Koing은 키 입력을 감지해, 한글 자판을 켜지 않고 친 영문을 한글로 바꿉니다.\n\n\
+                 이를 위해 손쉬운 사용 권한이 필요합니다. \"권한 열기\"를 눌러\n\
+                 시스템 설정 > 개인 정보 보호 및 보안 > 손쉬운 사용에서 Koing을 허용해주세요.\n\n\
+                 허용되면 자동으로 다음 단계로 넘어갑니다."
+            }
+            Self::Shortcuts => {
+                "준비가 끝났습니다. 영문 자판으로 한글을 치면 타이핑을 멈출 때 자동으로 바뀝니다.\n\n\
+                 ⌥ Space  지금 입력한 단어 바로 변환 (선택한 텍스트는 일괄 변환)\n\
+                 ⌥ Z  마지막 변환 되돌리기\n\
+                 ⌥ ⇧ Space  Koing 켜기/끄기\n\n\
+                 변환 속도 등은 메뉴바의 Koing 아이콘에서 바꿀 수 있습니다."
+            }
+        }
+    }
+
+    /// 주 버튼 제목
+    pub fn button_title(self) -> &'static str {
+        match self {
+            Self::Permission => "권한 열기",
+            Self::Shortcuts => "시작하기",
+        }
+    }
+}
+
+struct SendId(id);
+unsafe impl Send for SendId {}
+unsafe impl Sync for SendId {}
+
+/// 온보딩 윈도우와 갱신 대상 컨트롤
+struct OnboardingUi {
+    window: SendId,
+    message_label: SendId,
+    status_label: SendId,
+    button: SendId,
+    timer: SendId,
+    /// NSControl.target/NSTimer target 참조 유지
+    _delegate: SendId,
+}
+
+static ONBOARDING: Mutex<Option<OnboardingUi>> = Mutex::new(None);
+static ONBOARDING_STEP: Mutex<OnboardingStep> = Mutex::new(OnboardingStep::Permission);
+static ONBOARDING_DELEGATE_CLASS: OnceLock<&'static Class> = OnceLock::new();
+
+// --- ObjC 액션 핸들러 ---
+
+extern "C" fn primary_action(_: &Object, _: Sel, _: id) {
+    let step = *ONBOARDING_STEP.lock().unwrap_or_else(|e| e.into_inner());
+    match step {
+        OnboardingStep::Permission => {
+            if let Err(e) = open_accessibility_settings() {
+                log::warn!("{}", e);
+            }
+        }
+        OnboardingStep::Shortcuts => finish_onboarding(),
+    }
+}
+
+extern "C" fn poll_permission(_: &Object, _: Sel, _: id) {
+    if stop_if_closed() {
+        return;
+    }
+    let next = {
+        let mut step = ONBOARDING_STEP.lock().unwrap_or_else(|e| e.into_inner());
+        let next = step.advance(check_accessibility_permission());
+        if next == *step {
+            None
+        } else {
+            *step = next;
+            Some(next)
+        }
+    };
+    if let Some(step) = next {
+        log::info!("온보딩: 권한 허용됨, 다음 단계로");
+        update_step(step);
+    } else {
+        update_status();
+    }
+}
+
+fn get_delegate_class() -> &'static Class {
+    ONBOARDING_DELEGATE_CLASS.get_or_init(|| {
+        let superclass = class!(NSObject);
+        match ClassDecl::new("KoingOnboardingDelegate", superclass) {
+            Some(mut decl) => {
+                type ActionFn = extern "C" fn(&Object, Sel, id);
+
+                unsafe {
+                    decl.add_method(sel!(primaryAction:), primary_action as ActionFn);
+                    decl.add_method(sel!(pollPermission:), poll_permission as ActionFn);
+                }
+
+                decl.register()
+            }
+            None => Class::get("KoingOnboardingDelegate")
+                .expect("KoingOnboardingDelegate class not found"),
+        }
+    })
+}
+
+/// 완료 전에 윈도우가 닫혔으면 타이머 정지 후 true (다음 실행에서 다시 표시)
+fn stop_if_closed() -> bool {
+    let mut ui_guard = ONBOARDING.lock().unwrap_or_else(|e| e.into_inner());
+    let Some(ref ui) = *ui_guard else {
+        return true;
+    };
+    unsafe {
+        let is_visible: bool = msg_send![ui.window.0, isVisible];
+        if is_visible {
+            return false;
+        }
+        let _: () = msg_send![ui.timer.0, invalidate];
+    }
+    *ui_guard = None;
+    true
+}
+
+/// 현재 단계의 문구/버튼 반영 (메인 스레드에서 호출)
+fn update_step(step: OnboardingStep) {
+    let ui = ONBOARDING.lock().unwrap_or_else(|e| e.into_inner());
+    let Some(ref ui) = *ui else {
+        return;
+    };
+    unsafe {
+        let _: () = msg_send![ui.message_label.0, setStringValue: NSString::alloc(nil).init_str(step.message())];
+        let _: () =
+            msg_send![ui.button.0, setTitle: NSString::alloc(nil).init_str(step.button_title())];
+        let _: () = msg_send![ui.status_label.0, setStringValue: NSString::alloc(nil).init_str(permission_status_string())];
+    }
+}
+
+/// 권한 상태 문구만 갱신
+fn update_status() {
+    let ui = ONBOARDING.lock().unwrap_or_else(|e| e.into_inner());
+    let Some(ref ui) = *ui else {
+        return;
+    };
+    unsafe {
+        let _: () = msg_send![ui.status_label.0, setStringValue: NSString::alloc(nil).init_str(permission_status_string())];
+    }
+}
+
+/// 온보딩 완료: 설정에 기록하고 윈도우 닫기
+fn finish_onboarding() {
+    let config = KoingConfig {
+        onboarded: true,
+        ..current_config()
+    };
+    if let Err(e) = save_config(&config) {
+        log::error!("설정 저장 실패: {}", e);
+    }
+
+    let ui = ONBOARDING.lock().unwrap_or_else(|e| e.into_inner()).take();
+    if let Some(ui) = ui {
+        unsafe {
+            let _: () = msg_send![ui.timer.0, invalidate];
+            let _: () = msg_send![ui.window.0, close];
+        }
+    }
+}
+
+/// 온보딩 윈도우 표시 (메인 스레드에서 호출, 이미 떠 있으면 앞으로 가져오기)
+pub fn show_onboarding_window() {
+    let mut ui_guard = ONBOARDING.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(ref ui) = *ui_guard {
+        unsafe {
+            let _: () = msg_send![ui.window.0, makeKeyAndOrderFront: nil];
+        }
+        return;
+    }
+
+    let step = OnboardingStep::initial(check_accessibility_permission());
+    *ONBOARDING_STEP.lock().unwrap_or_else(|e| e.into_inner()) = step;
+
+    unsafe {
+        let delegate_class = get_delegate_class();
+        let delegate: id = msg_send![delegate_class, new];
+
+        let rect = NSRect::new(NSPoint::new(0.0, 0.0), NSSize::new(480.0, 280.0));
+        let style = NSWindowStyleMask::NSTitledWindowMask | NSWindowStyleMask::NSClosableWindowMask;
+        let window = NSWindow::alloc(nil).initWithContentRect_styleMask_backing_defer_(
+            rect,
+            style,
+            cocoa::appkit::NSBackingStoreType::NSBackingStoreBuffered,
+            NO,
+        );
+        let _: () = msg_send![window, center];
+        let _: () = msg_send![window, setTitle: NSString::alloc(nil).init_str("Koing 시작하기")];
+        let _: () = msg_send![window, setReleasedWhenClosed: NO];
+
+        let content_view: id = msg_send![window, contentView];
+
+        let message_label = create_label(
+            step.message(),
+            NSRect::new(NSPoint::new(30.0, 90.0), NSSize::new(420.0, 170.0)),
+        );
+        let _: () = msg_send![content_view, addSubview: message_label];
+
+        let status_label = create_label(
+            permission_status_string(),
+            NSRect::new(NSPoint::new(30.0, 60.0), NSSize::new(420.0, 20.0)),
+        );
+        let _: () = msg_send![status_label, setTextColor: {
+            let color: id = msg_send![class!(NSColor), secondaryLabelColor];
+            color
+        }];
+        let font: id = msg_send![class!(NSFont), systemFontOfSize: 11.0f64];
+        let _: () = msg_send![status_label, setFont: font];
+        let _: () = msg_send![content_view, addSubview: status_label];
+
+        let button = create_button(
+            step.button_title(),
+            NSRect::new(NSPoint::new(330.0, 16.0), NSSize::new(124.0, 30.0)),
+            delegate,
+            sel!(primaryAction:),
+        );
+        let _: () = msg_send![content_view, addSubview: button];
+
+        // 권한 상태를 주기적으로 확인해 허용되면 다음 단계로
+        let timer: id = msg_send![class!(NSTimer),
+            scheduledTimerWithTimeInterval: PERMISSION_POLL_SECS
+            target: delegate
+            selector: sel!(pollPermission:)
+            userInfo: nil
+            repeats: YES];
+
+        let _: () = msg_send![window, makeKeyAndOrderFront: nil];
+        let app: id = NSApp();
+        let _: () = msg_send![app, activateIgnoringOtherApps: YES];
+
+        *ui_guard = Some(OnboardingUi {
+            window: SendId(window),
+            message_label: SendId(message_label),
+            status_label: SendId(status_label),
+            button: SendId(button),
+            timer: SendId(timer),
+            _delegate: SendId(delegate),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_needs_onboarding_only_on_first_run() {
+        let config = KoingConfig::default();
+        assert!(needs_onboarding(false, &config));
+        // 기존 사용자 (설정 파일 있음, onboarded 필드 없음)
+        assert!(!needs_onboarding(true, &config));
+
+        let done = KoingConfig {
+            onboarded: true,
+            ..KoingConfig::default()
+        };
+        assert!(!needs_onboarding(false, &done));
+        assert!(!needs_onboarding(true, &done));
+    }
+
+    #[test]
+    fn test_step_advances_when_permission_granted() {
+        assert_eq!(OnboardingStep::initial(false), OnboardingStep::Permission);
+        assert_eq!(OnboardingStep::initial(true), OnboardingStep::Shortcuts);
+
+        let step = OnboardingStep::Permission;
+        assert_eq!(step.advance(false), OnboardingStep::Permission);
+        assert_eq!(step.advance(true), OnboardingStep::Shortcuts);
+        // 안내 단계에서 권한이 철회되어도 되돌아가지 않음
+        assert_eq!(
+            OnboardingStep::Shortcuts.advance(false),
+            OnboardingStep::Shortcuts
+        );
+    }
+
+    #[test]
+    fn test_shortcuts_step_lists_hotkeys() {
+        let message = OnboardingStep::Shortcuts.message();
+        for hotkey in ["⌥ Space", "⌥ Z", "⌥ ⇧ Space"] {
+            assert!(message.contains(hotkey), "{}", hotkey);
+        }
+        assert!(OnboardingStep::Permission.message().contains("손쉬운 사용"));
+        assert_ne!(
+            OnboardingStep::Permission.button_title(),
+            OnboardingStep::Shortcuts.button_title()
+        );
+    }
+}
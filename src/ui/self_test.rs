@@ -0,0 +1,404 @@
+//! 변환 self-test (메뉴바 "변환 테스트 실행")
+//!
+//! Koing 자체 테스트 창에 "dkssud"를 실제 키 입력처럼 보내 자동 변환 → 한글 자판 전환 → Undo가
+//! 사용자 환경에서 동작하는지 확인합니다. 다른 앱에는 입력하지 않습니다.
+//! 단계 진행/결과 집계는 [`SelfTestDriver`]로 분리되어 있어 UI 없이 테스트할 수 있습니다.
+#![allow(deprecated)] // cocoa 크레이트 deprecated API 사용
+
+use crate::platform::dispatch_to_main_sync;
+use crate::platform::input_source::{
+    get_current_input_source_id, is_korean_input_source_id, switch_to_english,
+};
+use crate::platform::text_replacer::{simulate_user_typing, simulate_user_undo};
+use crate::ui::menubar::EVENT_STATE;
+use cocoa::appkit::{NSApp, NSWindow, NSWindowStyleMask};
+use cocoa::base::{id, nil, NO, YES};
+use cocoa::foundation::{NSPoint, NSRect, NSSize, NSString};
+use objc::{class, msg_send, sel, sel_impl};
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// 테스트 입력 (영문 자판 키)
+pub const SELF_TEST_KEYS: &str = "dkssud";
+/// 기대 변환 결과
+pub const SELF_TEST_EXPECTED: &str = "안녕";
+
+/// 변환 대기 시간 (느린 debounce 설정까지 고려)
+const CONVERT_TIMEOUT: Duration = Duration::from_secs(4);
+/// 자판 전환 대기 시간
+const SWITCH_TIMEOUT: Duration = Duration::from_secs(1);
+/// Undo 대기 시간
+const UNDO_TIMEOUT: Duration = Duration::from_secs(2);
+/// 텍스트/입력 소스 확인 간격
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+/// 창이 포커스를 받을 때까지 입력 전 대기
+const FOCUS_SETTLE_DELAY: Duration = Duration::from_millis(300);
+
+/// self-test 중복 실행 방지
+static SELF_TEST_RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// self-test 단계
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelfTestStep {
+    /// 영문 입력이 한글로 자동 변환되는지
+    Convert,
+    /// 변환 후 한글 자판으로 전환되는지
+    Switch,
+    /// Option+Z로 원래 영문이 복원되는지
+    Undo,
+}
+
+impl SelfTestStep {
+    pub fn label(self) -> &'static str {
+        match self {
+            SelfTestStep::Convert => "변환",
+            SelfTestStep::Switch => "자판 전환",
+            SelfTestStep::Undo => "Undo",
+        }
+    }
+}
+
+/// 단계별 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StepOutcome {
+    Passed,
+    /// 실패 (사유)
+    Failed(String),
+    /// 앞 단계 실패로 실행하지 않음
+    Skipped,
+}
+
+/// self-test 결과 (단계 순서대로)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelfTestReport {
+    pub steps: Vec<(SelfTestStep, StepOutcome)>,
+}
+
+impl SelfTestReport {
+    /// 모든 단계 성공 여부
+    pub fn passed(&self) -> bool {
+        !self.steps.is_empty()
+            && self
+                .steps
+                .iter()
+                .all(|(_, outcome)| *outcome == StepOutcome::Passed)
+    }
+
+    /// 알림용 단계별 결과 문자열
+    pub fn summary(&self) -> String {
+        self.steps
+            .iter()
+            .map(|(step, outcome)| match outcome {
+                StepOutcome::Passed => format!("{}: 성공", step.label()),
+                StepOutcome::Failed(reason) => format!("{}: 실패 ({})", step.label(), reason),
+                StepOutcome::Skipped => format!("{}: 건너뜀", step.label()),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// self-test가 조작하는 환경 (실제 창/키 입력 또는 테스트용 가짜 구현)
+pub trait SelfTestDriver {
+    /// 영문 자판 키 입력
+    fn type_keys(&mut self, keys: &str) -> Result<(), String>;
+    /// 테스트 창 텍스트가 expected가 될 때까지 대기
+    fn wait_for_text(&mut self, expected: &str, timeout: Duration) -> Result<(), String>;
+    /// 한글 입력 소스가 될 때까지 대기 (제한 시간 안에 전환됐는지)
+    fn wait_for_korean_source(&mut self, timeout: Duration) -> bool;
+    /// Undo 단축키 입력
+    fn press_undo(&mut self) -> Result<(), String>;
+}
+
+/// 단계를 순서대로 실행하고 결과 집계 (변환이 실패하면 이후 단계는 건너뜀)
+pub fn run_self_test_steps(driver: &mut impl SelfTestDriver) -> SelfTestReport {
+    let convert = driver
+        .type_keys(SELF_TEST_KEYS)
+        .and_then(|_| driver.wait_for_text(SELF_TEST_EXPECTED, CONVERT_TIMEOUT));
+    if let Err(reason) = convert {
+        return SelfTestReport {
+            steps: vec![
+                (SelfTestStep::Convert, StepOutcome::Failed(reason)),
+                (SelfTestStep::Switch, StepOutcome::Skipped),
+                (SelfTestStep::Undo, StepOutcome::Skipped),
+            ],
+        };
+    }
+
+    let switch = if driver.wait_for_korean_source(SWITCH_TIMEOUT) {
+        StepOutcome::Passed
+    } else {
+        StepOutcome::Failed("한글 자판으로 전환되지 않음".to_string())
+    };
+
+    let undo = driver
+        .press_undo()
+        .and_then(|_| driver.wait_for_text(SELF_TEST_KEYS, UNDO_TIMEOUT));
+
+    SelfTestReport {
+        steps: vec![
+            (SelfTestStep::Convert, StepOutcome::Passed),
+            (SelfTestStep::Switch, switch),
+            (
+                SelfTestStep::Undo,
+                undo.map_or_else(StepOutcome::Failed, |_| StepOutcome::Passed),
+            ),
+        ],
+    }
+}
+
+struct SendId(id);
+unsafe impl Send for SendId {}
+
+/// Koing 테스트 창의 텍스트 뷰에 실제 키 이벤트를 보내는 드라이버 (워커 스레드에서 사용)
+struct WindowDriver {
+    text_view: SendId,
+}
+
+impl WindowDriver {
+    fn text(&self) -> String {
+        let text_view = SendId(self.text_view.0);
+        let mut text = String::new();
+        let out = &mut text;
+        dispatch_to_main_sync(move || unsafe {
+            let text_view = text_view; // 필드가 아닌 SendId 전체를 캡처
+            let string: id = msg_send![text_view.0, string];
+            let utf8: *const c_char = msg_send![string, UTF8String];
+            if !utf8.is_null() {
+                *out = CStr::from_ptr(utf8).to_string_lossy().into_owned();
+            }
+        });
+        text
+    }
+}
+
+impl SelfTestDriver for WindowDriver {
+    fn type_keys(&mut self, keys: &str) -> Result<(), String> {
+        simulate_user_typing(keys)
+    }
+
+    fn wait_for_text(&mut self, expected: &str, timeout: Duration) -> Result<(), String> {
+        let started = Instant::now();
+        loop {
+            let text = self.text();
+            if text.trim() == expected {
+                return Ok(());
+            }
+            if started.elapsed() >= timeout {
+                return Err(format!("\"{}\" 대신 \"{}\"", expected, text.trim()));
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    fn wait_for_korean_source(&mut self, timeout: Duration) -> bool {
+        let started = Instant::now();
+        loop {
+            let mut source = None;
+            dispatch_to_main_sync(|| source = get_current_input_source_id());
+            if source.is_some_and(|id| is_korean_input_source_id(&id)) {
+                return true;
+            }
+            if started.elapsed() >= timeout {
+                return false;
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    fn press_undo(&mut self) -> Result<(), String> {
+        simulate_user_undo()
+    }
+}
+
+/// self-test 실행 (메인 스레드에서 호출, 결과는 알림창으로 표시)
+pub fn run_self_test() {
+    if !EVENT_STATE.get().is_some_and(|state| state.is_enabled()) {
+        show_result_alert("Koing이 꺼져 있어 변환 테스트를 실행할 수 없습니다.", "");
+        return;
+    }
+    if SELF_TEST_RUNNING.swap(true, Ordering::AcqRel) {
+        return;
+    }
+
+    // 영문 자판에서 시작해야 키 입력이 영문으로 들어감
+    if let Err(e) = switch_to_english() {
+        log::warn!("self-test 영문 전환 실패: {}", e);
+    }
+
+    let (window, text_view) = unsafe { create_test_window() };
+    let window = SendId(window);
+    let text_view = SendId(text_view);
+
+    thread::spawn(move || {
+        thread::sleep(FOCUS_SETTLE_DELAY);
+        let mut driver = WindowDriver { text_view };
+        let report = run_self_test_steps(&mut driver);
+        log::info!("self-test 결과: {}", report.summary().replace('\n', ", "));
+
+        dispatch_to_main_sync(move || {
+            let window = window; // 필드가 아닌 SendId 전체를 캡처
+            unsafe {
+                let _: () = msg_send![window.0, close];
+            }
+            let title = if report.passed() {
+                "변환 테스트 성공"
+            } else {
+                "변환 테스트 실패"
+            };
+            show_result_alert(title, &report.summary());
+        });
+        SELF_TEST_RUNNING.store(false, Ordering::Release);
+    });
+}
+
+/// 테스트 입력용 창 생성 후 텍스트 뷰에 포커스
+unsafe fn create_test_window() -> (id, id) {
+    let rect = NSRect::new(NSPoint::new(0.0, 0.0), NSSize::new(360.0, 120.0));
+    let window = NSWindow::alloc(nil).initWithContentRect_styleMask_backing_defer_(
+        rect,
+        NSWindowStyleMask::NSTitledWindowMask,
+        cocoa::appkit::NSBackingStoreType::NSBackingStoreBuffered,
+        NO,
+    );
+    let _: () = msg_send![window, center];
+    let _: () = msg_send![window, setTitle: NSString::alloc(nil).init_str("Koing 변환 테스트")];
+    let _: () = msg_send![window, setReleasedWhenClosed: NO];
+
+    let text_view: id = msg_send![class!(NSTextView), alloc];
+    let text_view: id = msg_send![text_view, initWithFrame: rect];
+    let _: () = msg_send![text_view, setRichText: NO];
+    let _: () = msg_send![text_view, setAutomaticQuoteSubstitutionEnabled: NO];
+    let _: () = msg_send![text_view, setAutomaticSpellingCorrectionEnabled: NO];
+    let _: () = msg_send![window, setContentView: text_view];
+
+    let _: () = msg_send![window, makeKeyAndOrderFront: nil];
+    let _: bool = msg_send![window, makeFirstResponder: text_view];
+    let app: id = NSApp();
+    let _: () = msg_send![app, activateIgnoringOtherApps: YES];
+
+    (window, text_view)
+}
+
+fn show_result_alert(title: &str, detail: &str) {
+    unsafe {
+        let alert: id = msg_send![class!(NSAlert), new];
+        let _: () = msg_send![alert, setMessageText: NSString::alloc(nil).init_str(title)];
+        let _: () = msg_send![alert, setInformativeText: NSString::alloc(nil).init_str(detail)];
+        let _: id = msg_send![alert, addButtonWithTitle: NSString::alloc(nil).init_str("확인")];
+        let _: cocoa::foundation::NSInteger = msg_send![alert, runModal];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 입력을 흉내 내는 가짜 드라이버 (각 단계 동작을 지정)
+    struct FakeDriver {
+        text: String,
+        converts: bool,
+        switches: bool,
+        undoes: bool,
+        undo_pressed: bool,
+    }
+
+    impl FakeDriver {
+        fn new(converts: bool, switches: bool, undoes: bool) -> Self {
+            Self {
+                text: String::new(),
+                converts,
+                switches,
+                undoes,
+                undo_pressed: false,
+            }
+        }
+    }
+
+    impl SelfTestDriver for FakeDriver {
+        fn type_keys(&mut self, keys: &str) -> Result<(), String> {
+            self.text = if self.converts {
+                SELF_TEST_EXPECTED.to_string()
+            } else {
+                keys.to_string()
+            };
+            Ok(())
+        }
+
+        fn wait_for_text(&mut self, expected: &str, _timeout: Duration) -> Result<(), String> {
+            if self.text == expected {
+                Ok(())
+            } else {
+                Err(format!("\"{}\" 대신 \"{}\"", expected, self.text))
+            }
+        }
+
+        fn wait_for_korean_source(&mut self, _timeout: Duration) -> bool {
+            self.switches
+        }
+
+        fn press_undo(&mut self) -> Result<(), String> {
+            self.undo_pressed = true;
+            if self.undoes {
+                self.text = SELF_TEST_KEYS.to_string();
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_all_steps_pass() {
+        let report = run_self_test_steps(&mut FakeDriver::new(true, true, true));
+        assert!(report.passed());
+        assert_eq!(report.summary(), "변환: 성공\n자판 전환: 성공\nUndo: 성공");
+    }
+
+    #[test]
+    fn test_convert_failure_skips_remaining_steps() {
+        let mut driver = FakeDriver::new(false, true, true);
+        let report = run_self_test_steps(&mut driver);
+        assert!(!report.passed());
+        assert!(!driver.undo_pressed);
+        assert_eq!(
+            report.summary(),
+            "변환: 실패 (\"안녕\" 대신 \"dkssud\")\n자판 전환: 건너뜀\nUndo: 건너뜀"
+        );
+    }
+
+    #[test]
+    fn test_switch_and_undo_failures_are_collected_independently() {
+        // 전환 실패여도 Undo는 실행
+        let mut driver = FakeDriver::new(true, false, true);
+        let report = run_self_test_steps(&mut driver);
+        assert!(driver.undo_pressed);
+        assert_eq!(
+            report.steps,
+            vec![
+                (SelfTestStep::Convert, StepOutcome::Passed),
+                (
+                    SelfTestStep::Switch,
+                    StepOutcome::Failed("한글 자판으로 전환되지 않음".to_string())
+                ),
+                (SelfTestStep::Undo, StepOutcome::Passed),
+            ]
+        );
+
+        let report = run_self_test_steps(&mut FakeDriver::new(true, true, false));
+        assert!(!report.passed());
+        assert_eq!(
+            report.steps[2],
+            (
+                SelfTestStep::Undo,
+                StepOutcome::Failed("\"dkssud\" 대신 \"안녕\"".to_string())
+            )
+        );
+    }
+
+    #[test]
+    fn test_empty_report_is_not_passed() {
+        assert!(!SelfTestReport { steps: Vec::new() }.passed());
+    }
+}
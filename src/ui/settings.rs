@@ -2,6 +2,15 @@
 #![allow(deprecated)] // cocoa 크레이트 deprecated API 사용
 
 use crate::config::save_config;
+use crate::core::expansion::{format_expansion_rules, parse_expansion_rules};
+use crate::platform::accessibility::set_accessibility_label;
+use crate::platform::input_source::{
+    list_input_sources, preferred_korean_source, set_preferred_korean_source, InputSourceInfo,
+};
+use crate::platform::text_replacer::set_clipboard_string;
+use crate::snapshot::SNAPSHOT_FILE_NAME;
+use crate::ui::accessibility::{settings_control_label, A11yLanguage, SettingsControl};
+use crate::ui::diagnostics::Diagnostics;
 use crate::ui::menubar::{current_config, update_toggle_state};
 use cocoa::appkit::{NSApp, NSWindow, NSWindowStyleMask};
 use cocoa::base::{id, nil, NO, YES};
@@ -9,6 +18,9 @@ use cocoa::foundation::{NSPoint, NSRect, NSSize, NSString};
 use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel};
 use objc::{class, msg_send, sel, sel_impl};
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::path::Path;
 use std::sync::{Mutex, OnceLock};
 
 use super::menubar::EVENT_STATE;
@@ -26,6 +38,17 @@ static SETTINGS_WINDOW: Mutex<Option<SendId>> = Mutex::new(None);
 /// delegate 참조를 유지하여 해제 방지 (NSControl.target은 unretained)
 static SETTINGS_DELEGATE: Mutex<Option<SendId>> = Mutex::new(None);
 static SETTINGS_DELEGATE_CLASS: OnceLock<&'static Class> = OnceLock::new();
+/// 진단 정보 라벨 (새로고침 시 갱신)
+static DIAGNOSTICS_LABEL: Mutex<Option<SendId>> = Mutex::new(None);
+/// 한글 입력 소스 팝업 항목별 입력 소스 ID (None은 자동)
+static KOREAN_SOURCE_CHOICES: Mutex<Vec<Option<String>>> = Mutex::new(Vec::new());
+
+/// 통계 내보내기 기본 파일 이름
+const STATS_EXPORT_FILE_NAME: &str = "koing-stats.json";
+/// NSModalResponseOK
+const NS_MODAL_RESPONSE_OK: cocoa::foundation::NSInteger = 1;
+/// NSAlertFirstButtonReturn
+const NS_ALERT_FIRST_BUTTON_RETURN: cocoa::foundation::NSInteger = 1000;
 
 // --- ObjC 액션 핸들러 ---
 
@@ -103,6 +126,167 @@ extern "C" fn slow_debounce_changed(_: &Object, _: Sel, sender: id) {
     }
 }
 
+extern "C" fn korean_source_changed(_: &Object, _: Sel, sender: id) {
+    if EVENT_STATE.get().is_none() {
+        return;
+    }
+    unsafe {
+        let index: cocoa::foundation::NSInteger = msg_send![sender, indexOfSelectedItem];
+        let source_id = {
+            let choices = KOREAN_SOURCE_CHOICES
+                .lock()
+                .unwrap_or_else(|e| e.into_inner());
+            match choices.get(index as usize) {
+                Some(source_id) => source_id.clone(),
+                None => return,
+            }
+        };
+        set_preferred_korean_source(source_id.as_deref());
+
+        let config = current_config();
+        if let Err(e) = save_config(&config) {
+            log::error!("설정 저장 실패: {}", e);
+        }
+    }
+}
+
+extern "C" fn export_stats_action(_: &Object, _: Sel, _: id) {
+    let Some(state) = EVENT_STATE.get() else {
+        return;
+    };
+    let Some(path) = run_save_panel(STATS_EXPORT_FILE_NAME) else {
+        return;
+    };
+    if let Err(e) = state.export_stats(Path::new(&path)) {
+        log::error!("통계 내보내기 실패: {}", e);
+    }
+}
+
+/// 저장 위치 선택 패널 (취소하면 None)
+fn run_save_panel(file_name: &str) -> Option<String> {
+    unsafe {
+        let panel: id = msg_send![class!(NSSavePanel), savePanel];
+        let _: () =
+            msg_send![panel, setNameFieldStringValue: NSString::alloc(nil).init_str(file_name)];
+        let _: () = msg_send![panel, setCanCreateDirectories: YES];
+        let response: cocoa::foundation::NSInteger = msg_send![panel, runModal];
+        if response != NS_MODAL_RESPONSE_OK {
+            return None;
+        }
+
+        let url: id = msg_send![panel, URL];
+        let path: id = msg_send![url, path];
+        if path == nil {
+            return None;
+        }
+        let utf8: *const c_char = msg_send![path, UTF8String];
+        if utf8.is_null() {
+            return None;
+        }
+        Some(CStr::from_ptr(utf8).to_string_lossy().into_owned())
+    }
+}
+
+extern "C" fn edit_expansions_action(_: &Object, _: Sel, _: id) {
+    let Some(state) = EVENT_STATE.get() else {
+        return;
+    };
+    unsafe {
+        let mut config = current_config();
+
+        // "트리거 = 치환" 한 줄에 하나씩 편집하는 텍스트 뷰를 담은 alert
+        let scroll_view: id = msg_send![class!(NSScrollView), alloc];
+        let scroll_view: id = msg_send![scroll_view, initWithFrame: NSRect::new(
+            NSPoint::new(0.0, 0.0),
+            NSSize::new(320.0, 160.0),
+        )];
+        let _: () = msg_send![scroll_view, setHasVerticalScroller: YES];
+        let _: () = msg_send![scroll_view, setBorderType: 2i64]; // NSBezelBorder
+        let text_view: id = msg_send![class!(NSTextView), alloc];
+        let text_view: id = msg_send![text_view, initWithFrame: NSRect::new(
+            NSPoint::new(0.0, 0.0),
+            NSSize::new(320.0, 160.0),
+        )];
+        let _: () = msg_send![text_view, setRichText: NO];
+        let _: () = msg_send![text_view, setAutomaticQuoteSubstitutionEnabled: NO];
+        let text = format_expansion_rules(&config.expansions);
+        let _: () = msg_send![text_view, setString: NSString::alloc(nil).init_str(&text)];
+        let _: () = msg_send![scroll_view, setDocumentView: text_view];
+
+        let alert: id = msg_send![class!(NSAlert), new];
+        let _: () = msg_send![alert, setMessageText: NSString::alloc(nil).init_str("치환 규칙")];
+        let _: () = msg_send![alert, setInformativeText: NSString::alloc(nil).init_str(
+            "한 줄에 \"트리거 = 치환할 문구\" 형식으로 입력하세요.\n예: brb = 금방 올게, ㅇㅇ = 응응"
+        )];
+        let _: id = msg_send![alert, addButtonWithTitle: NSString::alloc(nil).init_str("저장")];
+        let _: id = msg_send![alert, addButtonWithTitle: NSString::alloc(nil).init_str("취소")];
+        let _: () = msg_send![alert, setAccessoryView: scroll_view];
+        let response: cocoa::foundation::NSInteger = msg_send![alert, runModal];
+        if response != NS_ALERT_FIRST_BUTTON_RETURN {
+            return;
+        }
+
+        let edited: id = msg_send![text_view, string];
+        let utf8: *const c_char = msg_send![edited, UTF8String];
+        if utf8.is_null() {
+            return;
+        }
+        config.expansions = parse_expansion_rules(&CStr::from_ptr(utf8).to_string_lossy());
+        state.set_expansions(&config.expansions);
+        if let Err(e) = save_config(&config) {
+            log::error!("설정 저장 실패: {}", e);
+        }
+    }
+}
+
+extern "C" fn refresh_diagnostics_action(_: &Object, _: Sel, _: id) {
+    refresh_diagnostics();
+}
+
+extern "C" fn copy_diagnostics_action(_: &Object, _: Sel, _: id) {
+    let report = Diagnostics::collect(EVENT_STATE.get().map(|s| s.as_ref())).to_report();
+    set_clipboard_string(&report);
+    refresh_diagnostics();
+}
+
+extern "C" fn copy_snapshot_action(_: &Object, _: Sel, _: id) {
+    let Some(state) = EVENT_STATE.get() else {
+        return;
+    };
+    match state.config_snapshot().to_json() {
+        Ok(json) => set_clipboard_string(&json),
+        Err(e) => log::error!("{}", e),
+    }
+}
+
+extern "C" fn save_snapshot_action(_: &Object, _: Sel, _: id) {
+    let Some(state) = EVENT_STATE.get() else {
+        return;
+    };
+    // 패널을 여는 동안 설정이 바뀌지 않도록 먼저 스냅샷
+    let snapshot = state.config_snapshot();
+    let Some(path) = run_save_panel(SNAPSHOT_FILE_NAME) else {
+        return;
+    };
+    if let Err(e) = snapshot.save(Path::new(&path)) {
+        log::error!("설정 스냅샷 저장 실패: {}", e);
+    }
+}
+
+/// 진단 라벨을 현재 상태로 갱신 (메인 스레드에서 호출)
+fn refresh_diagnostics() {
+    let label = DIAGNOSTICS_LABEL.lock().unwrap_or_else(|e| e.into_inner());
+    let Some(ref label) = *label else {
+        return;
+    };
+    let text = Diagnostics::collect(EVENT_STATE.get().map(|s| s.as_ref()))
+        .lines()
+        .join("\n");
+    unsafe {
+        let _: () = msg_send![label.0, setStringValue: NSString::alloc(nil).init_str(&text)];
+    }
+}
+
 fn get_delegate_class() -> &'static Class {
     SETTINGS_DELEGATE_CLASS.get_or_init(|| {
         let superclass = class!(NSObject);
@@ -114,10 +298,23 @@ fn get_delegate_class() -> &'static Class {
                     decl.add_method(sel!(toggleEnabled:), toggle_enabled_action as ActionFn);
                     decl.add_method(sel!(debounceChanged:), debounce_changed as ActionFn);
                     decl.add_method(sel!(switchChanged:), switch_changed as ActionFn);
+                    decl.add_method(
+                        sel!(koreanSourceChanged:),
+                        korean_source_changed as ActionFn,
+                    );
                     decl.add_method(
                         sel!(slowDebounceChanged:),
                         slow_debounce_changed as ActionFn,
                     );
+                    decl.add_method(sel!(exportStats:), export_stats_action as ActionFn);
+                    decl.add_method(sel!(editExpansions:), edit_expansions_action as ActionFn);
+                    decl.add_method(
+                        sel!(refreshDiagnostics:),
+                        refresh_diagnostics_action as ActionFn,
+                    );
+                    decl.add_method(sel!(copyDiagnostics:), copy_diagnostics_action as ActionFn);
+                    decl.add_method(sel!(copySnapshot:), copy_snapshot_action as ActionFn);
+                    decl.add_method(sel!(saveSnapshot:), save_snapshot_action as ActionFn);
                 }
 
                 decl.register()
@@ -135,7 +332,7 @@ pub fn show_settings_window() {
     // 설정 윈도우를 열 때 대기 중인 변환 타이머를 취소하여
     // 합성 이벤트(backspace+paste)가 설정 윈도우에 전송되는 것을 방지
     if let Some(state) = EVENT_STATE.get() {
-        state.cancel_pending_conversion();
+        state.reset_input_state();
     }
 
     let mut window_guard = SETTINGS_WINDOW.lock().unwrap_or_else(|e| e.into_inner());
@@ -148,6 +345,7 @@ pub fn show_settings_window() {
                 let _: () = msg_send![win.0, makeKeyAndOrderFront: nil];
                 let app: id = NSApp();
                 let _: () = msg_send![app, activateIgnoringOtherApps: YES];
+                refresh_diagnostics();
                 return;
             }
             // 닫혀있으면 이전 윈도우 해제 후 새로 생성 (현재 설정 반영)
@@ -158,6 +356,7 @@ pub fn show_settings_window() {
 
     unsafe {
         let config = current_config();
+        let lang = A11yLanguage::current();
 
         let delegate_class = get_delegate_class();
         let delegate: id = msg_send![delegate_class, new];
@@ -169,7 +368,7 @@ pub fn show_settings_window() {
         }
 
         // 윈도우 생성
-        let rect = NSRect::new(NSPoint::new(0.0, 0.0), NSSize::new(400.0, 330.0));
+        let rect = NSRect::new(NSPoint::new(0.0, 0.0), NSSize::new(400.0, 505.0));
         let style = NSWindowStyleMask::NSTitledWindowMask | NSWindowStyleMask::NSClosableWindowMask;
         let window = NSWindow::alloc(nil).initWithContentRect_styleMask_backing_defer_(
             rect,
@@ -186,16 +385,20 @@ pub fn show_settings_window() {
         // --- "Koing 활성화" 체크박스 ---
         let checkbox = create_checkbox(
             "Koing 활성화",
-            NSRect::new(NSPoint::new(30.0, 260.0), NSSize::new(200.0, 24.0)),
+            NSRect::new(NSPoint::new(30.0, 435.0), NSSize::new(200.0, 24.0)),
             config.enabled,
             delegate,
             sel!(toggleEnabled:),
         );
+        set_accessibility_label(
+            checkbox,
+            settings_control_label(SettingsControl::Enabled, lang),
+        );
         let _: () = msg_send![content_view, addSubview: checkbox];
 
         // --- 구분선 ---
         let separator = create_separator(NSRect::new(
-            NSPoint::new(20.0, 245.0),
+            NSPoint::new(20.0, 420.0),
             NSSize::new(360.0, 1.0),
         ));
         let _: () = msg_send![content_view, addSubview: separator];
@@ -203,13 +406,13 @@ pub fn show_settings_window() {
         // --- "변환 속도" 라벨 + 팝업 버튼 ---
         let debounce_label = create_label(
             "변환 속도",
-            NSRect::new(NSPoint::new(30.0, 205.0), NSSize::new(120.0, 20.0)),
+            NSRect::new(NSPoint::new(30.0, 380.0), NSSize::new(120.0, 20.0)),
         );
         let _: () = msg_send![content_view, addSubview: debounce_label];
 
         let debounce_popup = create_popup_button(
             &DEBOUNCE_LABELS,
-            NSRect::new(NSPoint::new(160.0, 202.0), NSSize::new(200.0, 26.0)),
+            NSRect::new(NSPoint::new(160.0, 377.0), NSSize::new(200.0, 26.0)),
             DEBOUNCE_PRESETS
                 .iter()
                 .position(|&v| v == config.debounce_ms)
@@ -217,18 +420,22 @@ pub fn show_settings_window() {
             delegate,
             sel!(debounceChanged:),
         );
+        set_accessibility_label(
+            debounce_popup,
+            settings_control_label(SettingsControl::Debounce, lang),
+        );
         let _: () = msg_send![content_view, addSubview: debounce_popup];
 
         // --- "느린 변환 속도" 라벨 + 팝업 버튼 ---
         let slow_debounce_label = create_label(
             "느린 변환 속도",
-            NSRect::new(NSPoint::new(30.0, 160.0), NSSize::new(120.0, 20.0)),
+            NSRect::new(NSPoint::new(30.0, 335.0), NSSize::new(120.0, 20.0)),
         );
         let _: () = msg_send![content_view, addSubview: slow_debounce_label];
 
         let slow_debounce_popup = create_popup_button(
             &SLOW_DEBOUNCE_LABELS,
-            NSRect::new(NSPoint::new(160.0, 157.0), NSSize::new(200.0, 26.0)),
+            NSRect::new(NSPoint::new(160.0, 332.0), NSSize::new(200.0, 26.0)),
             SLOW_DEBOUNCE_PRESETS
                 .iter()
                 .position(|&v| v == config.slow_debounce_ms)
@@ -236,18 +443,22 @@ pub fn show_settings_window() {
             delegate,
             sel!(slowDebounceChanged:),
         );
+        set_accessibility_label(
+            slow_debounce_popup,
+            settings_control_label(SettingsControl::SlowDebounce, lang),
+        );
         let _: () = msg_send![content_view, addSubview: slow_debounce_popup];
 
         // --- "자판 전환 지연" 라벨 + 팝업 버튼 ---
         let switch_label = create_label(
             "자판 전환 지연",
-            NSRect::new(NSPoint::new(30.0, 115.0), NSSize::new(120.0, 20.0)),
+            NSRect::new(NSPoint::new(30.0, 290.0), NSSize::new(120.0, 20.0)),
         );
         let _: () = msg_send![content_view, addSubview: switch_label];
 
         let switch_popup = create_popup_button(
             &SWITCH_LABELS,
-            NSRect::new(NSPoint::new(160.0, 112.0), NSSize::new(200.0, 26.0)),
+            NSRect::new(NSPoint::new(160.0, 287.0), NSSize::new(200.0, 26.0)),
             SWITCH_PRESETS
                 .iter()
                 .position(|&v| v == config.switch_delay_ms)
@@ -255,12 +466,71 @@ pub fn show_settings_window() {
             delegate,
             sel!(switchChanged:),
         );
+        set_accessibility_label(
+            switch_popup,
+            settings_control_label(SettingsControl::SwitchDelay, lang),
+        );
         let _: () = msg_send![content_view, addSubview: switch_popup];
 
+        // --- "한글 입력 소스" 라벨 + 팝업 버튼 (세벌식/구름 등 사용자 IME) ---
+        let korean_source_label = create_label(
+            "한글 입력 소스",
+            NSRect::new(NSPoint::new(30.0, 245.0), NSSize::new(120.0, 20.0)),
+        );
+        let _: () = msg_send![content_view, addSubview: korean_source_label];
+
+        let (choices, selected_index) =
+            korean_source_choices(&list_input_sources(), preferred_korean_source().as_deref());
+        let choice_labels: Vec<&str> = choices.iter().map(|(label, _)| label.as_str()).collect();
+        let korean_source_popup = create_popup_button(
+            &choice_labels,
+            NSRect::new(NSPoint::new(160.0, 242.0), NSSize::new(200.0, 26.0)),
+            selected_index,
+            delegate,
+            sel!(koreanSourceChanged:),
+        );
+        set_accessibility_label(
+            korean_source_popup,
+            settings_control_label(SettingsControl::KoreanSource, lang),
+        );
+        let _: () = msg_send![content_view, addSubview: korean_source_popup];
+        *KOREAN_SOURCE_CHOICES
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = choices
+            .into_iter()
+            .map(|(_, source_id)| source_id)
+            .collect();
+
+        // --- "통계 내보내기" 버튼 (집계 수치만 JSON으로 저장) ---
+        let export_button = create_button(
+            "통계 내보내기…",
+            NSRect::new(NSPoint::new(26.0, 204.0), NSSize::new(160.0, 28.0)),
+            delegate,
+            sel!(exportStats:),
+        );
+        set_accessibility_label(
+            export_button,
+            settings_control_label(SettingsControl::ExportStats, lang),
+        );
+        let _: () = msg_send![content_view, addSubview: export_button];
+
+        // --- "치환 규칙" 버튼 (트리거 → 한글 구절) ---
+        let expansions_button = create_button(
+            "치환 규칙…",
+            NSRect::new(NSPoint::new(190.0, 204.0), NSSize::new(160.0, 28.0)),
+            delegate,
+            sel!(editExpansions:),
+        );
+        set_accessibility_label(
+            expansions_button,
+            settings_control_label(SettingsControl::EditExpansions, lang),
+        );
+        let _: () = msg_send![content_view, addSubview: expansions_button];
+
         // --- 단축키 안내 ---
         let hotkey_label = create_label(
             "단축키: ⌥ Space (변환)  ⌥ Z (되돌리기)",
-            NSRect::new(NSPoint::new(30.0, 50.0), NSSize::new(340.0, 20.0)),
+            NSRect::new(NSPoint::new(30.0, 180.0), NSSize::new(340.0, 20.0)),
         );
         let _: () = msg_send![hotkey_label, setTextColor: {
             let color: id = msg_send![class!(NSColor), secondaryLabelColor];
@@ -270,6 +540,79 @@ pub fn show_settings_window() {
         let _: () = msg_send![hotkey_label, setFont: font];
         let _: () = msg_send![content_view, addSubview: hotkey_label];
 
+        // --- 진단 (입력 소스/권한/macOS 버전/이벤트 탭 상태) ---
+        let separator = create_separator(NSRect::new(
+            NSPoint::new(20.0, 170.0),
+            NSSize::new(360.0, 1.0),
+        ));
+        let _: () = msg_send![content_view, addSubview: separator];
+
+        let diagnostics_title = create_label(
+            "진단",
+            NSRect::new(NSPoint::new(30.0, 140.0), NSSize::new(120.0, 20.0)),
+        );
+        let _: () = msg_send![content_view, addSubview: diagnostics_title];
+
+        let diagnostics_label = create_label(
+            "",
+            NSRect::new(NSPoint::new(30.0, 52.0), NSSize::new(340.0, 84.0)),
+        );
+        let _: () = msg_send![diagnostics_label, setFont: font];
+        let _: () = msg_send![diagnostics_label, setSelectable: YES];
+        let _: () = msg_send![content_view, addSubview: diagnostics_label];
+        *DIAGNOSTICS_LABEL.lock().unwrap_or_else(|e| e.into_inner()) =
+            Some(SendId(diagnostics_label));
+        refresh_diagnostics();
+
+        let refresh_button = create_button(
+            "새로고침",
+            NSRect::new(NSPoint::new(26.0, 14.0), NSSize::new(86.0, 28.0)),
+            delegate,
+            sel!(refreshDiagnostics:),
+        );
+        set_accessibility_label(
+            refresh_button,
+            settings_control_label(SettingsControl::RefreshDiagnostics, lang),
+        );
+        let _: () = msg_send![content_view, addSubview: refresh_button];
+
+        let copy_button = create_button(
+            "복사",
+            NSRect::new(NSPoint::new(116.0, 14.0), NSSize::new(86.0, 28.0)),
+            delegate,
+            sel!(copyDiagnostics:),
+        );
+        set_accessibility_label(
+            copy_button,
+            settings_control_label(SettingsControl::CopyDiagnostics, lang),
+        );
+        let _: () = msg_send![content_view, addSubview: copy_button];
+
+        // 설정 스냅샷 (버그 리포트 재현용)
+        let copy_snapshot_button = create_button(
+            "스냅샷 복사",
+            NSRect::new(NSPoint::new(206.0, 14.0), NSSize::new(86.0, 28.0)),
+            delegate,
+            sel!(copySnapshot:),
+        );
+        set_accessibility_label(
+            copy_snapshot_button,
+            settings_control_label(SettingsControl::CopySnapshot, lang),
+        );
+        let _: () = msg_send![content_view, addSubview: copy_snapshot_button];
+
+        let save_snapshot_button = create_button(
+            "스냅샷 저장…",
+            NSRect::new(NSPoint::new(296.0, 14.0), NSSize::new(86.0, 28.0)),
+            delegate,
+            sel!(saveSnapshot:),
+        );
+        set_accessibility_label(
+            save_snapshot_button,
+            settings_control_label(SettingsControl::SaveSnapshot, lang),
+        );
+        let _: () = msg_send![content_view, addSubview: save_snapshot_button];
+
         // 윈도우 표시
         let _: () = msg_send![window, makeKeyAndOrderFront: nil];
         let app: id = NSApp();
@@ -279,6 +622,37 @@ pub fn show_settings_window() {
     }
 }
 
+/// 한글 입력 소스 팝업 항목 (표시 이름, 입력 소스 ID — None은 자동)과 선택 위치
+fn korean_source_choices(
+    sources: &[InputSourceInfo],
+    selected: Option<&str>,
+) -> (Vec<(String, Option<String>)>, usize) {
+    let mut choices: Vec<(String, Option<String>)> = vec![("자동 (기본 두벌식)".to_string(), None)];
+    for source in sources.iter().filter(|source| source.is_korean()) {
+        // NSPopUpButton은 같은 제목의 항목을 하나로 합치므로 중복 이름은 ID로 구분
+        let label = if choices.iter().any(|(label, _)| *label == source.name) {
+            format!("{} ({})", source.name, source.id)
+        } else {
+            source.name.clone()
+        };
+        choices.push((label, Some(source.id.clone())));
+    }
+    // 저장된 소스가 지금 비활성화되어 있어도 선택값이 사라지지 않도록 표시
+    if let Some(id) = selected {
+        if !choices
+            .iter()
+            .any(|(_, choice)| choice.as_deref() == Some(id))
+        {
+            choices.push((format!("{} (사용할 수 없음)", id), Some(id.to_string())));
+        }
+    }
+    let index = choices
+        .iter()
+        .position(|(_, choice)| choice.as_deref() == selected)
+        .unwrap_or(0);
+    (choices, index)
+}
+
 // --- UI 헬퍼 함수들 ---
 
 unsafe fn create_checkbox(
@@ -299,7 +673,17 @@ unsafe fn create_checkbox(
     button
 }
 
-unsafe fn create_label(text: &str, frame: NSRect) -> id {
+pub(crate) unsafe fn create_button(title: &str, frame: NSRect, target: id, action: Sel) -> id {
+    let button: id = msg_send![class!(NSButton), alloc];
+    let button: id = msg_send![button, initWithFrame: frame];
+    let _: () = msg_send![button, setBezelStyle: 1i64]; // NSBezelStyleRounded
+    let _: () = msg_send![button, setTitle: NSString::alloc(nil).init_str(title)];
+    let _: () = msg_send![button, setTarget: target];
+    let _: () = msg_send![button, setAction: action];
+    button
+}
+
+pub(crate) unsafe fn create_label(text: &str, frame: NSRect) -> id {
     let label: id = msg_send![class!(NSTextField), alloc];
     let label: id = msg_send![label, initWithFrame: frame];
     let _: () = msg_send![label, setStringValue: NSString::alloc(nil).init_str(text)];
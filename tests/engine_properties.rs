@@ -0,0 +1,110 @@
+//! 변환 엔진 프로퍼티 테스트 (임의 입력에서 패닉/불변식 위반 검사)
+//!
+//! 엔진 전용 빌드에서도 실행됩니다:
+//! `cargo test --no-default-features --features engine-only --test engine_properties`
+
+use koing::core::converter::convert;
+use koing::core::hangul_fsm::HangulFsm;
+use koing::core::jamo_mapper::{map_to_jamo, Jamo};
+use koing::core::unicode::{is_compat_jamo, is_hangul_syllable};
+use koing::ngram::korean_to_eng;
+use proptest::prelude::*;
+
+/// 두벌식 자판에서 자모로 매핑되는 키 (FSM 입력 알파벳)
+const HANGUL_KEYS: &str = "rRseEfaqQtTdwWczxvgkoiOjpuPhynbml";
+
+fn is_hangul(c: char) -> bool {
+    is_hangul_syllable(c) || is_compat_jamo(c)
+}
+
+/// 출력에서 한글을 뺀 나머지 문자
+fn non_hangul(text: &str) -> String {
+    text.chars().filter(|&c| !is_hangul(c)).collect()
+}
+
+/// 입력에서 자모로 매핑되지 않는 문자 (변환 후에도 그대로 남아야 함)
+fn passthrough(input: &str) -> String {
+    input
+        .chars()
+        .filter(|&c| map_to_jamo(c).is_none())
+        .collect()
+}
+
+/// FSM 입력: 자모 또는 passthrough 문자
+#[derive(Debug, Clone)]
+enum FsmInput {
+    Jamo(Jamo),
+    Passthrough(char),
+}
+
+fn fsm_input() -> impl Strategy<Value = FsmInput> {
+    let jamo: Vec<Jamo> = HANGUL_KEYS.chars().filter_map(map_to_jamo).collect();
+    prop_oneof![
+        4 => prop::sample::select(jamo).prop_map(FsmInput::Jamo),
+        1 => prop::char::range(' ', '~').prop_map(FsmInput::Passthrough),
+    ]
+}
+
+/// 두벌식 키 위주의 ASCII 문자열 (한글 조합이 많이 일어나도록)
+fn key_heavy_ascii() -> impl Strategy<Value = String> {
+    prop::collection::vec(
+        prop_oneof![
+            6 => prop::sample::select(HANGUL_KEYS.chars().collect::<Vec<_>>()),
+            1 => prop::char::range(' ', '~'),
+        ],
+        0..48,
+    )
+    .prop_map(|chars| chars.into_iter().collect())
+}
+
+proptest! {
+    #[test]
+    fn convert_keeps_passthrough_chars(input in "[ -~\t\n]{0,48}") {
+        let converted = convert(&input);
+        // 한글 외 문자는 순서 그대로 보존
+        prop_assert_eq!(non_hangul(&converted), passthrough(&input));
+        // 조합은 글자 수를 늘리지 않음
+        prop_assert!(converted.chars().count() <= input.chars().count());
+    }
+
+    #[test]
+    fn convert_output_is_hangul_or_passthrough(input in key_heavy_ascii()) {
+        let converted = convert(&input);
+        for c in converted.chars() {
+            prop_assert!(is_hangul(c) || map_to_jamo(c).is_none(), "{:?} in {:?}", c, converted);
+        }
+        prop_assert_eq!(non_hangul(&converted), passthrough(&input));
+    }
+
+    #[test]
+    fn syllables_roundtrip_through_keys(text in "[가-힣 0-9.,]{0,24}") {
+        // 완성 음절은 키 시퀀스로 역변환 후 다시 조합해도 같은 음절
+        let keys = korean_to_eng(&text);
+        prop_assert_eq!(convert(&keys), text);
+    }
+
+    #[test]
+    fn roundtrip_keeps_key_count(input in key_heavy_ascii()) {
+        // 낱자모는 그대로 남으므로 한 글자 = 키 하나로 계산
+        let back = korean_to_eng(&convert(&input));
+        prop_assert_eq!(back.chars().count(), input.chars().count());
+    }
+
+    #[test]
+    fn fsm_transitions_are_panic_free(inputs in prop::collection::vec(fsm_input(), 0..64)) {
+        let mut fsm = HangulFsm::new();
+        for input in &inputs {
+            match input {
+                FsmInput::Jamo(jamo) => fsm.feed(*jamo),
+                FsmInput::Passthrough(c) => fsm.feed_passthrough(*c),
+            }
+        }
+        let output = fsm.finish();
+        let passthrough_count = inputs
+            .iter()
+            .filter(|input| matches!(input, FsmInput::Passthrough(_)))
+            .count();
+        prop_assert!(output.chars().count() >= passthrough_count);
+        prop_assert!(output.chars().count() <= inputs.len());
+    }
+}
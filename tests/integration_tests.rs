@@ -1,6 +1,8 @@
 //! 통합 테스트 - Phase 1 핵심 변환 로직
 
 use koing::convert;
+use koing::core::jamo_mapper::is_vowel;
+use koing::{korean_to_eng, AutoDetector, KoreanValidator, RejectReason};
 
 #[test]
 fn test_basic_jamo_composition() {
@@ -58,6 +60,9 @@ fn test_consonant_only() {
 fn test_vowel_only() {
     assert_eq!(convert("k"), "ㅏ");
     assert_eq!(convert("kh"), "ㅏㅗ");
+    // 복합 모음 순서여도 초성이 없으면 조합하지 않음
+    assert_eq!(convert("hk"), "ㅗㅏ");
+    assert_eq!(convert("hl"), "ㅗㅣ");
 }
 
 #[test]
@@ -78,3 +83,38 @@ fn test_various_words() {
     assert_eq!(convert("zjavbxj"), "컴퓨터"); // 컴퓨터 = zjavbxj (ㅋㅓㅁㅍㅠㅌㅓ)
     assert_eq!(convert("vmfhrmfoa"), "프로그램"); // 프로그램 = vmfhrmfoa (ㅍㅡㄹㅗㄱㅡㄹㅐㅁ)
 }
+
+#[test]
+fn test_shift_vowel_conversion() {
+    // ㅒ = Shift+o, ㅖ = Shift+p
+    assert_eq!(convert("rO"), "걔");
+    assert_eq!(convert("dO"), "얘");
+    assert_eq!(convert("dP"), "예");
+    assert_eq!(convert("dOrl"), "얘기");
+    assert_eq!(convert("rPtks"), "계산");
+    assert_eq!(convert("tPrP"), "셰계");
+}
+
+#[test]
+fn test_shift_vowel_keys_recognized_in_realtime_path() {
+    assert!(is_vowel('O'));
+    assert!(is_vowel('P'));
+
+    let detector = AutoDetector::default();
+    assert!(detector.should_convert_realtime("rPtks")); // 계산
+    assert!(detector.should_convert_realtime("rOsms")); // 걔는
+    assert!(detector.should_convert("dOrl")); // 얘기
+}
+
+#[test]
+fn test_engine_api_without_platform() {
+    // 엔진 전용 빌드(--no-default-features --features engine-only)에서도 쓰는 공개 API
+    let validator = KoreanValidator::new();
+    assert_eq!(validator.classify("dkssud"), Ok("안녕".to_string()));
+    assert_eq!(
+        validator.classify("name"),
+        Err(RejectReason::IncompleteJamo)
+    );
+    assert_eq!(korean_to_eng(&convert("gksrmf")), "gksrmf");
+    assert!(!AutoDetector::default().should_convert("hello"));
+}
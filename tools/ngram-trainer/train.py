@@ -8,11 +8,13 @@ ngram_model.json 파일을 생성합니다.
 사용법:
     python train.py ./corpus.txt -o ./ngram_model.json --min-freq 5
     python train.py --generate-sample -o ./ngram_model.json
+    python train.py ./corpus.txt -o ./ngram_model.bin --binary
 """
 
 import argparse
 import json
 import re
+import struct
 import sys
 from collections import Counter
 from pathlib import Path
@@ -22,6 +24,11 @@ from pathlib import Path
 HANGUL_START = 0xAC00  # '가'
 HANGUL_END = 0xD7A3    # '힣'
 
+# 모델 메타데이터 (Rust NgramModel이 vocab_size 불일치 검사에 사용)
+VOCAB_SIZE = HANGUL_END - HANGUL_START + 1  # 11,172
+LAYOUT = "dubeolsik"
+MODEL_VERSION = 1
+
 
 def is_hangul_syllable(char: str) -> bool:
     """완성형 한글인지 확인"""
@@ -121,6 +128,9 @@ def generate_sample_model() -> dict:
 
     return {
         "metadata": {
+            "vocab_size": VOCAB_SIZE,
+            "layout": LAYOUT,
+            "model_version": MODEL_VERSION,
             "corpus_size": corpus_size,
             "unique_unigrams": len(unigrams),
             "unique_bigrams": len(bigrams),
@@ -165,6 +175,9 @@ def train_from_corpus(corpus_path: Path, min_freq: int = 5) -> dict:
 
     return {
         "metadata": {
+            "vocab_size": VOCAB_SIZE,
+            "layout": LAYOUT,
+            "model_version": MODEL_VERSION,
             "corpus_size": corpus_size,
             "unique_unigrams": len(unigrams),
             "unique_bigrams": len(bigrams),
@@ -187,6 +200,37 @@ def save_model(model: dict, output_path: Path) -> None:
     print(f"파일 크기: {output_path.stat().st_size:,} bytes")
 
 
+def save_binary_model(model: dict, output_path: Path) -> None:
+    """모델을 정렬된 키 배열 바이너리 포맷으로 저장 (Rust NgramModel::load_binary 호환)
+
+    헤더: b"KNGM" | 버전 u32 | 유니그램 수 u32 | 바이그램 수 u32 | 유니그램 총 빈도 u64 | 메타데이터 길이 u32
+    메타데이터: vocab_size/layout/model_version JSON 객체 (UTF-8)
+    엔트리: 키 u64 | 빈도 u64 (키 오름차순, 리틀 엔디언)
+    """
+    output_path.parent.mkdir(parents=True, exist_ok=True)
+
+    unigrams = sorted((ord(k), v) for k, v in model['unigrams'].items())
+    bigrams = sorted(
+        ((ord(k[0]) << 32) | ord(k[2]), v) for k, v in model['bigrams'].items()
+    )
+    total = sum(v for _, v in unigrams)
+    metadata = {
+        k: v for k, v in model.get('metadata', {}).items()
+        if k in ('vocab_size', 'layout', 'model_version')
+    }
+    metadata_bytes = json.dumps(metadata, ensure_ascii=False).encode('utf-8')
+
+    with open(output_path, 'wb') as f:
+        f.write(b"KNGM")
+        f.write(struct.pack('<IIIQI', 2, len(unigrams), len(bigrams), total, len(metadata_bytes)))
+        f.write(metadata_bytes)
+        for key, count in unigrams + bigrams:
+            f.write(struct.pack('<QQ', key, count))
+
+    print(f"바이너리 모델 저장 완료: {output_path}")
+    print(f"파일 크기: {output_path.stat().st_size:,} bytes")
+
+
 def main():
     parser = argparse.ArgumentParser(
         description="한국어 N-gram 모델 학습 스크립트",
@@ -198,6 +242,9 @@ def main():
 
     # 샘플 모델 생성 (테스트용)
     python train.py --generate-sample -o ./ngram_model.json
+
+    # mmap용 바이너리 모델 생성
+    python train.py ./corpus.txt -o ./ngram_model.bin --binary
         """
     )
 
@@ -228,6 +275,12 @@ def main():
         help="테스트용 샘플 모델 생성"
     )
 
+    parser.add_argument(
+        "--binary",
+        action="store_true",
+        help="정렬된 키 배열 바이너리 포맷으로 저장 (대형 모델용)"
+    )
+
     args = parser.parse_args()
 
     if args.generate_sample:
@@ -239,7 +292,10 @@ def main():
         parser.error("코퍼스 파일 경로 또는 --generate-sample 옵션이 필요합니다")
         return
 
-    save_model(model, args.output)
+    if args.binary:
+        save_binary_model(model, args.output)
+    else:
+        save_model(model, args.output)
 
     # 통계 출력
     print("\n=== 모델 통계 ===")